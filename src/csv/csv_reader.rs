@@ -1,23 +1,36 @@
-
-#[cfg(target_arch = "x86_64")]
-use crate::helpers::bytes_helper::locate_line_break_avx2;
-#[cfg(target_arch = "aarch64")]
-use crate::helpers::bytes_helper::locate_line_break_neon;
-use crate::helpers::bytes_helper::locate_line_break_memchr3;
+use crate::csv::sniffer::{sniff, Dialect};
+use crate::decoders::decoders::Encoding;
+use crate::helpers::bytes_helper::next_record;
+use crate::models::cancel_token::{CancelToken, ScanOutcome};
 use crate::models::csv_config::CsvConfig;
-use crate::models::csv_error::CsvError;
+use crate::models::csv_error::{CsvError, CsvErrorKind};
 use crate::models::platform_info::PlatformInfo;
+use crate::models::progress::{Progress, PROGRESS_INTERVAL};
 use memmap2::Mmap;
 use std::fs::File;
+use std::ops::{ControlFlow, Deref};
 use std::path::Path;
-use crate::models::row::Row;
+use std::sync::Arc;
+use crate::models::header::Header;
+use crate::models::row::{OffsetsPool, Row};
 #[derive(Debug)]
 #[repr(C)]
 pub struct CsvReaderWithMap {
     config: CsvConfig,
-    mmap: Mmap,
+    mmap: Source,
     platform: PlatformInfo,
     cursor: usize,
+    header: Option<Arc<Header>>,
+    data_start: usize,
+    data_record: usize,
+    record: usize,
+    progress: Option<Progress>,
+    progress_cursor: usize,
+    progress_record: usize,
+    /// Field offsets buffer of the rows built by `for_each_until`.
+    offsets: Vec<(usize, usize)>,
+    /// Field offsets buffer of the rows returned by `next_raw` and `peek_raw`.
+    offsets_pool: Arc<OffsetsPool>,
 }
 
 impl CsvReaderWithMap {
@@ -29,6 +42,7 @@ impl CsvReaderWithMap {
 
     /// ## Get Slice
     /// - Extracts the slice reference
+    /// - For UTF-16 files, the slice is the file transcoded to UTF-8.
     pub fn get_slice(&self) -> &[u8] {
         &self.mmap[..]
     }
@@ -36,74 +50,102 @@ impl CsvReaderWithMap {
     /// ## Open
     /// - Sync execution.
     /// - Open a CSV file and create a memory-mapped reader.
+    /// - A leading BOM is skipped. With `detect_encoding`, the encoding is detected from the first 64KB.
+    /// - UTF-16 files (`Encoding::Utf16Le` / `Utf16Be`, or a UTF-16 BOM) are transcoded into memory, and
+    ///   `get_config().encoding` becomes `Utf8`, the encoding to decode the fields with.
     pub fn open<P: AsRef<Path>>(path: P, config: &CsvConfig) -> Result<CsvReaderWithMap, CsvError> {
-        let file = File::open(path).map_err(|err| {
-            CsvError::FileError(format!("Cannot open file. Detail: {}", err))
-        })?;
+        Ok(Self::from_mmap(map_file(path)?, config))
+    }
 
-        let mmap = unsafe {
-            Mmap::map(&file).map_err(|bad| {
-                CsvError::FileError(format!("Cannot map file. Detail: {}", bad))
-            })?
-        };
+    /// ## Open Sniffed
+    /// - Sync execution.
+    /// - Open a CSV file, detecting the delimiter, quote, line break and header from its first 64KB.
+    /// - Returns the reader, and the detected `Dialect` with its confidence score.
+    pub fn open_sniffed<P: AsRef<Path>>(path: P) -> Result<(CsvReaderWithMap, Dialect), CsvError> {
+        let mmap = map_file(path)?;
+        let dialect = sniff(&mmap);
+        Ok((Self::from_mmap(mmap, &dialect.config), dialect))
+    }
 
-        Ok(CsvReaderWithMap {
-            config: config.clone(),
+    fn from_mmap(mmap: Mmap, config: &CsvConfig) -> CsvReaderWithMap {
+        let mut config = config.clone();
+        // A leading BOM is not part of the first field
+        let bom = config.prepare_input(&mmap);
+        let (mmap, start) = if config.encoding.is_utf16() {
+            // The splitters work on bytes, so UTF-16 is transcoded once, and fields are read as UTF-8
+            let text = config.encoding.decode(&mmap[bom..]).into_owned();
+            config.encoding = Encoding::Utf8;
+            (Source::Transcoded(text.into_bytes()), 0)
+        } else {
+            (Source::Mapped(mmap), bom)
+        };
+        let mut reader = CsvReaderWithMap {
+            config,
             platform: PlatformInfo::new(),
             mmap,
-            cursor: 0,
-        })
+            cursor: start,
+            header: None,
+            data_start: start,
+            data_record: 0,
+            record: 0,
+            progress: None,
+            progress_cursor: start,
+            progress_record: 0,
+            offsets: Vec::new(),
+            offsets_pool: Arc::default(),
+        };
+        reader.read_header();
+        reader
+    }
+
+    /// ## Get Header
+    /// - Extracts the header reference.
+    /// - If `has_header` is `false`, names are generated (`column_0`, `column_1`, ...).
+    /// - Returns `None` on empty files.
+    pub fn get_header(&self) -> Option<&Header> {
+        self.header.as_deref()
+    }
+
+    /// ## Get Data Slice
+    /// - Extracts the slice reference, without the header record.
+    /// - Use it with the parallel functions.
+    pub fn get_data_slice(&self) -> &[u8] {
+        &self.mmap[self.data_start..]
+    }
+
+    /// ## Get Data Origin
+    /// - Gets the position of the data slice: the offset of its first byte, and the record number of its first record.
+    /// - Use it with `ParallelConfig::with_origin`, so the parallel rows report their position in the file.
+    pub fn get_data_origin(&self) -> (usize, usize) {
+        (self.data_start, self.data_record + 1)
+    }
+
+    /// ## With Progress
+    /// - Publishes the bytes (from the first data record) and the records read by `next_raw` on `progress`, every
+    ///   `PROGRESS_INTERVAL` bytes and at the end of the file. Each pass from the first record starts it again.
+    pub fn with_progress(mut self, progress: &Progress) -> Self {
+        progress.start(self.mmap.len() - self.data_start, 1);
+        self.progress = Some(progress.clone());
+        self.progress_cursor = self.cursor;
+        self.progress_record = self.record;
+        self
     }
 
     /// ## Next Raw
     /// - Sync execution.
     /// - Returns the next row of data from the CSV file as a slice of bytes.
+    /// - Comment lines (and empty records, if `skip_empty_records` is set) are skipped.
+    /// - A last record without line break is returned. At the end of the file returns `None`, and goes back to the first record.
     pub fn next_raw(&mut self) -> Option<Row<'_>> {
-        let string_separator = self.config.string_separator;
-        let delimiter = self.config.delimiter;
-        let fm = self.config.force_memcach3;
-        let slice = if &self.config.force_memcach3 == &true {
-            self.next_raw_memchr3()
-        } else {
-            #[cfg(target_arch = "x86_64")]
-            {
-                if is_x86_feature_detected!("avx2") {
-                    unsafe { self.new_raw_avx2() }
-                } else {
-                    self.next_raw_memchr3()
-                }
-            }
-            #[cfg(target_arch = "aarch64")]
-            {
-                self.new_raw_neon()
-            }
-        }?;
-        Some(Row::new(slice, delimiter, string_separator, fm))
+        let (start, end) = self.next_bounds()?;
+        Some(self.build_row(start, end, self.record).with_offsets_pool(&self.offsets_pool))
     }
 
     /// ## Peek Raw
     /// - Returns next line without moving th cursor
     pub fn peek_raw(&self) -> Option<Row<'_>> {
-        let string_separator = self.config.string_separator;
-        let delimiter = self.config.delimiter;
-        let fm = self.config.force_memcach3;
-        let slice = if fm {
-            Self::peek_raw_memchr3(&self.mmap, self.cursor, self.config.line_break)
-        } else {
-            #[cfg(target_arch = "x86_64")]
-            {
-                if is_x86_feature_detected!("avx2") {
-                    unsafe { Self::peek_raw_avx2(&self.mmap, self.cursor, self.config.line_break) }
-                } else {
-                    Self::peek_raw_memchr3(&self.mmap, self.cursor, self.config.line_break)
-                }
-            }
-            #[cfg(target_arch = "aarch64")]
-            {
-                Self::peek_raw_neon(&self.mmap, self.cursor, self.config.line_break)
-            }
-        }?;
-        Some(Row::new(slice, delimiter, string_separator, fm))
+        let bounds = next_record(&self.mmap, self.cursor, &self.config)?;
+        Some(self.build_row(bounds.start, bounds.end, self.record + bounds.records).with_offsets_pool(&self.offsets_pool))
     }
 
     /// ## Advance Next
@@ -112,136 +154,149 @@ impl CsvReaderWithMap {
         let _ = self.next_raw();
     }
 
-    //--------------------- INTERNAL ---------------------------------------------------------------//
-    #[cfg(target_arch = "aarch64")]
-    pub(crate) fn new_raw_neon(&mut self) -> Option<&[u8]> {
-        unsafe {
-            let slice = &self.mmap[self.cursor..];
-            match locate_line_break_neon(slice, self.config.line_break) {
-                0 => {
-                    self.reset_cursor();
-                    None
-                }
-                sep_index => {
-                    let row = &self.mmap[self.cursor..self.cursor + sep_index];
-                    let end = if row.ends_with(b"\r\n") {
-                        2
-                    } else if row.ends_with(&[b'\n']) || row.ends_with(&[b'\r']) {
-                        1
-                    } else {
-                        0
-                    };
-                    let row = &row[..row.len() - end];
-                    self.cursor += sep_index;
-                    Some(row)
-                }
+    /// ## For Each Until
+    /// - Calls `func` with every remaining row, until it returns `ControlFlow::Break(())` or `cancel` is cancelled.
+    /// - Returns `ScanOutcome::Completed` at the end of the file (the cursor goes back to the first record), or
+    ///   `ScanOutcome::Cancelled`. After a cancellation, `next_raw` continues after the last processed row.
+    pub fn for_each_until<F>(&mut self, cancel: &CancelToken, mut func: F) -> ScanOutcome
+    where
+        F: FnMut(&Row<'_>) -> ControlFlow<()>,
+    {
+        let mut offsets = std::mem::take(&mut self.offsets);
+        let outcome = loop {
+            if cancel.is_cancelled() {
+                break ScanOutcome::Cancelled;
+            }
+            let (start, end) = match self.next_bounds() {
+                Some(bounds) => bounds,
+                None => break ScanOutcome::Completed,
+            };
+            let mut row = self.build_row(start, end, self.record).with_offsets_buffer(offsets);
+            let flow = func(&row);
+            offsets = row.take_offsets_buffer();
+            if flow.is_break() {
+                break ScanOutcome::Cancelled;
             }
-        }
-    }
-
-    #[cfg(target_arch = "x86_64")]
-    #[target_feature(enable = "avx2")]
-    pub(crate) unsafe fn new_raw_avx2(&mut self) -> Option<&[u8]> { unsafe {
-        let slice = &self.mmap[self.cursor..];
-        let sep_index = locate_line_break_avx2(slice, self.config.line_break);
-
-        if sep_index == 0 {
-            self.reset_cursor();
-            return None;
-        }
-
-        let full_row = &self.mmap[self.cursor..self.cursor + sep_index];
-        let trim_len = if full_row.ends_with(b"\r\n") {
-            2
-        } else if full_row.ends_with(&[b'\r']) || full_row.ends_with(&[b'\n']) {
-            1
-        } else {
-            0
         };
-
-        let valid_len = full_row.len().saturating_sub(trim_len);
-        let row = &full_row[..valid_len];
-        self.cursor += sep_index;
-        Some(row)
-        }
+        self.offsets = offsets;
+        outcome
     }
 
-    pub(crate) fn next_raw_memchr3(&mut self) -> Option<&[u8]> {
-        let slice = &self.mmap[self.cursor..];
-        match locate_line_break_memchr3(slice, self.cursor, self.config.line_break) {
-            0 => {
+    //--------------------- INTERNAL ---------------------------------------------------------------//
+    /// Moves the cursor to the next record, and returns its bounds. At the end of the file, goes back to the first
+    /// record and returns `None`.
+    fn next_bounds(&mut self) -> Option<(usize, usize)> {
+        let bounds = match next_record(&self.mmap, self.cursor, &self.config) {
+            Some(bounds) => bounds,
+            None => {
+                self.report_progress(true);
                 self.reset_cursor();
-                None
+                return None;
             }
-            i => {
-                let row = &self.mmap[self.cursor..i];
-                self.cursor = i;
-                Some(row)
-            }
-        }
+        };
+        self.report_progress(false);
+        self.cursor = bounds.next;
+        self.record += bounds.records;
+        Some((bounds.start, bounds.end))
     }
 
+    fn build_row(&self, start: usize, end: usize, record: usize) -> Row<'_> {
+        Row::new(&self.mmap[start..end], self.config.field_delimiter(), self.config.string_separator, self.config.force_memcach3)
+            .with_header(self.header.clone())
+            .with_record(record)
+            .with_offset(start)
+            .with_trim(self.config.trim)
+    }
 
-    #[cfg(target_arch = "x86_64")]
-    #[target_feature(enable = "avx2")]
-    unsafe fn peek_raw_avx2(mmap: &[u8], cursor: usize, line_break: u8) -> Option<&[u8]> {
-        let slice = &mmap[cursor..];
-        let sep_index = locate_line_break_avx2(slice, line_break);
-
-        if sep_index == 0 {
-            return None;
+    /// Publishes the bytes and records read since the last update, once `PROGRESS_INTERVAL` bytes are read, or at
+    /// the end of the file. Starts the counters again on the first record.
+    fn report_progress(&mut self, end: bool) {
+        let progress = match &self.progress {
+            Some(progress) => progress,
+            None => return,
+        };
+        if self.cursor == self.data_start && !end {
+            progress.start(self.mmap.len() - self.data_start, 1);
+            return;
+        }
+        let cursor = if end { self.mmap.len() } else { self.cursor };
+        let bytes = cursor - self.progress_cursor;
+        if end || bytes >= PROGRESS_INTERVAL {
+            progress.add(0, bytes, self.record - self.progress_record);
+            self.progress_cursor = cursor;
+            self.progress_record = self.record;
+        }
+        if end {
+            progress.finish();
         }
+    }
 
-        let full_row = &mmap[cursor..cursor + sep_index];
-        let trim_len = if full_row.ends_with(b"\r\n") {
-            2
-        } else if full_row.ends_with(&[b'\r']) || full_row.ends_with(&[b'\n']) {
-            1
-        } else {
-            0
+    /// Parses the first record as header, or generates the column names.
+    fn read_header(&mut self) {
+        let has_header = self.config.has_header;
+        let encoding = self.config.encoding;
+        let header = match self.next_raw() {
+            Some(row) if has_header => Header::from_row(&row, encoding),
+            Some(row) => Header::generated(row.field_count()),
+            None => return,
         };
-        Some(&full_row[..full_row.len().saturating_sub(trim_len)])
+        if has_header {
+            self.data_start = self.cursor;
+            self.data_record = self.record;
+        }
+        self.reset_cursor();
+        self.header = Some(Arc::new(header));
     }
 
-    #[cfg(target_arch = "aarch64")]
-    fn peek_raw_neon(mmap: &[u8], cursor: usize, line_break: u8) -> Option<&[u8]> {
-        unsafe {
-            let slice = &mmap[cursor..];
-            match locate_line_break_neon(slice, line_break) {
-                0 => None,
-                sep_index => {
-                    let row = &mmap[cursor..cursor + sep_index];
-                    let end = if row.ends_with(b"\r\n") {
-                        2
-                    } else if row.ends_with(&[b'\n']) || row.ends_with(&[b'\r']) {
-                        1
-                    } else {
-                        0
-                    };
-                    Some(&row[..row.len() - end])
-                }
-            }
-        }
+    /// Moves the cursor back to the first record after the header.
+    pub(crate) fn reset_cursor(&mut self) {
+        self.cursor = self.data_start;
+        self.record = self.data_record;
+        self.progress_cursor = self.data_start;
+        self.progress_record = self.data_record;
     }
+}
+
+/// Bytes read by `CsvReaderWithMap`: the mapped file, or the file transcoded to UTF-8.
+#[derive(Debug)]
+enum Source {
+    Mapped(Mmap),
+    Transcoded(Vec<u8>),
+}
 
-    fn peek_raw_memchr3(mmap: &[u8], cursor: usize, line_break: u8) -> Option<&[u8]> {
-        let slice = &mmap[cursor..];
-        match locate_line_break_memchr3(slice, cursor, line_break) {
-            0 => None,
-            i => Some(&mmap[cursor..i]),
+impl Deref for Source {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        match self {
+            Source::Mapped(mmap) => mmap,
+            Source::Transcoded(bytes) => bytes,
         }
     }
+}
 
+/// ## Map File
+/// - Opens a file and maps it into memory.
+pub(crate) fn map_file<P: AsRef<Path>>(path: P) -> Result<Mmap, CsvError> {
+    let path = path.as_ref();
+    // The cause is chained as source, not repeated in the message
+    let file = File::open(path).map_err(|err| {
+        CsvError::new(CsvErrorKind::File, format!("cannot open file {:?}", path)).with_source(err)
+    })?;
 
-    pub(crate) fn reset_cursor(&mut self) {
-        self.cursor = 0;
+    unsafe {
+        Mmap::map(&file).map_err(|bad| {
+            CsvError::new(CsvErrorKind::File, format!("cannot map file {:?}", path)).with_source(bad)
+        })
     }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::csv::csv_reader::CsvReaderWithMap;
+    use crate::decoders::decoders::Encoding;
     use crate::models::csv_config::CsvConfig;
+    use crate::models::progress::{Progress, PROGRESS_INTERVAL};
     use std::time::Instant;
 
     #[test]
@@ -259,7 +314,10 @@ mod tests {
         let time = Instant::now();
         let file = CsvReaderWithMap::open("no_existo.csv", &cfg);
         println!("Performed in :{:?}", time.elapsed());
-        assert!(file.is_err());
+        let err = file.unwrap_err();
+        // The cause is the source, not part of the message
+        assert_eq!(err.to_string(), "File error: cannot open file \"no_existo.csv\"");
+        assert!(std::error::Error::source(&err).is_some());
     }
     #[test]
     fn test_file_raw() {
@@ -283,4 +341,86 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_open_sniffed() {
+        let path = std::env::temp_dir().join("csv_lib_test_open_sniffed.csv");
+        std::fs::write(&path, "id\tcity\n1\tParis\n2\tLima\n").expect("write");
+        let (mut reader, dialect) = CsvReaderWithMap::open_sniffed(&path).expect("open");
+        assert_eq!(dialect.config.delimiter, b'\t');
+        assert!(dialect.config.has_header);
+        let row = reader.next_raw().expect("row");
+        assert_eq!(row.get_by_name("city").get_utf8_as_str(), "Paris");
+        drop(reader);
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_open_utf16() {
+        let text = "\u{FEFF}id\tname\r\n1\t\"José, \"\"Pepe\"\"\"\r\n2\t日本\r\n";
+        for (i, encoding) in [Encoding::Utf16Le, Encoding::Utf16Be].iter().enumerate() {
+            let path = std::env::temp_dir().join(format!("csv_lib_test_open_utf16_{}.csv", i));
+            std::fs::write(&path, encoding.encode(text)).expect("write");
+            // The BOM selects the encoding
+            let cfg = CsvConfig {
+                delimiter: b'\t',
+                string_separator: b'"',
+                has_header: true,
+                ..CsvConfig::default()
+            };
+            let mut reader = CsvReaderWithMap::open(&path, &cfg).expect("open");
+            assert_eq!(reader.get_config().encoding, Encoding::Utf8);
+            assert_eq!(reader.get_header().expect("header").names(), &["id", "name"]);
+            let enc = reader.get_config().encoding;
+            let row = reader.next_raw().expect("row");
+            assert_eq!(row.get_by_name("id").get_i32(), 1);
+            assert_eq!(row.get_by_name("name").get_as_string(enc), "José, \"Pepe\"");
+            let row = reader.next_raw().expect("row");
+            assert_eq!(row.get_by_name("name").get_utf8_as_str(), "日本");
+            assert!(reader.next_raw().is_none());
+
+            let (_, dialect) = CsvReaderWithMap::open_sniffed(&path).expect("open");
+            assert_eq!(dialect.config.encoding, *encoding);
+            assert_eq!(dialect.config.delimiter, b'\t');
+            assert!(dialect.config.has_header);
+            let _ = std::fs::remove_file(&path);
+        }
+    }
+
+    #[test]
+    fn test_progress() {
+        let mut text = String::from("id,flag\n");
+        for i in 0..300_000 {
+            text.push_str(&format!("{:06},x\n", i));
+        }
+        let path = std::env::temp_dir().join("csv_lib_test_progress.csv");
+        std::fs::write(&path, &text).expect("write");
+        let cfg = CsvConfig {
+            delimiter: b',',
+            has_header: true,
+            ..CsvConfig::default()
+        };
+        let progress = Progress::new();
+        let mut reader = CsvReaderWithMap::open(&path, &cfg).expect("open").with_progress(&progress);
+        let total = text.len() - 8;
+        assert_eq!(progress.total_bytes(), total);
+        let (mut records, mut updates, mut last) = (0, 0, 0);
+        while reader.next_raw().is_some() {
+            records += 1;
+            if progress.bytes() != last {
+                // Published every PROGRESS_INTERVAL bytes, never ahead of the rows returned
+                assert!(progress.bytes() - last >= PROGRESS_INTERVAL);
+                assert!(progress.records() <= records);
+                last = progress.bytes();
+                updates += 1;
+            }
+        }
+        assert_eq!(updates, 2);
+        assert_eq!((progress.bytes(), progress.records(), records), (total, 300_000, 300_000));
+        assert!(progress.is_finished());
+        // A new pass starts the counters again
+        reader.advance_next();
+        assert_eq!((progress.bytes(), progress.is_finished()), (0, false));
+        let _ = std::fs::remove_file(&path);
+    }
 }
@@ -1,5 +1,16 @@
+use std::fmt::Display;
 use std::io::{BufWriter, IntoInnerError, Write};
+use memchr::{memchr, memchr_iter};
 use crate::decoders::decoders::Encoding;
+use crate::helpers::bytes_helper::needs_quotes;
+use crate::io::number_formatter::{format_i64, format_u64, INTEGER_BUFFER_SIZE};
+use crate::models::quote_style::QuoteStyle;
+#[cfg(feature = "serde")]
+use crate::features::serialize::write_record;
+#[cfg(feature = "serde")]
+use crate::models::csv_error::CsvError;
+#[cfg(feature = "serde")]
+use serde::Serialize;
 
 /// A fast and configurable CSV writer with optional encoding support.
 ///
@@ -9,6 +20,18 @@ pub struct CsvWriter<'a, W: Write> {
     delimiter: u8,
     line_break: &'a [u8],
     encoder: Encoding,
+    string_separator: u8,
+    quote_style: QuoteStyle,
+    /// `true` until the first row is written, if the automatic header is enabled.
+    #[cfg_attr(not(feature = "serde"), allow(dead_code))]
+    header_pending: bool,
+    /// Decimals written by `push_f64`. `None` writes the shortest exact representation.
+    float_precision: Option<usize>,
+    /// Fields pushed to the current record.
+    record_fields: usize,
+    /// Record serialized by `serialize`, copied to the output once complete.
+    #[cfg_attr(not(feature = "serde"), allow(dead_code))]
+    record_buffer: Vec<u8>,
 }
 
 impl<'a, W: Write> CsvWriter<'a, W> {
@@ -26,22 +49,100 @@ impl<'a, W: Write> CsvWriter<'a, W> {
             delimiter,
             line_break,
             encoder: encoding,
+            string_separator: b'"',
+            quote_style: QuoteStyle::Necessary,
+            header_pending: true,
+            float_precision: None,
+            record_fields: 0,
+            record_buffer: Vec::new(),
         }
     }
 
+    /// Sets the byte used to quote fields (default `"`). Use `0u8` to disable quoting.
+    pub fn with_string_separator(mut self, string_separator: u8) -> Self {
+        self.string_separator = string_separator;
+        self
+    }
+
+    /// Sets which fields are quoted (default `QuoteStyle::Necessary`).
+    ///
+    /// Quoted fields have their string separators doubled, as RFC 4180 requires.
+    pub fn with_quote_style(mut self, quote_style: QuoteStyle) -> Self {
+        self.quote_style = quote_style;
+        self
+    }
+
+    /// Sets the decimals written by `push_f64` (by default, the shortest representation that reads back the same value).
+    pub fn with_float_precision(mut self, precision: usize) -> Self {
+        self.float_precision = Some(precision);
+        self
+    }
+
+    /// Enables or disables the header written by `serialize` before the first record (enabled by default).
+    ///
+    /// The header is only written if nothing was written before, and the record is a struct.
+    pub fn with_header(mut self, header: bool) -> Self {
+        self.header_pending = header;
+        self
+    }
+
+    /// Writes any `Serialize` value as a row. Needs the `serde` feature.
+    ///
+    /// # Arguments
+    ///
+    /// * `record` - A struct, tuple, sequence or map of scalar values. Map keys are ignored.
+    ///
+    /// On the first row of a struct, writes a header with the field names. Text is encoded with
+    /// the writer's encoding, and fields holding the delimiter, the string separator or a line break are quoted.
+    /// `None` and unit values are written as empty fields. Nested structs and sequences are not supported.
+    /// A record that fails to serialize writes nothing.
+    #[cfg(feature = "serde")]
+    pub fn serialize<T: Serialize + ?Sized>(&mut self, record: &T) -> Result<(), CsvError> {
+        let format = self.field_format();
+        // The record is serialized aside, and copied to the output only if it is complete.
+        // The field names for the header are only known after serializing the first record.
+        let mut names = Vec::new();
+        let mut buffer = std::mem::take(&mut self.record_buffer);
+        buffer.clear();
+        let names_out = if self.header_pending { Some(&mut names) } else { None };
+        let result = write_record(&mut buffer, format, names_out, record)
+            .and_then(|()| self.write_serialized(format, &names, &buffer));
+        self.record_buffer = buffer;
+        result
+    }
+
+    /// Writes a record serialized by `serialize`, after the header if it is still pending.
+    #[cfg(feature = "serde")]
+    fn write_serialized(&mut self, format: FieldFormat, names: &[&str], record: &[u8]) -> Result<(), CsvError> {
+        if self.header_pending {
+            self.header_pending = false;
+            if !names.is_empty() {
+                for (i, name) in names.iter().enumerate() {
+                    format.write_separator(&mut self.writer, i)?;
+                    format.write_str(&mut self.writer, name)?;
+                }
+                self.writer.write_all(self.line_break)?;
+            }
+        }
+        self.writer.write_all(record)?;
+        self.writer.write_all(self.line_break)?;
+        Ok(())
+    }
+
     /// Writes a row of raw byte fields to the output, without encoding.
     ///
     /// # Arguments
     ///
     /// * `fields` - A slice of field byte slices (`&[u8]`).
     ///
-    /// Fields are separated by the configured delimiter and the row is terminated by the line break.
+    /// Fields are separated by the configured delimiter, quoted according to the quote style,
+    /// and the row is terminated by the line break.
     pub fn write_row(&mut self, fields: &[&[u8]]) -> std::io::Result<()> {
+        self.header_pending = false;
+        let format = self.field_format();
         for (i, field) in fields.iter().enumerate() {
-            if i > 0 {
-                self.writer.write_all(&[self.delimiter])?;
-            }
-            self.writer.write_all(field)?;
+            format.write_separator(&mut self.writer, i)?;
+            format.write_bytes(&mut self.writer, field)?;
         }
         self.writer.write_all(self.line_break)?;
         Ok(())
@@ -54,13 +155,13 @@ impl<'a, W: Write> CsvWriter<'a, W> {
     /// * `fields` - A slice of field strings (`&str`).
     ///
     /// Each string is encoded before being written, allowing for custom character sets like Windows-1252.
+    /// Fields are quoted according to the quote style.
     pub fn write_row_encoded(&mut self, fields: &[&str]) -> std::io::Result<()> {
+        self.header_pending = false;
+        let format = self.field_format();
         for (i, field) in fields.iter().enumerate() {
-            if i > 0 {
-                self.writer.write_all(&[self.delimiter])?;
-            }
-            let encoded = self.encoder.encode(field);
-            self.writer.write_all(&encoded)?;
+            format.write_separator(&mut self.writer, i)?;
+            format.write_str(&mut self.writer, field)?;
         }
         self.writer.write_all(self.line_break)?;
         Ok(())
@@ -73,12 +174,16 @@ impl<'a, W: Write> CsvWriter<'a, W> {
     /// * `fields` - A slice of field strings (`&str`).
     ///
     /// Use this if you are certain that the strings are already valid UTF-8 and no transformation is needed.
+    /// Fields are quoted according to the quote style. UTF-16 writers encode the fields, like `write_row_encoded`.
     pub fn write_row_fast(&mut self, fields: &[&str]) -> std::io::Result<()> {
+        if self.encoder.is_utf16() {
+            return self.write_row_encoded(fields);
+        }
+        self.header_pending = false;
+        let format = self.field_format();
         for (i, field) in fields.iter().enumerate() {
-            if i > 0 {
-                self.writer.write_all(&[self.delimiter])?;
-            }
-            self.writer.write_all(field.as_bytes())?;
+            format.write_separator(&mut self.writer, i)?;
+            format.write_bytes(&mut self.writer, field.as_bytes())?;
         }
         self.writer.write_all(self.line_break)?;
         Ok(())
@@ -91,17 +196,44 @@ impl<'a, W: Write> CsvWriter<'a, W> {
     /// * `fields` - A slice of field byte slices (`&[u8]`).
     ///
     /// Uses an internal 4096-byte array to minimize syscalls, suitable for small-to-medium rows.
+    /// Bigger rows are written in parts. Fields are quoted according to the quote style.
+    /// On UTF-16 writers, the fields must be UTF-16 already, and the row is written by `write_row`.
     pub fn write_row_simd(&mut self, fields: &[&[u8]]) -> std::io::Result<()> {
+        if self.encoder.is_utf16() {
+            return self.write_row(fields);
+        }
+        self.header_pending = false;
+        let format = self.field_format();
         let mut tmp = [0u8; 4096];
         let mut cursor = 0usize;
 
         for (i, field) in fields.iter().enumerate() {
+            let quoted = format.should_quote(field);
+            // Delimiter, and every byte doubled plus the quotes in the worst case
+            let size = 1 + if quoted { field.len() * 2 + 2 } else { field.len() };
+            if cursor + size > tmp.len() {
+                self.writer.write_all(&tmp[..cursor])?;
+                cursor = 0;
+            }
             if i > 0 {
                 tmp[cursor] = self.delimiter;
                 cursor += 1;
             }
-            copy_bytes(&mut tmp[cursor..], field);
-            cursor += field.len();
+            if size > tmp.len() {
+                // Does not fit in the buffer, written directly
+                self.writer.write_all(&tmp[..cursor])?;
+                cursor = 0;
+                format.write_field(&mut self.writer, field, quoted)?;
+            } else if quoted {
+                cursor += copy_quoted(&mut tmp[cursor..], field, self.string_separator);
+            } else {
+                copy_bytes(&mut tmp[cursor..], field);
+                cursor += field.len();
+            }
+        }
+        if cursor + self.line_break.len() > tmp.len() {
+            self.writer.write_all(&tmp[..cursor])?;
+            cursor = 0;
         }
         copy_bytes(&mut tmp[cursor..], self.line_break);
         cursor += self.line_break.len();
@@ -109,6 +241,68 @@ impl<'a, W: Write> CsvWriter<'a, W> {
         self.writer.write_all(&tmp[..cursor])?;
         Ok(())
     }
+
+    /// Appends a signed integer to the current record, formatted directly into the buffer.
+    ///
+    /// The record builder (`push_*` functions) writes one field at a time. Finish each record with `end_record`.
+    ///
+    /// ### Code Example:
+    /// ```
+    /// use csv_lib::csv::csv_writer::CsvWriter;
+    /// use csv_lib::decoders::decoders::Encoding;
+    ///
+    /// let mut writer = CsvWriter::new(Vec::new(), b';', b"\n", Encoding::Utf8).with_float_precision(2);
+    /// writer.push_i64(-7).unwrap();
+    /// writer.push_f64(3.14159).unwrap();
+    /// writer.push_str("a;b").unwrap();
+    /// writer.push_bool(true).unwrap();
+    /// writer.end_record().unwrap();
+    /// assert_eq!(writer.flush_and_get().unwrap(), b"-7;3.14;\"a;b\";true\n");
+    /// ```
+    pub fn push_i64(&mut self, value: i64) -> std::io::Result<()> {
+        let mut buffer = [0u8; INTEGER_BUFFER_SIZE];
+        self.push_number(format_i64(&mut buffer, value))
+    }
+
+    /// Appends an unsigned integer to the current record, formatted directly into the buffer.
+    pub fn push_u64(&mut self, value: u64) -> std::io::Result<()> {
+        let mut buffer = [0u8; INTEGER_BUFFER_SIZE];
+        self.push_number(format_u64(&mut buffer, value))
+    }
+
+    /// Appends a float to the current record, with the precision set by `with_float_precision`.
+    pub fn push_f64(&mut self, value: f64) -> std::io::Result<()> {
+        self.begin_field()?;
+        let format = self.field_format();
+        match self.float_precision {
+            Some(precision) => format.write_number(&mut self.writer, format_args!("{:.*}", precision, value)),
+            None => format.write_number(&mut self.writer, value),
+        }
+    }
+
+    /// Appends `true` or `false` to the current record.
+    pub fn push_bool(&mut self, value: bool) -> std::io::Result<()> {
+        self.push_str(if value { "true" } else { "false" })
+    }
+
+    /// Appends a text field to the current record, encoded with the writer's encoding and quoted as needed.
+    pub fn push_str(&mut self, value: &str) -> std::io::Result<()> {
+        self.begin_field()?;
+        self.field_format().write_str(&mut self.writer, value)
+    }
+
+    /// Appends raw bytes to the current record, without encoding, quoted as needed.
+    pub fn push_bytes(&mut self, value: &[u8]) -> std::io::Result<()> {
+        self.begin_field()?;
+        self.field_format().write_bytes(&mut self.writer, value)
+    }
+
+    /// Finishes the current record, writing the line break.
+    pub fn end_record(&mut self) -> std::io::Result<()> {
+        self.record_fields = 0;
+        self.writer.write_all(self.line_break)
+    }
+
     pub fn flush_and_get(mut self) -> Result<W, IntoInnerError<BufWriter<W>>> {
         _ = self.flush();
         self.writer.into_inner()
@@ -118,6 +312,177 @@ impl<'a, W: Write> CsvWriter<'a, W> {
     pub fn flush(&mut self) -> std::io::Result<()> {
         self.writer.flush()
     }
+
+    /// Writes the delimiter if the record has fields already, and counts the new one.
+    #[inline(always)]
+    fn begin_field(&mut self) -> std::io::Result<()> {
+        self.header_pending = false;
+        self.field_format().write_separator(&mut self.writer, self.record_fields)?;
+        self.record_fields += 1;
+        Ok(())
+    }
+
+    /// Writes the digits of a formatted integer, only quoted by `QuoteStyle::Always`.
+    #[inline(always)]
+    fn push_number(&mut self, digits: &[u8]) -> std::io::Result<()> {
+        self.begin_field()?;
+        self.field_format().write_digits(&mut self.writer, digits)
+    }
+
+    fn field_format(&self) -> FieldFormat {
+        FieldFormat {
+            delimiter: self.delimiter,
+            string_separator: self.string_separator,
+            quote_style: self.quote_style,
+            encoding: self.encoder,
+        }
+    }
+}
+
+/// Settings used to write a single field.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct FieldFormat {
+    pub(crate) delimiter: u8,
+    pub(crate) string_separator: u8,
+    pub(crate) quote_style: QuoteStyle,
+    pub(crate) encoding: Encoding,
+}
+
+impl FieldFormat {
+    /// Writes the delimiter before every field, except the first one.
+    #[inline(always)]
+    pub(crate) fn write_separator<O: Write>(&self, out: &mut O, index: usize) -> std::io::Result<()> {
+        if index > 0 {
+            self.write_ascii(out, self.delimiter)?;
+        }
+        Ok(())
+    }
+
+    /// Encodes the text with the writer's encoding, and writes it quoted as needed.
+    /// - Quoting is decided, and string separators doubled, on the text: encoded bytes can look like a delimiter or a
+    ///   quote (`Ĭ` is `2C 01` in UTF-16LE).
+    pub(crate) fn write_str<O: Write>(&self, out: &mut O, text: &str) -> std::io::Result<()> {
+        if self.encoding == Encoding::Utf8 {
+            return self.write_bytes(out, text.as_bytes());
+        }
+        let quoted = self.should_quote(text.as_bytes());
+        if quoted && memchr(self.string_separator, text.as_bytes()).is_some() {
+            let quote = char::from(self.string_separator);
+            let escaped = text.replace(quote, &format!("{}{}", quote, quote));
+            return self.write_encoded(out, &self.encoding.encode(&escaped), quoted);
+        }
+        self.write_encoded(out, &self.encoding.encode(text), quoted)
+    }
+
+    /// Writes the bytes, quoted according to the quote style.
+    /// - On UTF-16 writers, the bytes must be UTF-16 already. They are decoded to be quoted like `write_str`.
+    #[inline(always)]
+    pub(crate) fn write_bytes<O: Write>(&self, out: &mut O, field: &[u8]) -> std::io::Result<()> {
+        if self.encoding.is_utf16() {
+            return self.write_str(out, &self.encoding.decode(field));
+        }
+        self.write_field(out, field, self.should_quote(field))
+    }
+
+    /// Writes a number, only quoted by `QuoteStyle::Always`.
+    /// - UTF-16 is not ASCII compatible, so the formatted number is encoded first.
+    pub(crate) fn write_number<O: Write, T: Display>(&self, out: &mut O, value: T) -> std::io::Result<()> {
+        let quoted = self.quotes_numbers();
+        if self.encoding.is_utf16() {
+            self.write_encoded(out, &self.encoding.encode(&value.to_string()), quoted)
+        } else if quoted {
+            write!(out, "{q}{}{q}", value, q = self.string_separator as char)
+        } else {
+            write!(out, "{}", value)
+        }
+    }
+
+    /// Writes the ASCII digits of a formatted number, only quoted by `QuoteStyle::Always`.
+    /// - UTF-16 is not ASCII compatible, so the digits are encoded first.
+    #[inline(always)]
+    pub(crate) fn write_digits<O: Write>(&self, out: &mut O, digits: &[u8]) -> std::io::Result<()> {
+        if self.encoding.is_utf16() {
+            // ASCII digits are valid UTF-8
+            let text = std::str::from_utf8(digits).unwrap_or_default();
+            return self.write_encoded(out, &self.encoding.encode(text), self.quotes_numbers());
+        }
+        self.write_field(out, digits, self.quotes_numbers())
+    }
+
+    /// Returns `true` if numbers are quoted: only by `QuoteStyle::Always`, with a string separator.
+    #[inline(always)]
+    fn quotes_numbers(&self) -> bool {
+        self.string_separator != 0 && self.quote_style == QuoteStyle::Always
+    }
+
+    /// Returns `true` if the field must be quoted. Always `false` without string separator.
+    #[inline(always)]
+    pub(crate) fn should_quote(&self, field: &[u8]) -> bool {
+        self.string_separator != 0
+            && self
+                .quote_style
+                .should_quote(field, || needs_quotes(field, self.delimiter, self.string_separator))
+    }
+
+    /// Writes encoded text, between encoded string separators if `quoted`. Embedded ones must be doubled already.
+    #[inline(always)]
+    fn write_encoded<O: Write>(&self, out: &mut O, field: &[u8], quoted: bool) -> std::io::Result<()> {
+        if quoted {
+            self.write_ascii(out, self.string_separator)?;
+        }
+        out.write_all(field)?;
+        if quoted {
+            self.write_ascii(out, self.string_separator)?;
+        }
+        Ok(())
+    }
+
+    /// Writes an ASCII delimiter or string separator, as a 16 bit unit on UTF-16 writers.
+    #[inline(always)]
+    fn write_ascii<O: Write>(&self, out: &mut O, byte: u8) -> std::io::Result<()> {
+        match self.encoding {
+            Encoding::Utf16Le => out.write_all(&[byte, 0]),
+            Encoding::Utf16Be => out.write_all(&[0, byte]),
+            _ => out.write_all(&[byte]),
+        }
+    }
+
+    /// Writes the bytes, between string separators if `quoted`, doubling the embedded ones.
+    pub(crate) fn write_field<O: Write>(&self, out: &mut O, field: &[u8], quoted: bool) -> std::io::Result<()> {
+        if !quoted {
+            return out.write_all(field);
+        }
+        let quote = self.string_separator;
+        out.write_all(&[quote])?;
+        let mut start = 0;
+        for pos in memchr_iter(quote, field) {
+            out.write_all(&field[start..=pos])?;
+            out.write_all(&[quote])?;
+            start = pos + 1;
+        }
+        out.write_all(&field[start..])?;
+        out.write_all(&[quote])
+    }
+}
+
+/// Copies `src` into `dest` between quotes, doubling the embedded ones. Returns the bytes written.
+/// `dest` must hold `src.len() * 2 + 2` bytes.
+#[inline(always)]
+fn copy_quoted(dest: &mut [u8], src: &[u8], quote: u8) -> usize {
+    dest[0] = quote;
+    let mut cursor = 1;
+    let mut start = 0;
+    for pos in memchr_iter(quote, src) {
+        copy_bytes(&mut dest[cursor..], &src[start..=pos]);
+        cursor += pos + 1 - start;
+        dest[cursor] = quote;
+        cursor += 1;
+        start = pos + 1;
+    }
+    copy_bytes(&mut dest[cursor..], &src[start..]);
+    cursor += src.len() - start;
+    dest[cursor] = quote;
+    cursor + 1
 }
 
 /// Copies the contents of `src` into `dest` without any SIMD or feature detection.
@@ -129,6 +494,7 @@ fn copy_bytes(dest: &mut [u8], src: &[u8]) {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::quote_style::QuoteStyle;
     use std::io::Cursor;
 
     fn as_utf8_str(bytes: &Cursor<Vec<u8>>) -> &str {
@@ -187,4 +553,105 @@ mod tests {
         let result = writer.writer.into_inner().expect("Failed to recover buffer");
         assert_eq!(as_utf8_str(&result), "\n");
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_write_quote_styles() {
+        let fields: Vec<&[u8]> = vec![b"plain", b"a,b", b"say \"hi\"", b"line\nbreak", b"-1.5", b""];
+        let expected = [
+            (QuoteStyle::Necessary, "plain,\"a,b\",\"say \"\"hi\"\"\",\"line\nbreak\",-1.5,\n"),
+            (QuoteStyle::Always, "\"plain\",\"a,b\",\"say \"\"hi\"\"\",\"line\nbreak\",\"-1.5\",\"\"\n"),
+            (QuoteStyle::NonNumeric, "\"plain\",\"a,b\",\"say \"\"hi\"\"\",\"line\nbreak\",-1.5,\"\"\n"),
+            (QuoteStyle::Never, "plain,a,b,say \"hi\",line\nbreak,-1.5,\n"),
+        ];
+        for (style, expected) in expected.iter() {
+            let mut writer = CsvWriter::new(Vec::new(), b',', b"\n", Encoding::Utf8).with_quote_style(*style);
+            writer.write_row(&fields).unwrap();
+            writer.write_row_simd(&fields).unwrap();
+            let text: Vec<&str> = fields.iter().map(|f| std::str::from_utf8(f).unwrap()).collect();
+            writer.write_row_fast(&text).unwrap();
+            writer.write_row_encoded(&text).unwrap();
+            let out = writer.flush_and_get().unwrap();
+            assert_eq!(std::str::from_utf8(&out).unwrap(), expected.repeat(4), "{:?}", style);
+        }
+    }
+
+    #[test]
+    fn test_write_row_simd_big_rows() {
+        let quoted = "\"".repeat(3000);
+        let long = "x".repeat(5000);
+        let fields: Vec<&[u8]> = vec![b"a", quoted.as_bytes(), long.as_bytes(), b"b;c"];
+        let mut simd = CsvWriter::new(Vec::new(), b';', b"\r\n", Encoding::Utf8);
+        simd.write_row_simd(&fields).unwrap();
+        let mut plain = CsvWriter::new(Vec::new(), b';', b"\r\n", Encoding::Utf8);
+        plain.write_row(&fields).unwrap();
+        let simd = simd.flush_and_get().unwrap();
+        assert_eq!(simd, plain.flush_and_get().unwrap());
+        assert_eq!(simd.len(), 1 + 1 + 6002 + 1 + 5000 + 1 + 5 + 2);
+    }
+
+    #[test]
+    fn test_write_without_string_separator() {
+        let mut writer = CsvWriter::new(Vec::new(), b',', b"\n", Encoding::Utf8)
+            .with_string_separator(0)
+            .with_quote_style(QuoteStyle::Always);
+        writer.write_row_fast(&["a,b", "c"]).unwrap();
+        assert_eq!(writer.flush_and_get().unwrap(), b"a,b,c\n");
+    }
+
+    #[test]
+    fn test_record_builder() {
+        let mut writer = CsvWriter::new(Vec::new(), b',', b"\r\n", Encoding::Windows1252);
+        writer.push_u64(u64::MAX).unwrap();
+        writer.push_i64(i64::MIN).unwrap();
+        writer.push_f64(0.1).unwrap();
+        writer.push_str("Müller").unwrap();
+        writer.push_bytes(b"say \"hi\"").unwrap();
+        writer.end_record().unwrap();
+        writer.push_bool(false).unwrap();
+        writer.end_record().unwrap();
+        assert_eq!(
+            writer.flush_and_get().unwrap(),
+            b"18446744073709551615,-9223372036854775808,0.1,M\xFCller,\"say \"\"hi\"\"\"\r\nfalse\r\n"
+        );
+
+        let mut writer = CsvWriter::new(Vec::new(), b';', b"\n", Encoding::Utf8)
+            .with_quote_style(QuoteStyle::Always)
+            .with_float_precision(3);
+        writer.push_i64(5).unwrap();
+        writer.push_f64(2.0).unwrap();
+        writer.push_bool(true).unwrap();
+        writer.end_record().unwrap();
+        assert_eq!(writer.flush_and_get().unwrap(), b"\"5\";\"2.000\";\"true\"\n");
+
+        // UTF-16 is not ASCII compatible, numbers are encoded too
+        let mut writer = CsvWriter::new(Vec::new(), b',', b"\n\x00", Encoding::Utf16Le);
+        writer.push_i64(-7).unwrap();
+        writer.end_record().unwrap();
+        writer.push_f64(1.5).unwrap();
+        writer.end_record().unwrap();
+        writer.push_bool(true).unwrap();
+        writer.end_record().unwrap();
+        assert_eq!(writer.flush_and_get().unwrap(), b"-\x007\x00\n\x001\x00.\x005\x00\n\x00t\x00r\x00u\x00e\x00\n\x00");
+    }
+
+    #[test]
+    fn test_utf16_fields_and_quotes() {
+        let fields = ["id", "Ĭ", "say \"hi\"", "a,b"];
+        let expected = "id,Ĭ,\"say \"\"hi\"\"\",\"a,b\"\n";
+        for encoding in [Encoding::Utf16Le, Encoding::Utf16Be].iter().copied() {
+            let line_break = encoding.encode("\n");
+            // `Ĭ` (U+012C) holds a `,` byte once encoded, but is not quoted
+            let mut writer = CsvWriter::new(Vec::new(), b',', &line_break, encoding);
+            writer.write_row_encoded(&fields).unwrap();
+            for field in fields.iter() {
+                writer.push_str(field).unwrap();
+            }
+            writer.end_record().unwrap();
+            let encoded: Vec<Vec<u8>> = fields.iter().map(|field| encoding.encode(field)).collect();
+            let raw: Vec<&[u8]> = encoded.iter().map(|field| field.as_slice()).collect();
+            writer.write_row(&raw).unwrap();
+            writer.write_row_simd(&raw).unwrap();
+            assert_eq!(writer.flush_and_get().unwrap(), encoding.encode(&expected.repeat(4)));
+        }
+    }
+}
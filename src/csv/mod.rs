@@ -1,3 +1,5 @@
 pub mod csv_reader;
 pub mod csv_writer;
-pub mod reader_slice;
\ No newline at end of file
+pub mod reader_slice;
+pub mod reader_stream;
+pub mod sniffer;
\ No newline at end of file
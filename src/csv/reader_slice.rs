@@ -1,12 +1,12 @@
-use crate::helpers::bytes_helper::locate_line_break_memchr3;
-#[cfg(target_arch = "aarch64")]
-use crate::helpers::bytes_helper::locate_line_break_neon;
-#[cfg(target_arch = "x86_64")]
-use crate::helpers::bytes_helper::locate_line_break_avx2;
-
+use crate::helpers::bytes_helper::next_record;
+use crate::models::cancel_token::{CancelToken, ScanOutcome};
 use crate::models::csv_config::CsvConfig;
+use crate::models::csv_error::{CsvError, CsvErrorKind};
+use crate::models::header::Header;
 use crate::models::platform_info::PlatformInfo;
-use crate::models::row::Row;
+use crate::models::row::{OffsetsPool, Row};
+use std::ops::ControlFlow;
+use std::sync::Arc;
 
 #[derive(Debug)]
 #[allow(dead_code)]
@@ -15,68 +15,65 @@ pub struct CsvReaderSlice<'mmap> {
     config: CsvConfig,
     platform_info: PlatformInfo,
     cursor: usize,
+    header: Option<Arc<Header>>,
+    data_start: usize,
+    data_record: usize,
+    record: usize,
+    /// Field offsets buffer of the rows built by `for_each_until`.
+    offsets: Vec<(usize, usize)>,
+    /// Field offsets buffer of the rows returned by `next_raw` and `peek_raw`.
+    offsets_pool: Arc<OffsetsPool>,
 }
 
 impl<'mmap> CsvReaderSlice<'mmap> {
     /// Create a new CsvReaderSlice
+    /// - A leading BOM is skipped.
+    /// - Panics on UTF-16 input (BOM, detected, or set in the config), see `try_new_from_slice`.
     pub fn new_from_slice(slice: &'mmap [u8], config: &CsvConfig) -> Self {
-        Self {
+        Self::try_new_from_slice(slice, config).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// ## Try New From Slice
+    /// - Same as `new_from_slice`, but fails with a `CsvErrorKind::Config` error on UTF-16 input.
+    /// - The rows borrow the slice, so UTF-16 can not be transcoded. Use `CsvReaderWithMap`, `CsvReaderStream`, or
+    ///   transcode the slice with `Encoding::decode` first.
+    pub fn try_new_from_slice(slice: &'mmap [u8], config: &CsvConfig) -> Result<Self, CsvError> {
+        let mut config = config.clone();
+        let bom = config.prepare_input(slice);
+        if config.encoding.is_utf16() {
+            return Err(CsvError::new(
+                CsvErrorKind::Config,
+                format!("{:?} input can not be read from a slice: transcode it to UTF-8 first", config.encoding),
+            ));
+        }
+        let mut reader = Self {
             slice,
-            config: config.clone(),
+            config,
             platform_info: PlatformInfo::new(),
-            cursor: 0,
-        }
+            cursor: bom,
+            header: None,
+            data_start: bom,
+            data_record: 0,
+            record: 0,
+            offsets: Vec::new(),
+            offsets_pool: Arc::default(),
+        };
+        reader.read_header();
+        Ok(reader)
     }
 
     /// Returns the next row as a `Row`
+    /// - Comment lines (and empty records, if `skip_empty_records` is set) are skipped.
+    /// - A last record without line break is returned. At the end of the slice returns `None`, and goes back to the first record.
     pub fn next_raw(&mut self) -> Option<Row<'mmap>> {
-        let delimiter = self.config.delimiter;
-        let string_separator = self.config.string_separator;
-        let force_memchr3 = self.config.force_memcach3;
-
-        let row_slice = if force_memchr3 {
-            self.next_raw_memchr3()
-        } else {
-            #[cfg(target_arch = "x86_64")]
-            {
-                if is_x86_feature_detected!("avx2") {
-                    unsafe { self.next_raw_avx2() }
-                } else {
-                    self.next_raw_memchr3()
-                }
-            }
-
-            #[cfg(target_arch = "aarch64")]
-            {
-                self.next_raw_neon()
-            }
-        }?;
-
-        Some(Row::new(row_slice, delimiter, string_separator, force_memchr3))
+        let (start, end) = self.next_bounds()?;
+        Some(self.build_row(start, end, self.record).with_offsets_pool(&self.offsets_pool))
     }
     /// ## Peek Raw
     /// - Retorna la siguiente línea **sin avanzar** el cursor.
     pub fn peek_raw(&self) -> Option<Row<'mmap>> {
-        let string_separator = self.config.string_separator;
-        let delimiter = self.config.delimiter;
-        let fm = self.config.force_memcach3;
-        let slice = if fm {
-            Self::peek_raw_memchr3(&self.slice, self.cursor, self.config.line_break)
-        } else {
-            #[cfg(target_arch = "x86_64")]
-            {
-                if is_x86_feature_detected!("avx2") {
-                    unsafe { Self::peek_raw_avx2(&self.slice, self.cursor, self.config.line_break) }
-                } else {
-                    Self::peek_raw_memchr3(&self.slice, self.cursor, self.config.line_break)
-                }
-            }
-            #[cfg(target_arch = "aarch64")]
-            {
-                Self::peek_raw_neon(&self.slice, self.cursor, self.config.line_break)
-            }
-        }?;
-        Some(Row::new(slice, delimiter, string_separator, fm))
+        let bounds = next_record(self.slice, self.cursor, &self.config)?;
+        Some(self.build_row(bounds.start, bounds.end, self.record + bounds.records).with_offsets_pool(&self.offsets_pool))
     }
 
     /// ## Advance Next
@@ -84,124 +81,470 @@ impl<'mmap> CsvReaderSlice<'mmap> {
     pub fn advance_next(&mut self) {
         let _ = self.next_raw();
     }
+
+    /// ## For Each Until
+    /// - Calls `func` with every remaining row, until it returns `ControlFlow::Break(())` or `cancel` is cancelled.
+    /// - Returns `ScanOutcome::Completed` at the end of the slice (the cursor goes back to the first record), or
+    ///   `ScanOutcome::Cancelled`. After a cancellation, `next_raw` continues after the last processed row.
+    pub fn for_each_until<F>(&mut self, cancel: &CancelToken, mut func: F) -> ScanOutcome
+    where
+        F: FnMut(&Row<'mmap>) -> ControlFlow<()>,
+    {
+        let mut offsets = std::mem::take(&mut self.offsets);
+        let outcome = loop {
+            if cancel.is_cancelled() {
+                break ScanOutcome::Cancelled;
+            }
+            let (start, end) = match self.next_bounds() {
+                Some(bounds) => bounds,
+                None => break ScanOutcome::Completed,
+            };
+            let mut row = self.build_row(start, end, self.record).with_offsets_buffer(offsets);
+            let flow = func(&row);
+            offsets = row.take_offsets_buffer();
+            if flow.is_break() {
+                break ScanOutcome::Cancelled;
+            }
+        };
+        self.offsets = offsets;
+        outcome
+    }
     //------------------------- PRIVATE -------------------
 
-    #[cfg(target_arch = "x86_64")]
-    #[target_feature(enable = "avx2")]
-    unsafe fn peek_raw_avx2(mmap: &[u8], cursor: usize, line_break: u8) -> Option<&[u8]> {
-        let slice = &mmap[cursor..];
-        let sep_index = locate_line_break_avx2(slice, line_break);
+    /// Moves the cursor to the next record, and returns its bounds. At the end of the slice, goes back to the first
+    /// record and returns `None`.
+    fn next_bounds(&mut self) -> Option<(usize, usize)> {
+        let bounds = match next_record(self.slice, self.cursor, &self.config) {
+            Some(bounds) => bounds,
+            None => {
+                self.reset_cursor();
+                return None;
+            }
+        };
+        self.cursor = bounds.next;
+        self.record += bounds.records;
+        Some((bounds.start, bounds.end))
+    }
 
-        if sep_index == 0 {
-            return None;
-        }
+    fn build_row(&self, start: usize, end: usize, record: usize) -> Row<'mmap> {
+        Row::new(&self.slice[start..end], self.config.field_delimiter(), self.config.string_separator, self.config.force_memcach3)
+            .with_header(self.header.clone())
+            .with_record(record)
+            .with_offset(start)
+            .with_trim(self.config.trim)
+    }
 
-        let full_row = &mmap[cursor..cursor + sep_index];
-        let trim_len = if full_row.ends_with(b"\r\n") {
-            2
-        } else if full_row.ends_with(&[b'\r']) || full_row.ends_with(&[b'\n']) {
-            1
-        } else {
-            0
+    /// Parses the first record as header, or generates the column names.
+    fn read_header(&mut self) {
+        let has_header = self.config.has_header;
+        let encoding = self.config.encoding;
+        let header = match self.next_raw() {
+            Some(row) if has_header => Header::from_row(&row, encoding),
+            Some(row) => Header::generated(row.field_count()),
+            None => return,
         };
-        Some(&full_row[..full_row.len().saturating_sub(trim_len)])
-    }
-
-    #[cfg(target_arch = "aarch64")]
-    fn peek_raw_neon(mmap: &[u8], cursor: usize, line_break: u8) -> Option<&[u8]> {
-        unsafe {
-            let slice = &mmap[cursor..];
-            match locate_line_break_neon(slice, line_break) {
-                0 => None,
-                sep_index => {
-                    let row = &mmap[cursor..cursor + sep_index];
-                    let end = if row.ends_with(b"\r\n") {
-                        2
-                    } else if row.ends_with(&[b'\n']) || row.ends_with(&[b'\r']) {
-                        1
-                    } else {
-                        0
-                    };
-                    Some(&row[..row.len() - end])
-                }
-            }
+        if has_header {
+            self.data_start = self.cursor;
+            self.data_record = self.record;
         }
+        self.reset_cursor();
+        self.header = Some(Arc::new(header));
     }
 
-    fn peek_raw_memchr3(mmap: &[u8], cursor: usize, line_break: u8) -> Option<&[u8]> {
-        let slice = &mmap[cursor..];
-        match locate_line_break_memchr3(slice, cursor, line_break) {
-            0 => None,
-            i => Some(&mmap[cursor..i]),
-        }
+    /// Moves the cursor back to the first record after the header.
+    pub fn reset_cursor(&mut self) {
+        self.cursor = self.data_start;
+        self.record = self.data_record;
+    }
+
+    /// ## Get Header
+    /// - Extracts the header reference.
+    /// - If `has_header` is `false`, names are generated (`column_0`, `column_1`, ...).
+    /// - Returns `None` on empty slices.
+    pub fn get_header(&self) -> Option<&Header> {
+        self.header.as_deref()
+    }
+
+    /// ## Get Data Slice
+    /// - Extracts the slice reference, without the header record.
+    pub fn get_data_slice(&self) -> &'mmap [u8] {
+        &self.slice[self.data_start..]
+    }
+
+    /// ## Get Data Origin
+    /// - Gets the position of the data slice: the offset of its first byte, and the record number of its first record.
+    /// - Use it with `ParallelConfig::with_origin`, so the parallel rows report their position in the slice.
+    pub fn get_data_origin(&self) -> (usize, usize) {
+        (self.data_start, self.data_record + 1)
     }
-    
-    /// AVX2 version
-    #[cfg(target_arch = "x86_64")]
-    #[target_feature(enable = "avx2")]
-    unsafe fn next_raw_avx2(&mut self) -> Option<&'mmap [u8]> {
-        let slice = &self.slice[self.cursor..];
-        let sep_index = locate_line_break_avx2(slice, self.config.line_break);
-
-        if sep_index == 0 {
-            self.cursor = 0;
-            return None;
+
+    pub fn get_config(&self) -> &CsvConfig {
+        &self.config
+    }
+
+    pub fn get_slice(&self) -> &'mmap [u8] {
+        self.slice
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csv::reader_slice::CsvReaderSlice;
+    use crate::decoders::decoders::Encoding;
+    use crate::models::cancel_token::{CancelToken, ScanOutcome};
+    use crate::models::csv_config::CsvConfig;
+    use crate::models::csv_error::CsvErrorKind;
+    use crate::models::delimiter::Delimiter;
+    use crate::models::record_terminator::RecordTerminator;
+    use crate::models::trim::Trim;
+
+    #[test]
+    fn test_quoted_field_with_line_breaks() {
+        let cfg = CsvConfig {
+            delimiter: b',',
+            string_separator: b'"',
+            ..CsvConfig::default()
+        };
+        let data = b"id,address\n1,\"Main St\nApt 4\"\n2,\"Say \"\"hi\"\"\r\nbye\"\n";
+        let mut reader = CsvReaderSlice::new_from_slice(data, &cfg);
+        let mut rows = Vec::new();
+        while let Some(row) = reader.next_raw() {
+            rows.push(row.get_slice().to_vec());
         }
+        let expected: Vec<&[u8]> = vec![b"id,address", b"1,\"Main St\nApt 4\"", b"2,\"Say \"\"hi\"\"\r\nbye\""];
+        assert_eq!(rows, expected);
+    }
 
-        let row = &self.slice[self.cursor..self.cursor + sep_index];
-        let end_trim = if row.ends_with(b"\r\n") { 2 }
-        else if row.ends_with(&[b'\n']) || row.ends_with(&[b'\r']) { 1 }
-        else { 0 };
+    #[test]
+    fn test_header_by_name() {
+        let cfg = CsvConfig {
+            delimiter: b',',
+            string_separator: b'"',
+            has_header: true,
+            ..CsvConfig::default()
+        };
+        let data = b"id,\"city name\",country\n1,Paris,France\n2,Lima,Peru\n";
+        let mut reader = CsvReaderSlice::new_from_slice(data, &cfg);
+        let header = reader.get_header().expect("header");
+        assert_eq!(header.names(), &["id", "city name", "country"]);
+        let city = header.column("city name").expect("column");
 
-        self.cursor += sep_index;
-        Some(&row[..row.len().saturating_sub(end_trim)])
+        let row = reader.next_raw().expect("first record");
+        assert_eq!(row.get_by_name("id").get_i32(), 1);
+        assert_eq!(row.get_column(city).get_utf8_as_str(), "Paris");
+        assert!(row.get_by_name("missing").is_empty());
+        let row = reader.next_raw().expect("second record");
+        assert_eq!(row.get_by_name("city name").get_utf8_as_str(), "Lima");
+        assert!(reader.next_raw().is_none());
+        // After EOF the cursor goes back to the first record, not to the header
+        let row = reader.next_raw().expect("first record again");
+        assert_eq!(row.get_by_name("id").get_i32(), 1);
     }
 
-    /// NEON version
-    #[cfg(target_arch = "aarch64")]
-    fn next_raw_neon(&mut self) -> Option<&'mmap [u8]> {
-        unsafe {
-            let slice = &self.slice[self.cursor..];
-            let sep_index = locate_line_break_neon(slice, self.config.line_break);
+    #[test]
+    fn test_generated_header() {
+        let cfg = CsvConfig {
+            delimiter: b';',
+            ..CsvConfig::default()
+        };
+        let data = b"1;Paris;France\n2;Lima;Peru\n";
+        let mut reader = CsvReaderSlice::new_from_slice(data, &cfg);
+        assert_eq!(reader.get_header().expect("header").names(), &["column_0", "column_1", "column_2"]);
+        let row = reader.next_raw().expect("first record is data");
+        assert_eq!(row.get_by_name("column_1").get_utf8_as_str(), "Paris");
+    }
 
-            if sep_index == 0 {
-                self.cursor = 0;
-                return None;
+    #[test]
+    fn test_byte_range_and_data_origin() {
+        let cfg = CsvConfig {
+            delimiter: b',',
+            has_header: true,
+            ..CsvConfig::default()
+        };
+        let data = b"\xEF\xBB\xBFid,amount\n1,10\n22,20\n";
+        let mut reader = CsvReaderSlice::new_from_slice(data, &cfg);
+        assert_eq!(reader.get_data_origin(), (13, 2));
+        let row = reader.next_raw().expect("first record");
+        assert_eq!((row.get_record_number(), row.get_byte_range()), (2, 13..17));
+        let row = reader.next_raw().expect("second record");
+        assert_eq!((row.get_record_number(), row.get_byte_range()), (3, 18..23));
+        assert_eq!(&data[row.get_byte_range()], b"22,20");
+    }
+
+    #[test]
+    fn test_for_each_until() {
+        let cfg = CsvConfig {
+            delimiter: b',',
+            has_header: true,
+            ..CsvConfig::default()
+        };
+        let data = b"id,amount\n1,10\n2,20\n3,30\n";
+        let mut reader = CsvReaderSlice::new_from_slice(data, &cfg);
+        let cancel = CancelToken::new();
+        let mut seen = Vec::new();
+        let outcome = reader.for_each_until(&cancel, |row| {
+            seen.push(row.get_index(0).get_i32());
+            if row.get_index(1).get_i32() == 20 {
+                std::ops::ControlFlow::Break(())
+            } else {
+                std::ops::ControlFlow::Continue(())
             }
+        });
+        assert_eq!((outcome, seen), (ScanOutcome::Cancelled, vec![1, 2]));
+        // The scan can go on after the last processed row
+        assert_eq!(reader.next_raw().expect("third record").get_index(0).get_i32(), 3);
+        reader.reset_cursor();
+        let mut count = 0;
+        cancel.cancel();
+        let outcome = reader.for_each_until(&cancel, |_| {
+            count += 1;
+            std::ops::ControlFlow::Continue(())
+        });
+        assert_eq!((outcome, count), (ScanOutcome::Cancelled, 0));
+        let outcome = reader.for_each_until(&CancelToken::new(), |_| {
+            count += 1;
+            std::ops::ControlFlow::Continue(())
+        });
+        assert_eq!((outcome, count), (ScanOutcome::Completed, 3));
+    }
+
+    #[test]
+    fn test_try_get_reports_record_number() {
+        let cfg = CsvConfig {
+            delimiter: b',',
+            has_header: true,
+            ..CsvConfig::default()
+        };
+        let data = b"id,amount\n1,10\n2,ten\n";
+        let mut reader = CsvReaderSlice::new_from_slice(data, &cfg);
+        let row = reader.next_raw().expect("first record");
+        assert_eq!(row.get_record_number(), 2);
+        assert_eq!(row.get_by_name("amount").try_get_i32().unwrap(), 10);
+        assert_eq!(crate::try_get_u8!(row, 1).unwrap(), 10);
+        let row = reader.next_raw().expect("second record");
+        let err = row.get_by_name("amount").try_get_i32().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parse error at record 3, field 1, byte 17: failed to parse \"ten\" as i32 (record: \"2,ten\")"
+        );
+    }
+
+    #[test]
+    fn test_cached_field_offsets() {
+        let cfg = CsvConfig {
+            delimiter: b',',
+            string_separator: b'"',
+            ..CsvConfig::default()
+        };
+        let data = b"a,\"b,c\",,d\nx,y\n";
+        let mut reader = CsvReaderSlice::new_from_slice(data, &cfg);
+        let row = reader.next_raw().expect("record");
+        assert_eq!(row.field_count(), 4);
+        // Random order, and repeated access, use the same cached offsets
+        assert_eq!(row.get_index(3).get_utf8_as_str(), "d");
+        assert_eq!(row.get_index(1).get_utf8_as_str(), "b,c");
+        assert!(row.get_index(2).is_empty());
+        assert_eq!(row.get_index(0).get_utf8_as_str(), "a");
+        assert_eq!(row.get_index(1).get_utf8_as_str(), "b,c");
+        assert!(row.get_index(4).is_empty());
+        drop(row);
+        // The next row reuses the buffer, split only up to the field asked
+        let row = reader.next_raw().expect("second record");
+        assert_eq!(row.get_index(0).get_utf8_as_str(), "x");
+        assert_eq!(row.field_count(), 2);
+        assert_eq!(row.get_index(1).get_utf8_as_str(), "y");
+    }
+
+    #[test]
+    fn test_offsets_buffer_of_rows() {
+        let cfg = CsvConfig {
+            delimiter: b',',
+            ..CsvConfig::default()
+        };
+        let data = b"a,b,c\n1,2,3\n";
+        let mut reader = CsvReaderSlice::new_from_slice(data, &cfg);
+        let mut last = Vec::new();
+        let outcome = reader.for_each_until(&CancelToken::new(), |row| {
+            last.push(row.get_index(2).get_utf8_as_str().to_string());
+            std::ops::ControlFlow::Continue(())
+        });
+        assert_eq!((outcome, last), (ScanOutcome::Completed, vec!["c".to_string(), "3".to_string()]));
+        // The scan keeps its buffer in the reader
+        assert!(reader.offsets.capacity() >= 3);
+        // Rows returned by `next_raw` may outlive the next one
+        let first = reader.next_raw().expect("first record");
+        let second = reader.next_raw().expect("second record");
+        assert_eq!(second.get_index(1).get_utf8_as_str(), "2");
+        assert_eq!(first.get_index(1).get_utf8_as_str(), "b");
+    }
 
-            let row = &self.slice[self.cursor..self.cursor + sep_index];
-            let end_trim = if row.ends_with(b"\r\n") { 2 }
-            else if row.ends_with(&[b'\n']) || row.ends_with(&[b'\r']) { 1 }
-            else { 0 };
+    #[test]
+    fn test_multi_byte_delimiter() {
+        let cfg = CsvConfig {
+            string_separator: b'"',
+            has_header: true,
+            ..CsvConfig::default()
+        }
+        .with_delimiter(Delimiter::new(b"||").unwrap());
+        let data = b"id||name\n1||\"a||b\"\n2||c|d\n";
+        let mut reader = CsvReaderSlice::new_from_slice(data, &cfg);
+        assert_eq!(reader.get_header().expect("header").names(), &["id", "name"]);
+        let row = reader.next_raw().expect("first record");
+        assert_eq!(row.get_by_name("name").get_utf8_as_str(), "a||b");
+        let mut row = reader.next_raw().expect("second record");
+        assert_eq!(row.field_count(), 2);
+        assert_eq!(row.next_field().expect("id").get_i32(), 2);
+        assert_eq!(row.next_field().expect("name").get_utf8_as_str(), "c|d");
+        assert!(row.next_field().is_none());
+    }
 
-            self.cursor += sep_index;
-            Some(&row[..row.len().saturating_sub(end_trim)])
+    #[test]
+    fn test_record_terminators() {
+        let cfg = CsvConfig {
+            delimiter: b',',
+            string_separator: b'"',
+            has_header: true,
+            ..CsvConfig::default()
+        }
+        .with_terminator(RecordTerminator::Crlf);
+        let data = b"id,note\r\n1,line\nbreak\r\n2,\"quoted\r\nbreak\"\r\n3,last";
+        let mut reader = CsvReaderSlice::new_from_slice(data, &cfg);
+        assert_eq!(reader.get_header().expect("header").names(), &["id", "note"]);
+        assert_eq!(reader.peek_raw().expect("peek").get_slice(), b"1,line\nbreak");
+        let mut rows = Vec::new();
+        while let Some(row) = reader.next_raw() {
+            rows.push(row.get_slice().to_vec());
         }
+        let expected: Vec<&[u8]> = vec![b"1,line\nbreak", b"2,\"quoted\r\nbreak\"", b"3,last"];
+        assert_eq!(rows, expected);
+
+        let cfg = CsvConfig::default().with_terminator(RecordTerminator::Ascii);
+        let data = b"a\x1Fb, c\x1Ed\x1Fe\nf\x1E";
+        let mut reader = CsvReaderSlice::new_from_slice(data, &cfg);
+        let row = reader.next_raw().expect("first record");
+        assert_eq!(row.get_index(1).get_utf8_as_str(), "b, c");
+        let row = reader.next_raw().expect("second record");
+        assert_eq!(row.get_index(1).get_utf8_as_str(), "e\nf");
+        assert!(reader.next_raw().is_none());
     }
-    fn next_raw_memchr3(&mut self) -> Option<&'mmap [u8]> {
-        let slice = &self.slice[self.cursor..];
-        match locate_line_break_memchr3(slice, self.cursor, self.config.line_break) {
-            0 => {
-                self.cursor = 0;
-                None
+
+    #[test]
+    fn test_comments_blank_lines_and_last_record() {
+        let data = b"# sensor \"A\nid,value\r\n\r\n1,10\n# calibration\n\n2,20";
+        for force_memcach3 in [false, true].iter().copied() {
+            let cfg = CsvConfig {
+                delimiter: b',',
+                string_separator: b'"',
+                has_header: true,
+                force_memcach3,
+                ..CsvConfig::default()
             }
-            i => {
-                let row = &self.slice[self.cursor..i];
-                self.cursor = i;
-                Some(row)
+            .with_comment_prefix(b'#');
+            let mut reader = CsvReaderSlice::new_from_slice(data, &cfg);
+            assert_eq!(reader.get_header().expect("header").names(), &["id", "value"]);
+            let mut rows = Vec::new();
+            while let Some(row) = reader.next_raw() {
+                rows.push((row.get_record_number(), row.get_slice().to_vec()));
             }
+            // Empty records are surfaced by default, and the last record has no line break
+            let expected: Vec<(usize, &[u8])> = vec![(3, b""), (4, b"1,10"), (6, b""), (7, b"2,20")];
+            let expected: Vec<(usize, Vec<u8>)> = expected.into_iter().map(|(n, r)| (n, r.to_vec())).collect();
+            assert_eq!(rows, expected, "force_memcach3: {}", force_memcach3);
+
+            let cfg = cfg.with_skip_empty_records(true);
+            let mut reader = CsvReaderSlice::new_from_slice(data, &cfg);
+            assert_eq!(reader.peek_raw().expect("peek").get_record_number(), 4);
+            assert_eq!(reader.next_raw().expect("first").get_slice(), b"1,10");
+            assert_eq!(reader.next_raw().expect("second").get_slice(), b"2,20");
+            assert!(reader.next_raw().is_none());
         }
     }
 
-    pub fn reset_cursor(&mut self) {
-        self.cursor = 0;
+    #[test]
+    fn test_trailing_line_break_is_not_a_record() {
+        for force_memcach3 in [false, true].iter().copied() {
+            let cfg = CsvConfig {
+                force_memcach3,
+                ..CsvConfig::default()
+            };
+            let mut reader = CsvReaderSlice::new_from_slice(b"a;b\r\nc;d\r\n", &cfg);
+            assert_eq!(reader.next_raw().expect("first").get_slice(), b"a;b");
+            assert_eq!(reader.next_raw().expect("second").get_slice(), b"c;d");
+            assert!(reader.next_raw().is_none());
+        }
     }
 
-    pub fn get_config(&self) -> &CsvConfig {
-        &self.config
+    #[test]
+    fn test_trim_fields() {
+        let cfg = CsvConfig {
+            delimiter: b',',
+            string_separator: b'"',
+            has_header: true,
+            ..CsvConfig::default()
+        }
+        .with_trim(Trim::Both);
+        let data = b" id , name ,  amount\n 7 , \" x, y \" ,  -12.5 \n";
+        let mut reader = CsvReaderSlice::new_from_slice(data, &cfg);
+        assert_eq!(reader.get_header().expect("header").names(), &["id", "name", "amount"]);
+        let row = reader.next_raw().expect("record");
+        assert_eq!(row.field_count(), 3);
+        assert_eq!(row.get_by_name("id").get_i32(), 7);
+        assert!(row.get_by_name("id").is_numeric_like());
+        // Spaces inside quotes are kept
+        assert_eq!(row.get_by_name("name").get_utf8_as_str(), " x, y ");
+        assert_eq!(row.get_by_name("amount").try_get_f64().unwrap(), -12.5);
+
+        let cfg = CsvConfig {
+            delimiter: b',',
+            ..CsvConfig::default()
+        }
+        .with_trim(Trim::Trailing);
+        let mut reader = CsvReaderSlice::new_from_slice(b" a ,   \n", &cfg);
+        let row = reader.next_raw().expect("record");
+        assert_eq!(row.get_index(0).get_slice(), b" a");
+        assert!(row.get_index(1).is_empty());
     }
 
-    pub fn get_slice(&self) -> &'mmap [u8] {
-        self.slice
+    #[test]
+    fn test_bom_and_encoding_detection() {
+        let cfg = CsvConfig {
+            delimiter: b';',
+            has_header: true,
+            ..CsvConfig::default()
+        };
+        let data = "\u{FEFF}id;ciudad\n1;Peñarol\n".as_bytes();
+        let mut reader = CsvReaderSlice::new_from_slice(data, &cfg);
+        assert_eq!(reader.get_header().expect("header").index_of("id"), Some(0));
+        assert_eq!(reader.get_config().encoding, Encoding::Windows1252);
+        assert_eq!(reader.next_raw().expect("record").get_by_name("id").get_i32(), 1);
+        // Without header, the BOM is skipped on each pass
+        let mut reader = CsvReaderSlice::new_from_slice(data, &cfg.clone().with_header(false));
+        assert_eq!(reader.next_raw().expect("record").get_index(0).get_slice(), b"id");
+        assert!(reader.next_raw().is_some());
+        assert!(reader.next_raw().is_none());
+        assert_eq!(reader.next_raw().expect("record").get_index(0).get_slice(), b"id");
+
+        let cfg = cfg.with_encoding_detection(true);
+        let reader = CsvReaderSlice::new_from_slice(data, &cfg);
+        assert_eq!(reader.get_config().encoding, Encoding::Utf8);
+        let reader = CsvReaderSlice::new_from_slice(b"id;ciudad\n1;Pe\xF1arol\n", &cfg);
+        assert_eq!(reader.get_config().encoding, Encoding::Windows1252);
+    }
+
+    #[test]
+    fn test_utf16_input_is_rejected() {
+        let cfg = CsvConfig {
+            delimiter: b'\t',
+            has_header: true,
+            ..CsvConfig::default()
+        };
+        for encoding in [Encoding::Utf16Le, Encoding::Utf16Be].iter() {
+            let data = encoding.encode("\u{FEFF}id\tname\r\n1\tJosé\r\n");
+            let err = CsvReaderSlice::try_new_from_slice(&data, &cfg).unwrap_err();
+            assert_eq!(err.kind(), CsvErrorKind::Config);
+        }
+        let data = "\u{FEFF}id\tname\r\n1\tJosé\r\n".as_bytes();
+        let reader = CsvReaderSlice::try_new_from_slice(data, &cfg).expect("UTF-8 input");
+        assert_eq!(reader.get_header().expect("header").names(), &["id", "name"]);
     }
 }
@@ -0,0 +1,562 @@
+use crate::decoders::decoders::Encoding;
+use crate::helpers::bytes_helper::{resume_line_break, resume_terminator, trim_record_end, RecordScan};
+use crate::models::cancel_token::{CancelToken, ScanOutcome};
+use crate::models::csv_config::CsvConfig;
+use crate::models::csv_error::CsvError;
+use crate::models::header::Header;
+use crate::models::row::{OffsetsPool, Row};
+use std::io::{ErrorKind, Read};
+use std::ops::ControlFlow;
+use std::sync::Arc;
+
+/// Default size of the internal buffer.
+const DEFAULT_CAPACITY: usize = 64 * 1024;
+
+/// ## CsvReaderStream
+/// - Reads a CSV from any `std::io::Read` (stdin, pipes, sockets, decompressors...).
+/// - Keeps a refillable buffer, and hands rows through the same `Row` / `Field` API as the other readers.
+/// - Records straddling the end of the buffer are moved to the start, and the buffer grows if a record does not fit.
+/// - UTF-16 input is transcoded to UTF-8 as it is read, so byte ranges of the rows are positions in the UTF-8 text.
+#[derive(Debug)]
+pub struct CsvReaderStream<R: Read> {
+    inner: R,
+    config: CsvConfig,
+    buffer: Vec<u8>,
+    start: usize,
+    end: usize,
+    /// Bytes of the input moved out of the buffer, the offset of `buffer[0]`.
+    consumed: usize,
+    eof: bool,
+    /// UTF-16 bytes read and not transcoded yet, if the input is UTF-16.
+    utf16: Option<Utf16Input>,
+    /// Progress of the search for the end of the record at `start`, kept across refills.
+    scan: RecordScan,
+    header: Option<Arc<Header>>,
+    record: usize,
+    /// Field offsets buffer of the rows built by `for_each_until`.
+    offsets: Vec<(usize, usize)>,
+    /// Field offsets buffer of the rows returned by `next_raw`.
+    offsets_pool: Arc<OffsetsPool>,
+}
+
+impl<R: Read> CsvReaderStream<R> {
+    /// ## New
+    /// - Creates a stream reader with a 64KB buffer.
+    /// - Reads the header (or generates the column names) before returning.
+    /// - A leading BOM is skipped. With `detect_encoding`, the encoding is detected from the first buffer.
+    /// - UTF-16 input (BOM or detected) is read as UTF-8, and `get_config().encoding` is `Utf8`.
+    pub fn new(inner: R, config: &CsvConfig) -> Result<Self, CsvError> {
+        Self::with_capacity(inner, config, DEFAULT_CAPACITY)
+    }
+
+    /// ## With Capacity
+    /// - Creates a stream reader with a custom initial buffer size.
+    /// - The buffer grows if a record is bigger than the capacity.
+    pub fn with_capacity(inner: R, config: &CsvConfig, capacity: usize) -> Result<Self, CsvError> {
+        let mut reader = Self {
+            inner,
+            config: config.clone(),
+            buffer: vec![0u8; capacity.max(1)],
+            start: 0,
+            end: 0,
+            consumed: 0,
+            eof: false,
+            utf16: None,
+            scan: RecordScan::default(),
+            header: None,
+            record: 0,
+            offsets: Vec::new(),
+            offsets_pool: Arc::default(),
+        };
+        reader.prepare_input()?;
+        reader.read_header()?;
+        Ok(reader)
+    }
+
+    /// ## Get Config
+    /// - Extracts configuration reference
+    pub fn get_config(&self) -> &CsvConfig {
+        &self.config
+    }
+
+    /// ## Get Header
+    /// - Extracts the header reference.
+    /// - If `has_header` is `false`, names are generated (`column_0`, `column_1`, ...).
+    /// - Returns `None` on empty inputs.
+    pub fn get_header(&self) -> Option<&Header> {
+        self.header.as_deref()
+    }
+
+    /// ## Next Raw
+    /// - Returns the next row, refilling the buffer from the inner reader when needed.
+    /// - Returns `Ok(None)` at the end of the input. A last record without line break is returned as a row.
+    pub fn next_raw(&mut self) -> Result<Option<Row<'_>>, CsvError> {
+        let (start, end) = match self.next_bounds()? {
+            Some(bounds) => bounds,
+            None => return Ok(None),
+        };
+        Ok(Some(self.build_row(start, end).with_offsets_pool(&self.offsets_pool)))
+    }
+
+    /// ## Advance Next
+    /// - Advance one line without returning it
+    pub fn advance_next(&mut self) -> Result<(), CsvError> {
+        self.next_bounds().map(|_| ())
+    }
+
+    /// ## For Each Until
+    /// - Calls `func` with every remaining row, until it returns `ControlFlow::Break(())` or `cancel` is cancelled.
+    /// - Returns `ScanOutcome::Completed` at the end of the input, or `ScanOutcome::Cancelled`. After a cancellation,
+    ///   `next_raw` continues after the last processed row. Read errors stop the scan.
+    pub fn for_each_until<F>(&mut self, cancel: &CancelToken, mut func: F) -> Result<ScanOutcome, CsvError>
+    where
+        F: FnMut(&Row<'_>) -> ControlFlow<()>,
+    {
+        let mut offsets = std::mem::take(&mut self.offsets);
+        let outcome = loop {
+            if cancel.is_cancelled() {
+                break Ok(ScanOutcome::Cancelled);
+            }
+            let (start, end) = match self.next_bounds() {
+                Ok(Some(bounds)) => bounds,
+                Ok(None) => break Ok(ScanOutcome::Completed),
+                Err(e) => break Err(e),
+            };
+            let mut row = self.build_row(start, end).with_offsets_buffer(offsets);
+            let flow = func(&row);
+            offsets = row.take_offsets_buffer();
+            if flow.is_break() {
+                break Ok(ScanOutcome::Cancelled);
+            }
+        };
+        self.offsets = offsets;
+        outcome
+    }
+
+    /// ## Into Inner
+    /// - Returns the inner reader. Buffered bytes not consumed yet are lost.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    //--------------------- INTERNAL ---------------------------------------------------------------//
+
+    /// Builds the row of the record at `start..end`, the last one located.
+    fn build_row(&self, start: usize, end: usize) -> Row<'_> {
+        Row::new(&self.buffer[start..end], self.config.field_delimiter(), self.config.string_separator, self.config.force_memcach3)
+            .with_header(self.header.clone())
+            .with_record(self.record)
+            .with_offset(self.consumed + start)
+            .with_trim(self.config.trim)
+    }
+
+    /// Locates the next record inside the buffer, refilling it until the record is complete.
+    /// Comment lines, and empty records if `skip_empty_records` is set, are skipped.
+    /// Returns the bounds of the record without its line break.
+    fn next_bounds(&mut self) -> Result<Option<(usize, usize)>, CsvError> {
+        loop {
+            let (start, end, comment) = match self.next_record_bounds()? {
+                Some(bounds) => bounds,
+                None => return Ok(None),
+            };
+            let skipped = comment || (start == end && self.config.skip_empty_records);
+            if !skipped {
+                return Ok(Some((start, end)));
+            }
+        }
+    }
+
+    /// Locates the next record inside the buffer, without skipping anything.
+    /// After a refill, the search resumes where it stopped, with the same quote state.
+    /// Returns the bounds of the record without its line break, and if it is a comment line.
+    fn next_record_bounds(&mut self) -> Result<Option<(usize, usize, bool)>, CsvError> {
+        let prefix_len = self.config.comment_prefix.map_or(0, |prefix| prefix.len());
+        loop {
+            let window = &self.buffer[self.start..self.end];
+            // The comment prefix may be cut by the end of the buffer
+            if window.len() < prefix_len && !self.eof {
+                self.fill_buffer()?;
+                continue;
+            }
+            let comment = self.config.is_comment(window);
+            // Quotes inside comment lines are not string separators
+            let string_separator = if comment { 0 } else { self.config.string_separator };
+            if let Some(terminator) = self.config.terminator.sequence() {
+                if let Some(end) = resume_terminator(window, terminator, string_separator, &mut self.scan) {
+                    let record_start = self.start;
+                    self.start += end;
+                    self.scan = RecordScan::default();
+                    self.record += 1;
+                    return Ok(Some((record_start, record_start + end - terminator.len(), comment)));
+                }
+            } else {
+                let line_break = self.config.line_break;
+                if let Some(brk) = resume_line_break(window, line_break, string_separator, self.config.force_memcach3, &mut self.scan) {
+                    // A `\r` at the end of the window may be the first half of a `\r\n`
+                    if brk == window.len() && window[brk - 1] == b'\r' && !self.eof {
+                        self.scan.pos = brk - 1;
+                    } else {
+                        let record_start = self.start;
+                        let record_len = trim_record_end(&window[..brk], line_break).len();
+                        self.start += brk;
+                        self.scan = RecordScan::default();
+                        self.record += 1;
+                        return Ok(Some((record_start, record_start + record_len, comment)));
+                    }
+                }
+            }
+            if self.eof {
+                if self.start == self.end {
+                    return Ok(None);
+                }
+                let bounds = (self.start, self.end, comment);
+                self.start = self.end;
+                self.scan = RecordScan::default();
+                self.record += 1;
+                return Ok(Some(bounds));
+            }
+            self.fill_buffer()?;
+        }
+    }
+
+    /// Moves the pending bytes to the start of the buffer, grows it if full, and reads more data.
+    fn fill_buffer(&mut self) -> Result<(), CsvError> {
+        if self.start > 0 {
+            self.buffer.copy_within(self.start..self.end, 0);
+            self.end -= self.start;
+            self.consumed += self.start;
+            self.start = 0;
+        }
+        if self.end == self.buffer.len() {
+            let len = self.buffer.len();
+            self.buffer.resize(len * 2, 0);
+        }
+        loop {
+            let read = match &mut self.utf16 {
+                Some(input) => input.read(&mut self.inner, &mut self.buffer, &mut self.end),
+                None => self.inner.read(&mut self.buffer[self.end..]).inspect(|n| self.end += n),
+            };
+            match read {
+                Ok(0) => {
+                    self.eof = true;
+                    return Ok(());
+                }
+                Ok(_) => return Ok(()),
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(e) => return Err(CsvError::from(e)),
+            }
+        }
+    }
+
+    /// Fills the buffer (until the BOM can be checked, or the whole buffer if the encoding is detected),
+    /// runs the encoding detection and skips the BOM. UTF-16 bytes already read are transcoded.
+    fn prepare_input(&mut self) -> Result<(), CsvError> {
+        let wanted = if self.config.detect_encoding { self.buffer.len() } else { 3 };
+        while !self.eof && self.end < wanted {
+            self.fill_buffer()?;
+        }
+        self.start += self.config.prepare_input(&self.buffer[..self.end]);
+        if self.config.encoding.is_utf16() {
+            // The splitters work on bytes, so UTF-16 is transcoded, and fields are read as UTF-8
+            let mut input = Utf16Input {
+                encoding: self.config.encoding,
+                raw: self.buffer[self.start..self.end].to_vec(),
+            };
+            self.start = 0;
+            self.end = 0;
+            input.transcode(&mut self.buffer, &mut self.end, self.eof);
+            self.utf16 = Some(input);
+            self.config.encoding = Encoding::Utf8;
+        }
+        Ok(())
+    }
+
+    /// Parses the first record as header, or generates the column names.
+    fn read_header(&mut self) -> Result<(), CsvError> {
+        let (start, end) = match self.next_bounds()? {
+            Some(bounds) => bounds,
+            None => return Ok(()),
+        };
+        let row = Row::new(&self.buffer[start..end], self.config.field_delimiter(), self.config.string_separator, self.config.force_memcach3)
+            .with_trim(self.config.trim);
+        let header = if self.config.has_header {
+            Header::from_row(&row, self.config.encoding)
+        } else {
+            Header::generated(row.field_count())
+        };
+        if !self.config.has_header {
+            // The first record is data, so it is not consumed
+            self.start = start;
+            self.record -= 1;
+        }
+        self.header = Some(Arc::new(header));
+        Ok(())
+    }
+}
+
+/// ## UTF-16 Input
+/// - Transcodes a UTF-16 input to UTF-8, as the buffer of the stream reader is filled.
+#[derive(Debug)]
+struct Utf16Input {
+    encoding: Encoding,
+    /// Bytes not transcoded yet: an odd byte, or the first half of a surrogate pair.
+    raw: Vec<u8>,
+}
+
+impl Utf16Input {
+    /// Reads more bytes from `inner`, and transcodes them at `buffer[*end..]`.
+    /// Returns the number of bytes read, `0` at the end of the input.
+    fn read<R: Read>(&mut self, inner: &mut R, buffer: &mut Vec<u8>, end: &mut usize) -> std::io::Result<usize> {
+        // 2 bytes of UTF-16 take 3 bytes of UTF-8 at most, and 4 bytes (surrogate pairs) take 4
+        let wanted = ((buffer.len() - *end) / 3 * 2).max(4);
+        let pending = self.raw.len();
+        self.raw.resize(pending + wanted, 0);
+        let read = inner.read(&mut self.raw[pending..]);
+        self.raw.truncate(pending + *read.as_ref().unwrap_or(&0));
+        if let Ok(n) = read {
+            self.transcode(buffer, end, n == 0);
+        }
+        read
+    }
+
+    /// Transcodes the complete characters of `raw` at `buffer[*end..]`, growing the buffer if needed.
+    /// With `last`, incomplete characters are transcoded too, as U+FFFD.
+    fn transcode(&mut self, buffer: &mut Vec<u8>, end: &mut usize, last: bool) {
+        let mut len = if last { self.raw.len() } else { self.raw.len() & !1 };
+        if !last && len >= 2 {
+            let pair = [self.raw[len - 2], self.raw[len - 1]];
+            let unit = if self.encoding == Encoding::Utf16Be {
+                u16::from_be_bytes(pair)
+            } else {
+                u16::from_le_bytes(pair)
+            };
+            // A high surrogate waits for the second half of its pair
+            if (0xD800..0xDC00).contains(&unit) {
+                len -= 2;
+            }
+        }
+        let text = self.encoding.decode(&self.raw[..len]);
+        if buffer.len() < *end + text.len() {
+            buffer.resize(*end + text.len(), 0);
+        }
+        buffer[*end..*end + text.len()].copy_from_slice(text.as_bytes());
+        *end += text.len();
+        self.raw.drain(..len);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csv::reader_stream::CsvReaderStream;
+    use crate::decoders::decoders::Encoding;
+    use crate::models::cancel_token::{CancelToken, ScanOutcome};
+    use crate::models::csv_config::CsvConfig;
+    use crate::models::delimiter::Delimiter;
+    use crate::models::record_terminator::RecordTerminator;
+    use std::io::{Cursor, Read};
+
+    /// Reader returning at most `step` bytes on each call, to force records across buffer refills.
+    struct Trickle<'a> {
+        data: &'a [u8],
+        step: usize,
+    }
+
+    impl Read for Trickle<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            let n = self.step.min(buf.len()).min(self.data.len());
+            buf[..n].copy_from_slice(&self.data[..n]);
+            self.data = &self.data[n..];
+            Ok(n)
+        }
+    }
+
+    fn collect<R: Read>(reader: &mut CsvReaderStream<R>) -> Vec<Vec<u8>> {
+        let mut rows = Vec::new();
+        while let Some(row) = reader.next_raw().expect("read") {
+            rows.push(row.get_slice().to_vec());
+        }
+        rows
+    }
+
+    #[test]
+    fn test_stream_records_across_refills() {
+        let cfg = CsvConfig {
+            delimiter: b',',
+            string_separator: b'"',
+            ..CsvConfig::default()
+        };
+        let data = b"1,\"Main St\r\nApt 4\",x\r\n2,\"say \"\"hi\"\"\",y\r\n3,last,z";
+        for step in 1..8 {
+            let mut reader = CsvReaderStream::with_capacity(Trickle { data, step }, &cfg, 4).expect("open");
+            let expected: Vec<&[u8]> = vec![b"1,\"Main St\r\nApt 4\",x", b"2,\"say \"\"hi\"\"\",y", b"3,last,z"];
+            assert_eq!(collect(&mut reader), expected, "step {}", step);
+        }
+    }
+
+    #[test]
+    fn test_stream_long_quoted_record_on_every_backend() {
+        let mut data = b"1,\"".to_vec();
+        for i in 0..40 {
+            data.extend_from_slice(if i % 3 == 0 { b"line\r\n\"\"" } else { b"abcdefgh" });
+        }
+        data.extend_from_slice(b"\",x\r\n2,y\r");
+        for force_memcach3 in [false, true].iter().copied() {
+            let cfg = CsvConfig {
+                delimiter: b',',
+                string_separator: b'"',
+                force_memcach3,
+                ..CsvConfig::default()
+            };
+            for step in [1, 7, 33].iter().copied() {
+                let mut reader = CsvReaderStream::with_capacity(Trickle { data: &data, step }, &cfg, 4).expect("open");
+                let expected: Vec<&[u8]> = vec![&data[..data.len() - 6], b"2,y"];
+                assert_eq!(collect(&mut reader), expected, "step {} memchr3 {}", step, force_memcach3);
+            }
+        }
+    }
+
+    #[test]
+    fn test_stream_byte_ranges() {
+        let cfg = CsvConfig {
+            delimiter: b',',
+            string_separator: b'"',
+            has_header: true,
+            ..CsvConfig::default()
+        };
+        let data = b"\xEF\xBB\xBFid,note\n1,\"a\nb\"\n22,ccc\n333,d";
+        for step in 1..6 {
+            let mut reader = CsvReaderStream::with_capacity(Trickle { data, step }, &cfg, 4).expect("open");
+            let mut ranges = Vec::new();
+            while let Some(row) = reader.next_raw().expect("read") {
+                assert_eq!(&data[row.get_byte_range()], row.get_slice());
+                ranges.push((row.get_record_number(), row.get_byte_range()));
+            }
+            assert_eq!(ranges, vec![(2, 11..18), (3, 19..25), (4, 26..31)], "step {}", step);
+        }
+    }
+
+    #[test]
+    fn test_stream_for_each_until() {
+        let cfg = CsvConfig {
+            delimiter: b',',
+            ..CsvConfig::default()
+        };
+        let data = b"1,a\n2,b\n3,c\n";
+        let mut reader = CsvReaderStream::with_capacity(Trickle { data, step: 2 }, &cfg, 4).expect("open");
+        let outcome = reader.for_each_until(&CancelToken::new(), |row| {
+            if row.get_index(1).get_slice() == b"b" {
+                std::ops::ControlFlow::Break(())
+            } else {
+                std::ops::ControlFlow::Continue(())
+            }
+        });
+        assert_eq!(outcome.expect("read"), ScanOutcome::Cancelled);
+        let mut rest = Vec::new();
+        let outcome = reader.for_each_until(&CancelToken::new(), |row| {
+            rest.push(row.get_slice().to_vec());
+            std::ops::ControlFlow::Continue(())
+        });
+        assert_eq!(outcome.expect("read"), ScanOutcome::Completed);
+        assert_eq!(rest, vec![b"3,c".to_vec()]);
+    }
+
+    #[test]
+    fn test_stream_header_and_fields() {
+        let cfg = CsvConfig {
+            delimiter: b';',
+            has_header: true,
+            ..CsvConfig::default()
+        };
+        let data = b"id;city\n1;Paris\n2;Lima\n";
+        let mut reader = CsvReaderStream::with_capacity(Trickle { data, step: 3 }, &cfg, 8).expect("open");
+        assert_eq!(reader.get_header().expect("header").names(), &["id", "city"]);
+        let row = reader.next_raw().expect("read").expect("row");
+        assert_eq!(row.get_by_name("city").get_utf8_as_str(), "Paris");
+        reader.advance_next().expect("read");
+        assert!(reader.next_raw().expect("read").is_none());
+    }
+
+    #[test]
+    fn test_stream_custom_terminator() {
+        let cfg = CsvConfig {
+            delimiter: b',',
+            string_separator: b'"',
+            ..CsvConfig::default()
+        }
+        .with_terminator(RecordTerminator::Custom(Delimiter::new(b"<EOR>").unwrap()));
+        let data = b"1,a\nb<EOR>2,\"<EOR>\"<EOR>3,c";
+        for step in 1..6 {
+            let mut reader = CsvReaderStream::with_capacity(Trickle { data, step }, &cfg, 4).expect("open");
+            let expected: Vec<&[u8]> = vec![b"1,a\nb", b"2,\"<EOR>\"", b"3,c"];
+            assert_eq!(collect(&mut reader), expected, "step {}", step);
+        }
+    }
+
+    #[test]
+    fn test_stream_comments_and_blank_lines() {
+        let cfg = CsvConfig {
+            delimiter: b',',
+            string_separator: b'"',
+            ..CsvConfig::default()
+        }
+        .with_comment_prefix(Delimiter::new(b"//").unwrap())
+        .with_skip_empty_records(true);
+        let data = b"// don't \"quote\n1,a\n\n// end\n2,b";
+        for step in 1..6 {
+            let mut reader = CsvReaderStream::with_capacity(Trickle { data, step }, &cfg, 4).expect("open");
+            assert_eq!(reader.get_header().expect("header").len(), 2);
+            let row = reader.next_raw().expect("read").expect("first");
+            assert_eq!(row.get_record_number(), 2);
+            let expected: Vec<&[u8]> = vec![b"2,b"];
+            assert_eq!(collect(&mut reader), expected, "step {}", step);
+        }
+    }
+
+    #[test]
+    fn test_stream_utf16() {
+        let text = "\u{FEFF}id\tname\r\n1\t\"José, \"\"Pepe\"\"\"\r\n2\t日本 😀\r\n";
+        let cfg = CsvConfig {
+            delimiter: b'\t',
+            string_separator: b'"',
+            has_header: true,
+            ..CsvConfig::default()
+        };
+        for encoding in [Encoding::Utf16Le, Encoding::Utf16Be].iter() {
+            let data = encoding.encode(text);
+            for step in 1..6 {
+                // The BOM selects the encoding
+                let mut reader = CsvReaderStream::with_capacity(Trickle { data: &data, step }, &cfg, 4).expect("open");
+                assert_eq!(reader.get_config().encoding, Encoding::Utf8);
+                assert_eq!(reader.get_header().expect("header").names(), &["id", "name"], "step {}", step);
+                let row = reader.next_raw().expect("read").expect("row");
+                assert_eq!(row.get_by_name("id").get_i32(), 1);
+                assert_eq!(row.get_by_name("name").get_as_string(Encoding::Utf8), "José, \"Pepe\"");
+                let row = reader.next_raw().expect("read").expect("row");
+                assert_eq!(row.get_by_name("name").get_utf8_as_str(), "日本 😀", "step {}", step);
+                assert!(reader.next_raw().expect("read").is_none());
+            }
+        }
+    }
+
+    #[test]
+    fn test_stream_skips_bom() {
+        let cfg = CsvConfig {
+            has_header: true,
+            ..CsvConfig::default()
+        };
+        let data = b"\xEF\xBB\xBFid;city\n1;Paris\n";
+        for step in 1..4 {
+            let mut reader = CsvReaderStream::with_capacity(Trickle { data, step }, &cfg, 2).expect("open");
+            assert_eq!(reader.get_header().expect("header").names(), &["id", "city"], "step {}", step);
+            let expected: Vec<&[u8]> = vec![b"1;Paris"];
+            assert_eq!(collect(&mut reader), expected, "step {}", step);
+        }
+    }
+
+    #[test]
+    fn test_stream_empty_input() {
+        let mut reader = CsvReaderStream::new(Cursor::new(Vec::new()), &CsvConfig::default()).expect("open");
+        assert!(reader.get_header().is_none());
+        assert!(reader.next_raw().expect("read").is_none());
+    }
+}
@@ -0,0 +1,335 @@
+use crate::decoders::decoders::Encoding;
+use crate::decoders::detect::detect_utf16;
+use crate::helpers::bytes_helper::next_record;
+use crate::models::csv_config::CsvConfig;
+use crate::models::csv_error::CsvError;
+use crate::models::record_terminator::RecordTerminator;
+use crate::models::row::Row;
+use crate::models::trim::Trim;
+use std::path::Path;
+
+/// Bytes sampled from the head of the input.
+pub const DEFAULT_SAMPLE_SIZE: usize = 64 * 1024;
+/// Field delimiters tried by the sniffer, by order of preference.
+pub const CANDIDATE_DELIMITERS: [u8; 4] = [b',', b';', b'\t', b'|'];
+/// String separators tried by the sniffer, by order of preference.
+pub const CANDIDATE_QUOTES: [u8; 2] = [b'"', b'\''];
+/// Max number of records sampled.
+const SAMPLE_RECORDS: usize = 100;
+
+/// ## Dialect struct
+/// - Result of sniffing a CSV input.
+/// - `config` is ready to use with the readers: delimiter, `string_separator`, line break, terminator and header.
+/// - `confidence` goes from `0.0` (guess) to `1.0` (every sampled record agrees, and no other delimiter fits).
+#[derive(Debug, Clone)]
+pub struct Dialect {
+    /// Detected configuration. Fields not sniffed keep the `CsvConfig::default()` values.
+    pub config: CsvConfig,
+    /// Confidence score of the detected delimiter, between `0.0` and `1.0`.
+    pub confidence: f32,
+}
+
+/// ## Sniff
+/// - Detects the dialect from the first 64KB of a slice (`DEFAULT_SAMPLE_SIZE`).
+/// ### Code Example:
+/// ```
+/// use csv_lib::csv::sniffer::sniff;
+///
+/// let dialect = sniff(b"id|name|price\r\n1|\"Smith | Co\"|2.5\r\n2|Lee|3\r\n");
+/// assert_eq!(dialect.config.delimiter, b'|');
+/// assert_eq!(dialect.config.string_separator, b'"');
+/// assert!(dialect.config.has_header);
+/// assert!(dialect.confidence > 0.9);
+/// ```
+pub fn sniff(slice: &[u8]) -> Dialect {
+    sniff_with_sample_size(slice, DEFAULT_SAMPLE_SIZE)
+}
+
+/// ## Sniff With Sample Size
+/// - Detects the dialect from the first `sample_size` bytes of a slice.
+/// - A record cut by the end of the sample is not used.
+/// - UTF-16 input (with BOM, or detected by its zero bytes) is sniffed on its UTF-8 transcoding, and `encoding`
+///   is set to `Utf16Le` / `Utf16Be`.
+pub fn sniff_with_sample_size(slice: &[u8], sample_size: usize) -> Dialect {
+    let bom = Encoding::from_bom(slice);
+    let data = &slice[bom.map_or(0, |(_, len)| len)..];
+    let truncated = data.len() > sample_size;
+    let sample = &data[..data.len().min(sample_size)];
+    let utf16 = match bom {
+        Some((encoding, _)) => Some(encoding).filter(|encoding| encoding.is_utf16()),
+        None => detect_utf16(sample),
+    };
+    match utf16 {
+        Some(encoding) => {
+            let mut dialect = sniff_sample(encoding.decode(sample).as_bytes(), truncated);
+            dialect.config.encoding = encoding;
+            dialect
+        }
+        None => sniff_sample(sample, truncated),
+    }
+}
+
+/// ## Sniff File
+/// - Maps a file, and detects the dialect from its first 64KB.
+pub fn sniff_file<P: AsRef<Path>>(path: P) -> Result<Dialect, CsvError> {
+    let mmap = crate::csv::csv_reader::map_file(path)?;
+    Ok(sniff(&mmap))
+}
+
+//--------------------- INTERNAL ---------------------------------------------------------------//
+
+/// Detects the dialect of a byte oriented sample, without BOM.
+fn sniff_sample(sample: &[u8], truncated: bool) -> Dialect {
+    let quote = detect_quote(sample);
+    let breaks = count_line_breaks(sample, quote);
+    let line_break = if breaks.lf == 0 && breaks.crlf == 0 && breaks.cr > 0 { b'\r' } else { b'\n' };
+    // Bare `\n` next to `\r\n` records may be data inside unquoted fields
+    let mut terminators = vec![RecordTerminator::Any];
+    if breaks.crlf > 0 && breaks.lf > 0 {
+        terminators.push(RecordTerminator::Crlf);
+    }
+
+    let mut best: Option<(Candidate, f32)> = None;
+    for terminator in terminators {
+        let base = CsvConfig {
+            string_separator: quote,
+            line_break,
+            terminator,
+            skip_empty_records: true,
+            ..CsvConfig::default()
+        };
+        let mut scored: Vec<Candidate> = CANDIDATE_DELIMITERS
+            .iter()
+            .map(|d| score_delimiter(sample, truncated, &base, *d))
+            .collect();
+        let winner = scored
+            .iter()
+            .enumerate()
+            // Stable on ties: the first candidate wins
+            .fold(None, |acc: Option<(usize, f32)>, (i, c)| match acc {
+                Some((_, score)) if score >= c.consistency => acc,
+                _ => Some((i, c.consistency)),
+            })
+            .map_or(0, |(i, _)| i);
+        let candidate = scored.swap_remove(winner);
+        let runner_up = scored.iter().map(|c| c.consistency).fold(0.0f32, f32::max);
+        let mut confidence = candidate.consistency * (1.0 - 0.5 * runner_up);
+        if candidate.records < 2 {
+            confidence *= 0.5;
+        }
+        let better = match &best {
+            Some((_, current)) => confidence > *current,
+            None => true,
+        };
+        if better {
+            best = Some((candidate, confidence));
+        }
+    }
+
+    let (candidate, confidence) = match best {
+        Some(best) if best.0.consistency > 0.0 => best,
+        // Empty input, or a single column: nothing to detect
+        _ => {
+            return Dialect {
+                config: CsvConfig {
+                    string_separator: quote,
+                    line_break,
+                    ..CsvConfig::default()
+                },
+                confidence: 0.0,
+            }
+        }
+    };
+    let mut config = candidate.config;
+    config.skip_empty_records = false;
+    config.has_header = detect_header(sample, truncated, &config);
+    Dialect { config, confidence }
+}
+
+/// A delimiter tried by the sniffer.
+struct Candidate {
+    config: CsvConfig,
+    /// Share of the sampled records with the most common field count. `0.0` if that count is 1.
+    consistency: f32,
+    records: usize,
+}
+
+struct LineBreaks {
+    crlf: usize,
+    lf: usize,
+    cr: usize,
+}
+
+/// Picks the string separator found more times at the start or end of a field.
+/// Returns `0u8` if no quote is used.
+fn detect_quote(sample: &[u8]) -> u8 {
+    let is_boundary = |b: Option<&u8>| match b {
+        None => true,
+        Some(b) => *b == b'\r' || *b == b'\n' || CANDIDATE_DELIMITERS.contains(b),
+    };
+    let mut best = (0u8, 0usize);
+    for quote in CANDIDATE_QUOTES.iter() {
+        let hits = sample
+            .iter()
+            .enumerate()
+            .filter(|(i, b)| {
+                **b == *quote
+                    && (is_boundary(i.checked_sub(1).and_then(|p| sample.get(p))) || is_boundary(sample.get(i + 1)))
+            })
+            .count();
+        if hits > best.1 {
+            best = (*quote, hits);
+        }
+    }
+    best.0
+}
+
+/// Counts the line breaks outside quotes.
+fn count_line_breaks(sample: &[u8], quote: u8) -> LineBreaks {
+    let mut breaks = LineBreaks { crlf: 0, lf: 0, cr: 0 };
+    let mut in_quotes = false;
+    let mut i = 0usize;
+    while i < sample.len() {
+        match sample[i] {
+            b if quote != 0 && b == quote => in_quotes = !in_quotes,
+            b'\r' if !in_quotes => {
+                if sample.get(i + 1) == Some(&b'\n') {
+                    breaks.crlf += 1;
+                    i += 1;
+                } else {
+                    breaks.cr += 1;
+                }
+            }
+            b'\n' if !in_quotes => breaks.lf += 1,
+            _ => {}
+        }
+        i += 1;
+    }
+    breaks
+}
+
+/// Collects the sampled records. A record cut by the end of a truncated sample is dropped.
+fn sample_records<'a>(sample: &'a [u8], truncated: bool, config: &CsvConfig) -> Vec<Row<'a>> {
+    let mut rows = Vec::new();
+    let mut cursor = 0usize;
+    while let Some(bounds) = next_record(sample, cursor, config) {
+        if truncated && bounds.next >= sample.len() {
+            break;
+        }
+        rows.push(Row::new(&sample[bounds.start..bounds.end], config.field_delimiter(), config.string_separator, false));
+        if rows.len() == SAMPLE_RECORDS {
+            break;
+        }
+        cursor = bounds.next;
+    }
+    rows
+}
+
+/// Scores a delimiter by how many records share the most common field count.
+fn score_delimiter(sample: &[u8], truncated: bool, base: &CsvConfig, delimiter: u8) -> Candidate {
+    let config = base.clone().with_delimiter(delimiter);
+    let rows = sample_records(sample, truncated, &config);
+    let mut counts: Vec<(usize, usize)> = Vec::new();
+    for row in rows.iter() {
+        let fields = row.field_count();
+        match counts.iter_mut().find(|(count, _)| *count == fields) {
+            Some((_, hits)) => *hits += 1,
+            None => counts.push((fields, 1)),
+        }
+    }
+    let consistency = match counts.iter().max_by_key(|(_, hits)| *hits) {
+        Some((fields, hits)) if *fields > 1 => *hits as f32 / rows.len() as f32,
+        _ => 0.0,
+    };
+    Candidate {
+        config,
+        consistency,
+        records: rows.len(),
+    }
+}
+
+/// Returns `true` if the value looks like a number (`-1`, `2.5`, `1,5`).
+fn is_number(value: &[u8]) -> bool {
+    value.iter().any(u8::is_ascii_digit)
+        && value
+            .iter()
+            .all(|b| b.is_ascii_digit() || *b == b'.' || *b == b',' || *b == b'-' || *b == b'+')
+}
+
+/// Votes, column by column, if the first record is a header.
+/// - Numeric columns: a text value on the first record votes for a header, a number votes against.
+/// - Text columns with a fixed length: a first value with another length votes for a header.
+fn detect_header(sample: &[u8], truncated: bool, config: &CsvConfig) -> bool {
+    let rows = sample_records(sample, truncated, config);
+    let (first, data) = match rows.split_first() {
+        Some((first, data)) if !data.is_empty() => (first, data),
+        _ => return false,
+    };
+    let columns = first.field_count();
+    let value = |row: &Row<'_>, i: usize| -> Vec<u8> { Trim::Both.apply(row.get_index(i).get_cleaned_slice()).to_vec() };
+    let mut votes = 0i32;
+    for i in 0..columns {
+        let title = value(first, i);
+        let values: Vec<Vec<u8>> = data
+            .iter()
+            .filter(|row| row.field_count() == columns)
+            .map(|row| value(row, i))
+            .filter(|v| !v.is_empty())
+            .collect();
+        if values.is_empty() {
+            continue;
+        }
+        if values.iter().all(|v| is_number(v)) {
+            votes += if is_number(&title) { -1 } else { 1 };
+        } else if values.len() > 1 && values.iter().all(|v| v.len() == values[0].len()) {
+            votes += if title.len() != values[0].len() { 1 } else { -1 };
+        }
+    }
+    votes > 0
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csv::sniffer::{sniff, sniff_with_sample_size};
+    use crate::models::record_terminator::RecordTerminator;
+
+    #[test]
+    fn test_sniff_delimiters() {
+        let semicolon = sniff(b"name;price\nbread;1,5\nmilk;2,25\nrice;10\n");
+        assert_eq!(semicolon.config.delimiter, b';');
+        assert!(semicolon.config.has_header);
+        assert!(semicolon.confidence > 0.5);
+
+        let tab = sniff(b"1\tParis\t2.1\n2\tLima\t9.7\n");
+        assert_eq!(tab.config.delimiter, b'\t');
+        assert_eq!(tab.config.string_separator, 0);
+        assert!(!tab.config.has_header);
+    }
+
+    #[test]
+    fn test_sniff_quotes_and_terminators() {
+        // Bare `\n` inside an unquoted field, with `\r\n` records
+        let data = b"'id','note'\r\n1,'a, b'\r\n2,line\nnext\r\n3,'x'\r\n";
+        let dialect = sniff(data);
+        assert_eq!(dialect.config.delimiter, b',');
+        assert_eq!(dialect.config.string_separator, b'\'');
+        assert_eq!(dialect.config.terminator, RecordTerminator::Crlf);
+        assert!(dialect.config.has_header);
+
+        let old_mac = sniff(b"a|b\r1|2\r");
+        assert_eq!(old_mac.config.line_break, b'\r');
+        assert_eq!(old_mac.config.delimiter, b'|');
+    }
+
+    #[test]
+    fn test_sniff_truncated_sample_and_fallback() {
+        // The cut record (`3,c,`) would have another field count
+        let dialect = sniff_with_sample_size(b"1,a,x\n2,b,y\n3,c,z\n", 16);
+        assert_eq!(dialect.config.delimiter, b',');
+        assert!(dialect.confidence > 0.9);
+
+        let single_column = sniff(b"alpha\nbeta\n");
+        assert_eq!(single_column.confidence, 0.0);
+        assert_eq!(sniff(b"").confidence, 0.0);
+    }
+}
@@ -10,12 +10,34 @@ use std::arch::aarch64::*;
 #[cfg(target_arch = "aarch64")]
 use std::arch::is_aarch64_feature_detected;
 
-/// Scalar Big5 decoder (minimal version).
-/// Passes ASCII, replaces valid multibyte pairs with '�' for now.
+use crate::decoders::big5_table::{BIG5_COMBINING, BIG5_TABLE, BIG5_TRAIL_COUNT};
 
+/// Index of a two byte sequence inside `BIG5_TABLE`, if the lead and trail bytes are valid.
+#[inline(always)]
+pub(crate) fn big5_index(lead: u8, trail: u8) -> Option<usize> {
+    if !(0x87..=0xFE).contains(&lead) {
+        return None;
+    }
+    let trail = match trail {
+        0x40..=0x7E => trail - 0x40,
+        0xA1..=0xFE => trail - 0x62,
+        _ => return None,
+    };
+    Some((lead - 0x87) as usize * BIG5_TRAIL_COUNT + trail as usize)
+}
+
+/// Scalar Big5 (Big5-HKSCS) decoder.
+/// - Two byte sequences use the Big5-HKSCS table. Some HKSCS characters are outside the BMP, and four
+///   sequences decode to a letter with a combining mark.
+/// - Invalid sequences are replaced with '�'. An ASCII byte after a lead byte is read again.
 #[allow(dead_code)]
 pub(crate) fn decode_big5_scalar(input: &[u8]) -> Cow<'_, str> {
-    let mut out = String::with_capacity(input.len());
+    if let Ok(ascii) = std::str::from_utf8(input) {
+        if ascii.is_ascii() {
+            return Cow::Borrowed(ascii);
+        }
+    }
+    let mut out = String::with_capacity(input.len() * 3 / 2);
     let mut i = 0;
 
     while i < input.len() {
@@ -23,20 +45,24 @@ pub(crate) fn decode_big5_scalar(input: &[u8]) -> Cow<'_, str> {
         if b <= 0x7F {
             out.push(b as char);
             i += 1;
-        } else if b >= 0x81 && b <= 0xFE {
-            if i + 1 < input.len() {
-                let next = input[i + 1];
-                if (next >= 0x40 && next <= 0x7E) || (next >= 0xA1 && next <= 0xFE) {
-                    out.push('�'); // Placeholder for now
-                    i += 2;
-                    continue;
-                }
-            }
-            out.push('�');
+            continue;
+        }
+        if !(0x81..=0xFE).contains(&b) {
+            out.push('\u{FFFD}');
             i += 1;
+            continue;
+        }
+        let second = input.get(i + 1).copied().unwrap_or(0);
+        let code_point = big5_index(b, second).map_or(0, |index| BIG5_TABLE[index]);
+        if let Some(ch) = char::from_u32(code_point).filter(|_| code_point != 0) {
+            out.push(ch);
+            i += 2;
+        } else if let Some((_, pair)) = BIG5_COMBINING.iter().find(|(bytes, _)| *bytes == [b, second]) {
+            out.extend(pair.iter());
+            i += 2;
         } else {
-            out.push('�');
-            i += 1;
+            out.push('\u{FFFD}');
+            i += if second < 0x80 { 1 } else { 2 };
         }
     }
 
@@ -88,7 +114,12 @@ mod tests {
     #[test]
     fn test_decode_big5_scalar_doublebyte() {
         let decoded = decode_big5_scalar(VALID_BIG5_DOUBLE);
-        assert_eq!(decoded.as_ref(), "��");
+        assert_eq!(decoded.as_ref(), "一乙");
+        // 中文;資料, HKSCS outside the BMP, and a combining sequence
+        let decoded = decode_big5_scalar(&[0xA4, 0xA4, 0xA4, 0xE5, b';', 0xB8, 0xEA, 0xAE, 0xC6, 0x87, 0x40, 0x88, 0x62]);
+        assert_eq!(decoded.as_ref(), "中文;資料\u{43F0}\u{CA}\u{304}");
+        // Lead byte followed by ASCII
+        assert_eq!(decode_big5_scalar(&[0xA4, b';']).as_ref(), "�;");
     }
 
     #[test]
@@ -0,0 +1,1904 @@
+//! Big5 (Big5-HKSCS) mapping table.
+//! Generated from the Big5-HKSCS mappings: Big5, Eten extensions and the HKSCS characters, some of them
+//! outside the BMP. Do not edit by hand.
+
+/// Lead bytes go from 0x87 to 0xFE.
+pub(crate) const BIG5_LEAD_COUNT: usize = 120;
+/// Trail bytes go from 0x40 to 0x7E, and from 0xA1 to 0xFE.
+pub(crate) const BIG5_TRAIL_COUNT: usize = 157;
+
+/// Sequences decoded as two code points (a letter with a combining mark).
+pub(crate) static BIG5_COMBINING: [([u8; 2], [char; 2]); 4] = [
+    ([0x88, 0x62], ['\u{00CA}', '\u{0304}']),
+    ([0x88, 0x64], ['\u{00CA}', '\u{030C}']),
+    ([0x88, 0xA3], ['\u{00EA}', '\u{0304}']),
+    ([0x88, 0xA5], ['\u{00EA}', '\u{030C}']),
+];
+
+/// Two byte code points, indexed by `lead offset * 157 + trail offset`. `0` marks unmapped sequences.
+pub(crate) static BIG5_TABLE: [u32; BIG5_LEAD_COUNT * BIG5_TRAIL_COUNT] = [
+    0x043F0, 0x04C32, 0x04603, 0x045A6, 0x04578, 0x27267, 0x04D77, 0x045B3, 0x27CB1, 0x04CE2,
+    0x27CC5, 0x03B95, 0x04736, 0x04744, 0x04C47, 0x04C40, 0x242BF, 0x23617, 0x27352, 0x26E8B,
+    0x270D2, 0x04C57, 0x2A351, 0x0474F, 0x045DA, 0x04C85, 0x27C6C, 0x04D07, 0x04AA4, 0x046A1,
+    0x26B23, 0x07225, 0x25A54, 0x21A63, 0x23E06, 0x23F61, 0x0664D, 0x056FB, 0x00000, 0x07D95,
+    0x0591D, 0x28BB9, 0x03DF4, 0x09734, 0x27BEF, 0x05BDB, 0x21D5E, 0x05AA4, 0x03625, 0x29EB0,
+    0x05AD1, 0x05BB7, 0x05CFC, 0x0676E, 0x08593, 0x29945, 0x07461, 0x0749D, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x031C0, 0x031C1, 0x031C2,
+    0x031C3, 0x031C4, 0x2010C, 0x031C5, 0x200D1, 0x200CD, 0x031C6, 0x031C7, 0x200CB, 0x21FE8,
+    0x031C8, 0x200CA, 0x031C9, 0x031CA, 0x031CB, 0x031CC, 0x2010E, 0x031CD, 0x031CE, 0x00100,
+    0x000C1, 0x001CD, 0x000C0, 0x00112, 0x000C9, 0x0011A, 0x000C8, 0x0014C, 0x000D3, 0x001D1,
+    0x000D2, 0x00000, 0x01EBE, 0x00000, 0x01EC0, 0x000CA, 0x00101, 0x000E1, 0x001CE, 0x000E0,
+    0x00251, 0x00113, 0x000E9, 0x0011B, 0x000E8, 0x0012B, 0x000ED, 0x001D0, 0x000EC, 0x0014D,
+    0x000F3, 0x001D2, 0x000F2, 0x0016B, 0x000FA, 0x001D4, 0x000F9, 0x001D6, 0x001D8, 0x001DA,
+    0x001DC, 0x000FC, 0x00000, 0x01EBF, 0x00000, 0x01EC1, 0x000EA, 0x00261, 0x023DA, 0x023DB,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x2A3A9, 0x21145, 0x00000, 0x0650A, 0x00000, 0x00000,
+    0x04E3D, 0x06EDD, 0x09D4E, 0x091DF, 0x00000, 0x00000, 0x27735, 0x06491, 0x04F1A, 0x04F28,
+    0x04FA8, 0x05156, 0x05174, 0x0519C, 0x051E4, 0x052A1, 0x052A8, 0x0533B, 0x0534E, 0x053D1,
+    0x053D8, 0x056E2, 0x058F0, 0x05904, 0x05907, 0x05932, 0x05934, 0x05B66, 0x05B9E, 0x05B9F,
+    0x05C9A, 0x05E86, 0x0603B, 0x06589, 0x067FE, 0x06804, 0x06865, 0x06D4E, 0x070BC, 0x07535,
+    0x07EA4, 0x07EAC, 0x07EBA, 0x07EC7, 0x07ECF, 0x07EDF, 0x07F06, 0x07F37, 0x0827A, 0x082CF,
+    0x0836F, 0x089C6, 0x08BBE, 0x08BE2, 0x08F66, 0x08F67, 0x08F6E, 0x07411, 0x07CFC, 0x07DCD,
+    0x06946, 0x07AC9, 0x05227, 0x00000, 0x00000, 0x00000, 0x00000, 0x0918C, 0x078B8, 0x0915E,
+    0x080BC, 0x00000, 0x08D0B, 0x080F6, 0x209E7, 0x00000, 0x00000, 0x0809F, 0x09EC7, 0x04CCD,
+    0x09DC9, 0x09E0C, 0x04C3E, 0x29DF6, 0x2700E, 0x09E0A, 0x2A133, 0x035C1, 0x00000, 0x06E9A,
+    0x0823E, 0x07519, 0x00000, 0x04911, 0x09A6C, 0x09A8F, 0x09F99, 0x07987, 0x2846C, 0x21DCA,
+    0x205D0, 0x22AE6, 0x04E24, 0x04E81, 0x04E80, 0x04E87, 0x04EBF, 0x04EEB, 0x04F37, 0x0344C,
+    0x04FBD, 0x03E48, 0x05003, 0x05088, 0x0347D, 0x03493, 0x034A5, 0x05186, 0x05905, 0x051DB,
+    0x051FC, 0x05205, 0x04E89, 0x05279, 0x05290, 0x05327, 0x035C7, 0x053A9, 0x03551, 0x053B0,
+    0x03553, 0x053C2, 0x05423, 0x0356D, 0x03572, 0x03681, 0x05493, 0x054A3, 0x054B4, 0x054B9,
+    0x054D0, 0x054EF, 0x05518, 0x05523, 0x05528, 0x03598, 0x0553F, 0x035A5, 0x035BF, 0x055D7,
+    0x035C5, 0x27D84, 0x05525, 0x00000, 0x20C42, 0x20D15, 0x2512B, 0x05590, 0x22CC6, 0x039EC,
+    0x20341, 0x08E46, 0x24DB8, 0x294E5, 0x04053, 0x280BE, 0x0777A, 0x22C38, 0x03A34, 0x047D5,
+    0x2815D, 0x269F2, 0x24DEA, 0x064DD, 0x20D7C, 0x20FB4, 0x20CD5, 0x210F4, 0x0648D, 0x08E7E,
+    0x20E96, 0x20C0B, 0x20F64, 0x22CA9, 0x28256, 0x244D3, 0x00000, 0x20D46, 0x29A4D, 0x280E9,
+    0x047F4, 0x24EA7, 0x22CC2, 0x09AB2, 0x03A67, 0x295F4, 0x03FED, 0x03506, 0x252C7, 0x297D4,
+    0x278C8, 0x22D44, 0x09D6E, 0x09815, 0x00000, 0x043D9, 0x260A5, 0x064B4, 0x054E3, 0x22D4C,
+    0x22BCA, 0x21077, 0x039FB, 0x2106F, 0x266DA, 0x26716, 0x279A0, 0x064EA, 0x25052, 0x20C43,
+    0x08E68, 0x221A1, 0x28B4C, 0x20731, 0x00000, 0x0480B, 0x201A9, 0x03FFA, 0x05873, 0x22D8D,
+    0x00000, 0x245C8, 0x204FC, 0x26097, 0x20F4C, 0x20D96, 0x05579, 0x040BB, 0x043BA, 0x00000,
+    0x04AB4, 0x22A66, 0x2109D, 0x081AA, 0x098F5, 0x20D9C, 0x06379, 0x039FE, 0x22775, 0x08DC0,
+    0x056A1, 0x0647C, 0x03E43, 0x00000, 0x2A601, 0x20E09, 0x22ACF, 0x22CC9, 0x00000, 0x210C8,
+    0x239C2, 0x03992, 0x03A06, 0x2829B, 0x03578, 0x25E49, 0x220C7, 0x05652, 0x20F31, 0x22CB2,
+    0x29720, 0x034BC, 0x06C3D, 0x24E3B, 0x00000, 0x00000, 0x27574, 0x22E8B, 0x22208, 0x2A65B,
+    0x28CCD, 0x20E7A, 0x20C34, 0x2681C, 0x07F93, 0x210CF, 0x22803, 0x22939, 0x035FB, 0x251E3,
+    0x20E8C, 0x20F8D, 0x20EAA, 0x03F93, 0x20F30, 0x20D47, 0x2114F, 0x20E4C, 0x00000, 0x20EAB,
+    0x20BA9, 0x20D48, 0x210C0, 0x2113D, 0x03FF9, 0x22696, 0x06432, 0x20FAD, 0x233F4, 0x27639,
+    0x22BCE, 0x20D7E, 0x20D7F, 0x22C51, 0x22C55, 0x03A18, 0x20E98, 0x210C7, 0x20F2E, 0x2A632,
+    0x26B50, 0x28CD2, 0x28D99, 0x28CCA, 0x095AA, 0x054CC, 0x082C4, 0x055B9, 0x00000, 0x29EC3,
+    0x09C26, 0x09AB6, 0x2775E, 0x22DEE, 0x07140, 0x0816D, 0x080EC, 0x05C1C, 0x26572, 0x08134,
+    0x03797, 0x0535F, 0x280BD, 0x091B6, 0x20EFA, 0x20E0F, 0x20E77, 0x20EFB, 0x035DD, 0x24DEB,
+    0x03609, 0x20CD6, 0x056AF, 0x227B5, 0x210C9, 0x20E10, 0x20E78, 0x21078, 0x21148, 0x28207,
+    0x21455, 0x20E79, 0x24E50, 0x22DA4, 0x05A54, 0x2101D, 0x2101E, 0x210F5, 0x210F6, 0x0579C,
+    0x20E11, 0x27694, 0x282CD, 0x20FB5, 0x20E7B, 0x2517E, 0x03703, 0x20FB6, 0x21180, 0x252D8,
+    0x2A2BD, 0x249DA, 0x2183A, 0x24177, 0x2827C, 0x05899, 0x05268, 0x0361A, 0x2573D, 0x07BB2,
+    0x05B68, 0x04800, 0x04B2C, 0x09F27, 0x049E7, 0x09C1F, 0x09B8D, 0x25B74, 0x2313D, 0x055FB,
+    0x035F2, 0x05689, 0x04E28, 0x05902, 0x21BC1, 0x2F878, 0x09751, 0x20086, 0x04E5B, 0x04EBB,
+    0x0353E, 0x05C23, 0x05F51, 0x05FC4, 0x038FA, 0x0624C, 0x06535, 0x06B7A, 0x06C35, 0x06C3A,
+    0x0706C, 0x0722B, 0x04E2C, 0x072AD, 0x248E9, 0x07F52, 0x0793B, 0x07CF9, 0x07F53, 0x2626A,
+    0x034C1, 0x00000, 0x2634B, 0x08002, 0x08080, 0x26612, 0x26951, 0x0535D, 0x08864, 0x089C1,
+    0x278B2, 0x08BA0, 0x08D1D, 0x09485, 0x09578, 0x0957F, 0x095E8, 0x28E0F, 0x097E6, 0x09875,
+    0x098CE, 0x098DE, 0x09963, 0x29810, 0x09C7C, 0x09E1F, 0x09EC4, 0x06B6F, 0x0F907, 0x04E37,
+    0x20087, 0x0961D, 0x06237, 0x094A2, 0x00000, 0x0503B, 0x06DFE, 0x29C73, 0x09FA6, 0x03DC9,
+    0x0888F, 0x2414E, 0x07077, 0x05CF5, 0x04B20, 0x251CD, 0x03559, 0x25D30, 0x06122, 0x28A32,
+    0x08FA7, 0x091F6, 0x07191, 0x06719, 0x073BA, 0x23281, 0x2A107, 0x03C8B, 0x21980, 0x04B10,
+    0x078E4, 0x07402, 0x051AE, 0x2870F, 0x04009, 0x06A63, 0x2A2BA, 0x04223, 0x0860F, 0x20A6F,
+    0x07A2A, 0x29947, 0x28AEA, 0x09755, 0x0704D, 0x05324, 0x2207E, 0x093F4, 0x076D9, 0x289E3,
+    0x09FA7, 0x077DD, 0x04EA3, 0x04FF0, 0x050BC, 0x04E2F, 0x04F17, 0x09FA8, 0x05434, 0x07D8B,
+    0x05892, 0x058D0, 0x21DB6, 0x05E92, 0x05E99, 0x05FC2, 0x22712, 0x0658B, 0x233F9, 0x06919,
+    0x06A43, 0x23C63, 0x06CFF, 0x00000, 0x07200, 0x24505, 0x0738C, 0x03EDB, 0x24A13, 0x05B15,
+    0x074B9, 0x08B83, 0x25CA4, 0x25695, 0x07A93, 0x07BEC, 0x07CC3, 0x07E6C, 0x082F8, 0x08597,
+    0x09FA9, 0x08890, 0x09FAA, 0x08EB9, 0x09FAB, 0x08FCF, 0x0855F, 0x099E0, 0x09221, 0x09FAC,
+    0x28DB9, 0x2143F, 0x04071, 0x042A2, 0x05A1A, 0x00000, 0x00000, 0x00000, 0x09868, 0x0676B,
+    0x04276, 0x0573D, 0x00000, 0x085D6, 0x2497B, 0x082BF, 0x2710D, 0x04C81, 0x26D74, 0x05D7B,
+    0x26B15, 0x26FBE, 0x09FAD, 0x09FAE, 0x05B96, 0x09FAF, 0x066E7, 0x07E5B, 0x06E57, 0x079CA,
+    0x03D88, 0x044C3, 0x23256, 0x22796, 0x0439A, 0x04536, 0x00000, 0x05CD5, 0x23B1A, 0x08AF9,
+    0x05C78, 0x03D12, 0x23551, 0x05D78, 0x09FB2, 0x07157, 0x04558, 0x240EC, 0x21E23, 0x04C77,
+    0x03978, 0x0344A, 0x201A4, 0x26C41, 0x08ACC, 0x04FB4, 0x20239, 0x059BF, 0x0816C, 0x09856,
+    0x298FA, 0x05F3B, 0x20B9F, 0x00000, 0x221C1, 0x2896D, 0x04102, 0x046BB, 0x29079, 0x03F07,
+    0x09FB3, 0x2A1B5, 0x040F8, 0x037D6, 0x046F7, 0x26C46, 0x0417C, 0x286B2, 0x273FF, 0x0456D,
+    0x038D4, 0x2549A, 0x04561, 0x0451B, 0x04D89, 0x04C7B, 0x04D76, 0x045EA, 0x03FC8, 0x24B0F,
+    0x03661, 0x044DE, 0x044BD, 0x041ED, 0x05D3E, 0x05D48, 0x05D56, 0x03DFC, 0x0380F, 0x05DA4,
+    0x05DB9, 0x03820, 0x03838, 0x05E42, 0x05EBD, 0x05F25, 0x05F83, 0x03908, 0x03914, 0x0393F,
+    0x0394D, 0x060D7, 0x0613D, 0x05CE5, 0x03989, 0x061B7, 0x061B9, 0x061CF, 0x039B8, 0x0622C,
+    0x06290, 0x062E5, 0x06318, 0x039F8, 0x056B1, 0x03A03, 0x063E2, 0x063FB, 0x06407, 0x0645A,
+    0x03A4B, 0x064C0, 0x05D15, 0x05621, 0x09F9F, 0x03A97, 0x06586, 0x03ABD, 0x065FF, 0x06653,
+    0x03AF2, 0x06692, 0x03B22, 0x06716, 0x03B42, 0x067A4, 0x06800, 0x03B58, 0x0684A, 0x06884,
+    0x03B72, 0x03B71, 0x03B7B, 0x06909, 0x06943, 0x0725C, 0x06964, 0x0699F, 0x06985, 0x03BBC,
+    0x069D6, 0x03BDD, 0x06A65, 0x06A74, 0x06A71, 0x06A82, 0x03BEC, 0x06A99, 0x03BF2, 0x06AAB,
+    0x06AB5, 0x06AD4, 0x06AF6, 0x06B81, 0x06BC1, 0x06BEA, 0x06C75, 0x06CAA, 0x03CCB, 0x06D02,
+    0x06D06, 0x06D26, 0x06D81, 0x03CEF, 0x06DA4, 0x06DB1, 0x06E15, 0x06E18, 0x06E29, 0x06E86,
+    0x289C0, 0x06EBB, 0x06EE2, 0x06EDA, 0x09F7F, 0x06EE8, 0x06EE9, 0x06F24, 0x06F34, 0x03D46,
+    0x23F41, 0x06F81, 0x06FBE, 0x03D6A, 0x03D75, 0x071B7, 0x05C99, 0x03D8A, 0x0702C, 0x03D91,
+    0x07050, 0x07054, 0x0706F, 0x0707F, 0x07089, 0x20325, 0x043C1, 0x035F1, 0x20ED8, 0x23ED7,
+    0x057BE, 0x26ED3, 0x0713E, 0x257E0, 0x0364E, 0x069A2, 0x28BE9, 0x05B74, 0x07A49, 0x258E1,
+    0x294D9, 0x07A65, 0x07A7D, 0x259AC, 0x07ABB, 0x07AB0, 0x07AC2, 0x07AC3, 0x071D1, 0x2648D,
+    0x041CA, 0x07ADA, 0x07ADD, 0x07AEA, 0x041EF, 0x054B2, 0x25C01, 0x07B0B, 0x07B55, 0x07B29,
+    0x2530E, 0x25CFE, 0x07BA2, 0x07B6F, 0x0839C, 0x25BB4, 0x26C7F, 0x07BD0, 0x08421, 0x07B92,
+    0x00000, 0x25D20, 0x03DAD, 0x25C65, 0x08492, 0x07BFA, 0x00000, 0x07C35, 0x25CC1, 0x07C44,
+    0x07C83, 0x24882, 0x07CA6, 0x0667D, 0x24578, 0x07CC9, 0x07CC7, 0x07CE6, 0x07C74, 0x07CF3,
+    0x07CF5, 0x00000, 0x07E67, 0x0451D, 0x26E44, 0x07D5D, 0x26ED6, 0x0748D, 0x07D89, 0x07DAB,
+    0x07135, 0x07DB3, 0x00000, 0x24057, 0x26029, 0x07DE4, 0x03D13, 0x07DF5, 0x217F9, 0x07DE5,
+    0x2836D, 0x00000, 0x26121, 0x2615A, 0x07E6E, 0x07E92, 0x0432B, 0x0946C, 0x07E27, 0x07F40,
+    0x07F41, 0x07F47, 0x07936, 0x262D0, 0x099E1, 0x07F97, 0x26351, 0x07FA3, 0x21661, 0x20068,
+    0x0455C, 0x23766, 0x04503, 0x2833A, 0x07FFA, 0x26489, 0x00000, 0x08008, 0x0801D, 0x00000,
+    0x0802F, 0x2A087, 0x26CC3, 0x0803B, 0x0803C, 0x08061, 0x22714, 0x04989, 0x26626, 0x23DE3,
+    0x266E8, 0x06725, 0x080A7, 0x28A48, 0x08107, 0x0811A, 0x058B0, 0x226F6, 0x06C7F, 0x26498,
+    0x24FB8, 0x064E7, 0x2148A, 0x08218, 0x2185E, 0x06A53, 0x24A65, 0x24A95, 0x0447A, 0x08229,
+    0x20B0D, 0x26A52, 0x23D7E, 0x04FF9, 0x214FD, 0x084E2, 0x08362, 0x26B0A, 0x249A7, 0x23530,
+    0x21773, 0x23DF8, 0x082AA, 0x0691B, 0x2F994, 0x041DB, 0x0854B, 0x082D0, 0x0831A, 0x20E16,
+    0x217B4, 0x036C1, 0x2317D, 0x2355A, 0x0827B, 0x082E2, 0x08318, 0x23E8B, 0x26DA3, 0x26B05,
+    0x26B97, 0x235CE, 0x03DBF, 0x0831D, 0x055EC, 0x08385, 0x0450B, 0x26DA5, 0x083AC, 0x00000,
+    0x083D3, 0x0347E, 0x26ED4, 0x06A57, 0x0855A, 0x03496, 0x26E42, 0x22EEF, 0x08458, 0x25BE4,
+    0x08471, 0x03DD3, 0x044E4, 0x06AA7, 0x0844A, 0x23CB5, 0x07958, 0x00000, 0x26B96, 0x26E77,
+    0x26E43, 0x084DE, 0x00000, 0x08391, 0x044A0, 0x08493, 0x084E4, 0x25C91, 0x04240, 0x25CC0,
+    0x04543, 0x08534, 0x05AF2, 0x26E99, 0x04527, 0x08573, 0x04516, 0x067BF, 0x08616, 0x28625,
+    0x2863B, 0x085C1, 0x27088, 0x08602, 0x21582, 0x270CD, 0x2F9B2, 0x0456A, 0x08628, 0x03648,
+    0x218A2, 0x053F7, 0x2739A, 0x0867E, 0x08771, 0x2A0F8, 0x087EE, 0x22C27, 0x087B1, 0x087DA,
+    0x0880F, 0x05661, 0x0866C, 0x06856, 0x0460F, 0x08845, 0x08846, 0x275E0, 0x23DB9, 0x275E4,
+    0x0885E, 0x0889C, 0x0465B, 0x088B4, 0x088B5, 0x063C1, 0x088C5, 0x07777, 0x2770F, 0x08987,
+    0x0898A, 0x00000, 0x00000, 0x089A7, 0x089BC, 0x28A25, 0x089E7, 0x27924, 0x27ABD, 0x08A9C,
+    0x07793, 0x091FE, 0x08A90, 0x27A59, 0x07AE9, 0x27B3A, 0x23F8F, 0x04713, 0x27B38, 0x0717C,
+    0x08B0C, 0x08B1F, 0x25430, 0x25565, 0x08B3F, 0x08B4C, 0x08B4D, 0x08AA9, 0x24A7A, 0x08B90,
+    0x08B9B, 0x08AAF, 0x216DF, 0x04615, 0x0884F, 0x08C9B, 0x27D54, 0x27D8F, 0x2F9D4, 0x03725,
+    0x27D53, 0x08CD6, 0x27D98, 0x27DBD, 0x08D12, 0x08D03, 0x21910, 0x08CDB, 0x0705C, 0x08D11,
+    0x24CC9, 0x03ED0, 0x00000, 0x08DA9, 0x28002, 0x21014, 0x2498A, 0x03B7C, 0x281BC, 0x2710C,
+    0x07AE7, 0x08EAD, 0x08EB6, 0x08EC3, 0x092D4, 0x08F19, 0x08F2D, 0x28365, 0x28412, 0x08FA5,
+    0x09303, 0x2A29F, 0x20A50, 0x08FB3, 0x0492A, 0x289DE, 0x2853D, 0x23DBB, 0x05EF8, 0x23262,
+    0x08FF9, 0x2A014, 0x286BC, 0x28501, 0x22325, 0x03980, 0x26ED7, 0x09037, 0x2853C, 0x27ABE,
+    0x09061, 0x2856C, 0x2860B, 0x090A8, 0x28713, 0x090C4, 0x286E6, 0x090AE, 0x00000, 0x09167,
+    0x03AF0, 0x091A9, 0x091C4, 0x07CAC, 0x28933, 0x21E89, 0x0920E, 0x06C9F, 0x09241, 0x09262,
+    0x255B9, 0x00000, 0x28AC6, 0x23C9B, 0x28B0C, 0x255DB, 0x20D31, 0x0932C, 0x0936B, 0x28AE1,
+    0x28BEB, 0x0708F, 0x05AC3, 0x28AE2, 0x28AE5, 0x04965, 0x09244, 0x28BEC, 0x28C39, 0x28BFF,
+    0x09373, 0x0945B, 0x08EBC, 0x09585, 0x095A6, 0x09426, 0x095A0, 0x06FF6, 0x042B9, 0x2267A,
+    0x286D8, 0x2127C, 0x23E2E, 0x049DF, 0x06C1C, 0x0967B, 0x09696, 0x0416C, 0x096A3, 0x26ED5,
+    0x061DA, 0x096B6, 0x078F5, 0x28AE0, 0x096BD, 0x053CC, 0x049A1, 0x26CB8, 0x20274, 0x26410,
+    0x290AF, 0x290E5, 0x24AD1, 0x21915, 0x2330A, 0x09731, 0x08642, 0x09736, 0x04A0F, 0x0453D,
+    0x04585, 0x24AE9, 0x07075, 0x05B41, 0x0971B, 0x00000, 0x291D5, 0x09757, 0x05B4A, 0x291EB,
+    0x0975F, 0x09425, 0x050D0, 0x230B7, 0x230BC, 0x09789, 0x0979F, 0x097B1, 0x097BE, 0x097C0,
+    0x097D2, 0x097E0, 0x2546C, 0x097EE, 0x0741C, 0x29433, 0x00000, 0x097F5, 0x2941D, 0x2797A,
+    0x04AD1, 0x09834, 0x09833, 0x0984B, 0x09866, 0x03B0E, 0x27175, 0x03D51, 0x20630, 0x2415C,
+    0x25706, 0x098CA, 0x098B7, 0x098C8, 0x098C7, 0x04AFF, 0x26D27, 0x216D3, 0x055B0, 0x098E1,
+    0x098E6, 0x098EC, 0x09378, 0x09939, 0x24A29, 0x04B72, 0x29857, 0x29905, 0x099F5, 0x09A0C,
+    0x09A3B, 0x09A10, 0x09A58, 0x25725, 0x036C4, 0x290B1, 0x29BD5, 0x09AE0, 0x09AE2, 0x29B05,
+    0x09AF4, 0x04C0E, 0x09B14, 0x09B2D, 0x28600, 0x05034, 0x09B34, 0x269A8, 0x038C3, 0x2307D,
+    0x09B50, 0x09B40, 0x29D3E, 0x05A45, 0x21863, 0x09B8E, 0x2424B, 0x09C02, 0x09BFF, 0x09C0C,
+    0x29E68, 0x09DD4, 0x29FB7, 0x2A192, 0x2A1AB, 0x2A0E1, 0x2A123, 0x2A1DF, 0x09D7E, 0x09D83,
+    0x2A134, 0x09E0E, 0x06888, 0x09DC4, 0x2215B, 0x2A193, 0x2A220, 0x2193B, 0x2A233, 0x09D39,
+    0x2A0B9, 0x2A2B4, 0x09E90, 0x09E95, 0x09E9E, 0x09EA2, 0x04D34, 0x09EAA, 0x09EAF, 0x24364,
+    0x09EC1, 0x03B60, 0x039E5, 0x03D1D, 0x04F32, 0x037BE, 0x28C2B, 0x09F02, 0x09F08, 0x04B96,
+    0x09424, 0x26DA2, 0x09F17, 0x00000, 0x09F39, 0x0569F, 0x0568A, 0x09F45, 0x099B8, 0x2908B,
+    0x097F2, 0x0847F, 0x09F62, 0x09F69, 0x07ADC, 0x09F8E, 0x07216, 0x04BBE, 0x24975, 0x249BB,
+    0x07177, 0x249F8, 0x24348, 0x24A51, 0x0739E, 0x28BDA, 0x218FA, 0x0799F, 0x2897E, 0x28E36,
+    0x09369, 0x093F3, 0x28A44, 0x092EC, 0x09381, 0x093CB, 0x2896C, 0x244B9, 0x07217, 0x03EEB,
+    0x07772, 0x07A43, 0x070D0, 0x24473, 0x243F8, 0x0717E, 0x217EF, 0x070A3, 0x218BE, 0x23599,
+    0x03EC7, 0x21885, 0x2542F, 0x217F8, 0x03722, 0x216FB, 0x21839, 0x036E1, 0x21774, 0x218D1,
+    0x25F4B, 0x03723, 0x216C0, 0x0575B, 0x24A25, 0x213FE, 0x212A8, 0x213C6, 0x214B6, 0x08503,
+    0x236A6, 0x00000, 0x08455, 0x24994, 0x27165, 0x23E31, 0x2555C, 0x23EFB, 0x27052, 0x044F4,
+    0x236EE, 0x2999D, 0x26F26, 0x067F9, 0x03733, 0x03C15, 0x03DE7, 0x0586C, 0x21922, 0x06810,
+    0x04057, 0x2373F, 0x240E1, 0x2408B, 0x2410F, 0x26C21, 0x054CB, 0x0569E, 0x266B1, 0x05692,
+    0x20FDF, 0x20BA8, 0x20E0D, 0x093C6, 0x28B13, 0x0939C, 0x04EF8, 0x0512B, 0x03819, 0x24436,
+    0x04EBC, 0x20465, 0x2037F, 0x04F4B, 0x04F8A, 0x25651, 0x05A68, 0x201AB, 0x203CB, 0x03999,
+    0x2030A, 0x20414, 0x03435, 0x04F29, 0x202C0, 0x28EB3, 0x20275, 0x08ADA, 0x2020C, 0x04E98,
+    0x050CD, 0x0510D, 0x04FA2, 0x04F03, 0x24A0E, 0x23E8A, 0x04F42, 0x0502E, 0x0506C, 0x05081,
+    0x04FCC, 0x04FE5, 0x05058, 0x050FC, 0x00000, 0x00000, 0x00000, 0x00000, 0x06E76, 0x23595,
+    0x23E39, 0x23EBF, 0x06D72, 0x21884, 0x23E89, 0x051A8, 0x051C3, 0x205E0, 0x044DD, 0x204A3,
+    0x20492, 0x20491, 0x08D7A, 0x28A9C, 0x2070E, 0x05259, 0x052A4, 0x20873, 0x052E1, 0x00000,
+    0x0467A, 0x0718C, 0x2438C, 0x20C20, 0x249AC, 0x210E4, 0x069D1, 0x20E1D, 0x00000, 0x03EDE,
+    0x07499, 0x07414, 0x07456, 0x07398, 0x04B8E, 0x24ABC, 0x2408D, 0x053D0, 0x03584, 0x0720F,
+    0x240C9, 0x055B4, 0x20345, 0x054CD, 0x20BC6, 0x0571D, 0x0925D, 0x096F4, 0x09366, 0x057DD,
+    0x0578D, 0x0577F, 0x0363E, 0x058CB, 0x05A99, 0x28A46, 0x216FA, 0x2176F, 0x21710, 0x05A2C,
+    0x059B8, 0x0928F, 0x05A7E, 0x05ACF, 0x05A12, 0x25946, 0x219F3, 0x21861, 0x24295, 0x036F5,
+    0x06D05, 0x07443, 0x05A21, 0x25E83, 0x05A81, 0x28BD7, 0x20413, 0x093E0, 0x0748C, 0x21303,
+    0x07105, 0x04972, 0x09408, 0x289FB, 0x093BD, 0x037A0, 0x05C1E, 0x05C9E, 0x05E5E, 0x05E48,
+    0x21996, 0x2197C, 0x23AEE, 0x05ECD, 0x05B4F, 0x21903, 0x21904, 0x03701, 0x218A0, 0x036DD,
+    0x216FE, 0x036D3, 0x0812A, 0x28A47, 0x21DBA, 0x23472, 0x289A8, 0x05F0C, 0x05F0E, 0x21927,
+    0x217AB, 0x05A6B, 0x2173B, 0x05B44, 0x08614, 0x275FD, 0x08860, 0x0607E, 0x22860, 0x2262B,
+    0x05FDB, 0x03EB8, 0x225AF, 0x225BE, 0x29088, 0x26F73, 0x061C0, 0x2003E, 0x20046, 0x2261B,
+    0x06199, 0x06198, 0x06075, 0x22C9B, 0x22D07, 0x246D4, 0x2914D, 0x06471, 0x24665, 0x22B6A,
+    0x03A29, 0x22B22, 0x23450, 0x298EA, 0x22E78, 0x06337, 0x2A45B, 0x064B6, 0x06331, 0x063D1,
+    0x249E3, 0x22D67, 0x062A4, 0x22CA1, 0x0643B, 0x0656B, 0x06972, 0x03BF4, 0x2308E, 0x232AD,
+    0x24989, 0x232AB, 0x0550D, 0x232E0, 0x218D9, 0x2943F, 0x066CE, 0x23289, 0x231B3, 0x03AE0,
+    0x04190, 0x25584, 0x28B22, 0x2558F, 0x216FC, 0x2555B, 0x25425, 0x078EE, 0x23103, 0x2182A,
+    0x23234, 0x03464, 0x2320F, 0x23182, 0x242C9, 0x0668E, 0x26D24, 0x0666B, 0x04B93, 0x06630,
+    0x27870, 0x21DEB, 0x06663, 0x232D2, 0x232E1, 0x0661E, 0x25872, 0x038D1, 0x2383A, 0x237BC,
+    0x03B99, 0x237A2, 0x233FE, 0x074D0, 0x03B96, 0x0678F, 0x2462A, 0x068B6, 0x0681E, 0x03BC4,
+    0x06ABE, 0x03863, 0x237D5, 0x24487, 0x06A33, 0x06A52, 0x06AC9, 0x06B05, 0x21912, 0x06511,
+    0x06898, 0x06A4C, 0x03BD7, 0x06A7A, 0x06B57, 0x23FC0, 0x23C9A, 0x093A0, 0x092F2, 0x28BEA,
+    0x28ACB, 0x09289, 0x2801E, 0x289DC, 0x09467, 0x06DA5, 0x06F0B, 0x249EC, 0x00000, 0x23F7F,
+    0x03D8F, 0x06E04, 0x2403C, 0x05A3D, 0x06E0A, 0x05847, 0x06D24, 0x07842, 0x0713B, 0x2431A,
+    0x24276, 0x070F1, 0x07250, 0x07287, 0x07294, 0x2478F, 0x24725, 0x05179, 0x24AA4, 0x205EB,
+    0x0747A, 0x23EF8, 0x2365F, 0x24A4A, 0x24917, 0x25FE1, 0x03F06, 0x03EB1, 0x24ADF, 0x28C23,
+    0x23F35, 0x060A7, 0x03EF3, 0x074CC, 0x0743C, 0x09387, 0x07437, 0x0449F, 0x26DEA, 0x04551,
+    0x07583, 0x03F63, 0x24CD9, 0x24D06, 0x03F58, 0x07555, 0x07673, 0x2A5C6, 0x03B19, 0x07468,
+    0x28ACC, 0x249AB, 0x2498E, 0x03AFB, 0x03DCD, 0x24A4E, 0x03EFF, 0x249C5, 0x248F3, 0x091FA,
+    0x05732, 0x09342, 0x28AE3, 0x21864, 0x050DF, 0x25221, 0x251E7, 0x07778, 0x23232, 0x0770E,
+    0x0770F, 0x0777B, 0x24697, 0x23781, 0x03A5E, 0x248F0, 0x07438, 0x0749B, 0x03EBF, 0x24ABA,
+    0x24AC7, 0x040C8, 0x24A96, 0x261AE, 0x09307, 0x25581, 0x0781E, 0x0788D, 0x07888, 0x078D2,
+    0x073D0, 0x07959, 0x27741, 0x256E3, 0x0410E, 0x00000, 0x08496, 0x079A5, 0x06A2D, 0x23EFA,
+    0x07A3A, 0x079F4, 0x0416E, 0x216E6, 0x04132, 0x09235, 0x079F1, 0x20D4C, 0x2498C, 0x20299,
+    0x23DBA, 0x2176E, 0x03597, 0x0556B, 0x03570, 0x036AA, 0x201D4, 0x20C0D, 0x07AE2, 0x05A59,
+    0x226F5, 0x25AAF, 0x25A9C, 0x05A0D, 0x2025B, 0x078F0, 0x05A2A, 0x25BC6, 0x07AFE, 0x041F9,
+    0x07C5D, 0x07C6D, 0x04211, 0x25BB3, 0x25EBC, 0x25EA6, 0x07CCD, 0x249F9, 0x217B0, 0x07C8E,
+    0x07C7C, 0x07CAE, 0x06AB2, 0x07DDC, 0x07E07, 0x07DD3, 0x07F4E, 0x26261, 0x2615C, 0x27B48,
+    0x07D97, 0x25E82, 0x0426A, 0x26B75, 0x20916, 0x067D6, 0x2004E, 0x235CF, 0x057C4, 0x26412,
+    0x263F8, 0x24962, 0x07FDD, 0x07B27, 0x2082C, 0x25AE9, 0x25D43, 0x07B0C, 0x25E0E, 0x099E6,
+    0x08645, 0x09A63, 0x06A1C, 0x2343F, 0x039E2, 0x249F7, 0x265AD, 0x09A1F, 0x265A0, 0x08480,
+    0x27127, 0x26CD1, 0x044EA, 0x08137, 0x04402, 0x080C6, 0x08109, 0x08142, 0x267B4, 0x098C3,
+    0x26A42, 0x08262, 0x08265, 0x26A51, 0x08453, 0x26DA7, 0x08610, 0x2721B, 0x05A86, 0x0417F,
+    0x21840, 0x05B2B, 0x218A1, 0x05AE4, 0x218D8, 0x086A0, 0x2F9BC, 0x23D8F, 0x0882D, 0x27422,
+    0x05A02, 0x0886E, 0x04F45, 0x08887, 0x088BF, 0x088E6, 0x08965, 0x0894D, 0x25683, 0x08954,
+    0x27785, 0x27784, 0x28BF5, 0x28BD9, 0x28B9C, 0x289F9, 0x03EAD, 0x084A3, 0x046F5, 0x046CF,
+    0x037F2, 0x08A3D, 0x08A1C, 0x29448, 0x05F4D, 0x0922B, 0x24284, 0x065D4, 0x07129, 0x070C4,
+    0x21845, 0x09D6D, 0x08C9F, 0x08CE9, 0x27DDC, 0x0599A, 0x077C3, 0x059F0, 0x0436E, 0x036D4,
+    0x08E2A, 0x08EA7, 0x24C09, 0x08F30, 0x08F4A, 0x042F4, 0x06C58, 0x06FBB, 0x22321, 0x0489B,
+    0x06F79, 0x06E8B, 0x217DA, 0x09BE9, 0x036B5, 0x2492F, 0x090BB, 0x00000, 0x05571, 0x04906,
+    0x091BB, 0x09404, 0x28A4B, 0x04062, 0x28AFC, 0x09427, 0x28C1D, 0x28C3B, 0x084E5, 0x08A2B,
+    0x09599, 0x095A7, 0x09597, 0x09596, 0x28D34, 0x07445, 0x03EC2, 0x248FF, 0x24A42, 0x243EA,
+    0x03EE7, 0x23225, 0x0968F, 0x28EE7, 0x28E66, 0x28E65, 0x03ECC, 0x249ED, 0x24A78, 0x23FEE,
+    0x07412, 0x0746B, 0x03EFC, 0x09741, 0x290B0, 0x06847, 0x04A1D, 0x29093, 0x257DF, 0x00000,
+    0x09368, 0x28989, 0x28C26, 0x28B2F, 0x263BE, 0x092BA, 0x05B11, 0x08B69, 0x0493C, 0x073F9,
+    0x2421B, 0x0979B, 0x09771, 0x09938, 0x20F26, 0x05DC1, 0x28BC5, 0x24AB2, 0x0981F, 0x294DA,
+    0x092F6, 0x295D7, 0x091E5, 0x044C0, 0x28B50, 0x24A67, 0x28B64, 0x098DC, 0x28A45, 0x03F00,
+    0x0922A, 0x04925, 0x08414, 0x0993B, 0x0994D, 0x27B06, 0x03DFD, 0x0999B, 0x04B6F, 0x099AA,
+    0x09A5C, 0x28B65, 0x258C8, 0x06A8F, 0x09A21, 0x05AFE, 0x09A2F, 0x298F1, 0x04B90, 0x29948,
+    0x099BC, 0x04BBD, 0x04B97, 0x0937D, 0x05872, 0x21302, 0x05822, 0x249B8, 0x214E8, 0x07844,
+    0x2271F, 0x23DB8, 0x068C5, 0x03D7D, 0x09458, 0x03927, 0x06150, 0x22781, 0x2296B, 0x06107,
+    0x09C4F, 0x09C53, 0x09C7B, 0x09C35, 0x09C10, 0x09B7F, 0x09BCF, 0x29E2D, 0x09B9F, 0x2A1F5,
+    0x2A0FE, 0x09D21, 0x04CAE, 0x24104, 0x09E18, 0x04CB0, 0x09D0C, 0x2A1B4, 0x2A0ED, 0x2A0F3,
+    0x2992F, 0x09DA5, 0x084BD, 0x26E12, 0x26FDF, 0x26B82, 0x085FC, 0x04533, 0x26DA4, 0x26E84,
+    0x26DF0, 0x08420, 0x085EE, 0x26E00, 0x237D7, 0x26064, 0x079E2, 0x2359C, 0x23640, 0x0492D,
+    0x249DE, 0x03D62, 0x093DB, 0x092BE, 0x09348, 0x202BF, 0x078B9, 0x09277, 0x0944D, 0x04FE4,
+    0x03440, 0x09064, 0x2555D, 0x0783D, 0x07854, 0x078B6, 0x0784B, 0x21757, 0x231C9, 0x24941,
+    0x0369A, 0x04F72, 0x06FDA, 0x06FD9, 0x00000, 0x0701E, 0x05414, 0x241B5, 0x057BB, 0x058F3,
+    0x0578A, 0x09D16, 0x057D7, 0x07134, 0x034AF, 0x241AC, 0x071EB, 0x26C40, 0x24F97, 0x00000,
+    0x217B5, 0x28A49, 0x0610C, 0x05ACE, 0x05A0B, 0x042BC, 0x24488, 0x0372C, 0x04B7B, 0x289FC,
+    0x093BB, 0x093B8, 0x218D6, 0x20F1D, 0x08472, 0x26CC0, 0x21413, 0x242FA, 0x22C26, 0x243C1,
+    0x05994, 0x23DB7, 0x26741, 0x07DA8, 0x2615B, 0x260A4, 0x249B9, 0x2498B, 0x289FA, 0x092E5,
+    0x073E2, 0x03EE9, 0x074B4, 0x28B63, 0x2189F, 0x03EE1, 0x24AB3, 0x06AD8, 0x073F3, 0x073FB,
+    0x03ED6, 0x24A3E, 0x24A94, 0x217D9, 0x24A66, 0x203A7, 0x21424, 0x249E5, 0x07448, 0x24916,
+    0x070A5, 0x24976, 0x09284, 0x073E6, 0x0935F, 0x204FE, 0x09331, 0x28ACE, 0x28A16, 0x09386,
+    0x28BE7, 0x255D5, 0x04935, 0x28A82, 0x0716B, 0x24943, 0x20CFF, 0x056A4, 0x2061A, 0x20BEB,
+    0x20CB8, 0x05502, 0x079C4, 0x217FA, 0x07DFE, 0x216C2, 0x24A50, 0x21852, 0x0452E, 0x09401,
+    0x0370A, 0x28AC0, 0x249AD, 0x059B0, 0x218BF, 0x21883, 0x27484, 0x05AA1, 0x036E2, 0x23D5B,
+    0x036B0, 0x0925F, 0x05A79, 0x28A81, 0x21862, 0x09374, 0x03CCD, 0x20AB4, 0x04A96, 0x0398A,
+    0x050F4, 0x03D69, 0x03D4C, 0x2139C, 0x07175, 0x042FB, 0x28218, 0x06E0F, 0x290E4, 0x044EB,
+    0x06D57, 0x27E4F, 0x07067, 0x06CAF, 0x03CD6, 0x23FED, 0x23E2D, 0x06E02, 0x06F0C, 0x03D6F,
+    0x203F5, 0x07551, 0x036BC, 0x034C8, 0x04680, 0x03EDA, 0x04871, 0x059C4, 0x0926E, 0x0493E,
+    0x08F41, 0x28C1C, 0x26BC0, 0x05812, 0x057C8, 0x036D6, 0x21452, 0x070FE, 0x24362, 0x24A71,
+    0x22FE3, 0x212B0, 0x223BD, 0x068B9, 0x06967, 0x21398, 0x234E5, 0x27BF4, 0x236DF, 0x28A83,
+    0x237D6, 0x233FA, 0x24C9F, 0x06A1A, 0x236AD, 0x26CB7, 0x0843E, 0x044DF, 0x044CE, 0x26D26,
+    0x26D51, 0x26C82, 0x26FDE, 0x06F17, 0x27109, 0x0833D, 0x2173A, 0x083ED, 0x26C80, 0x27053,
+    0x217DB, 0x05989, 0x05A82, 0x217B3, 0x05A61, 0x05A71, 0x21905, 0x241FC, 0x0372D, 0x059EF,
+    0x2173C, 0x036C7, 0x0718E, 0x09390, 0x0669A, 0x242A5, 0x05A6E, 0x05A2B, 0x24293, 0x06A2B,
+    0x23EF9, 0x27736, 0x2445B, 0x242CA, 0x0711D, 0x24259, 0x289E1, 0x04FB0, 0x26D28, 0x05CC2,
+    0x244CE, 0x27E4D, 0x243BD, 0x06A0C, 0x24256, 0x21304, 0x070A6, 0x07133, 0x243E9, 0x03DA5,
+    0x06CDF, 0x2F825, 0x24A4F, 0x07E65, 0x059EB, 0x05D2F, 0x03DF3, 0x05F5C, 0x24A5D, 0x217DF,
+    0x07DA4, 0x08426, 0x05485, 0x23AFA, 0x23300, 0x20214, 0x0577E, 0x208D5, 0x20619, 0x03FE5,
+    0x21F9E, 0x2A2B6, 0x07003, 0x2915B, 0x05D70, 0x0738F, 0x07CD3, 0x28A59, 0x29420, 0x04FC8,
+    0x07FE7, 0x072CD, 0x07310, 0x27AF4, 0x07338, 0x07339, 0x256F6, 0x07341, 0x07348, 0x03EA9,
+    0x27B18, 0x0906C, 0x071F5, 0x248F2, 0x073E1, 0x081F6, 0x03ECA, 0x0770C, 0x03ED1, 0x06CA2,
+    0x056FD, 0x07419, 0x0741E, 0x0741F, 0x03EE2, 0x03EF0, 0x03EF4, 0x03EFA, 0x074D3, 0x03F0E,
+    0x03F53, 0x07542, 0x0756D, 0x07572, 0x0758D, 0x03F7C, 0x075C8, 0x075DC, 0x03FC0, 0x0764D,
+    0x03FD7, 0x07674, 0x03FDC, 0x0767A, 0x24F5C, 0x07188, 0x05623, 0x08980, 0x05869, 0x0401D,
+    0x07743, 0x04039, 0x06761, 0x04045, 0x035DB, 0x07798, 0x0406A, 0x0406F, 0x05C5E, 0x077BE,
+    0x077CB, 0x058F2, 0x07818, 0x070B9, 0x0781C, 0x040A8, 0x07839, 0x07847, 0x07851, 0x07866,
+    0x08448, 0x25535, 0x07933, 0x06803, 0x07932, 0x04103, 0x04109, 0x07991, 0x07999, 0x08FBB,
+    0x07A06, 0x08FBC, 0x04167, 0x07A91, 0x041B2, 0x07ABC, 0x08279, 0x041C4, 0x07ACF, 0x07ADB,
+    0x041CF, 0x04E21, 0x07B62, 0x07B6C, 0x07B7B, 0x07C12, 0x07C1B, 0x04260, 0x0427A, 0x07C7B,
+    0x07C9C, 0x0428C, 0x07CB8, 0x04294, 0x07CED, 0x08F93, 0x070C0, 0x20CCF, 0x07DCF, 0x07DD4,
+    0x07DD0, 0x07DFD, 0x07FAE, 0x07FB4, 0x0729F, 0x04397, 0x08020, 0x08025, 0x07B39, 0x0802E,
+    0x08031, 0x08054, 0x03DCC, 0x057B4, 0x070A0, 0x080B7, 0x080E9, 0x043ED, 0x0810C, 0x0732A,
+    0x0810E, 0x08112, 0x07560, 0x08114, 0x04401, 0x03B39, 0x08156, 0x08159, 0x0815A, 0x04413,
+    0x0583A, 0x0817C, 0x08184, 0x04425, 0x08193, 0x0442D, 0x081A5, 0x057EF, 0x081C1, 0x081E4,
+    0x08254, 0x0448F, 0x082A6, 0x08276, 0x082CA, 0x082D8, 0x082FF, 0x044B0, 0x08357, 0x09669,
+    0x0698A, 0x08405, 0x070F5, 0x08464, 0x060E3, 0x08488, 0x04504, 0x084BE, 0x084E1, 0x084F8,
+    0x08510, 0x08538, 0x08552, 0x0453B, 0x0856F, 0x08570, 0x085E0, 0x04577, 0x08672, 0x08692,
+    0x086B2, 0x086EF, 0x09645, 0x0878B, 0x04606, 0x04617, 0x088AE, 0x088FF, 0x08924, 0x08947,
+    0x08991, 0x27967, 0x08A29, 0x08A38, 0x08A94, 0x08AB4, 0x08C51, 0x08CD4, 0x08CF2, 0x08D1C,
+    0x04798, 0x0585F, 0x08DC3, 0x047ED, 0x04EEE, 0x08E3A, 0x055D8, 0x05754, 0x08E71, 0x055F5,
+    0x08EB0, 0x04837, 0x08ECE, 0x08EE2, 0x08EE4, 0x08EED, 0x08EF2, 0x08FB7, 0x08FC1, 0x08FCA,
+    0x08FCC, 0x09033, 0x099C4, 0x048AD, 0x098E0, 0x09213, 0x0491E, 0x09228, 0x09258, 0x0926B,
+    0x092B1, 0x092AE, 0x092BF, 0x092E3, 0x092EB, 0x092F3, 0x092F4, 0x092FD, 0x09343, 0x09384,
+    0x093AD, 0x04945, 0x04951, 0x09EBF, 0x09417, 0x05301, 0x0941D, 0x0942D, 0x0943E, 0x0496A,
+    0x09454, 0x09479, 0x0952D, 0x095A2, 0x049A7, 0x095F4, 0x09633, 0x049E5, 0x067A0, 0x04A24,
+    0x09740, 0x04A35, 0x097B2, 0x097C2, 0x05654, 0x04AE4, 0x060E8, 0x098B9, 0x04B19, 0x098F1,
+    0x05844, 0x0990E, 0x09919, 0x051B4, 0x0991C, 0x09937, 0x09942, 0x0995D, 0x09962, 0x04B70,
+    0x099C5, 0x04B9D, 0x09A3C, 0x09B0F, 0x07A83, 0x09B69, 0x09B81, 0x09BDD, 0x09BF1, 0x09BF4,
+    0x04C6D, 0x09C20, 0x0376F, 0x21BC2, 0x09D49, 0x09C3A, 0x09EFE, 0x05650, 0x09D93, 0x09DBD,
+    0x09DC0, 0x09DFC, 0x094F6, 0x08FB6, 0x09E7B, 0x09EAC, 0x09EB1, 0x09EBD, 0x09EC6, 0x094DC,
+    0x09EE2, 0x09EF1, 0x09EF8, 0x07AC8, 0x09F44, 0x20094, 0x202B7, 0x203A0, 0x0691A, 0x094C3,
+    0x059AC, 0x204D7, 0x05840, 0x094C1, 0x037B9, 0x205D5, 0x20615, 0x20676, 0x216BA, 0x05757,
+    0x07173, 0x20AC2, 0x20ACD, 0x20BBF, 0x0546A, 0x2F83B, 0x20BCB, 0x0549E, 0x20BFB, 0x20C3B,
+    0x20C53, 0x20C65, 0x20C7C, 0x060E7, 0x20C8D, 0x0567A, 0x20CB5, 0x20CDD, 0x20CED, 0x20D6F,
+    0x20DB2, 0x20DC8, 0x06955, 0x09C2F, 0x087A5, 0x20E04, 0x20E0E, 0x20ED7, 0x20F90, 0x20F2D,
+    0x20E73, 0x05C20, 0x20FBC, 0x05E0B, 0x2105C, 0x2104F, 0x21076, 0x0671E, 0x2107B, 0x21088,
+    0x21096, 0x03647, 0x210BF, 0x210D3, 0x2112F, 0x2113B, 0x05364, 0x084AD, 0x212E3, 0x21375,
+    0x21336, 0x08B81, 0x21577, 0x21619, 0x217C3, 0x217C7, 0x04E78, 0x070BB, 0x2182D, 0x2196A,
+    0x21A2D, 0x21A45, 0x21C2A, 0x21C70, 0x21CAC, 0x21EC8, 0x062C3, 0x21ED5, 0x21F15, 0x07198,
+    0x06855, 0x22045, 0x069E9, 0x036C8, 0x2227C, 0x223D7, 0x223FA, 0x2272A, 0x22871, 0x2294F,
+    0x082FD, 0x22967, 0x22993, 0x22AD5, 0x089A5, 0x22AE8, 0x08FA0, 0x22B0E, 0x097B8, 0x22B3F,
+    0x09847, 0x09ABD, 0x22C4C, 0x00000, 0x22C88, 0x22CB7, 0x25BE8, 0x22D08, 0x22D12, 0x22DB7,
+    0x22D95, 0x22E42, 0x22F74, 0x22FCC, 0x23033, 0x23066, 0x2331F, 0x233DE, 0x05FB1, 0x06648,
+    0x066BF, 0x27A79, 0x23567, 0x235F3, 0x00000, 0x249BA, 0x00000, 0x2361A, 0x23716, 0x00000,
+    0x20346, 0x058B5, 0x0670E, 0x06918, 0x23AA7, 0x27657, 0x25FE2, 0x23E11, 0x23EB9, 0x275FE,
+    0x2209A, 0x048D0, 0x04AB8, 0x24119, 0x28A9A, 0x242EE, 0x2430D, 0x2403B, 0x24334, 0x24396,
+    0x24A45, 0x205CA, 0x051D2, 0x20611, 0x0599F, 0x21EA8, 0x03BBE, 0x23CFF, 0x24404, 0x244D6,
+    0x05788, 0x24674, 0x0399B, 0x2472F, 0x285E8, 0x299C9, 0x03762, 0x221C3, 0x08B5E, 0x28B4E,
+    0x00000, 0x24812, 0x248FB, 0x24A15, 0x07209, 0x24AC0, 0x20C78, 0x05965, 0x24EA5, 0x24F86,
+    0x20779, 0x08EDA, 0x2502C, 0x0528F, 0x0573F, 0x07171, 0x25299, 0x25419, 0x23F4A, 0x24AA7,
+    0x055BC, 0x25446, 0x2546E, 0x26B52, 0x00000, 0x03473, 0x2553F, 0x27632, 0x2555E, 0x04718,
+    0x25562, 0x25566, 0x257C7, 0x2493F, 0x2585D, 0x05066, 0x034FB, 0x233CC, 0x00000, 0x25903,
+    0x0477C, 0x28948, 0x25AAE, 0x25B89, 0x25C06, 0x21D90, 0x057A1, 0x07151, 0x00000, 0x26102,
+    0x27C12, 0x09056, 0x261B2, 0x24F9A, 0x08B62, 0x26402, 0x2644A, 0x05D5B, 0x26BF7, 0x00000,
+    0x26484, 0x2191C, 0x08AEA, 0x249F6, 0x26488, 0x23FEF, 0x26512, 0x04BC0, 0x265BF, 0x266B5,
+    0x2271B, 0x09465, 0x257E1, 0x06195, 0x05A27, 0x2F8CD, 0x00000, 0x056B9, 0x24521, 0x266FC,
+    0x04E6A, 0x24934, 0x09656, 0x06D8F, 0x26CBD, 0x03618, 0x08977, 0x26799, 0x2686E, 0x26411,
+    0x2685E, 0x00000, 0x268C7, 0x07B42, 0x290C0, 0x20A11, 0x26926, 0x00000, 0x26939, 0x07A45,
+    0x00000, 0x269FA, 0x09A26, 0x26A2D, 0x0365F, 0x26469, 0x20021, 0x07983, 0x26A34, 0x26B5B,
+    0x05D2C, 0x23519, 0x00000, 0x26B9D, 0x046D0, 0x26CA4, 0x0753B, 0x08865, 0x26DAE, 0x058B6,
+    0x0371C, 0x2258D, 0x2704B, 0x271CD, 0x03C54, 0x27280, 0x27285, 0x09281, 0x2217A, 0x2728B,
+    0x09330, 0x272E6, 0x249D0, 0x06C39, 0x0949F, 0x27450, 0x20EF8, 0x08827, 0x088F5, 0x22926,
+    0x28473, 0x217B1, 0x06EB8, 0x24A2A, 0x21820, 0x039A4, 0x036B9, 0x00000, 0x00000, 0x0453F,
+    0x066B6, 0x29CAD, 0x298A4, 0x08943, 0x277CC, 0x27858, 0x056D6, 0x040DF, 0x2160A, 0x039A1,
+    0x2372F, 0x280E8, 0x213C5, 0x071AD, 0x08366, 0x279DD, 0x291A8, 0x00000, 0x04CB7, 0x270AF,
+    0x289AB, 0x279FD, 0x27A0A, 0x27B0B, 0x27D66, 0x2417A, 0x07B43, 0x0797E, 0x28009, 0x06FB5,
+    0x2A2DF, 0x06A03, 0x28318, 0x053A2, 0x26E07, 0x093BF, 0x06836, 0x0975D, 0x2816F, 0x28023,
+    0x269B5, 0x213ED, 0x2322F, 0x28048, 0x05D85, 0x28C30, 0x28083, 0x05715, 0x09823, 0x28949,
+    0x05DAB, 0x24988, 0x065BE, 0x069D5, 0x053D2, 0x24AA5, 0x23F81, 0x03C11, 0x06736, 0x28090,
+    0x280F4, 0x2812E, 0x21FA1, 0x2814F, 0x28189, 0x281AF, 0x2821A, 0x28306, 0x2832F, 0x2838A,
+    0x035CA, 0x28468, 0x286AA, 0x048FA, 0x063E6, 0x28956, 0x07808, 0x09255, 0x289B8, 0x043F2,
+    0x289E7, 0x043DF, 0x289E8, 0x28B46, 0x28BD4, 0x059F8, 0x28C09, 0x00000, 0x28FC5, 0x290EC,
+    0x00000, 0x29110, 0x2913C, 0x03DF7, 0x2915E, 0x24ACA, 0x08FD0, 0x0728F, 0x0568B, 0x294E7,
+    0x295E9, 0x295B0, 0x295B8, 0x29732, 0x298D1, 0x29949, 0x2996A, 0x299C3, 0x29A28, 0x29B0E,
+    0x29D5A, 0x29D9B, 0x07E9F, 0x29EF8, 0x29F23, 0x04CA4, 0x09547, 0x2A293, 0x071A2, 0x2A2FF,
+    0x04D91, 0x09012, 0x2A5CB, 0x04D9C, 0x20C9C, 0x08FBE, 0x055C1, 0x08FBA, 0x224B0, 0x08FB9,
+    0x24A93, 0x04509, 0x07E7F, 0x06F56, 0x06AB1, 0x04EEA, 0x034E4, 0x28B2C, 0x2789D, 0x0373A,
+    0x08E80, 0x217F5, 0x28024, 0x28B6C, 0x28B99, 0x27A3E, 0x266AF, 0x03DEB, 0x27655, 0x23CB7,
+    0x25635, 0x25956, 0x04E9A, 0x25E81, 0x26258, 0x056BF, 0x20E6D, 0x08E0E, 0x05B6D, 0x23E88,
+    0x24C9E, 0x063DE, 0x00000, 0x217F6, 0x2187B, 0x06530, 0x0562D, 0x25C4A, 0x0541A, 0x25311,
+    0x03DC6, 0x29D98, 0x04C7D, 0x05622, 0x0561E, 0x07F49, 0x25ED8, 0x05975, 0x23D40, 0x08770,
+    0x04E1C, 0x20FEA, 0x20D49, 0x236BA, 0x08117, 0x09D5E, 0x08D18, 0x0763B, 0x09C45, 0x0764E,
+    0x077B9, 0x09345, 0x05432, 0x08148, 0x082F7, 0x05625, 0x08132, 0x08418, 0x080BD, 0x055EA,
+    0x07962, 0x05643, 0x05416, 0x20E9D, 0x035CE, 0x05605, 0x055F1, 0x066F1, 0x282E2, 0x0362D,
+    0x07534, 0x055F0, 0x055BA, 0x05497, 0x05572, 0x20C41, 0x20C96, 0x05ED0, 0x25148, 0x20E76,
+    0x22C62, 0x20EA2, 0x09EAB, 0x07D5A, 0x055DE, 0x21075, 0x0629D, 0x0976D, 0x05494, 0x08CCD,
+    0x071F6, 0x09176, 0x063FC, 0x063B9, 0x063FE, 0x05569, 0x22B43, 0x09C72, 0x22EB3, 0x0519A,
+    0x034DF, 0x20DA7, 0x051A7, 0x0544D, 0x0551E, 0x05513, 0x07666, 0x08E2D, 0x2688A, 0x075B1,
+    0x080B6, 0x08804, 0x08786, 0x088C7, 0x081B6, 0x0841C, 0x210C1, 0x044EC, 0x07304, 0x24706,
+    0x05B90, 0x0830B, 0x26893, 0x0567B, 0x226F4, 0x27D2F, 0x241A3, 0x27D73, 0x26ED0, 0x272B6,
+    0x09170, 0x211D9, 0x09208, 0x23CFC, 0x2A6A9, 0x20EAC, 0x20EF9, 0x07266, 0x21CA2, 0x0474E,
+    0x24FC2, 0x27FF9, 0x20FEB, 0x040FA, 0x09C5D, 0x0651F, 0x22DA0, 0x048F3, 0x247E0, 0x29D7C,
+    0x20FEC, 0x20E0A, 0x00000, 0x275A3, 0x20FED, 0x00000, 0x26048, 0x21187, 0x071A3, 0x07E8E,
+    0x09D50, 0x04E1A, 0x04E04, 0x03577, 0x05B0D, 0x06CB2, 0x05367, 0x036AC, 0x039DC, 0x0537D,
+    0x036A5, 0x24618, 0x0589A, 0x24B6E, 0x0822D, 0x0544B, 0x057AA, 0x25A95, 0x20979, 0x00000,
+    0x03A52, 0x22465, 0x07374, 0x29EAC, 0x04D09, 0x09BED, 0x23CFE, 0x29F30, 0x04C5B, 0x24FA9,
+    0x2959E, 0x29FDE, 0x0845C, 0x23DB6, 0x272B2, 0x267B3, 0x23720, 0x0632E, 0x07D25, 0x23EF7,
+    0x23E2C, 0x03A2A, 0x09008, 0x052CC, 0x03E74, 0x0367A, 0x045E9, 0x2048E, 0x07640, 0x05AF0,
+    0x20EB6, 0x0787A, 0x27F2E, 0x058A7, 0x040BF, 0x0567C, 0x09B8B, 0x05D74, 0x07654, 0x2A434,
+    0x09E85, 0x04CE1, 0x00000, 0x037FB, 0x06119, 0x230DA, 0x243F2, 0x00000, 0x0565D, 0x212A9,
+    0x057A7, 0x24963, 0x29E06, 0x05234, 0x270AE, 0x035AD, 0x00000, 0x09D7C, 0x07C56, 0x09B39,
+    0x057DE, 0x2176C, 0x05C53, 0x064D3, 0x294D0, 0x26335, 0x27164, 0x086AD, 0x20D28, 0x26D22,
+    0x24AE2, 0x20D71, 0x00000, 0x051FE, 0x21F0F, 0x05D8E, 0x09703, 0x21DD1, 0x09E81, 0x0904C,
+    0x07B1F, 0x09B02, 0x05CD1, 0x07BA3, 0x06268, 0x06335, 0x09AFF, 0x07BCF, 0x09B2A, 0x07C7E,
+    0x00000, 0x07C42, 0x07C86, 0x09C15, 0x07BFC, 0x09B09, 0x00000, 0x09C1B, 0x2493E, 0x09F5A,
+    0x05573, 0x05BC3, 0x04FFD, 0x09E98, 0x04FF2, 0x05260, 0x03E06, 0x052D1, 0x05767, 0x05056,
+    0x059B7, 0x05E12, 0x097C8, 0x09DAB, 0x08F5C, 0x05469, 0x097B4, 0x09940, 0x097BA, 0x0532C,
+    0x06130, 0x0692C, 0x053DA, 0x09C0A, 0x09D02, 0x04C3B, 0x09641, 0x06980, 0x050A6, 0x07546,
+    0x2176D, 0x099DA, 0x05273, 0x00000, 0x09159, 0x09681, 0x0915C, 0x00000, 0x09151, 0x28E97,
+    0x0637F, 0x26D23, 0x06ACA, 0x05611, 0x0918E, 0x0757A, 0x06285, 0x203FC, 0x0734F, 0x07C70,
+    0x25C21, 0x23CFD, 0x00000, 0x24919, 0x076D6, 0x09B9D, 0x04E2A, 0x20CD4, 0x083BE, 0x08842,
+    0x00000, 0x05C4A, 0x069C0, 0x00000, 0x0577A, 0x0521F, 0x05DF5, 0x04ECE, 0x06C31, 0x201F2,
+    0x04F39, 0x0549C, 0x054DA, 0x0529A, 0x08D82, 0x035FE, 0x00000, 0x035F3, 0x00000, 0x06B52,
+    0x0917C, 0x09FA5, 0x09B97, 0x0982E, 0x098B4, 0x09ABA, 0x09EA8, 0x09E84, 0x0717A, 0x07B14,
+    0x00000, 0x06BFA, 0x08818, 0x07F78, 0x00000, 0x05620, 0x2A64A, 0x08E77, 0x09F53, 0x00000,
+    0x08DD4, 0x08E4F, 0x09E1C, 0x08E01, 0x06282, 0x2837D, 0x08E28, 0x08E75, 0x07AD3, 0x24A77,
+    0x07A3E, 0x078D8, 0x06CEA, 0x08A67, 0x07607, 0x28A5A, 0x09F26, 0x06CCE, 0x087D6, 0x075C3,
+    0x2A2B2, 0x07853, 0x2F840, 0x08D0C, 0x072E2, 0x07371, 0x08B2D, 0x07302, 0x074F1, 0x08CEB,
+    0x24ABB, 0x0862F, 0x05FBA, 0x088A0, 0x044B7, 0x00000, 0x2183B, 0x26E05, 0x00000, 0x08A7E,
+    0x2251B, 0x00000, 0x060FD, 0x07667, 0x09AD7, 0x09D44, 0x0936E, 0x09B8F, 0x087F5, 0x00000,
+    0x00000, 0x08CF7, 0x0732C, 0x09721, 0x09BB0, 0x035D6, 0x072B2, 0x04C07, 0x07C51, 0x0994A,
+    0x26159, 0x06159, 0x04C04, 0x09E96, 0x0617D, 0x00000, 0x0575F, 0x0616F, 0x062A6, 0x06239,
+    0x00000, 0x03A5C, 0x061E2, 0x053AA, 0x233F5, 0x06364, 0x06802, 0x035D2, 0x05D57, 0x28BC2,
+    0x08FDA, 0x28E39, 0x00000, 0x050D9, 0x21D46, 0x07906, 0x05332, 0x09638, 0x20F3B, 0x04065,
+    0x00000, 0x077FE, 0x00000, 0x07CC2, 0x25F1A, 0x07CDA, 0x07A2D, 0x08066, 0x08063, 0x07D4D,
+    0x07505, 0x074F2, 0x08994, 0x0821A, 0x0670C, 0x08062, 0x27486, 0x0805B, 0x074F0, 0x08103,
+    0x07724, 0x08989, 0x267CC, 0x07553, 0x26ED1, 0x087A9, 0x087CE, 0x081C8, 0x0878C, 0x08A49,
+    0x08CAD, 0x08B43, 0x0772B, 0x074F8, 0x084DA, 0x03635, 0x069B2, 0x08DA6, 0x00000, 0x089A9,
+    0x00000, 0x06DB9, 0x087C1, 0x24011, 0x074E7, 0x03DDB, 0x07176, 0x060A4, 0x0619C, 0x03CD1,
+    0x00000, 0x06077, 0x00000, 0x07F71, 0x28B2D, 0x00000, 0x060E9, 0x04B7E, 0x05220, 0x03C18,
+    0x23CC7, 0x25ED7, 0x27656, 0x25531, 0x21944, 0x212FE, 0x29903, 0x26DDC, 0x270AD, 0x05CC1,
+    0x261AD, 0x28A0F, 0x23677, 0x200EE, 0x26846, 0x24F0E, 0x04562, 0x05B1F, 0x2634C, 0x09F50,
+    0x09EA6, 0x2626B, 0x03000, 0x0FF0C, 0x03001, 0x03002, 0x0FF0E, 0x02022, 0x0FF1B, 0x0FF1A,
+    0x0FF1F, 0x0FF01, 0x0FE30, 0x02026, 0x02025, 0x0FE50, 0x0FF64, 0x0FE52, 0x000B7, 0x0FE54,
+    0x0FE55, 0x0FE56, 0x0FE57, 0x0FF5C, 0x02013, 0x0FE31, 0x02014, 0x0FE33, 0x02574, 0x0FE34,
+    0x0FE4F, 0x0FF08, 0x0FF09, 0x0FE35, 0x0FE36, 0x0FF5B, 0x0FF5D, 0x0FE37, 0x0FE38, 0x03014,
+    0x03015, 0x0FE39, 0x0FE3A, 0x03010, 0x03011, 0x0FE3B, 0x0FE3C, 0x0300A, 0x0300B, 0x0FE3D,
+    0x0FE3E, 0x03008, 0x03009, 0x0FE3F, 0x0FE40, 0x0300C, 0x0300D, 0x0FE41, 0x0FE42, 0x0300E,
+    0x0300F, 0x0FE43, 0x0FE44, 0x0FE59, 0x0FE5A, 0x0FE5B, 0x0FE5C, 0x0FE5D, 0x0FE5E, 0x02018,
+    0x02019, 0x0201C, 0x0201D, 0x0301D, 0x0301E, 0x02035, 0x02032, 0x0FF03, 0x0FF06, 0x0FF0A,
+    0x0203B, 0x000A7, 0x03003, 0x025CB, 0x025CF, 0x025B3, 0x025B2, 0x025CE, 0x02606, 0x02605,
+    0x025C7, 0x025C6, 0x025A1, 0x025A0, 0x025BD, 0x025BC, 0x032A3, 0x02105, 0x0203E, 0x0FFE3,
+    0x0FF3F, 0x002CD, 0x0FE49, 0x0FE4A, 0x0FE4D, 0x0FE4E, 0x0FE4B, 0x0FE4C, 0x0FE5F, 0x0FE60,
+    0x0FE61, 0x0FF0B, 0x0FF0D, 0x000D7, 0x000F7, 0x000B1, 0x0221A, 0x0FF1C, 0x0FF1E, 0x0FF1D,
+    0x02266, 0x02267, 0x02260, 0x0221E, 0x02252, 0x02261, 0x0FE62, 0x0FE63, 0x0FE64, 0x0FE65,
+    0x0FE66, 0x0223C, 0x02229, 0x0222A, 0x022A5, 0x02220, 0x0221F, 0x022BF, 0x033D2, 0x033D1,
+    0x0222B, 0x0222E, 0x02235, 0x02234, 0x02640, 0x02642, 0x02641, 0x02609, 0x02191, 0x02193,
+    0x02190, 0x02192, 0x02196, 0x02197, 0x02199, 0x02198, 0x02225, 0x02223, 0x0FF0F, 0x0FF3C,
+    0x0FF0F, 0x0FF3C, 0x0FF04, 0x000A5, 0x03012, 0x000A2, 0x000A3, 0x0FF05, 0x0FF20, 0x02103,
+    0x02109, 0x0FE69, 0x0FE6A, 0x0FE6B, 0x033D5, 0x0339C, 0x0339D, 0x0339E, 0x033CE, 0x033A1,
+    0x0338E, 0x0338F, 0x033C4, 0x000B0, 0x05159, 0x0515B, 0x0515E, 0x0515D, 0x05161, 0x05163,
+    0x055E7, 0x074E9, 0x07CCE, 0x02581, 0x02582, 0x02583, 0x02584, 0x02585, 0x02586, 0x02587,
+    0x02588, 0x0258F, 0x0258E, 0x0258D, 0x0258C, 0x0258B, 0x0258A, 0x02589, 0x0253C, 0x02534,
+    0x0252C, 0x02524, 0x0251C, 0x02594, 0x02500, 0x02502, 0x02595, 0x0250C, 0x02510, 0x02514,
+    0x02518, 0x0256D, 0x0256E, 0x02570, 0x0256F, 0x02550, 0x0255E, 0x0256A, 0x02561, 0x025E2,
+    0x025E3, 0x025E5, 0x025E4, 0x02571, 0x02572, 0x02573, 0x0FF10, 0x0FF11, 0x0FF12, 0x0FF13,
+    0x0FF14, 0x0FF15, 0x0FF16, 0x0FF17, 0x0FF18, 0x0FF19, 0x02160, 0x02161, 0x02162, 0x02163,
+    0x02164, 0x02165, 0x02166, 0x02167, 0x02168, 0x02169, 0x03021, 0x03022, 0x03023, 0x03024,
+    0x03025, 0x03026, 0x03027, 0x03028, 0x03029, 0x05341, 0x05344, 0x05345, 0x0FF21, 0x0FF22,
+    0x0FF23, 0x0FF24, 0x0FF25, 0x0FF26, 0x0FF27, 0x0FF28, 0x0FF29, 0x0FF2A, 0x0FF2B, 0x0FF2C,
+    0x0FF2D, 0x0FF2E, 0x0FF2F, 0x0FF30, 0x0FF31, 0x0FF32, 0x0FF33, 0x0FF34, 0x0FF35, 0x0FF36,
+    0x0FF37, 0x0FF38, 0x0FF39, 0x0FF3A, 0x0FF41, 0x0FF42, 0x0FF43, 0x0FF44, 0x0FF45, 0x0FF46,
+    0x0FF47, 0x0FF48, 0x0FF49, 0x0FF4A, 0x0FF4B, 0x0FF4C, 0x0FF4D, 0x0FF4E, 0x0FF4F, 0x0FF50,
+    0x0FF51, 0x0FF52, 0x0FF53, 0x0FF54, 0x0FF55, 0x0FF56, 0x0FF57, 0x0FF58, 0x0FF59, 0x0FF5A,
+    0x00391, 0x00392, 0x00393, 0x00394, 0x00395, 0x00396, 0x00397, 0x00398, 0x00399, 0x0039A,
+    0x0039B, 0x0039C, 0x0039D, 0x0039E, 0x0039F, 0x003A0, 0x003A1, 0x003A3, 0x003A4, 0x003A5,
+    0x003A6, 0x003A7, 0x003A8, 0x003A9, 0x003B1, 0x003B2, 0x003B3, 0x003B4, 0x003B5, 0x003B6,
+    0x003B7, 0x003B8, 0x003B9, 0x003BA, 0x003BB, 0x003BC, 0x003BD, 0x003BE, 0x003BF, 0x003C0,
+    0x003C1, 0x003C3, 0x003C4, 0x003C5, 0x003C6, 0x003C7, 0x003C8, 0x003C9, 0x03105, 0x03106,
+    0x03107, 0x03108, 0x03109, 0x0310A, 0x0310B, 0x0310C, 0x0310D, 0x0310E, 0x0310F, 0x03110,
+    0x03111, 0x03112, 0x03113, 0x03114, 0x03115, 0x03116, 0x03117, 0x03118, 0x03119, 0x0311A,
+    0x0311B, 0x0311C, 0x0311D, 0x0311E, 0x0311F, 0x03120, 0x03121, 0x03122, 0x03123, 0x03124,
+    0x03125, 0x03126, 0x03127, 0x03128, 0x03129, 0x002D9, 0x002C9, 0x002CA, 0x002C7, 0x002CB,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x04E00, 0x04E59, 0x04E01, 0x04E03, 0x04E43, 0x04E5D, 0x04E86,
+    0x04E8C, 0x04EBA, 0x0513F, 0x05165, 0x0516B, 0x051E0, 0x05200, 0x05201, 0x0529B, 0x05315,
+    0x05341, 0x0535C, 0x053C8, 0x04E09, 0x04E0B, 0x04E08, 0x04E0A, 0x04E2B, 0x04E38, 0x051E1,
+    0x04E45, 0x04E48, 0x04E5F, 0x04E5E, 0x04E8E, 0x04EA1, 0x05140, 0x05203, 0x052FA, 0x05343,
+    0x053C9, 0x053E3, 0x0571F, 0x058EB, 0x05915, 0x05927, 0x05973, 0x05B50, 0x05B51, 0x05B53,
+    0x05BF8, 0x05C0F, 0x05C22, 0x05C38, 0x05C71, 0x05DDD, 0x05DE5, 0x05DF1, 0x05DF2, 0x05DF3,
+    0x05DFE, 0x05E72, 0x05EFE, 0x05F0B, 0x05F13, 0x0624D, 0x04E11, 0x04E10, 0x04E0D, 0x04E2D,
+    0x04E30, 0x04E39, 0x04E4B, 0x05C39, 0x04E88, 0x04E91, 0x04E95, 0x04E92, 0x04E94, 0x04EA2,
+    0x04EC1, 0x04EC0, 0x04EC3, 0x04EC6, 0x04EC7, 0x04ECD, 0x04ECA, 0x04ECB, 0x04EC4, 0x05143,
+    0x05141, 0x05167, 0x0516D, 0x0516E, 0x0516C, 0x05197, 0x051F6, 0x05206, 0x05207, 0x05208,
+    0x052FB, 0x052FE, 0x052FF, 0x05316, 0x05339, 0x05348, 0x05347, 0x05345, 0x0535E, 0x05384,
+    0x053CB, 0x053CA, 0x053CD, 0x058EC, 0x05929, 0x0592B, 0x0592A, 0x0592D, 0x05B54, 0x05C11,
+    0x05C24, 0x05C3A, 0x05C6F, 0x05DF4, 0x05E7B, 0x05EFF, 0x05F14, 0x05F15, 0x05FC3, 0x06208,
+    0x06236, 0x0624B, 0x0624E, 0x0652F, 0x06587, 0x06597, 0x065A4, 0x065B9, 0x065E5, 0x066F0,
+    0x06708, 0x06728, 0x06B20, 0x06B62, 0x06B79, 0x06BCB, 0x06BD4, 0x06BDB, 0x06C0F, 0x06C34,
+    0x0706B, 0x0722A, 0x07236, 0x0723B, 0x07247, 0x07259, 0x0725B, 0x072AC, 0x0738B, 0x04E19,
+    0x04E16, 0x04E15, 0x04E14, 0x04E18, 0x04E3B, 0x04E4D, 0x04E4F, 0x04E4E, 0x04EE5, 0x04ED8,
+    0x04ED4, 0x04ED5, 0x04ED6, 0x04ED7, 0x04EE3, 0x04EE4, 0x04ED9, 0x04EDE, 0x05145, 0x05144,
+    0x05189, 0x0518A, 0x051AC, 0x051F9, 0x051FA, 0x051F8, 0x0520A, 0x052A0, 0x0529F, 0x05305,
+    0x05306, 0x05317, 0x0531D, 0x04EDF, 0x0534A, 0x05349, 0x05361, 0x05360, 0x0536F, 0x0536E,
+    0x053BB, 0x053EF, 0x053E4, 0x053F3, 0x053EC, 0x053EE, 0x053E9, 0x053E8, 0x053FC, 0x053F8,
+    0x053F5, 0x053EB, 0x053E6, 0x053EA, 0x053F2, 0x053F1, 0x053F0, 0x053E5, 0x053ED, 0x053FB,
+    0x056DB, 0x056DA, 0x05916, 0x0592E, 0x05931, 0x05974, 0x05976, 0x05B55, 0x05B83, 0x05C3C,
+    0x05DE8, 0x05DE7, 0x05DE6, 0x05E02, 0x05E03, 0x05E73, 0x05E7C, 0x05F01, 0x05F18, 0x05F17,
+    0x05FC5, 0x0620A, 0x06253, 0x06254, 0x06252, 0x06251, 0x065A5, 0x065E6, 0x0672E, 0x0672C,
+    0x0672A, 0x0672B, 0x0672D, 0x06B63, 0x06BCD, 0x06C11, 0x06C10, 0x06C38, 0x06C41, 0x06C40,
+    0x06C3E, 0x072AF, 0x07384, 0x07389, 0x074DC, 0x074E6, 0x07518, 0x0751F, 0x07528, 0x07529,
+    0x07530, 0x07531, 0x07532, 0x07533, 0x0758B, 0x0767D, 0x076AE, 0x076BF, 0x076EE, 0x077DB,
+    0x077E2, 0x077F3, 0x0793A, 0x079BE, 0x07A74, 0x07ACB, 0x04E1E, 0x04E1F, 0x04E52, 0x04E53,
+    0x04E69, 0x04E99, 0x04EA4, 0x04EA6, 0x04EA5, 0x04EFF, 0x04F09, 0x04F19, 0x04F0A, 0x04F15,
+    0x04F0D, 0x04F10, 0x04F11, 0x04F0F, 0x04EF2, 0x04EF6, 0x04EFB, 0x04EF0, 0x04EF3, 0x04EFD,
+    0x04F01, 0x04F0B, 0x05149, 0x05147, 0x05146, 0x05148, 0x05168, 0x05171, 0x0518D, 0x051B0,
+    0x05217, 0x05211, 0x05212, 0x0520E, 0x05216, 0x052A3, 0x05308, 0x05321, 0x05320, 0x05370,
+    0x05371, 0x05409, 0x0540F, 0x0540C, 0x0540A, 0x05410, 0x05401, 0x0540B, 0x05404, 0x05411,
+    0x0540D, 0x05408, 0x05403, 0x0540E, 0x05406, 0x05412, 0x056E0, 0x056DE, 0x056DD, 0x05733,
+    0x05730, 0x05728, 0x0572D, 0x0572C, 0x0572F, 0x05729, 0x05919, 0x0591A, 0x05937, 0x05938,
+    0x05984, 0x05978, 0x05983, 0x0597D, 0x05979, 0x05982, 0x05981, 0x05B57, 0x05B58, 0x05B87,
+    0x05B88, 0x05B85, 0x05B89, 0x05BFA, 0x05C16, 0x05C79, 0x05DDE, 0x05E06, 0x05E76, 0x05E74,
+    0x05F0F, 0x05F1B, 0x05FD9, 0x05FD6, 0x0620E, 0x0620C, 0x0620D, 0x06210, 0x06263, 0x0625B,
+    0x06258, 0x06536, 0x065E9, 0x065E8, 0x065EC, 0x065ED, 0x066F2, 0x066F3, 0x06709, 0x0673D,
+    0x06734, 0x06731, 0x06735, 0x06B21, 0x06B64, 0x06B7B, 0x06C16, 0x06C5D, 0x06C57, 0x06C59,
+    0x06C5F, 0x06C60, 0x06C50, 0x06C55, 0x06C61, 0x06C5B, 0x06C4D, 0x06C4E, 0x07070, 0x0725F,
+    0x0725D, 0x0767E, 0x07AF9, 0x07C73, 0x07CF8, 0x07F36, 0x07F8A, 0x07FBD, 0x08001, 0x08003,
+    0x0800C, 0x08012, 0x08033, 0x0807F, 0x08089, 0x0808B, 0x0808C, 0x081E3, 0x081EA, 0x081F3,
+    0x081FC, 0x0820C, 0x0821B, 0x0821F, 0x0826E, 0x08272, 0x0827E, 0x0866B, 0x08840, 0x0884C,
+    0x08863, 0x0897F, 0x09621, 0x04E32, 0x04EA8, 0x04F4D, 0x04F4F, 0x04F47, 0x04F57, 0x04F5E,
+    0x04F34, 0x04F5B, 0x04F55, 0x04F30, 0x04F50, 0x04F51, 0x04F3D, 0x04F3A, 0x04F38, 0x04F43,
+    0x04F54, 0x04F3C, 0x04F46, 0x04F63, 0x04F5C, 0x04F60, 0x04F2F, 0x04F4E, 0x04F36, 0x04F59,
+    0x04F5D, 0x04F48, 0x04F5A, 0x0514C, 0x0514B, 0x0514D, 0x05175, 0x051B6, 0x051B7, 0x05225,
+    0x05224, 0x05229, 0x0522A, 0x05228, 0x052AB, 0x052A9, 0x052AA, 0x052AC, 0x05323, 0x05373,
+    0x05375, 0x0541D, 0x0542D, 0x0541E, 0x0543E, 0x05426, 0x0544E, 0x05427, 0x05446, 0x05443,
+    0x05433, 0x05448, 0x05442, 0x0541B, 0x05429, 0x0544A, 0x05439, 0x0543B, 0x05438, 0x0542E,
+    0x05435, 0x05436, 0x05420, 0x0543C, 0x05440, 0x05431, 0x0542B, 0x0541F, 0x0542C, 0x056EA,
+    0x056F0, 0x056E4, 0x056EB, 0x0574A, 0x05751, 0x05740, 0x0574D, 0x05747, 0x0574E, 0x0573E,
+    0x05750, 0x0574F, 0x0573B, 0x058EF, 0x0593E, 0x0599D, 0x05992, 0x059A8, 0x0599E, 0x059A3,
+    0x05999, 0x05996, 0x0598D, 0x059A4, 0x05993, 0x0598A, 0x059A5, 0x05B5D, 0x05B5C, 0x05B5A,
+    0x05B5B, 0x05B8C, 0x05B8B, 0x05B8F, 0x05C2C, 0x05C40, 0x05C41, 0x05C3F, 0x05C3E, 0x05C90,
+    0x05C91, 0x05C94, 0x05C8C, 0x05DEB, 0x05E0C, 0x05E8F, 0x05E87, 0x05E8A, 0x05EF7, 0x05F04,
+    0x05F1F, 0x05F64, 0x05F62, 0x05F77, 0x05F79, 0x05FD8, 0x05FCC, 0x05FD7, 0x05FCD, 0x05FF1,
+    0x05FEB, 0x05FF8, 0x05FEA, 0x06212, 0x06211, 0x06284, 0x06297, 0x06296, 0x06280, 0x06276,
+    0x06289, 0x0626D, 0x0628A, 0x0627C, 0x0627E, 0x06279, 0x06273, 0x06292, 0x0626F, 0x06298,
+    0x0626E, 0x06295, 0x06293, 0x06291, 0x06286, 0x06539, 0x0653B, 0x06538, 0x065F1, 0x066F4,
+    0x0675F, 0x0674E, 0x0674F, 0x06750, 0x06751, 0x0675C, 0x06756, 0x0675E, 0x06749, 0x06746,
+    0x06760, 0x06753, 0x06757, 0x06B65, 0x06BCF, 0x06C42, 0x06C5E, 0x06C99, 0x06C81, 0x06C88,
+    0x06C89, 0x06C85, 0x06C9B, 0x06C6A, 0x06C7A, 0x06C90, 0x06C70, 0x06C8C, 0x06C68, 0x06C96,
+    0x06C92, 0x06C7D, 0x06C83, 0x06C72, 0x06C7E, 0x06C74, 0x06C86, 0x06C76, 0x06C8D, 0x06C94,
+    0x06C98, 0x06C82, 0x07076, 0x0707C, 0x0707D, 0x07078, 0x07262, 0x07261, 0x07260, 0x072C4,
+    0x072C2, 0x07396, 0x0752C, 0x0752B, 0x07537, 0x07538, 0x07682, 0x076EF, 0x077E3, 0x079C1,
+    0x079C0, 0x079BF, 0x07A76, 0x07CFB, 0x07F55, 0x08096, 0x08093, 0x0809D, 0x08098, 0x0809B,
+    0x0809A, 0x080B2, 0x0826F, 0x08292, 0x0828B, 0x0828D, 0x0898B, 0x089D2, 0x08A00, 0x08C37,
+    0x08C46, 0x08C55, 0x08C9D, 0x08D64, 0x08D70, 0x08DB3, 0x08EAB, 0x08ECA, 0x08F9B, 0x08FB0,
+    0x08FC2, 0x08FC6, 0x08FC5, 0x08FC4, 0x05DE1, 0x09091, 0x090A2, 0x090AA, 0x090A6, 0x090A3,
+    0x09149, 0x091C6, 0x091CC, 0x09632, 0x0962E, 0x09631, 0x0962A, 0x0962C, 0x04E26, 0x04E56,
+    0x04E73, 0x04E8B, 0x04E9B, 0x04E9E, 0x04EAB, 0x04EAC, 0x04F6F, 0x04F9D, 0x04F8D, 0x04F73,
+    0x04F7F, 0x04F6C, 0x04F9B, 0x04F8B, 0x04F86, 0x04F83, 0x04F70, 0x04F75, 0x04F88, 0x04F69,
+    0x04F7B, 0x04F96, 0x04F7E, 0x04F8F, 0x04F91, 0x04F7A, 0x05154, 0x05152, 0x05155, 0x05169,
+    0x05177, 0x05176, 0x05178, 0x051BD, 0x051FD, 0x0523B, 0x05238, 0x05237, 0x0523A, 0x05230,
+    0x0522E, 0x05236, 0x05241, 0x052BE, 0x052BB, 0x05352, 0x05354, 0x05353, 0x05351, 0x05366,
+    0x05377, 0x05378, 0x05379, 0x053D6, 0x053D4, 0x053D7, 0x05473, 0x05475, 0x05496, 0x05478,
+    0x05495, 0x05480, 0x0547B, 0x05477, 0x05484, 0x05492, 0x05486, 0x0547C, 0x05490, 0x05471,
+    0x05476, 0x0548C, 0x0549A, 0x05462, 0x05468, 0x0548B, 0x0547D, 0x0548E, 0x056FA, 0x05783,
+    0x05777, 0x0576A, 0x05769, 0x05761, 0x05766, 0x05764, 0x0577C, 0x0591C, 0x05949, 0x05947,
+    0x05948, 0x05944, 0x05954, 0x059BE, 0x059BB, 0x059D4, 0x059B9, 0x059AE, 0x059D1, 0x059C6,
+    0x059D0, 0x059CD, 0x059CB, 0x059D3, 0x059CA, 0x059AF, 0x059B3, 0x059D2, 0x059C5, 0x05B5F,
+    0x05B64, 0x05B63, 0x05B97, 0x05B9A, 0x05B98, 0x05B9C, 0x05B99, 0x05B9B, 0x05C1A, 0x05C48,
+    0x05C45, 0x05C46, 0x05CB7, 0x05CA1, 0x05CB8, 0x05CA9, 0x05CAB, 0x05CB1, 0x05CB3, 0x05E18,
+    0x05E1A, 0x05E16, 0x05E15, 0x05E1B, 0x05E11, 0x05E78, 0x05E9A, 0x05E97, 0x05E9C, 0x05E95,
+    0x05E96, 0x05EF6, 0x05F26, 0x05F27, 0x05F29, 0x05F80, 0x05F81, 0x05F7F, 0x05F7C, 0x05FDD,
+    0x05FE0, 0x05FFD, 0x05FF5, 0x05FFF, 0x0600F, 0x06014, 0x0602F, 0x06035, 0x06016, 0x0602A,
+    0x06015, 0x06021, 0x06027, 0x06029, 0x0602B, 0x0601B, 0x06216, 0x06215, 0x0623F, 0x0623E,
+    0x06240, 0x0627F, 0x062C9, 0x062CC, 0x062C4, 0x062BF, 0x062C2, 0x062B9, 0x062D2, 0x062DB,
+    0x062AB, 0x062D3, 0x062D4, 0x062CB, 0x062C8, 0x062A8, 0x062BD, 0x062BC, 0x062D0, 0x062D9,
+    0x062C7, 0x062CD, 0x062B5, 0x062DA, 0x062B1, 0x062D8, 0x062D6, 0x062D7, 0x062C6, 0x062AC,
+    0x062CE, 0x0653E, 0x065A7, 0x065BC, 0x065FA, 0x06614, 0x06613, 0x0660C, 0x06606, 0x06602,
+    0x0660E, 0x06600, 0x0660F, 0x06615, 0x0660A, 0x06607, 0x0670D, 0x0670B, 0x0676D, 0x0678B,
+    0x06795, 0x06771, 0x0679C, 0x06773, 0x06777, 0x06787, 0x0679D, 0x06797, 0x0676F, 0x06770,
+    0x0677F, 0x06789, 0x0677E, 0x06790, 0x06775, 0x0679A, 0x06793, 0x0677C, 0x0676A, 0x06772,
+    0x06B23, 0x06B66, 0x06B67, 0x06B7F, 0x06C13, 0x06C1B, 0x06CE3, 0x06CE8, 0x06CF3, 0x06CB1,
+    0x06CCC, 0x06CE5, 0x06CB3, 0x06CBD, 0x06CBE, 0x06CBC, 0x06CE2, 0x06CAB, 0x06CD5, 0x06CD3,
+    0x06CB8, 0x06CC4, 0x06CB9, 0x06CC1, 0x06CAE, 0x06CD7, 0x06CC5, 0x06CF1, 0x06CBF, 0x06CBB,
+    0x06CE1, 0x06CDB, 0x06CCA, 0x06CAC, 0x06CEF, 0x06CDC, 0x06CD6, 0x06CE0, 0x07095, 0x0708E,
+    0x07092, 0x0708A, 0x07099, 0x0722C, 0x0722D, 0x07238, 0x07248, 0x07267, 0x07269, 0x072C0,
+    0x072CE, 0x072D9, 0x072D7, 0x072D0, 0x073A9, 0x073A8, 0x0739F, 0x073AB, 0x073A5, 0x0753D,
+    0x0759D, 0x07599, 0x0759A, 0x07684, 0x076C2, 0x076F2, 0x076F4, 0x077E5, 0x077FD, 0x0793E,
+    0x07940, 0x07941, 0x079C9, 0x079C8, 0x07A7A, 0x07A79, 0x07AFA, 0x07CFE, 0x07F54, 0x07F8C,
+    0x07F8B, 0x08005, 0x080BA, 0x080A5, 0x080A2, 0x080B1, 0x080A1, 0x080AB, 0x080A9, 0x080B4,
+    0x080AA, 0x080AF, 0x081E5, 0x081FE, 0x0820D, 0x082B3, 0x0829D, 0x08299, 0x082AD, 0x082BD,
+    0x0829F, 0x082B9, 0x082B1, 0x082AC, 0x082A5, 0x082AF, 0x082B8, 0x082A3, 0x082B0, 0x082BE,
+    0x082B7, 0x0864E, 0x08671, 0x0521D, 0x08868, 0x08ECB, 0x08FCE, 0x08FD4, 0x08FD1, 0x090B5,
+    0x090B8, 0x090B1, 0x090B6, 0x091C7, 0x091D1, 0x09577, 0x09580, 0x0961C, 0x09640, 0x0963F,
+    0x0963B, 0x09644, 0x09642, 0x096B9, 0x096E8, 0x09752, 0x0975E, 0x04E9F, 0x04EAD, 0x04EAE,
+    0x04FE1, 0x04FB5, 0x04FAF, 0x04FBF, 0x04FE0, 0x04FD1, 0x04FCF, 0x04FDD, 0x04FC3, 0x04FB6,
+    0x04FD8, 0x04FDF, 0x04FCA, 0x04FD7, 0x04FAE, 0x04FD0, 0x04FC4, 0x04FC2, 0x04FDA, 0x04FCE,
+    0x04FDE, 0x04FB7, 0x05157, 0x05192, 0x05191, 0x051A0, 0x0524E, 0x05243, 0x0524A, 0x0524D,
+    0x0524C, 0x0524B, 0x05247, 0x052C7, 0x052C9, 0x052C3, 0x052C1, 0x0530D, 0x05357, 0x0537B,
+    0x0539A, 0x053DB, 0x054AC, 0x054C0, 0x054A8, 0x054CE, 0x054C9, 0x054B8, 0x054A6, 0x054B3,
+    0x054C7, 0x054C2, 0x054BD, 0x054AA, 0x054C1, 0x054C4, 0x054C8, 0x054AF, 0x054AB, 0x054B1,
+    0x054BB, 0x054A9, 0x054A7, 0x054BF, 0x056FF, 0x05782, 0x0578B, 0x057A0, 0x057A3, 0x057A2,
+    0x057CE, 0x057AE, 0x05793, 0x05955, 0x05951, 0x0594F, 0x0594E, 0x05950, 0x059DC, 0x059D8,
+    0x059FF, 0x059E3, 0x059E8, 0x05A03, 0x059E5, 0x059EA, 0x059DA, 0x059E6, 0x05A01, 0x059FB,
+    0x05B69, 0x05BA3, 0x05BA6, 0x05BA4, 0x05BA2, 0x05BA5, 0x05C01, 0x05C4E, 0x05C4F, 0x05C4D,
+    0x05C4B, 0x05CD9, 0x05CD2, 0x05DF7, 0x05E1D, 0x05E25, 0x05E1F, 0x05E7D, 0x05EA0, 0x05EA6,
+    0x05EFA, 0x05F08, 0x05F2D, 0x05F65, 0x05F88, 0x05F85, 0x05F8A, 0x05F8B, 0x05F87, 0x05F8C,
+    0x05F89, 0x06012, 0x0601D, 0x06020, 0x06025, 0x0600E, 0x06028, 0x0604D, 0x06070, 0x06068,
+    0x06062, 0x06046, 0x06043, 0x0606C, 0x0606B, 0x0606A, 0x06064, 0x06241, 0x062DC, 0x06316,
+    0x06309, 0x062FC, 0x062ED, 0x06301, 0x062EE, 0x062FD, 0x06307, 0x062F1, 0x062F7, 0x062EF,
+    0x062EC, 0x062FE, 0x062F4, 0x06311, 0x06302, 0x0653F, 0x06545, 0x065AB, 0x065BD, 0x065E2,
+    0x06625, 0x0662D, 0x06620, 0x06627, 0x0662F, 0x0661F, 0x06628, 0x06631, 0x06624, 0x066F7,
+    0x067FF, 0x067D3, 0x067F1, 0x067D4, 0x067D0, 0x067EC, 0x067B6, 0x067AF, 0x067F5, 0x067E9,
+    0x067EF, 0x067C4, 0x067D1, 0x067B4, 0x067DA, 0x067E5, 0x067B8, 0x067CF, 0x067DE, 0x067F3,
+    0x067B0, 0x067D9, 0x067E2, 0x067DD, 0x067D2, 0x06B6A, 0x06B83, 0x06B86, 0x06BB5, 0x06BD2,
+    0x06BD7, 0x06C1F, 0x06CC9, 0x06D0B, 0x06D32, 0x06D2A, 0x06D41, 0x06D25, 0x06D0C, 0x06D31,
+    0x06D1E, 0x06D17, 0x06D3B, 0x06D3D, 0x06D3E, 0x06D36, 0x06D1B, 0x06CF5, 0x06D39, 0x06D27,
+    0x06D38, 0x06D29, 0x06D2E, 0x06D35, 0x06D0E, 0x06D2B, 0x070AB, 0x070BA, 0x070B3, 0x070AC,
+    0x070AF, 0x070AD, 0x070B8, 0x070AE, 0x070A4, 0x07230, 0x07272, 0x0726F, 0x07274, 0x072E9,
+    0x072E0, 0x072E1, 0x073B7, 0x073CA, 0x073BB, 0x073B2, 0x073CD, 0x073C0, 0x073B3, 0x0751A,
+    0x0752D, 0x0754F, 0x0754C, 0x0754E, 0x0754B, 0x075AB, 0x075A4, 0x075A5, 0x075A2, 0x075A3,
+    0x07678, 0x07686, 0x07687, 0x07688, 0x076C8, 0x076C6, 0x076C3, 0x076C5, 0x07701, 0x076F9,
+    0x076F8, 0x07709, 0x0770B, 0x076FE, 0x076FC, 0x07707, 0x077DC, 0x07802, 0x07814, 0x0780C,
+    0x0780D, 0x07946, 0x07949, 0x07948, 0x07947, 0x079B9, 0x079BA, 0x079D1, 0x079D2, 0x079CB,
+    0x07A7F, 0x07A81, 0x07AFF, 0x07AFD, 0x07C7D, 0x07D02, 0x07D05, 0x07D00, 0x07D09, 0x07D07,
+    0x07D04, 0x07D06, 0x07F38, 0x07F8E, 0x07FBF, 0x08004, 0x08010, 0x0800D, 0x08011, 0x08036,
+    0x080D6, 0x080E5, 0x080DA, 0x080C3, 0x080C4, 0x080CC, 0x080E1, 0x080DB, 0x080CE, 0x080DE,
+    0x080E4, 0x080DD, 0x081F4, 0x08222, 0x082E7, 0x08303, 0x08305, 0x082E3, 0x082DB, 0x082E6,
+    0x08304, 0x082E5, 0x08302, 0x08309, 0x082D2, 0x082D7, 0x082F1, 0x08301, 0x082DC, 0x082D4,
+    0x082D1, 0x082DE, 0x082D3, 0x082DF, 0x082EF, 0x08306, 0x08650, 0x08679, 0x0867B, 0x0867A,
+    0x0884D, 0x0886B, 0x08981, 0x089D4, 0x08A08, 0x08A02, 0x08A03, 0x08C9E, 0x08CA0, 0x08D74,
+    0x08D73, 0x08DB4, 0x08ECD, 0x08ECC, 0x08FF0, 0x08FE6, 0x08FE2, 0x08FEA, 0x08FE5, 0x08FED,
+    0x08FEB, 0x08FE4, 0x08FE8, 0x090CA, 0x090CE, 0x090C1, 0x090C3, 0x0914B, 0x0914A, 0x091CD,
+    0x09582, 0x09650, 0x0964B, 0x0964C, 0x0964D, 0x09762, 0x09769, 0x097CB, 0x097ED, 0x097F3,
+    0x09801, 0x098A8, 0x098DB, 0x098DF, 0x09996, 0x09999, 0x04E58, 0x04EB3, 0x0500C, 0x0500D,
+    0x05023, 0x04FEF, 0x05026, 0x05025, 0x04FF8, 0x05029, 0x05016, 0x05006, 0x0503C, 0x0501F,
+    0x0501A, 0x05012, 0x05011, 0x04FFA, 0x05000, 0x05014, 0x05028, 0x04FF1, 0x05021, 0x0500B,
+    0x05019, 0x05018, 0x04FF3, 0x04FEE, 0x0502D, 0x0502A, 0x04FFE, 0x0502B, 0x05009, 0x0517C,
+    0x051A4, 0x051A5, 0x051A2, 0x051CD, 0x051CC, 0x051C6, 0x051CB, 0x05256, 0x0525C, 0x05254,
+    0x0525B, 0x0525D, 0x0532A, 0x0537F, 0x0539F, 0x0539D, 0x053DF, 0x054E8, 0x05510, 0x05501,
+    0x05537, 0x054FC, 0x054E5, 0x054F2, 0x05506, 0x054FA, 0x05514, 0x054E9, 0x054ED, 0x054E1,
+    0x05509, 0x054EE, 0x054EA, 0x054E6, 0x05527, 0x05507, 0x054FD, 0x0550F, 0x05703, 0x05704,
+    0x057C2, 0x057D4, 0x057CB, 0x057C3, 0x05809, 0x0590F, 0x05957, 0x05958, 0x0595A, 0x05A11,
+    0x05A18, 0x05A1C, 0x05A1F, 0x05A1B, 0x05A13, 0x059EC, 0x05A20, 0x05A23, 0x05A29, 0x05A25,
+    0x05A0C, 0x05A09, 0x05B6B, 0x05C58, 0x05BB0, 0x05BB3, 0x05BB6, 0x05BB4, 0x05BAE, 0x05BB5,
+    0x05BB9, 0x05BB8, 0x05C04, 0x05C51, 0x05C55, 0x05C50, 0x05CED, 0x05CFD, 0x05CFB, 0x05CEA,
+    0x05CE8, 0x05CF0, 0x05CF6, 0x05D01, 0x05CF4, 0x05DEE, 0x05E2D, 0x05E2B, 0x05EAB, 0x05EAD,
+    0x05EA7, 0x05F31, 0x05F92, 0x05F91, 0x05F90, 0x06059, 0x06063, 0x06065, 0x06050, 0x06055,
+    0x0606D, 0x06069, 0x0606F, 0x06084, 0x0609F, 0x0609A, 0x0608D, 0x06094, 0x0608C, 0x06085,
+    0x06096, 0x06247, 0x062F3, 0x06308, 0x062FF, 0x0634E, 0x0633E, 0x0632F, 0x06355, 0x06342,
+    0x06346, 0x0634F, 0x06349, 0x0633A, 0x06350, 0x0633D, 0x0632A, 0x0632B, 0x06328, 0x0634D,
+    0x0634C, 0x06548, 0x06549, 0x06599, 0x065C1, 0x065C5, 0x06642, 0x06649, 0x0664F, 0x06643,
+    0x06652, 0x0664C, 0x06645, 0x06641, 0x066F8, 0x06714, 0x06715, 0x06717, 0x06821, 0x06838,
+    0x06848, 0x06846, 0x06853, 0x06839, 0x06842, 0x06854, 0x06829, 0x068B3, 0x06817, 0x0684C,
+    0x06851, 0x0683D, 0x067F4, 0x06850, 0x06840, 0x0683C, 0x06843, 0x0682A, 0x06845, 0x06813,
+    0x06818, 0x06841, 0x06B8A, 0x06B89, 0x06BB7, 0x06C23, 0x06C27, 0x06C28, 0x06C26, 0x06C24,
+    0x06CF0, 0x06D6A, 0x06D95, 0x06D88, 0x06D87, 0x06D66, 0x06D78, 0x06D77, 0x06D59, 0x06D93,
+    0x06D6C, 0x06D89, 0x06D6E, 0x06D5A, 0x06D74, 0x06D69, 0x06D8C, 0x06D8A, 0x06D79, 0x06D85,
+    0x06D65, 0x06D94, 0x070CA, 0x070D8, 0x070E4, 0x070D9, 0x070C8, 0x070CF, 0x07239, 0x07279,
+    0x072FC, 0x072F9, 0x072FD, 0x072F8, 0x072F7, 0x07386, 0x073ED, 0x07409, 0x073EE, 0x073E0,
+    0x073EA, 0x073DE, 0x07554, 0x0755D, 0x0755C, 0x0755A, 0x07559, 0x075BE, 0x075C5, 0x075C7,
+    0x075B2, 0x075B3, 0x075BD, 0x075BC, 0x075B9, 0x075C2, 0x075B8, 0x0768B, 0x076B0, 0x076CA,
+    0x076CD, 0x076CE, 0x07729, 0x0771F, 0x07720, 0x07728, 0x077E9, 0x07830, 0x07827, 0x07838,
+    0x0781D, 0x07834, 0x07837, 0x07825, 0x0782D, 0x07820, 0x0781F, 0x07832, 0x07955, 0x07950,
+    0x07960, 0x0795F, 0x07956, 0x0795E, 0x0795D, 0x07957, 0x0795A, 0x079E4, 0x079E3, 0x079E7,
+    0x079DF, 0x079E6, 0x079E9, 0x079D8, 0x07A84, 0x07A88, 0x07AD9, 0x07B06, 0x07B11, 0x07C89,
+    0x07D21, 0x07D17, 0x07D0B, 0x07D0A, 0x07D20, 0x07D22, 0x07D14, 0x07D10, 0x07D15, 0x07D1A,
+    0x07D1C, 0x07D0D, 0x07D19, 0x07D1B, 0x07F3A, 0x07F5F, 0x07F94, 0x07FC5, 0x07FC1, 0x08006,
+    0x08018, 0x08015, 0x08019, 0x08017, 0x0803D, 0x0803F, 0x080F1, 0x08102, 0x080F0, 0x08105,
+    0x080ED, 0x080F4, 0x08106, 0x080F8, 0x080F3, 0x08108, 0x080FD, 0x0810A, 0x080FC, 0x080EF,
+    0x081ED, 0x081EC, 0x08200, 0x08210, 0x0822A, 0x0822B, 0x08228, 0x0822C, 0x082BB, 0x0832B,
+    0x08352, 0x08354, 0x0834A, 0x08338, 0x08350, 0x08349, 0x08335, 0x08334, 0x0834F, 0x08332,
+    0x08339, 0x08336, 0x08317, 0x08340, 0x08331, 0x08328, 0x08343, 0x08654, 0x0868A, 0x086AA,
+    0x08693, 0x086A4, 0x086A9, 0x0868C, 0x086A3, 0x0869C, 0x08870, 0x08877, 0x08881, 0x08882,
+    0x0887D, 0x08879, 0x08A18, 0x08A10, 0x08A0E, 0x08A0C, 0x08A15, 0x08A0A, 0x08A17, 0x08A13,
+    0x08A16, 0x08A0F, 0x08A11, 0x08C48, 0x08C7A, 0x08C79, 0x08CA1, 0x08CA2, 0x08D77, 0x08EAC,
+    0x08ED2, 0x08ED4, 0x08ECF, 0x08FB1, 0x09001, 0x09006, 0x08FF7, 0x09000, 0x08FFA, 0x08FF4,
+    0x09003, 0x08FFD, 0x09005, 0x08FF8, 0x09095, 0x090E1, 0x090DD, 0x090E2, 0x09152, 0x0914D,
+    0x0914C, 0x091D8, 0x091DD, 0x091D7, 0x091DC, 0x091D9, 0x09583, 0x09662, 0x09663, 0x09661,
+    0x0965B, 0x0965D, 0x09664, 0x09658, 0x0965E, 0x096BB, 0x098E2, 0x099AC, 0x09AA8, 0x09AD8,
+    0x09B25, 0x09B32, 0x09B3C, 0x04E7E, 0x0507A, 0x0507D, 0x0505C, 0x05047, 0x05043, 0x0504C,
+    0x0505A, 0x05049, 0x05065, 0x05076, 0x0504E, 0x05055, 0x05075, 0x05074, 0x05077, 0x0504F,
+    0x0500F, 0x0506F, 0x0506D, 0x0515C, 0x05195, 0x051F0, 0x0526A, 0x0526F, 0x052D2, 0x052D9,
+    0x052D8, 0x052D5, 0x05310, 0x0530F, 0x05319, 0x0533F, 0x05340, 0x0533E, 0x053C3, 0x066FC,
+    0x05546, 0x0556A, 0x05566, 0x05544, 0x0555E, 0x05561, 0x05543, 0x0554A, 0x05531, 0x05556,
+    0x0554F, 0x05555, 0x0552F, 0x05564, 0x05538, 0x0552E, 0x0555C, 0x0552C, 0x05563, 0x05533,
+    0x05541, 0x05557, 0x05708, 0x0570B, 0x05709, 0x057DF, 0x05805, 0x0580A, 0x05806, 0x057E0,
+    0x057E4, 0x057FA, 0x05802, 0x05835, 0x057F7, 0x057F9, 0x05920, 0x05962, 0x05A36, 0x05A41,
+    0x05A49, 0x05A66, 0x05A6A, 0x05A40, 0x05A3C, 0x05A62, 0x05A5A, 0x05A46, 0x05A4A, 0x05B70,
+    0x05BC7, 0x05BC5, 0x05BC4, 0x05BC2, 0x05BBF, 0x05BC6, 0x05C09, 0x05C08, 0x05C07, 0x05C60,
+    0x05C5C, 0x05C5D, 0x05D07, 0x05D06, 0x05D0E, 0x05D1B, 0x05D16, 0x05D22, 0x05D11, 0x05D29,
+    0x05D14, 0x05D19, 0x05D24, 0x05D27, 0x05D17, 0x05DE2, 0x05E38, 0x05E36, 0x05E33, 0x05E37,
+    0x05EB7, 0x05EB8, 0x05EB6, 0x05EB5, 0x05EBE, 0x05F35, 0x05F37, 0x05F57, 0x05F6C, 0x05F69,
+    0x05F6B, 0x05F97, 0x05F99, 0x05F9E, 0x05F98, 0x05FA1, 0x05FA0, 0x05F9C, 0x0607F, 0x060A3,
+    0x06089, 0x060A0, 0x060A8, 0x060CB, 0x060B4, 0x060E6, 0x060BD, 0x060C5, 0x060BB, 0x060B5,
+    0x060DC, 0x060BC, 0x060D8, 0x060D5, 0x060C6, 0x060DF, 0x060B8, 0x060DA, 0x060C7, 0x0621A,
+    0x0621B, 0x06248, 0x063A0, 0x063A7, 0x06372, 0x06396, 0x063A2, 0x063A5, 0x06377, 0x06367,
+    0x06398, 0x063AA, 0x06371, 0x063A9, 0x06389, 0x06383, 0x0639B, 0x0636B, 0x063A8, 0x06384,
+    0x06388, 0x06399, 0x063A1, 0x063AC, 0x06392, 0x0638F, 0x06380, 0x0637B, 0x06369, 0x06368,
+    0x0637A, 0x0655D, 0x06556, 0x06551, 0x06559, 0x06557, 0x0555F, 0x0654F, 0x06558, 0x06555,
+    0x06554, 0x0659C, 0x0659B, 0x065AC, 0x065CF, 0x065CB, 0x065CC, 0x065CE, 0x0665D, 0x0665A,
+    0x06664, 0x06668, 0x06666, 0x0665E, 0x066F9, 0x052D7, 0x0671B, 0x06881, 0x068AF, 0x068A2,
+    0x06893, 0x068B5, 0x0687F, 0x06876, 0x068B1, 0x068A7, 0x06897, 0x068B0, 0x06883, 0x068C4,
+    0x068AD, 0x06886, 0x06885, 0x06894, 0x0689D, 0x068A8, 0x0689F, 0x068A1, 0x06882, 0x06B32,
+    0x06BBA, 0x06BEB, 0x06BEC, 0x06C2B, 0x06D8E, 0x06DBC, 0x06DF3, 0x06DD9, 0x06DB2, 0x06DE1,
+    0x06DCC, 0x06DE4, 0x06DFB, 0x06DFA, 0x06E05, 0x06DC7, 0x06DCB, 0x06DAF, 0x06DD1, 0x06DAE,
+    0x06DDE, 0x06DF9, 0x06DB8, 0x06DF7, 0x06DF5, 0x06DC5, 0x06DD2, 0x06E1A, 0x06DB5, 0x06DDA,
+    0x06DEB, 0x06DD8, 0x06DEA, 0x06DF1, 0x06DEE, 0x06DE8, 0x06DC6, 0x06DC4, 0x06DAA, 0x06DEC,
+    0x06DBF, 0x06DE6, 0x070F9, 0x07109, 0x0710A, 0x070FD, 0x070EF, 0x0723D, 0x0727D, 0x07281,
+    0x0731C, 0x0731B, 0x07316, 0x07313, 0x07319, 0x07387, 0x07405, 0x0740A, 0x07403, 0x07406,
+    0x073FE, 0x0740D, 0x074E0, 0x074F6, 0x074F7, 0x0751C, 0x07522, 0x07565, 0x07566, 0x07562,
+    0x07570, 0x0758F, 0x075D4, 0x075D5, 0x075B5, 0x075CA, 0x075CD, 0x0768E, 0x076D4, 0x076D2,
+    0x076DB, 0x07737, 0x0773E, 0x0773C, 0x07736, 0x07738, 0x0773A, 0x0786B, 0x07843, 0x0784E,
+    0x07965, 0x07968, 0x0796D, 0x079FB, 0x07A92, 0x07A95, 0x07B20, 0x07B28, 0x07B1B, 0x07B2C,
+    0x07B26, 0x07B19, 0x07B1E, 0x07B2E, 0x07C92, 0x07C97, 0x07C95, 0x07D46, 0x07D43, 0x07D71,
+    0x07D2E, 0x07D39, 0x07D3C, 0x07D40, 0x07D30, 0x07D33, 0x07D44, 0x07D2F, 0x07D42, 0x07D32,
+    0x07D31, 0x07F3D, 0x07F9E, 0x07F9A, 0x07FCC, 0x07FCE, 0x07FD2, 0x0801C, 0x0804A, 0x08046,
+    0x0812F, 0x08116, 0x08123, 0x0812B, 0x08129, 0x08130, 0x08124, 0x08202, 0x08235, 0x08237,
+    0x08236, 0x08239, 0x0838E, 0x0839E, 0x08398, 0x08378, 0x083A2, 0x08396, 0x083BD, 0x083AB,
+    0x08392, 0x0838A, 0x08393, 0x08389, 0x083A0, 0x08377, 0x0837B, 0x0837C, 0x08386, 0x083A7,
+    0x08655, 0x05F6A, 0x086C7, 0x086C0, 0x086B6, 0x086C4, 0x086B5, 0x086C6, 0x086CB, 0x086B1,
+    0x086AF, 0x086C9, 0x08853, 0x0889E, 0x08888, 0x088AB, 0x08892, 0x08896, 0x0888D, 0x0888B,
+    0x08993, 0x0898F, 0x08A2A, 0x08A1D, 0x08A23, 0x08A25, 0x08A31, 0x08A2D, 0x08A1F, 0x08A1B,
+    0x08A22, 0x08C49, 0x08C5A, 0x08CA9, 0x08CAC, 0x08CAB, 0x08CA8, 0x08CAA, 0x08CA7, 0x08D67,
+    0x08D66, 0x08DBE, 0x08DBA, 0x08EDB, 0x08EDF, 0x09019, 0x0900D, 0x0901A, 0x09017, 0x09023,
+    0x0901F, 0x0901D, 0x09010, 0x09015, 0x0901E, 0x09020, 0x0900F, 0x09022, 0x09016, 0x0901B,
+    0x09014, 0x090E8, 0x090ED, 0x090FD, 0x09157, 0x091CE, 0x091F5, 0x091E6, 0x091E3, 0x091E7,
+    0x091ED, 0x091E9, 0x09589, 0x0966A, 0x09675, 0x09673, 0x09678, 0x09670, 0x09674, 0x09676,
+    0x09677, 0x0966C, 0x096C0, 0x096EA, 0x096E9, 0x07AE0, 0x07ADF, 0x09802, 0x09803, 0x09B5A,
+    0x09CE5, 0x09E75, 0x09E7F, 0x09EA5, 0x09EBB, 0x050A2, 0x0508D, 0x05085, 0x05099, 0x05091,
+    0x05080, 0x05096, 0x05098, 0x0509A, 0x06700, 0x051F1, 0x05272, 0x05274, 0x05275, 0x05269,
+    0x052DE, 0x052DD, 0x052DB, 0x0535A, 0x053A5, 0x0557B, 0x05580, 0x055A7, 0x0557C, 0x0558A,
+    0x0559D, 0x05598, 0x05582, 0x0559C, 0x055AA, 0x05594, 0x05587, 0x0558B, 0x05583, 0x055B3,
+    0x055AE, 0x0559F, 0x0553E, 0x055B2, 0x0559A, 0x055BB, 0x055AC, 0x055B1, 0x0557E, 0x05589,
+    0x055AB, 0x05599, 0x0570D, 0x0582F, 0x0582A, 0x05834, 0x05824, 0x05830, 0x05831, 0x05821,
+    0x0581D, 0x05820, 0x058F9, 0x058FA, 0x05960, 0x05A77, 0x05A9A, 0x05A7F, 0x05A92, 0x05A9B,
+    0x05AA7, 0x05B73, 0x05B71, 0x05BD2, 0x05BCC, 0x05BD3, 0x05BD0, 0x05C0A, 0x05C0B, 0x05C31,
+    0x05D4C, 0x05D50, 0x05D34, 0x05D47, 0x05DFD, 0x05E45, 0x05E3D, 0x05E40, 0x05E43, 0x05E7E,
+    0x05ECA, 0x05EC1, 0x05EC2, 0x05EC4, 0x05F3C, 0x05F6D, 0x05FA9, 0x05FAA, 0x05FA8, 0x060D1,
+    0x060E1, 0x060B2, 0x060B6, 0x060E0, 0x0611C, 0x06123, 0x060FA, 0x06115, 0x060F0, 0x060FB,
+    0x060F4, 0x06168, 0x060F1, 0x0610E, 0x060F6, 0x06109, 0x06100, 0x06112, 0x0621F, 0x06249,
+    0x063A3, 0x0638C, 0x063CF, 0x063C0, 0x063E9, 0x063C9, 0x063C6, 0x063CD, 0x063D2, 0x063E3,
+    0x063D0, 0x063E1, 0x063D6, 0x063ED, 0x063EE, 0x06376, 0x063F4, 0x063EA, 0x063DB, 0x06452,
+    0x063DA, 0x063F9, 0x0655E, 0x06566, 0x06562, 0x06563, 0x06591, 0x06590, 0x065AF, 0x0666E,
+    0x06670, 0x06674, 0x06676, 0x0666F, 0x06691, 0x0667A, 0x0667E, 0x06677, 0x066FE, 0x066FF,
+    0x0671F, 0x0671D, 0x068FA, 0x068D5, 0x068E0, 0x068D8, 0x068D7, 0x06905, 0x068DF, 0x068F5,
+    0x068EE, 0x068E7, 0x068F9, 0x068D2, 0x068F2, 0x068E3, 0x068CB, 0x068CD, 0x0690D, 0x06912,
+    0x0690E, 0x068C9, 0x068DA, 0x0696E, 0x068FB, 0x06B3E, 0x06B3A, 0x06B3D, 0x06B98, 0x06B96,
+    0x06BBC, 0x06BEF, 0x06C2E, 0x06C2F, 0x06C2C, 0x06E2F, 0x06E38, 0x06E54, 0x06E21, 0x06E32,
+    0x06E67, 0x06E4A, 0x06E20, 0x06E25, 0x06E23, 0x06E1B, 0x06E5B, 0x06E58, 0x06E24, 0x06E56,
+    0x06E6E, 0x06E2D, 0x06E26, 0x06E6F, 0x06E34, 0x06E4D, 0x06E3A, 0x06E2C, 0x06E43, 0x06E1D,
+    0x06E3E, 0x06ECB, 0x06E89, 0x06E19, 0x06E4E, 0x06E63, 0x06E44, 0x06E72, 0x06E69, 0x06E5F,
+    0x07119, 0x0711A, 0x07126, 0x07130, 0x07121, 0x07136, 0x0716E, 0x0711C, 0x0724C, 0x07284,
+    0x07280, 0x07336, 0x07325, 0x07334, 0x07329, 0x0743A, 0x0742A, 0x07433, 0x07422, 0x07425,
+    0x07435, 0x07436, 0x07434, 0x0742F, 0x0741B, 0x07426, 0x07428, 0x07525, 0x07526, 0x0756B,
+    0x0756A, 0x075E2, 0x075DB, 0x075E3, 0x075D9, 0x075D8, 0x075DE, 0x075E0, 0x0767B, 0x0767C,
+    0x07696, 0x07693, 0x076B4, 0x076DC, 0x0774F, 0x077ED, 0x0785D, 0x0786C, 0x0786F, 0x07A0D,
+    0x07A08, 0x07A0B, 0x07A05, 0x07A00, 0x07A98, 0x07A97, 0x07A96, 0x07AE5, 0x07AE3, 0x07B49,
+    0x07B56, 0x07B46, 0x07B50, 0x07B52, 0x07B54, 0x07B4D, 0x07B4B, 0x07B4F, 0x07B51, 0x07C9F,
+    0x07CA5, 0x07D5E, 0x07D50, 0x07D68, 0x07D55, 0x07D2B, 0x07D6E, 0x07D72, 0x07D61, 0x07D66,
+    0x07D62, 0x07D70, 0x07D73, 0x05584, 0x07FD4, 0x07FD5, 0x0800B, 0x08052, 0x08085, 0x08155,
+    0x08154, 0x0814B, 0x08151, 0x0814E, 0x08139, 0x08146, 0x0813E, 0x0814C, 0x08153, 0x08174,
+    0x08212, 0x0821C, 0x083E9, 0x08403, 0x083F8, 0x0840D, 0x083E0, 0x083C5, 0x0840B, 0x083C1,
+    0x083EF, 0x083F1, 0x083F4, 0x08457, 0x0840A, 0x083F0, 0x0840C, 0x083CC, 0x083FD, 0x083F2,
+    0x083CA, 0x08438, 0x0840E, 0x08404, 0x083DC, 0x08407, 0x083D4, 0x083DF, 0x0865B, 0x086DF,
+    0x086D9, 0x086ED, 0x086D4, 0x086DB, 0x086E4, 0x086D0, 0x086DE, 0x08857, 0x088C1, 0x088C2,
+    0x088B1, 0x08983, 0x08996, 0x08A3B, 0x08A60, 0x08A55, 0x08A5E, 0x08A3C, 0x08A41, 0x08A54,
+    0x08A5B, 0x08A50, 0x08A46, 0x08A34, 0x08A3A, 0x08A36, 0x08A56, 0x08C61, 0x08C82, 0x08CAF,
+    0x08CBC, 0x08CB3, 0x08CBD, 0x08CC1, 0x08CBB, 0x08CC0, 0x08CB4, 0x08CB7, 0x08CB6, 0x08CBF,
+    0x08CB8, 0x08D8A, 0x08D85, 0x08D81, 0x08DCE, 0x08DDD, 0x08DCB, 0x08DDA, 0x08DD1, 0x08DCC,
+    0x08DDB, 0x08DC6, 0x08EFB, 0x08EF8, 0x08EFC, 0x08F9C, 0x0902E, 0x09035, 0x09031, 0x09038,
+    0x09032, 0x09036, 0x09102, 0x090F5, 0x09109, 0x090FE, 0x09163, 0x09165, 0x091CF, 0x09214,
+    0x09215, 0x09223, 0x09209, 0x0921E, 0x0920D, 0x09210, 0x09207, 0x09211, 0x09594, 0x0958F,
+    0x0958B, 0x09591, 0x09593, 0x09592, 0x0958E, 0x0968A, 0x0968E, 0x0968B, 0x0967D, 0x09685,
+    0x09686, 0x0968D, 0x09672, 0x09684, 0x096C1, 0x096C5, 0x096C4, 0x096C6, 0x096C7, 0x096EF,
+    0x096F2, 0x097CC, 0x09805, 0x09806, 0x09808, 0x098E7, 0x098EA, 0x098EF, 0x098E9, 0x098F2,
+    0x098ED, 0x099AE, 0x099AD, 0x09EC3, 0x09ECD, 0x09ED1, 0x04E82, 0x050AD, 0x050B5, 0x050B2,
+    0x050B3, 0x050C5, 0x050BE, 0x050AC, 0x050B7, 0x050BB, 0x050AF, 0x050C7, 0x0527F, 0x05277,
+    0x0527D, 0x052DF, 0x052E6, 0x052E4, 0x052E2, 0x052E3, 0x0532F, 0x055DF, 0x055E8, 0x055D3,
+    0x055E6, 0x055CE, 0x055DC, 0x055C7, 0x055D1, 0x055E3, 0x055E4, 0x055EF, 0x055DA, 0x055E1,
+    0x055C5, 0x055C6, 0x055E5, 0x055C9, 0x05712, 0x05713, 0x0585E, 0x05851, 0x05858, 0x05857,
+    0x0585A, 0x05854, 0x0586B, 0x0584C, 0x0586D, 0x0584A, 0x05862, 0x05852, 0x0584B, 0x05967,
+    0x05AC1, 0x05AC9, 0x05ACC, 0x05ABE, 0x05ABD, 0x05ABC, 0x05AB3, 0x05AC2, 0x05AB2, 0x05D69,
+    0x05D6F, 0x05E4C, 0x05E79, 0x05EC9, 0x05EC8, 0x05F12, 0x05F59, 0x05FAC, 0x05FAE, 0x0611A,
+    0x0610F, 0x06148, 0x0611F, 0x060F3, 0x0611B, 0x060F9, 0x06101, 0x06108, 0x0614E, 0x0614C,
+    0x06144, 0x0614D, 0x0613E, 0x06134, 0x06127, 0x0610D, 0x06106, 0x06137, 0x06221, 0x06222,
+    0x06413, 0x0643E, 0x0641E, 0x0642A, 0x0642D, 0x0643D, 0x0642C, 0x0640F, 0x0641C, 0x06414,
+    0x0640D, 0x06436, 0x06416, 0x06417, 0x06406, 0x0656C, 0x0659F, 0x065B0, 0x06697, 0x06689,
+    0x06687, 0x06688, 0x06696, 0x06684, 0x06698, 0x0668D, 0x06703, 0x06994, 0x0696D, 0x0695A,
+    0x06977, 0x06960, 0x06954, 0x06975, 0x06930, 0x06982, 0x0694A, 0x06968, 0x0696B, 0x0695E,
+    0x06953, 0x06979, 0x06986, 0x0695D, 0x06963, 0x0695B, 0x06B47, 0x06B72, 0x06BC0, 0x06BBF,
+    0x06BD3, 0x06BFD, 0x06EA2, 0x06EAF, 0x06ED3, 0x06EB6, 0x06EC2, 0x06E90, 0x06E9D, 0x06EC7,
+    0x06EC5, 0x06EA5, 0x06E98, 0x06EBC, 0x06EBA, 0x06EAB, 0x06ED1, 0x06E96, 0x06E9C, 0x06EC4,
+    0x06ED4, 0x06EAA, 0x06EA7, 0x06EB4, 0x0714E, 0x07159, 0x07169, 0x07164, 0x07149, 0x07167,
+    0x0715C, 0x0716C, 0x07166, 0x0714C, 0x07165, 0x0715E, 0x07146, 0x07168, 0x07156, 0x0723A,
+    0x07252, 0x07337, 0x07345, 0x0733F, 0x0733E, 0x0746F, 0x0745A, 0x07455, 0x0745F, 0x0745E,
+    0x07441, 0x0743F, 0x07459, 0x0745B, 0x0745C, 0x07576, 0x07578, 0x07600, 0x075F0, 0x07601,
+    0x075F2, 0x075F1, 0x075FA, 0x075FF, 0x075F4, 0x075F3, 0x076DE, 0x076DF, 0x0775B, 0x0776B,
+    0x07766, 0x0775E, 0x07763, 0x07779, 0x0776A, 0x0776C, 0x0775C, 0x07765, 0x07768, 0x07762,
+    0x077EE, 0x0788E, 0x078B0, 0x07897, 0x07898, 0x0788C, 0x07889, 0x0787C, 0x07891, 0x07893,
+    0x0787F, 0x0797A, 0x0797F, 0x07981, 0x0842C, 0x079BD, 0x07A1C, 0x07A1A, 0x07A20, 0x07A14,
+    0x07A1F, 0x07A1E, 0x07A9F, 0x07AA0, 0x07B77, 0x07BC0, 0x07B60, 0x07B6E, 0x07B67, 0x07CB1,
+    0x07CB3, 0x07CB5, 0x07D93, 0x07D79, 0x07D91, 0x07D81, 0x07D8F, 0x07D5B, 0x07F6E, 0x07F69,
+    0x07F6A, 0x07F72, 0x07FA9, 0x07FA8, 0x07FA4, 0x08056, 0x08058, 0x08086, 0x08084, 0x08171,
+    0x08170, 0x08178, 0x08165, 0x0816E, 0x08173, 0x0816B, 0x08179, 0x0817A, 0x08166, 0x08205,
+    0x08247, 0x08482, 0x08477, 0x0843D, 0x08431, 0x08475, 0x08466, 0x0846B, 0x08449, 0x0846C,
+    0x0845B, 0x0843C, 0x08435, 0x08461, 0x08463, 0x08469, 0x0846D, 0x08446, 0x0865E, 0x0865C,
+    0x0865F, 0x086F9, 0x08713, 0x08708, 0x08707, 0x08700, 0x086FE, 0x086FB, 0x08702, 0x08703,
+    0x08706, 0x0870A, 0x08859, 0x088DF, 0x088D4, 0x088D9, 0x088DC, 0x088D8, 0x088DD, 0x088E1,
+    0x088CA, 0x088D5, 0x088D2, 0x0899C, 0x089E3, 0x08A6B, 0x08A72, 0x08A73, 0x08A66, 0x08A69,
+    0x08A70, 0x08A87, 0x08A7C, 0x08A63, 0x08AA0, 0x08A71, 0x08A85, 0x08A6D, 0x08A62, 0x08A6E,
+    0x08A6C, 0x08A79, 0x08A7B, 0x08A3E, 0x08A68, 0x08C62, 0x08C8A, 0x08C89, 0x08CCA, 0x08CC7,
+    0x08CC8, 0x08CC4, 0x08CB2, 0x08CC3, 0x08CC2, 0x08CC5, 0x08DE1, 0x08DDF, 0x08DE8, 0x08DEF,
+    0x08DF3, 0x08DFA, 0x08DEA, 0x08DE4, 0x08DE6, 0x08EB2, 0x08F03, 0x08F09, 0x08EFE, 0x08F0A,
+    0x08F9F, 0x08FB2, 0x0904B, 0x0904A, 0x09053, 0x09042, 0x09054, 0x0903C, 0x09055, 0x09050,
+    0x09047, 0x0904F, 0x0904E, 0x0904D, 0x09051, 0x0903E, 0x09041, 0x09112, 0x09117, 0x0916C,
+    0x0916A, 0x09169, 0x091C9, 0x09237, 0x09257, 0x09238, 0x0923D, 0x09240, 0x0923E, 0x0925B,
+    0x0924B, 0x09264, 0x09251, 0x09234, 0x09249, 0x0924D, 0x09245, 0x09239, 0x0923F, 0x0925A,
+    0x09598, 0x09698, 0x09694, 0x09695, 0x096CD, 0x096CB, 0x096C9, 0x096CA, 0x096F7, 0x096FB,
+    0x096F9, 0x096F6, 0x09756, 0x09774, 0x09776, 0x09810, 0x09811, 0x09813, 0x0980A, 0x09812,
+    0x0980C, 0x098FC, 0x098F4, 0x098FD, 0x098FE, 0x099B3, 0x099B1, 0x099B4, 0x09AE1, 0x09CE9,
+    0x09E82, 0x09F0E, 0x09F13, 0x09F20, 0x050E7, 0x050EE, 0x050E5, 0x050D6, 0x050ED, 0x050DA,
+    0x050D5, 0x050CF, 0x050D1, 0x050F1, 0x050CE, 0x050E9, 0x05162, 0x051F3, 0x05283, 0x05282,
+    0x05331, 0x053AD, 0x055FE, 0x05600, 0x0561B, 0x05617, 0x055FD, 0x05614, 0x05606, 0x05609,
+    0x0560D, 0x0560E, 0x055F7, 0x05616, 0x0561F, 0x05608, 0x05610, 0x055F6, 0x05718, 0x05716,
+    0x05875, 0x0587E, 0x05883, 0x05893, 0x0588A, 0x05879, 0x05885, 0x0587D, 0x058FD, 0x05925,
+    0x05922, 0x05924, 0x0596A, 0x05969, 0x05AE1, 0x05AE6, 0x05AE9, 0x05AD7, 0x05AD6, 0x05AD8,
+    0x05AE3, 0x05B75, 0x05BDE, 0x05BE7, 0x05BE1, 0x05BE5, 0x05BE6, 0x05BE8, 0x05BE2, 0x05BE4,
+    0x05BDF, 0x05C0D, 0x05C62, 0x05D84, 0x05D87, 0x05E5B, 0x05E63, 0x05E55, 0x05E57, 0x05E54,
+    0x05ED3, 0x05ED6, 0x05F0A, 0x05F46, 0x05F70, 0x05FB9, 0x06147, 0x0613F, 0x0614B, 0x06177,
+    0x06162, 0x06163, 0x0615F, 0x0615A, 0x06158, 0x06175, 0x0622A, 0x06487, 0x06458, 0x06454,
+    0x064A4, 0x06478, 0x0645F, 0x0647A, 0x06451, 0x06467, 0x06434, 0x0646D, 0x0647B, 0x06572,
+    0x065A1, 0x065D7, 0x065D6, 0x066A2, 0x066A8, 0x0669D, 0x0699C, 0x069A8, 0x06995, 0x069C1,
+    0x069AE, 0x069D3, 0x069CB, 0x0699B, 0x069B7, 0x069BB, 0x069AB, 0x069B4, 0x069D0, 0x069CD,
+    0x069AD, 0x069CC, 0x069A6, 0x069C3, 0x069A3, 0x06B49, 0x06B4C, 0x06C33, 0x06F33, 0x06F14,
+    0x06EFE, 0x06F13, 0x06EF4, 0x06F29, 0x06F3E, 0x06F20, 0x06F2C, 0x06F0F, 0x06F02, 0x06F22,
+    0x06EFF, 0x06EEF, 0x06F06, 0x06F31, 0x06F38, 0x06F32, 0x06F23, 0x06F15, 0x06F2B, 0x06F2F,
+    0x06F88, 0x06F2A, 0x06EEC, 0x06F01, 0x06EF2, 0x06ECC, 0x06EF7, 0x07194, 0x07199, 0x0717D,
+    0x0718A, 0x07184, 0x07192, 0x0723E, 0x07292, 0x07296, 0x07344, 0x07350, 0x07464, 0x07463,
+    0x0746A, 0x07470, 0x0746D, 0x07504, 0x07591, 0x07627, 0x0760D, 0x0760B, 0x07609, 0x07613,
+    0x076E1, 0x076E3, 0x07784, 0x0777D, 0x0777F, 0x07761, 0x078C1, 0x0789F, 0x078A7, 0x078B3,
+    0x078A9, 0x078A3, 0x0798E, 0x0798F, 0x0798D, 0x07A2E, 0x07A31, 0x07AAA, 0x07AA9, 0x07AED,
+    0x07AEF, 0x07BA1, 0x07B95, 0x07B8B, 0x07B75, 0x07B97, 0x07B9D, 0x07B94, 0x07B8F, 0x07BB8,
+    0x07B87, 0x07B84, 0x07CB9, 0x07CBD, 0x07CBE, 0x07DBB, 0x07DB0, 0x07D9C, 0x07DBD, 0x07DBE,
+    0x07DA0, 0x07DCA, 0x07DB4, 0x07DB2, 0x07DB1, 0x07DBA, 0x07DA2, 0x07DBF, 0x07DB5, 0x07DB8,
+    0x07DAD, 0x07DD2, 0x07DC7, 0x07DAC, 0x07F70, 0x07FE0, 0x07FE1, 0x07FDF, 0x0805E, 0x0805A,
+    0x08087, 0x08150, 0x08180, 0x0818F, 0x08188, 0x0818A, 0x0817F, 0x08182, 0x081E7, 0x081FA,
+    0x08207, 0x08214, 0x0821E, 0x0824B, 0x084C9, 0x084BF, 0x084C6, 0x084C4, 0x08499, 0x0849E,
+    0x084B2, 0x0849C, 0x084CB, 0x084B8, 0x084C0, 0x084D3, 0x08490, 0x084BC, 0x084D1, 0x084CA,
+    0x0873F, 0x0871C, 0x0873B, 0x08722, 0x08725, 0x08734, 0x08718, 0x08755, 0x08737, 0x08729,
+    0x088F3, 0x08902, 0x088F4, 0x088F9, 0x088F8, 0x088FD, 0x088E8, 0x0891A, 0x088EF, 0x08AA6,
+    0x08A8C, 0x08A9E, 0x08AA3, 0x08A8D, 0x08AA1, 0x08A93, 0x08AA4, 0x08AAA, 0x08AA5, 0x08AA8,
+    0x08A98, 0x08A91, 0x08A9A, 0x08AA7, 0x08C6A, 0x08C8D, 0x08C8C, 0x08CD3, 0x08CD1, 0x08CD2,
+    0x08D6B, 0x08D99, 0x08D95, 0x08DFC, 0x08F14, 0x08F12, 0x08F15, 0x08F13, 0x08FA3, 0x09060,
+    0x09058, 0x0905C, 0x09063, 0x09059, 0x0905E, 0x09062, 0x0905D, 0x0905B, 0x09119, 0x09118,
+    0x0911E, 0x09175, 0x09178, 0x09177, 0x09174, 0x09278, 0x09280, 0x09285, 0x09298, 0x09296,
+    0x0927B, 0x09293, 0x0929C, 0x092A8, 0x0927C, 0x09291, 0x095A1, 0x095A8, 0x095A9, 0x095A3,
+    0x095A5, 0x095A4, 0x09699, 0x0969C, 0x0969B, 0x096CC, 0x096D2, 0x09700, 0x0977C, 0x09785,
+    0x097F6, 0x09817, 0x09818, 0x098AF, 0x098B1, 0x09903, 0x09905, 0x0990C, 0x09909, 0x099C1,
+    0x09AAF, 0x09AB0, 0x09AE6, 0x09B41, 0x09B42, 0x09CF4, 0x09CF6, 0x09CF3, 0x09EBC, 0x09F3B,
+    0x09F4A, 0x05104, 0x05100, 0x050FB, 0x050F5, 0x050F9, 0x05102, 0x05108, 0x05109, 0x05105,
+    0x051DC, 0x05287, 0x05288, 0x05289, 0x0528D, 0x0528A, 0x052F0, 0x053B2, 0x0562E, 0x0563B,
+    0x05639, 0x05632, 0x0563F, 0x05634, 0x05629, 0x05653, 0x0564E, 0x05657, 0x05674, 0x05636,
+    0x0562F, 0x05630, 0x05880, 0x0589F, 0x0589E, 0x058B3, 0x0589C, 0x058AE, 0x058A9, 0x058A6,
+    0x0596D, 0x05B09, 0x05AFB, 0x05B0B, 0x05AF5, 0x05B0C, 0x05B08, 0x05BEE, 0x05BEC, 0x05BE9,
+    0x05BEB, 0x05C64, 0x05C65, 0x05D9D, 0x05D94, 0x05E62, 0x05E5F, 0x05E61, 0x05EE2, 0x05EDA,
+    0x05EDF, 0x05EDD, 0x05EE3, 0x05EE0, 0x05F48, 0x05F71, 0x05FB7, 0x05FB5, 0x06176, 0x06167,
+    0x0616E, 0x0615D, 0x06155, 0x06182, 0x0617C, 0x06170, 0x0616B, 0x0617E, 0x061A7, 0x06190,
+    0x061AB, 0x0618E, 0x061AC, 0x0619A, 0x061A4, 0x06194, 0x061AE, 0x0622E, 0x06469, 0x0646F,
+    0x06479, 0x0649E, 0x064B2, 0x06488, 0x06490, 0x064B0, 0x064A5, 0x06493, 0x06495, 0x064A9,
+    0x06492, 0x064AE, 0x064AD, 0x064AB, 0x0649A, 0x064AC, 0x06499, 0x064A2, 0x064B3, 0x06575,
+    0x06577, 0x06578, 0x066AE, 0x066AB, 0x066B4, 0x066B1, 0x06A23, 0x06A1F, 0x069E8, 0x06A01,
+    0x06A1E, 0x06A19, 0x069FD, 0x06A21, 0x06A13, 0x06A0A, 0x069F3, 0x06A02, 0x06A05, 0x069ED,
+    0x06A11, 0x06B50, 0x06B4E, 0x06BA4, 0x06BC5, 0x06BC6, 0x06F3F, 0x06F7C, 0x06F84, 0x06F51,
+    0x06F66, 0x06F54, 0x06F86, 0x06F6D, 0x06F5B, 0x06F78, 0x06F6E, 0x06F8E, 0x06F7A, 0x06F70,
+    0x06F64, 0x06F97, 0x06F58, 0x06ED5, 0x06F6F, 0x06F60, 0x06F5F, 0x0719F, 0x071AC, 0x071B1,
+    0x071A8, 0x07256, 0x0729B, 0x0734E, 0x07357, 0x07469, 0x0748B, 0x07483, 0x0747E, 0x07480,
+    0x0757F, 0x07620, 0x07629, 0x0761F, 0x07624, 0x07626, 0x07621, 0x07622, 0x0769A, 0x076BA,
+    0x076E4, 0x0778E, 0x07787, 0x0778C, 0x07791, 0x0778B, 0x078CB, 0x078C5, 0x078BA, 0x078CA,
+    0x078BE, 0x078D5, 0x078BC, 0x078D0, 0x07A3F, 0x07A3C, 0x07A40, 0x07A3D, 0x07A37, 0x07A3B,
+    0x07AAF, 0x07AAE, 0x07BAD, 0x07BB1, 0x07BC4, 0x07BB4, 0x07BC6, 0x07BC7, 0x07BC1, 0x07BA0,
+    0x07BCC, 0x07CCA, 0x07DE0, 0x07DF4, 0x07DEF, 0x07DFB, 0x07DD8, 0x07DEC, 0x07DDD, 0x07DE8,
+    0x07DE3, 0x07DDA, 0x07DDE, 0x07DE9, 0x07D9E, 0x07DD9, 0x07DF2, 0x07DF9, 0x07F75, 0x07F77,
+    0x07FAF, 0x07FE9, 0x08026, 0x0819B, 0x0819C, 0x0819D, 0x081A0, 0x0819A, 0x08198, 0x08517,
+    0x0853D, 0x0851A, 0x084EE, 0x0852C, 0x0852D, 0x08513, 0x08511, 0x08523, 0x08521, 0x08514,
+    0x084EC, 0x08525, 0x084FF, 0x08506, 0x08782, 0x08774, 0x08776, 0x08760, 0x08766, 0x08778,
+    0x08768, 0x08759, 0x08757, 0x0874C, 0x08753, 0x0885B, 0x0885D, 0x08910, 0x08907, 0x08912,
+    0x08913, 0x08915, 0x0890A, 0x08ABC, 0x08AD2, 0x08AC7, 0x08AC4, 0x08A95, 0x08ACB, 0x08AF8,
+    0x08AB2, 0x08AC9, 0x08AC2, 0x08ABF, 0x08AB0, 0x08AD6, 0x08ACD, 0x08AB6, 0x08AB9, 0x08ADB,
+    0x08C4C, 0x08C4E, 0x08C6C, 0x08CE0, 0x08CDE, 0x08CE6, 0x08CE4, 0x08CEC, 0x08CED, 0x08CE2,
+    0x08CE3, 0x08CDC, 0x08CEA, 0x08CE1, 0x08D6D, 0x08D9F, 0x08DA3, 0x08E2B, 0x08E10, 0x08E1D,
+    0x08E22, 0x08E0F, 0x08E29, 0x08E1F, 0x08E21, 0x08E1E, 0x08EBA, 0x08F1D, 0x08F1B, 0x08F1F,
+    0x08F29, 0x08F26, 0x08F2A, 0x08F1C, 0x08F1E, 0x08F25, 0x09069, 0x0906E, 0x09068, 0x0906D,
+    0x09077, 0x09130, 0x0912D, 0x09127, 0x09131, 0x09187, 0x09189, 0x0918B, 0x09183, 0x092C5,
+    0x092BB, 0x092B7, 0x092EA, 0x092AC, 0x092E4, 0x092C1, 0x092B3, 0x092BC, 0x092D2, 0x092C7,
+    0x092F0, 0x092B2, 0x095AD, 0x095B1, 0x09704, 0x09706, 0x09707, 0x09709, 0x09760, 0x0978D,
+    0x0978B, 0x0978F, 0x09821, 0x0982B, 0x0981C, 0x098B3, 0x0990A, 0x09913, 0x09912, 0x09918,
+    0x099DD, 0x099D0, 0x099DF, 0x099DB, 0x099D1, 0x099D5, 0x099D2, 0x099D9, 0x09AB7, 0x09AEE,
+    0x09AEF, 0x09B27, 0x09B45, 0x09B44, 0x09B77, 0x09B6F, 0x09D06, 0x09D09, 0x09D03, 0x09EA9,
+    0x09EBE, 0x09ECE, 0x058A8, 0x09F52, 0x05112, 0x05118, 0x05114, 0x05110, 0x05115, 0x05180,
+    0x051AA, 0x051DD, 0x05291, 0x05293, 0x052F3, 0x05659, 0x0566B, 0x05679, 0x05669, 0x05664,
+    0x05678, 0x0566A, 0x05668, 0x05665, 0x05671, 0x0566F, 0x0566C, 0x05662, 0x05676, 0x058C1,
+    0x058BE, 0x058C7, 0x058C5, 0x0596E, 0x05B1D, 0x05B34, 0x05B78, 0x05BF0, 0x05C0E, 0x05F4A,
+    0x061B2, 0x06191, 0x061A9, 0x0618A, 0x061CD, 0x061B6, 0x061BE, 0x061CA, 0x061C8, 0x06230,
+    0x064C5, 0x064C1, 0x064CB, 0x064BB, 0x064BC, 0x064DA, 0x064C4, 0x064C7, 0x064C2, 0x064CD,
+    0x064BF, 0x064D2, 0x064D4, 0x064BE, 0x06574, 0x066C6, 0x066C9, 0x066B9, 0x066C4, 0x066C7,
+    0x066B8, 0x06A3D, 0x06A38, 0x06A3A, 0x06A59, 0x06A6B, 0x06A58, 0x06A39, 0x06A44, 0x06A62,
+    0x06A61, 0x06A4B, 0x06A47, 0x06A35, 0x06A5F, 0x06A48, 0x06B59, 0x06B77, 0x06C05, 0x06FC2,
+    0x06FB1, 0x06FA1, 0x06FC3, 0x06FA4, 0x06FC1, 0x06FA7, 0x06FB3, 0x06FC0, 0x06FB9, 0x06FB6,
+    0x06FA6, 0x06FA0, 0x06FB4, 0x071BE, 0x071C9, 0x071D0, 0x071D2, 0x071C8, 0x071D5, 0x071B9,
+    0x071CE, 0x071D9, 0x071DC, 0x071C3, 0x071C4, 0x07368, 0x0749C, 0x074A3, 0x07498, 0x0749F,
+    0x0749E, 0x074E2, 0x0750C, 0x0750D, 0x07634, 0x07638, 0x0763A, 0x076E7, 0x076E5, 0x077A0,
+    0x0779E, 0x0779F, 0x077A5, 0x078E8, 0x078DA, 0x078EC, 0x078E7, 0x079A6, 0x07A4D, 0x07A4E,
+    0x07A46, 0x07A4C, 0x07A4B, 0x07ABA, 0x07BD9, 0x07C11, 0x07BC9, 0x07BE4, 0x07BDB, 0x07BE1,
+    0x07BE9, 0x07BE6, 0x07CD5, 0x07CD6, 0x07E0A, 0x07E11, 0x07E08, 0x07E1B, 0x07E23, 0x07E1E,
+    0x07E1D, 0x07E09, 0x07E10, 0x07F79, 0x07FB2, 0x07FF0, 0x07FF1, 0x07FEE, 0x08028, 0x081B3,
+    0x081A9, 0x081A8, 0x081FB, 0x08208, 0x08258, 0x08259, 0x0854A, 0x08559, 0x08548, 0x08568,
+    0x08569, 0x08543, 0x08549, 0x0856D, 0x0856A, 0x0855E, 0x08783, 0x0879F, 0x0879E, 0x087A2,
+    0x0878D, 0x08861, 0x0892A, 0x08932, 0x08925, 0x0892B, 0x08921, 0x089AA, 0x089A6, 0x08AE6,
+    0x08AFA, 0x08AEB, 0x08AF1, 0x08B00, 0x08ADC, 0x08AE7, 0x08AEE, 0x08AFE, 0x08B01, 0x08B02,
+    0x08AF7, 0x08AED, 0x08AF3, 0x08AF6, 0x08AFC, 0x08C6B, 0x08C6D, 0x08C93, 0x08CF4, 0x08E44,
+    0x08E31, 0x08E34, 0x08E42, 0x08E39, 0x08E35, 0x08F3B, 0x08F2F, 0x08F38, 0x08F33, 0x08FA8,
+    0x08FA6, 0x09075, 0x09074, 0x09078, 0x09072, 0x0907C, 0x0907A, 0x09134, 0x09192, 0x09320,
+    0x09336, 0x092F8, 0x09333, 0x0932F, 0x09322, 0x092FC, 0x0932B, 0x09304, 0x0931A, 0x09310,
+    0x09326, 0x09321, 0x09315, 0x0932E, 0x09319, 0x095BB, 0x096A7, 0x096A8, 0x096AA, 0x096D5,
+    0x0970E, 0x09711, 0x09716, 0x0970D, 0x09713, 0x0970F, 0x0975B, 0x0975C, 0x09766, 0x09798,
+    0x09830, 0x09838, 0x0983B, 0x09837, 0x0982D, 0x09839, 0x09824, 0x09910, 0x09928, 0x0991E,
+    0x0991B, 0x09921, 0x0991A, 0x099ED, 0x099E2, 0x099F1, 0x09AB8, 0x09ABC, 0x09AFB, 0x09AED,
+    0x09B28, 0x09B91, 0x09D15, 0x09D23, 0x09D26, 0x09D28, 0x09D12, 0x09D1B, 0x09ED8, 0x09ED4,
+    0x09F8D, 0x09F9C, 0x0512A, 0x0511F, 0x05121, 0x05132, 0x052F5, 0x0568E, 0x05680, 0x05690,
+    0x05685, 0x05687, 0x0568F, 0x058D5, 0x058D3, 0x058D1, 0x058CE, 0x05B30, 0x05B2A, 0x05B24,
+    0x05B7A, 0x05C37, 0x05C68, 0x05DBC, 0x05DBA, 0x05DBD, 0x05DB8, 0x05E6B, 0x05F4C, 0x05FBD,
+    0x061C9, 0x061C2, 0x061C7, 0x061E6, 0x061CB, 0x06232, 0x06234, 0x064CE, 0x064CA, 0x064D8,
+    0x064E0, 0x064F0, 0x064E6, 0x064EC, 0x064F1, 0x064E2, 0x064ED, 0x06582, 0x06583, 0x066D9,
+    0x066D6, 0x06A80, 0x06A94, 0x06A84, 0x06AA2, 0x06A9C, 0x06ADB, 0x06AA3, 0x06A7E, 0x06A97,
+    0x06A90, 0x06AA0, 0x06B5C, 0x06BAE, 0x06BDA, 0x06C08, 0x06FD8, 0x06FF1, 0x06FDF, 0x06FE0,
+    0x06FDB, 0x06FE4, 0x06FEB, 0x06FEF, 0x06F80, 0x06FEC, 0x06FE1, 0x06FE9, 0x06FD5, 0x06FEE,
+    0x06FF0, 0x071E7, 0x071DF, 0x071EE, 0x071E6, 0x071E5, 0x071ED, 0x071EC, 0x071F4, 0x071E0,
+    0x07235, 0x07246, 0x07370, 0x07372, 0x074A9, 0x074B0, 0x074A6, 0x074A8, 0x07646, 0x07642,
+    0x0764C, 0x076EA, 0x077B3, 0x077AA, 0x077B0, 0x077AC, 0x077A7, 0x077AD, 0x077EF, 0x078F7,
+    0x078FA, 0x078F4, 0x078EF, 0x07901, 0x079A7, 0x079AA, 0x07A57, 0x07ABF, 0x07C07, 0x07C0D,
+    0x07BFE, 0x07BF7, 0x07C0C, 0x07BE0, 0x07CE0, 0x07CDC, 0x07CDE, 0x07CE2, 0x07CDF, 0x07CD9,
+    0x07CDD, 0x07E2E, 0x07E3E, 0x07E46, 0x07E37, 0x07E32, 0x07E43, 0x07E2B, 0x07E3D, 0x07E31,
+    0x07E45, 0x07E41, 0x07E34, 0x07E39, 0x07E48, 0x07E35, 0x07E3F, 0x07E2F, 0x07F44, 0x07FF3,
+    0x07FFC, 0x08071, 0x08072, 0x08070, 0x0806F, 0x08073, 0x081C6, 0x081C3, 0x081BA, 0x081C2,
+    0x081C0, 0x081BF, 0x081BD, 0x081C9, 0x081BE, 0x081E8, 0x08209, 0x08271, 0x085AA, 0x08584,
+    0x0857E, 0x0859C, 0x08591, 0x08594, 0x085AF, 0x0859B, 0x08587, 0x085A8, 0x0858A, 0x08667,
+    0x087C0, 0x087D1, 0x087B3, 0x087D2, 0x087C6, 0x087AB, 0x087BB, 0x087BA, 0x087C8, 0x087CB,
+    0x0893B, 0x08936, 0x08944, 0x08938, 0x0893D, 0x089AC, 0x08B0E, 0x08B17, 0x08B19, 0x08B1B,
+    0x08B0A, 0x08B20, 0x08B1D, 0x08B04, 0x08B10, 0x08C41, 0x08C3F, 0x08C73, 0x08CFA, 0x08CFD,
+    0x08CFC, 0x08CF8, 0x08CFB, 0x08DA8, 0x08E49, 0x08E4B, 0x08E48, 0x08E4A, 0x08F44, 0x08F3E,
+    0x08F42, 0x08F45, 0x08F3F, 0x0907F, 0x0907D, 0x09084, 0x09081, 0x09082, 0x09080, 0x09139,
+    0x091A3, 0x0919E, 0x0919C, 0x0934D, 0x09382, 0x09328, 0x09375, 0x0934A, 0x09365, 0x0934B,
+    0x09318, 0x0937E, 0x0936C, 0x0935B, 0x09370, 0x0935A, 0x09354, 0x095CA, 0x095CB, 0x095CC,
+    0x095C8, 0x095C6, 0x096B1, 0x096B8, 0x096D6, 0x0971C, 0x0971E, 0x097A0, 0x097D3, 0x09846,
+    0x098B6, 0x09935, 0x09A01, 0x099FF, 0x09BAE, 0x09BAB, 0x09BAA, 0x09BAD, 0x09D3B, 0x09D3F,
+    0x09E8B, 0x09ECF, 0x09EDE, 0x09EDC, 0x09EDD, 0x09EDB, 0x09F3E, 0x09F4B, 0x053E2, 0x05695,
+    0x056AE, 0x058D9, 0x058D8, 0x05B38, 0x05F5D, 0x061E3, 0x06233, 0x064F4, 0x064F2, 0x064FE,
+    0x06506, 0x064FA, 0x064FB, 0x064F7, 0x065B7, 0x066DC, 0x06726, 0x06AB3, 0x06AAC, 0x06AC3,
+    0x06ABB, 0x06AB8, 0x06AC2, 0x06AAE, 0x06AAF, 0x06B5F, 0x06B78, 0x06BAF, 0x07009, 0x0700B,
+    0x06FFE, 0x07006, 0x06FFA, 0x07011, 0x0700F, 0x071FB, 0x071FC, 0x071FE, 0x071F8, 0x07377,
+    0x07375, 0x074A7, 0x074BF, 0x07515, 0x07656, 0x07658, 0x07652, 0x077BD, 0x077BF, 0x077BB,
+    0x077BC, 0x0790E, 0x079AE, 0x07A61, 0x07A62, 0x07A60, 0x07AC4, 0x07AC5, 0x07C2B, 0x07C27,
+    0x07C2A, 0x07C1E, 0x07C23, 0x07C21, 0x07CE7, 0x07E54, 0x07E55, 0x07E5E, 0x07E5A, 0x07E61,
+    0x07E52, 0x07E59, 0x07F48, 0x07FF9, 0x07FFB, 0x08077, 0x08076, 0x081CD, 0x081CF, 0x0820A,
+    0x085CF, 0x085A9, 0x085CD, 0x085D0, 0x085C9, 0x085B0, 0x085BA, 0x085B9, 0x085A6, 0x087EF,
+    0x087EC, 0x087F2, 0x087E0, 0x08986, 0x089B2, 0x089F4, 0x08B28, 0x08B39, 0x08B2C, 0x08B2B,
+    0x08C50, 0x08D05, 0x08E59, 0x08E63, 0x08E66, 0x08E64, 0x08E5F, 0x08E55, 0x08EC0, 0x08F49,
+    0x08F4D, 0x09087, 0x09083, 0x09088, 0x091AB, 0x091AC, 0x091D0, 0x09394, 0x0938A, 0x09396,
+    0x093A2, 0x093B3, 0x093AE, 0x093AC, 0x093B0, 0x09398, 0x0939A, 0x09397, 0x095D4, 0x095D6,
+    0x095D0, 0x095D5, 0x096E2, 0x096DC, 0x096D9, 0x096DB, 0x096DE, 0x09724, 0x097A3, 0x097A6,
+    0x097AD, 0x097F9, 0x0984D, 0x0984F, 0x0984C, 0x0984E, 0x09853, 0x098BA, 0x0993E, 0x0993F,
+    0x0993D, 0x0992E, 0x099A5, 0x09A0E, 0x09AC1, 0x09B03, 0x09B06, 0x09B4F, 0x09B4E, 0x09B4D,
+    0x09BCA, 0x09BC9, 0x09BFD, 0x09BC8, 0x09BC0, 0x09D51, 0x09D5D, 0x09D60, 0x09EE0, 0x09F15,
+    0x09F2C, 0x05133, 0x056A5, 0x058DE, 0x058DF, 0x058E2, 0x05BF5, 0x09F90, 0x05EEC, 0x061F2,
+    0x061F7, 0x061F6, 0x061F5, 0x06500, 0x0650F, 0x066E0, 0x066DD, 0x06AE5, 0x06ADD, 0x06ADA,
+    0x06AD3, 0x0701B, 0x0701F, 0x07028, 0x0701A, 0x0701D, 0x07015, 0x07018, 0x07206, 0x0720D,
+    0x07258, 0x072A2, 0x07378, 0x0737A, 0x074BD, 0x074CA, 0x074E3, 0x07587, 0x07586, 0x0765F,
+    0x07661, 0x077C7, 0x07919, 0x079B1, 0x07A6B, 0x07A69, 0x07C3E, 0x07C3F, 0x07C38, 0x07C3D,
+    0x07C37, 0x07C40, 0x07E6B, 0x07E6D, 0x07E79, 0x07E69, 0x07E6A, 0x07F85, 0x07E73, 0x07FB6,
+    0x07FB9, 0x07FB8, 0x081D8, 0x085E9, 0x085DD, 0x085EA, 0x085D5, 0x085E4, 0x085E5, 0x085F7,
+    0x087FB, 0x08805, 0x0880D, 0x087F9, 0x087FE, 0x08960, 0x0895F, 0x08956, 0x0895E, 0x08B41,
+    0x08B5C, 0x08B58, 0x08B49, 0x08B5A, 0x08B4E, 0x08B4F, 0x08B46, 0x08B59, 0x08D08, 0x08D0A,
+    0x08E7C, 0x08E72, 0x08E87, 0x08E76, 0x08E6C, 0x08E7A, 0x08E74, 0x08F54, 0x08F4E, 0x08FAD,
+    0x0908A, 0x0908B, 0x091B1, 0x091AE, 0x093E1, 0x093D1, 0x093DF, 0x093C3, 0x093C8, 0x093DC,
+    0x093DD, 0x093D6, 0x093E2, 0x093CD, 0x093D8, 0x093E4, 0x093D7, 0x093E8, 0x095DC, 0x096B4,
+    0x096E3, 0x0972A, 0x09727, 0x09761, 0x097DC, 0x097FB, 0x0985E, 0x09858, 0x0985B, 0x098BC,
+    0x09945, 0x09949, 0x09A16, 0x09A19, 0x09B0D, 0x09BE8, 0x09BE7, 0x09BD6, 0x09BDB, 0x09D89,
+    0x09D61, 0x09D72, 0x09D6A, 0x09D6C, 0x09E92, 0x09E97, 0x09E93, 0x09EB4, 0x052F8, 0x056A8,
+    0x056B7, 0x056B6, 0x056B4, 0x056BC, 0x058E4, 0x05B40, 0x05B43, 0x05B7D, 0x05BF6, 0x05DC9,
+    0x061F8, 0x061FA, 0x06518, 0x06514, 0x06519, 0x066E6, 0x06727, 0x06AEC, 0x0703E, 0x07030,
+    0x07032, 0x07210, 0x0737B, 0x074CF, 0x07662, 0x07665, 0x07926, 0x0792A, 0x0792C, 0x0792B,
+    0x07AC7, 0x07AF6, 0x07C4C, 0x07C43, 0x07C4D, 0x07CEF, 0x07CF0, 0x08FAE, 0x07E7D, 0x07E7C,
+    0x07E82, 0x07F4C, 0x08000, 0x081DA, 0x08266, 0x085FB, 0x085F9, 0x08611, 0x085FA, 0x08606,
+    0x0860B, 0x08607, 0x0860A, 0x08814, 0x08815, 0x08964, 0x089BA, 0x089F8, 0x08B70, 0x08B6C,
+    0x08B66, 0x08B6F, 0x08B5F, 0x08B6B, 0x08D0F, 0x08D0D, 0x08E89, 0x08E81, 0x08E85, 0x08E82,
+    0x091B4, 0x091CB, 0x09418, 0x09403, 0x093FD, 0x095E1, 0x09730, 0x098C4, 0x09952, 0x09951,
+    0x099A8, 0x09A2B, 0x09A30, 0x09A37, 0x09A35, 0x09C13, 0x09C0D, 0x09E79, 0x09EB5, 0x09EE8,
+    0x09F2F, 0x09F5F, 0x09F63, 0x09F61, 0x05137, 0x05138, 0x056C1, 0x056C0, 0x056C2, 0x05914,
+    0x05C6C, 0x05DCD, 0x061FC, 0x061FE, 0x0651D, 0x0651C, 0x06595, 0x066E9, 0x06AFB, 0x06B04,
+    0x06AFA, 0x06BB2, 0x0704C, 0x0721B, 0x072A7, 0x074D6, 0x074D4, 0x07669, 0x077D3, 0x07C50,
+    0x07E8F, 0x07E8C, 0x07FBC, 0x08617, 0x0862D, 0x0861A, 0x08823, 0x08822, 0x08821, 0x0881F,
+    0x0896A, 0x0896C, 0x089BD, 0x08B74, 0x08B77, 0x08B7D, 0x08D13, 0x08E8A, 0x08E8D, 0x08E8B,
+    0x08F5F, 0x08FAF, 0x091BA, 0x0942E, 0x09433, 0x09435, 0x0943A, 0x09438, 0x09432, 0x0942B,
+    0x095E2, 0x09738, 0x09739, 0x09732, 0x097FF, 0x09867, 0x09865, 0x09957, 0x09A45, 0x09A43,
+    0x09A40, 0x09A3E, 0x09ACF, 0x09B54, 0x09B51, 0x09C2D, 0x09C25, 0x09DAF, 0x09DB4, 0x09DC2,
+    0x09DB8, 0x09E9D, 0x09EEF, 0x09F19, 0x09F5C, 0x09F66, 0x09F67, 0x0513C, 0x0513B, 0x056C8,
+    0x056CA, 0x056C9, 0x05B7F, 0x05DD4, 0x05DD2, 0x05F4E, 0x061FF, 0x06524, 0x06B0A, 0x06B61,
+    0x07051, 0x07058, 0x07380, 0x074E4, 0x0758A, 0x0766E, 0x0766C, 0x079B3, 0x07C60, 0x07C5F,
+    0x0807E, 0x0807D, 0x081DF, 0x08972, 0x0896F, 0x089FC, 0x08B80, 0x08D16, 0x08D17, 0x08E91,
+    0x08E93, 0x08F61, 0x09148, 0x09444, 0x09451, 0x09452, 0x0973D, 0x0973E, 0x097C3, 0x097C1,
+    0x0986B, 0x09955, 0x09A55, 0x09A4D, 0x09AD2, 0x09B1A, 0x09C49, 0x09C31, 0x09C3E, 0x09C3B,
+    0x09DD3, 0x09DD7, 0x09F34, 0x09F6C, 0x09F6A, 0x09F94, 0x056CC, 0x05DD6, 0x06200, 0x06523,
+    0x0652B, 0x0652A, 0x066EC, 0x06B10, 0x074DA, 0x07ACA, 0x07C64, 0x07C63, 0x07C65, 0x07E93,
+    0x07E96, 0x07E94, 0x081E2, 0x08638, 0x0863F, 0x08831, 0x08B8A, 0x09090, 0x0908F, 0x09463,
+    0x09460, 0x09464, 0x09768, 0x0986F, 0x0995C, 0x09A5A, 0x09A5B, 0x09A57, 0x09AD3, 0x09AD4,
+    0x09AD1, 0x09C54, 0x09C57, 0x09C56, 0x09DE5, 0x09E9F, 0x09EF4, 0x056D1, 0x058E9, 0x0652C,
+    0x0705E, 0x07671, 0x07672, 0x077D7, 0x07F50, 0x07F88, 0x08836, 0x08839, 0x08862, 0x08B93,
+    0x08B92, 0x08B96, 0x08277, 0x08D1B, 0x091C0, 0x0946A, 0x09742, 0x09748, 0x09744, 0x097C6,
+    0x09870, 0x09A5F, 0x09B22, 0x09B58, 0x09C5F, 0x09DF9, 0x09DFA, 0x09E7C, 0x09E7D, 0x09F07,
+    0x09F77, 0x09F72, 0x05EF3, 0x06B16, 0x07063, 0x07C6C, 0x07C6E, 0x0883B, 0x089C0, 0x08EA1,
+    0x091C1, 0x09472, 0x09470, 0x09871, 0x0995E, 0x09AD6, 0x09B23, 0x09ECC, 0x07064, 0x077DA,
+    0x08B9A, 0x09477, 0x097C9, 0x09A62, 0x09A65, 0x07E9C, 0x08B9C, 0x08EAA, 0x091C5, 0x0947D,
+    0x0947E, 0x0947C, 0x09C77, 0x09C78, 0x09EF7, 0x08C54, 0x0947F, 0x09E1A, 0x07228, 0x09A6A,
+    0x09B31, 0x09E1B, 0x09E1E, 0x07C72, 0x02460, 0x02461, 0x02462, 0x02463, 0x02464, 0x02465,
+    0x02466, 0x02467, 0x02468, 0x02469, 0x02474, 0x02475, 0x02476, 0x02477, 0x02478, 0x02479,
+    0x0247A, 0x0247B, 0x0247C, 0x0247D, 0x02170, 0x02171, 0x02172, 0x02173, 0x02174, 0x02175,
+    0x02176, 0x02177, 0x02178, 0x02179, 0x04E36, 0x04E3F, 0x04E85, 0x04EA0, 0x05182, 0x05196,
+    0x051AB, 0x052F9, 0x05338, 0x05369, 0x053B6, 0x0590A, 0x05B80, 0x05DDB, 0x02F33, 0x05E7F,
+    0x00000, 0x05F50, 0x05F61, 0x06534, 0x00000, 0x07592, 0x00000, 0x08FB5, 0x00000, 0x000A8,
+    0x002C6, 0x030FD, 0x030FE, 0x0309D, 0x0309E, 0x00000, 0x00000, 0x03005, 0x03006, 0x03007,
+    0x030FC, 0x0FF3B, 0x0FF3D, 0x0273D, 0x03041, 0x03042, 0x03043, 0x03044, 0x03045, 0x03046,
+    0x03047, 0x03048, 0x03049, 0x0304A, 0x0304B, 0x0304C, 0x0304D, 0x0304E, 0x0304F, 0x03050,
+    0x03051, 0x03052, 0x03053, 0x03054, 0x03055, 0x03056, 0x03057, 0x03058, 0x03059, 0x0305A,
+    0x0305B, 0x0305C, 0x0305D, 0x0305E, 0x0305F, 0x03060, 0x03061, 0x03062, 0x03063, 0x03064,
+    0x03065, 0x03066, 0x03067, 0x03068, 0x03069, 0x0306A, 0x0306B, 0x0306C, 0x0306D, 0x0306E,
+    0x0306F, 0x03070, 0x03071, 0x03072, 0x03073, 0x03074, 0x03075, 0x03076, 0x03077, 0x03078,
+    0x03079, 0x0307A, 0x0307B, 0x0307C, 0x0307D, 0x0307E, 0x0307F, 0x03080, 0x03081, 0x03082,
+    0x03083, 0x03084, 0x03085, 0x03086, 0x03087, 0x03088, 0x03089, 0x0308A, 0x0308B, 0x0308C,
+    0x0308D, 0x0308E, 0x0308F, 0x03090, 0x03091, 0x03092, 0x03093, 0x030A1, 0x030A2, 0x030A3,
+    0x030A4, 0x030A5, 0x030A6, 0x030A7, 0x030A8, 0x030A9, 0x030AA, 0x030AB, 0x030AC, 0x030AD,
+    0x030AE, 0x030AF, 0x030B0, 0x030B1, 0x030B2, 0x030B3, 0x030B4, 0x030B5, 0x030B6, 0x030B7,
+    0x030B8, 0x030B9, 0x030BA, 0x030BB, 0x030BC, 0x030BD, 0x030BE, 0x030BF, 0x030C0, 0x030C1,
+    0x030C2, 0x030C3, 0x030C4, 0x030C5, 0x030C6, 0x030C7, 0x030C8, 0x030C9, 0x030CA, 0x030CB,
+    0x030CC, 0x030CD, 0x030CE, 0x030CF, 0x030D0, 0x030D1, 0x030D2, 0x030D3, 0x030D4, 0x030D5,
+    0x030D6, 0x030D7, 0x030D8, 0x030D9, 0x030DA, 0x030DB, 0x030DC, 0x030DD, 0x030DE, 0x030DF,
+    0x030E0, 0x030E1, 0x030E2, 0x030E3, 0x030E4, 0x030E5, 0x030E6, 0x030E7, 0x030E8, 0x030E9,
+    0x030EA, 0x030EB, 0x030EC, 0x030ED, 0x030EE, 0x030EF, 0x030F0, 0x030F1, 0x030F2, 0x030F3,
+    0x030F4, 0x030F5, 0x030F6, 0x00410, 0x00411, 0x00412, 0x00413, 0x00414, 0x00415, 0x00401,
+    0x00416, 0x00417, 0x00418, 0x00419, 0x0041A, 0x0041B, 0x0041C, 0x0041D, 0x0041E, 0x0041F,
+    0x00420, 0x00421, 0x00422, 0x00423, 0x00424, 0x00425, 0x00426, 0x00427, 0x00428, 0x00429,
+    0x0042A, 0x0042B, 0x0042C, 0x0042D, 0x0042E, 0x0042F, 0x00430, 0x00431, 0x00432, 0x00433,
+    0x00434, 0x00435, 0x00451, 0x00436, 0x00437, 0x00438, 0x00439, 0x0043A, 0x0043B, 0x0043C,
+    0x0043D, 0x0043E, 0x0043F, 0x00440, 0x00441, 0x00442, 0x00443, 0x00444, 0x00445, 0x00446,
+    0x00447, 0x00448, 0x00449, 0x0044A, 0x0044B, 0x0044C, 0x0044D, 0x0044E, 0x0044F, 0x021E7,
+    0x021B8, 0x021B9, 0x031CF, 0x200CC, 0x04E5A, 0x2008A, 0x05202, 0x04491, 0x09FB0, 0x05188,
+    0x09FB1, 0x27607, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x0FFE2, 0x0FFE4, 0x0FF07, 0x0FF02, 0x03231, 0x02116, 0x02121, 0x0309B,
+    0x0309C, 0x02E80, 0x02E84, 0x02E86, 0x02E87, 0x02E88, 0x02E8A, 0x02E8C, 0x02E8D, 0x02E95,
+    0x02E9C, 0x02E9D, 0x02EA5, 0x02EA7, 0x02EAA, 0x02EAC, 0x02EAE, 0x02EB6, 0x02EBC, 0x02EBE,
+    0x02EC6, 0x02ECA, 0x02ECC, 0x02ECD, 0x02ECF, 0x02ED6, 0x02ED7, 0x02EDE, 0x02EE3, 0x00000,
+    0x00000, 0x00000, 0x00283, 0x00250, 0x0025B, 0x00254, 0x00275, 0x00153, 0x000F8, 0x0014B,
+    0x0028A, 0x0026A, 0x04E42, 0x04E5C, 0x051F5, 0x0531A, 0x05382, 0x04E07, 0x04E0C, 0x04E47,
+    0x04E8D, 0x056D7, 0x0FA0C, 0x05C6E, 0x05F73, 0x04E0F, 0x05187, 0x04E0E, 0x04E2E, 0x04E93,
+    0x04EC2, 0x04EC9, 0x04EC8, 0x05198, 0x052FC, 0x0536C, 0x053B9, 0x05720, 0x05903, 0x0592C,
+    0x05C10, 0x05DFF, 0x065E1, 0x06BB3, 0x06BCC, 0x06C14, 0x0723F, 0x04E31, 0x04E3C, 0x04EE8,
+    0x04EDC, 0x04EE9, 0x04EE1, 0x04EDD, 0x04EDA, 0x0520C, 0x0531C, 0x0534C, 0x05722, 0x05723,
+    0x05917, 0x0592F, 0x05B81, 0x05B84, 0x05C12, 0x05C3B, 0x05C74, 0x05C73, 0x05E04, 0x05E80,
+    0x05E82, 0x05FC9, 0x06209, 0x06250, 0x06C15, 0x06C36, 0x06C43, 0x06C3F, 0x06C3B, 0x072AE,
+    0x072B0, 0x0738A, 0x079B8, 0x0808A, 0x0961E, 0x04F0E, 0x04F18, 0x04F2C, 0x04EF5, 0x04F14,
+    0x04EF1, 0x04F00, 0x04EF7, 0x04F08, 0x04F1D, 0x04F02, 0x04F05, 0x04F22, 0x04F13, 0x04F04,
+    0x04EF4, 0x04F12, 0x051B1, 0x05213, 0x05209, 0x05210, 0x052A6, 0x05322, 0x0531F, 0x0534D,
+    0x0538A, 0x05407, 0x056E1, 0x056DF, 0x0572E, 0x0572A, 0x05734, 0x0593C, 0x05980, 0x0597C,
+    0x05985, 0x0597B, 0x0597E, 0x05977, 0x0597F, 0x05B56, 0x05C15, 0x05C25, 0x05C7C, 0x05C7A,
+    0x05C7B, 0x05C7E, 0x05DDF, 0x05E75, 0x05E84, 0x05F02, 0x05F1A, 0x05F74, 0x05FD5, 0x05FD4,
+    0x05FCF, 0x0625C, 0x0625E, 0x06264, 0x06261, 0x06266, 0x06262, 0x06259, 0x06260, 0x0625A,
+    0x06265, 0x065EF, 0x065EE, 0x0673E, 0x06739, 0x06738, 0x0673B, 0x0673A, 0x0673F, 0x0673C,
+    0x06733, 0x06C18, 0x06C46, 0x06C52, 0x06C5C, 0x06C4F, 0x06C4A, 0x06C54, 0x06C4B, 0x06C4C,
+    0x07071, 0x0725E, 0x072B4, 0x072B5, 0x0738E, 0x0752A, 0x0767F, 0x07A75, 0x07F51, 0x08278,
+    0x0827C, 0x08280, 0x0827D, 0x0827F, 0x0864D, 0x0897E, 0x09099, 0x09097, 0x09098, 0x0909B,
+    0x09094, 0x09622, 0x09624, 0x09620, 0x09623, 0x04F56, 0x04F3B, 0x04F62, 0x04F49, 0x04F53,
+    0x04F64, 0x04F3E, 0x04F67, 0x04F52, 0x04F5F, 0x04F41, 0x04F58, 0x04F2D, 0x04F33, 0x04F3F,
+    0x04F61, 0x0518F, 0x051B9, 0x0521C, 0x0521E, 0x05221, 0x052AD, 0x052AE, 0x05309, 0x05363,
+    0x05372, 0x0538E, 0x0538F, 0x05430, 0x05437, 0x0542A, 0x05454, 0x05445, 0x05419, 0x0541C,
+    0x05425, 0x05418, 0x0543D, 0x0544F, 0x05441, 0x05428, 0x05424, 0x05447, 0x056EE, 0x056E7,
+    0x056E5, 0x05741, 0x05745, 0x0574C, 0x05749, 0x0574B, 0x05752, 0x05906, 0x05940, 0x059A6,
+    0x05998, 0x059A0, 0x05997, 0x0598E, 0x059A2, 0x05990, 0x0598F, 0x059A7, 0x059A1, 0x05B8E,
+    0x05B92, 0x05C28, 0x05C2A, 0x05C8D, 0x05C8F, 0x05C88, 0x05C8B, 0x05C89, 0x05C92, 0x05C8A,
+    0x05C86, 0x05C93, 0x05C95, 0x05DE0, 0x05E0A, 0x05E0E, 0x05E8B, 0x05E89, 0x05E8C, 0x05E88,
+    0x05E8D, 0x05F05, 0x05F1D, 0x05F78, 0x05F76, 0x05FD2, 0x05FD1, 0x05FD0, 0x05FED, 0x05FE8,
+    0x05FEE, 0x05FF3, 0x05FE1, 0x05FE4, 0x05FE3, 0x05FFA, 0x05FEF, 0x05FF7, 0x05FFB, 0x06000,
+    0x05FF4, 0x0623A, 0x06283, 0x0628C, 0x0628E, 0x0628F, 0x06294, 0x06287, 0x06271, 0x0627B,
+    0x0627A, 0x06270, 0x06281, 0x06288, 0x06277, 0x0627D, 0x06272, 0x06274, 0x06537, 0x065F0,
+    0x065F4, 0x065F3, 0x065F2, 0x065F5, 0x06745, 0x06747, 0x06759, 0x06755, 0x0674C, 0x06748,
+    0x0675D, 0x0674D, 0x0675A, 0x0674B, 0x06BD0, 0x06C19, 0x06C1A, 0x06C78, 0x06C67, 0x06C6B,
+    0x06C84, 0x06C8B, 0x06C8F, 0x06C71, 0x06C6F, 0x06C69, 0x06C9A, 0x06C6D, 0x06C87, 0x06C95,
+    0x06C9C, 0x06C66, 0x06C73, 0x06C65, 0x06C7B, 0x06C8E, 0x07074, 0x0707A, 0x07263, 0x072BF,
+    0x072BD, 0x072C3, 0x072C6, 0x072C1, 0x072BA, 0x072C5, 0x07395, 0x07397, 0x07393, 0x07394,
+    0x07392, 0x0753A, 0x07539, 0x07594, 0x07595, 0x07681, 0x0793D, 0x08034, 0x08095, 0x08099,
+    0x08090, 0x08092, 0x0809C, 0x08290, 0x0828F, 0x08285, 0x0828E, 0x08291, 0x08293, 0x0828A,
+    0x08283, 0x08284, 0x08C78, 0x08FC9, 0x08FBF, 0x0909F, 0x090A1, 0x090A5, 0x0909E, 0x090A7,
+    0x090A0, 0x09630, 0x09628, 0x0962F, 0x0962D, 0x04E33, 0x04F98, 0x04F7C, 0x04F85, 0x04F7D,
+    0x04F80, 0x04F87, 0x04F76, 0x04F74, 0x04F89, 0x04F84, 0x04F77, 0x04F4C, 0x04F97, 0x04F6A,
+    0x04F9A, 0x04F79, 0x04F81, 0x04F78, 0x04F90, 0x04F9C, 0x04F94, 0x04F9E, 0x04F92, 0x04F82,
+    0x04F95, 0x04F6B, 0x04F6E, 0x0519E, 0x051BC, 0x051BE, 0x05235, 0x05232, 0x05233, 0x05246,
+    0x05231, 0x052BC, 0x0530A, 0x0530B, 0x0533C, 0x05392, 0x05394, 0x05487, 0x0547F, 0x05481,
+    0x05491, 0x05482, 0x05488, 0x0546B, 0x0547A, 0x0547E, 0x05465, 0x0546C, 0x05474, 0x05466,
+    0x0548D, 0x0546F, 0x05461, 0x05460, 0x05498, 0x05463, 0x05467, 0x05464, 0x056F7, 0x056F9,
+    0x0576F, 0x05772, 0x0576D, 0x0576B, 0x05771, 0x05770, 0x05776, 0x05780, 0x05775, 0x0577B,
+    0x05773, 0x05774, 0x05762, 0x05768, 0x0577D, 0x0590C, 0x05945, 0x059B5, 0x059BA, 0x059CF,
+    0x059CE, 0x059B2, 0x059CC, 0x059C1, 0x059B6, 0x059BC, 0x059C3, 0x059D6, 0x059B1, 0x059BD,
+    0x059C0, 0x059C8, 0x059B4, 0x059C7, 0x05B62, 0x05B65, 0x05B93, 0x05B95, 0x05C44, 0x05C47,
+    0x05CAE, 0x05CA4, 0x05CA0, 0x05CB5, 0x05CAF, 0x05CA8, 0x05CAC, 0x05C9F, 0x05CA3, 0x05CAD,
+    0x05CA2, 0x05CAA, 0x05CA7, 0x05C9D, 0x05CA5, 0x05CB6, 0x05CB0, 0x05CA6, 0x05E17, 0x05E14,
+    0x05E19, 0x05F28, 0x05F22, 0x05F23, 0x05F24, 0x05F54, 0x05F82, 0x05F7E, 0x05F7D, 0x05FDE,
+    0x05FE5, 0x0602D, 0x06026, 0x06019, 0x06032, 0x0600B, 0x06034, 0x0600A, 0x06017, 0x06033,
+    0x0601A, 0x0601E, 0x0602C, 0x06022, 0x0600D, 0x06010, 0x0602E, 0x06013, 0x06011, 0x0600C,
+    0x06009, 0x0601C, 0x06214, 0x0623D, 0x062AD, 0x062B4, 0x062D1, 0x062BE, 0x062AA, 0x062B6,
+    0x062CA, 0x062AE, 0x062B3, 0x062AF, 0x062BB, 0x062A9, 0x062B0, 0x062B8, 0x0653D, 0x065A8,
+    0x065BB, 0x06609, 0x065FC, 0x06604, 0x06612, 0x06608, 0x065FB, 0x06603, 0x0660B, 0x0660D,
+    0x06605, 0x065FD, 0x06611, 0x06610, 0x066F6, 0x0670A, 0x06785, 0x0676C, 0x0678E, 0x06792,
+    0x06776, 0x0677B, 0x06798, 0x06786, 0x06784, 0x06774, 0x0678D, 0x0678C, 0x0677A, 0x0679F,
+    0x06791, 0x06799, 0x06783, 0x0677D, 0x06781, 0x06778, 0x06779, 0x06794, 0x06B25, 0x06B80,
+    0x06B7E, 0x06BDE, 0x06C1D, 0x06C93, 0x06CEC, 0x06CEB, 0x06CEE, 0x06CD9, 0x06CB6, 0x06CD4,
+    0x06CAD, 0x06CE7, 0x06CB7, 0x06CD0, 0x06CC2, 0x06CBA, 0x06CC3, 0x06CC6, 0x06CED, 0x06CF2,
+    0x06CD2, 0x06CDD, 0x06CB4, 0x06C8A, 0x06C9D, 0x06C80, 0x06CDE, 0x06CC0, 0x06D30, 0x06CCD,
+    0x06CC7, 0x06CB0, 0x06CF9, 0x06CCF, 0x06CE9, 0x06CD1, 0x07094, 0x07098, 0x07085, 0x07093,
+    0x07086, 0x07084, 0x07091, 0x07096, 0x07082, 0x0709A, 0x07083, 0x0726A, 0x072D6, 0x072CB,
+    0x072D8, 0x072C9, 0x072DC, 0x072D2, 0x072D4, 0x072DA, 0x072CC, 0x072D1, 0x073A4, 0x073A1,
+    0x073AD, 0x073A6, 0x073A2, 0x073A0, 0x073AC, 0x0739D, 0x074DD, 0x074E8, 0x0753F, 0x07540,
+    0x0753E, 0x0758C, 0x07598, 0x076AF, 0x076F3, 0x076F1, 0x076F0, 0x076F5, 0x077F8, 0x077FC,
+    0x077F9, 0x077FB, 0x077FA, 0x077F7, 0x07942, 0x0793F, 0x079C5, 0x07A78, 0x07A7B, 0x07AFB,
+    0x07C75, 0x07CFD, 0x08035, 0x0808F, 0x080AE, 0x080A3, 0x080B8, 0x080B5, 0x080AD, 0x08220,
+    0x082A0, 0x082C0, 0x082AB, 0x0829A, 0x08298, 0x0829B, 0x082B5, 0x082A7, 0x082AE, 0x082BC,
+    0x0829E, 0x082BA, 0x082B4, 0x082A8, 0x082A1, 0x082A9, 0x082C2, 0x082A4, 0x082C3, 0x082B6,
+    0x082A2, 0x08670, 0x0866F, 0x0866D, 0x0866E, 0x08C56, 0x08FD2, 0x08FCB, 0x08FD3, 0x08FCD,
+    0x08FD6, 0x08FD5, 0x08FD7, 0x090B2, 0x090B4, 0x090AF, 0x090B3, 0x090B0, 0x09639, 0x0963D,
+    0x0963C, 0x0963A, 0x09643, 0x04FCD, 0x04FC5, 0x04FD3, 0x04FB2, 0x04FC9, 0x04FCB, 0x04FC1,
+    0x04FD4, 0x04FDC, 0x04FD9, 0x04FBB, 0x04FB3, 0x04FDB, 0x04FC7, 0x04FD6, 0x04FBA, 0x04FC0,
+    0x04FB9, 0x04FEC, 0x05244, 0x05249, 0x052C0, 0x052C2, 0x0533D, 0x0537C, 0x05397, 0x05396,
+    0x05399, 0x05398, 0x054BA, 0x054A1, 0x054AD, 0x054A5, 0x054CF, 0x054C3, 0x0830D, 0x054B7,
+    0x054AE, 0x054D6, 0x054B6, 0x054C5, 0x054C6, 0x054A0, 0x05470, 0x054BC, 0x054A2, 0x054BE,
+    0x05472, 0x054DE, 0x054B0, 0x057B5, 0x0579E, 0x0579F, 0x057A4, 0x0578C, 0x05797, 0x0579D,
+    0x0579B, 0x05794, 0x05798, 0x0578F, 0x05799, 0x057A5, 0x0579A, 0x05795, 0x058F4, 0x0590D,
+    0x05953, 0x059E1, 0x059DE, 0x059EE, 0x05A00, 0x059F1, 0x059DD, 0x059FA, 0x059FD, 0x059FC,
+    0x059F6, 0x059E4, 0x059F2, 0x059F7, 0x059DB, 0x059E9, 0x059F3, 0x059F5, 0x059E0, 0x059FE,
+    0x059F4, 0x059ED, 0x05BA8, 0x05C4C, 0x05CD0, 0x05CD8, 0x05CCC, 0x05CD7, 0x05CCB, 0x05CDB,
+    0x05CDE, 0x05CDA, 0x05CC9, 0x05CC7, 0x05CCA, 0x05CD6, 0x05CD3, 0x05CD4, 0x05CCF, 0x05CC8,
+    0x05CC6, 0x05CCE, 0x05CDF, 0x05CF8, 0x05DF9, 0x05E21, 0x05E22, 0x05E23, 0x05E20, 0x05E24,
+    0x05EB0, 0x05EA4, 0x05EA2, 0x05E9B, 0x05EA3, 0x05EA5, 0x05F07, 0x05F2E, 0x05F56, 0x05F86,
+    0x06037, 0x06039, 0x06054, 0x06072, 0x0605E, 0x06045, 0x06053, 0x06047, 0x06049, 0x0605B,
+    0x0604C, 0x06040, 0x06042, 0x0605F, 0x06024, 0x06044, 0x06058, 0x06066, 0x0606E, 0x06242,
+    0x06243, 0x062CF, 0x0630D, 0x0630B, 0x062F5, 0x0630E, 0x06303, 0x062EB, 0x062F9, 0x0630F,
+    0x0630C, 0x062F8, 0x062F6, 0x06300, 0x06313, 0x06314, 0x062FA, 0x06315, 0x062FB, 0x062F0,
+    0x06541, 0x06543, 0x065AA, 0x065BF, 0x06636, 0x06621, 0x06632, 0x06635, 0x0661C, 0x06626,
+    0x06622, 0x06633, 0x0662B, 0x0663A, 0x0661D, 0x06634, 0x06639, 0x0662E, 0x0670F, 0x06710,
+    0x067C1, 0x067F2, 0x067C8, 0x067BA, 0x067DC, 0x067BB, 0x067F8, 0x067D8, 0x067C0, 0x067B7,
+    0x067C5, 0x067EB, 0x067E4, 0x067DF, 0x067B5, 0x067CD, 0x067B3, 0x067F7, 0x067F6, 0x067EE,
+    0x067E3, 0x067C2, 0x067B9, 0x067CE, 0x067E7, 0x067F0, 0x067B2, 0x067FC, 0x067C6, 0x067ED,
+    0x067CC, 0x067AE, 0x067E6, 0x067DB, 0x067FA, 0x067C9, 0x067CA, 0x067C3, 0x067EA, 0x067CB,
+    0x06B28, 0x06B82, 0x06B84, 0x06BB6, 0x06BD6, 0x06BD8, 0x06BE0, 0x06C20, 0x06C21, 0x06D28,
+    0x06D34, 0x06D2D, 0x06D1F, 0x06D3C, 0x06D3F, 0x06D12, 0x06D0A, 0x06CDA, 0x06D33, 0x06D04,
+    0x06D19, 0x06D3A, 0x06D1A, 0x06D11, 0x06D00, 0x06D1D, 0x06D42, 0x06D01, 0x06D18, 0x06D37,
+    0x06D03, 0x06D0F, 0x06D40, 0x06D07, 0x06D20, 0x06D2C, 0x06D08, 0x06D22, 0x06D09, 0x06D10,
+    0x070B7, 0x0709F, 0x070BE, 0x070B1, 0x070B0, 0x070A1, 0x070B4, 0x070B5, 0x070A9, 0x07241,
+    0x07249, 0x0724A, 0x0726C, 0x07270, 0x07273, 0x0726E, 0x072CA, 0x072E4, 0x072E8, 0x072EB,
+    0x072DF, 0x072EA, 0x072E6, 0x072E3, 0x07385, 0x073CC, 0x073C2, 0x073C8, 0x073C5, 0x073B9,
+    0x073B6, 0x073B5, 0x073B4, 0x073EB, 0x073BF, 0x073C7, 0x073BE, 0x073C3, 0x073C6, 0x073B8,
+    0x073CB, 0x074EC, 0x074EE, 0x0752E, 0x07547, 0x07548, 0x075A7, 0x075AA, 0x07679, 0x076C4,
+    0x07708, 0x07703, 0x07704, 0x07705, 0x0770A, 0x076F7, 0x076FB, 0x076FA, 0x077E7, 0x077E8,
+    0x07806, 0x07811, 0x07812, 0x07805, 0x07810, 0x0780F, 0x0780E, 0x07809, 0x07803, 0x07813,
+    0x0794A, 0x0794C, 0x0794B, 0x07945, 0x07944, 0x079D5, 0x079CD, 0x079CF, 0x079D6, 0x079CE,
+    0x07A80, 0x07A7E, 0x07AD1, 0x07B00, 0x07B01, 0x07C7A, 0x07C78, 0x07C79, 0x07C7F, 0x07C80,
+    0x07C81, 0x07D03, 0x07D08, 0x07D01, 0x07F58, 0x07F91, 0x07F8D, 0x07FBE, 0x08007, 0x0800E,
+    0x0800F, 0x08014, 0x08037, 0x080D8, 0x080C7, 0x080E0, 0x080D1, 0x080C8, 0x080C2, 0x080D0,
+    0x080C5, 0x080E3, 0x080D9, 0x080DC, 0x080CA, 0x080D5, 0x080C9, 0x080CF, 0x080D7, 0x080E6,
+    0x080CD, 0x081FF, 0x08221, 0x08294, 0x082D9, 0x082FE, 0x082F9, 0x08307, 0x082E8, 0x08300,
+    0x082D5, 0x0833A, 0x082EB, 0x082D6, 0x082F4, 0x082EC, 0x082E1, 0x082F2, 0x082F5, 0x0830C,
+    0x082FB, 0x082F6, 0x082F0, 0x082EA, 0x082E4, 0x082E0, 0x082FA, 0x082F3, 0x082ED, 0x08677,
+    0x08674, 0x0867C, 0x08673, 0x08841, 0x0884E, 0x08867, 0x0886A, 0x08869, 0x089D3, 0x08A04,
+    0x08A07, 0x08D72, 0x08FE3, 0x08FE1, 0x08FEE, 0x08FE0, 0x090F1, 0x090BD, 0x090BF, 0x090D5,
+    0x090C5, 0x090BE, 0x090C7, 0x090CB, 0x090C8, 0x091D4, 0x091D3, 0x09654, 0x0964F, 0x09651,
+    0x09653, 0x0964A, 0x0964E, 0x0501E, 0x05005, 0x05007, 0x05013, 0x05022, 0x05030, 0x0501B,
+    0x04FF5, 0x04FF4, 0x05033, 0x05037, 0x0502C, 0x04FF6, 0x04FF7, 0x05017, 0x0501C, 0x05020,
+    0x05027, 0x05035, 0x0502F, 0x05031, 0x0500E, 0x0515A, 0x05194, 0x05193, 0x051CA, 0x051C4,
+    0x051C5, 0x051C8, 0x051CE, 0x05261, 0x0525A, 0x05252, 0x0525E, 0x0525F, 0x05255, 0x05262,
+    0x052CD, 0x0530E, 0x0539E, 0x05526, 0x054E2, 0x05517, 0x05512, 0x054E7, 0x054F3, 0x054E4,
+    0x0551A, 0x054FF, 0x05504, 0x05508, 0x054EB, 0x05511, 0x05505, 0x054F1, 0x0550A, 0x054FB,
+    0x054F7, 0x054F8, 0x054E0, 0x0550E, 0x05503, 0x0550B, 0x05701, 0x05702, 0x057CC, 0x05832,
+    0x057D5, 0x057D2, 0x057BA, 0x057C6, 0x057BD, 0x057BC, 0x057B8, 0x057B6, 0x057BF, 0x057C7,
+    0x057D0, 0x057B9, 0x057C1, 0x0590E, 0x0594A, 0x05A19, 0x05A16, 0x05A2D, 0x05A2E, 0x05A15,
+    0x05A0F, 0x05A17, 0x05A0A, 0x05A1E, 0x05A33, 0x05B6C, 0x05BA7, 0x05BAD, 0x05BAC, 0x05C03,
+    0x05C56, 0x05C54, 0x05CEC, 0x05CFF, 0x05CEE, 0x05CF1, 0x05CF7, 0x05D00, 0x05CF9, 0x05E29,
+    0x05E28, 0x05EA8, 0x05EAE, 0x05EAA, 0x05EAC, 0x05F33, 0x05F30, 0x05F67, 0x0605D, 0x0605A,
+    0x06067, 0x06041, 0x060A2, 0x06088, 0x06080, 0x06092, 0x06081, 0x0609D, 0x06083, 0x06095,
+    0x0609B, 0x06097, 0x06087, 0x0609C, 0x0608E, 0x06219, 0x06246, 0x062F2, 0x06310, 0x06356,
+    0x0632C, 0x06344, 0x06345, 0x06336, 0x06343, 0x063E4, 0x06339, 0x0634B, 0x0634A, 0x0633C,
+    0x06329, 0x06341, 0x06334, 0x06358, 0x06354, 0x06359, 0x0632D, 0x06347, 0x06333, 0x0635A,
+    0x06351, 0x06338, 0x06357, 0x06340, 0x06348, 0x0654A, 0x06546, 0x065C6, 0x065C3, 0x065C4,
+    0x065C2, 0x0664A, 0x0665F, 0x06647, 0x06651, 0x06712, 0x06713, 0x0681F, 0x0681A, 0x06849,
+    0x06832, 0x06833, 0x0683B, 0x0684B, 0x0684F, 0x06816, 0x06831, 0x0681C, 0x06835, 0x0682B,
+    0x0682D, 0x0682F, 0x0684E, 0x06844, 0x06834, 0x0681D, 0x06812, 0x06814, 0x06826, 0x06828,
+    0x0682E, 0x0684D, 0x0683A, 0x06825, 0x06820, 0x06B2C, 0x06B2F, 0x06B2D, 0x06B31, 0x06B34,
+    0x06B6D, 0x08082, 0x06B88, 0x06BE6, 0x06BE4, 0x06BE8, 0x06BE3, 0x06BE2, 0x06BE7, 0x06C25,
+    0x06D7A, 0x06D63, 0x06D64, 0x06D76, 0x06D0D, 0x06D61, 0x06D92, 0x06D58, 0x06D62, 0x06D6D,
+    0x06D6F, 0x06D91, 0x06D8D, 0x06DEF, 0x06D7F, 0x06D86, 0x06D5E, 0x06D67, 0x06D60, 0x06D97,
+    0x06D70, 0x06D7C, 0x06D5F, 0x06D82, 0x06D98, 0x06D2F, 0x06D68, 0x06D8B, 0x06D7E, 0x06D80,
+    0x06D84, 0x06D16, 0x06D83, 0x06D7B, 0x06D7D, 0x06D75, 0x06D90, 0x070DC, 0x070D3, 0x070D1,
+    0x070DD, 0x070CB, 0x07F39, 0x070E2, 0x070D7, 0x070D2, 0x070DE, 0x070E0, 0x070D4, 0x070CD,
+    0x070C5, 0x070C6, 0x070C7, 0x070DA, 0x070CE, 0x070E1, 0x07242, 0x07278, 0x07277, 0x07276,
+    0x07300, 0x072FA, 0x072F4, 0x072FE, 0x072F6, 0x072F3, 0x072FB, 0x07301, 0x073D3, 0x073D9,
+    0x073E5, 0x073D6, 0x073BC, 0x073E7, 0x073E3, 0x073E9, 0x073DC, 0x073D2, 0x073DB, 0x073D4,
+    0x073DD, 0x073DA, 0x073D7, 0x073D8, 0x073E8, 0x074DE, 0x074DF, 0x074F4, 0x074F5, 0x07521,
+    0x0755B, 0x0755F, 0x075B0, 0x075C1, 0x075BB, 0x075C4, 0x075C0, 0x075BF, 0x075B6, 0x075BA,
+    0x0768A, 0x076C9, 0x0771D, 0x0771B, 0x07710, 0x07713, 0x07712, 0x07723, 0x07711, 0x07715,
+    0x07719, 0x0771A, 0x07722, 0x07727, 0x07823, 0x0782C, 0x07822, 0x07835, 0x0782F, 0x07828,
+    0x0782E, 0x0782B, 0x07821, 0x07829, 0x07833, 0x0782A, 0x07831, 0x07954, 0x0795B, 0x0794F,
+    0x0795C, 0x07953, 0x07952, 0x07951, 0x079EB, 0x079EC, 0x079E0, 0x079EE, 0x079ED, 0x079EA,
+    0x079DC, 0x079DE, 0x079DD, 0x07A86, 0x07A89, 0x07A85, 0x07A8B, 0x07A8C, 0x07A8A, 0x07A87,
+    0x07AD8, 0x07B10, 0x07B04, 0x07B13, 0x07B05, 0x07B0F, 0x07B08, 0x07B0A, 0x07B0E, 0x07B09,
+    0x07B12, 0x07C84, 0x07C91, 0x07C8A, 0x07C8C, 0x07C88, 0x07C8D, 0x07C85, 0x07D1E, 0x07D1D,
+    0x07D11, 0x07D0E, 0x07D18, 0x07D16, 0x07D13, 0x07D1F, 0x07D12, 0x07D0F, 0x07D0C, 0x07F5C,
+    0x07F61, 0x07F5E, 0x07F60, 0x07F5D, 0x07F5B, 0x07F96, 0x07F92, 0x07FC3, 0x07FC2, 0x07FC0,
+    0x08016, 0x0803E, 0x08039, 0x080FA, 0x080F2, 0x080F9, 0x080F5, 0x08101, 0x080FB, 0x08100,
+    0x08201, 0x0822F, 0x08225, 0x08333, 0x0832D, 0x08344, 0x08319, 0x08351, 0x08325, 0x08356,
+    0x0833F, 0x08341, 0x08326, 0x0831C, 0x08322, 0x08342, 0x0834E, 0x0831B, 0x0832A, 0x08308,
+    0x0833C, 0x0834D, 0x08316, 0x08324, 0x08320, 0x08337, 0x0832F, 0x08329, 0x08347, 0x08345,
+    0x0834C, 0x08353, 0x0831E, 0x0832C, 0x0834B, 0x08327, 0x08348, 0x08653, 0x08652, 0x086A2,
+    0x086A8, 0x08696, 0x0868D, 0x08691, 0x0869E, 0x08687, 0x08697, 0x08686, 0x0868B, 0x0869A,
+    0x08685, 0x086A5, 0x08699, 0x086A1, 0x086A7, 0x08695, 0x08698, 0x0868E, 0x0869D, 0x08690,
+    0x08694, 0x08843, 0x08844, 0x0886D, 0x08875, 0x08876, 0x08872, 0x08880, 0x08871, 0x0887F,
+    0x0886F, 0x08883, 0x0887E, 0x08874, 0x0887C, 0x08A12, 0x08C47, 0x08C57, 0x08C7B, 0x08CA4,
+    0x08CA3, 0x08D76, 0x08D78, 0x08DB5, 0x08DB7, 0x08DB6, 0x08ED1, 0x08ED3, 0x08FFE, 0x08FF5,
+    0x09002, 0x08FFF, 0x08FFB, 0x09004, 0x08FFC, 0x08FF6, 0x090D6, 0x090E0, 0x090D9, 0x090DA,
+    0x090E3, 0x090DF, 0x090E5, 0x090D8, 0x090DB, 0x090D7, 0x090DC, 0x090E4, 0x09150, 0x0914E,
+    0x0914F, 0x091D5, 0x091E2, 0x091DA, 0x0965C, 0x0965F, 0x096BC, 0x098E3, 0x09ADF, 0x09B2F,
+    0x04E7F, 0x05070, 0x0506A, 0x05061, 0x0505E, 0x05060, 0x05053, 0x0504B, 0x0505D, 0x05072,
+    0x05048, 0x0504D, 0x05041, 0x0505B, 0x0504A, 0x05062, 0x05015, 0x05045, 0x0505F, 0x05069,
+    0x0506B, 0x05063, 0x05064, 0x05046, 0x05040, 0x0506E, 0x05073, 0x05057, 0x05051, 0x051D0,
+    0x0526B, 0x0526D, 0x0526C, 0x0526E, 0x052D6, 0x052D3, 0x0532D, 0x0539C, 0x05575, 0x05576,
+    0x0553C, 0x0554D, 0x05550, 0x05534, 0x0552A, 0x05551, 0x05562, 0x05536, 0x05535, 0x05530,
+    0x05552, 0x05545, 0x0550C, 0x05532, 0x05565, 0x0554E, 0x05539, 0x05548, 0x0552D, 0x0553B,
+    0x05540, 0x0554B, 0x0570A, 0x05707, 0x057FB, 0x05814, 0x057E2, 0x057F6, 0x057DC, 0x057F4,
+    0x05800, 0x057ED, 0x057FD, 0x05808, 0x057F8, 0x0580B, 0x057F3, 0x057CF, 0x05807, 0x057EE,
+    0x057E3, 0x057F2, 0x057E5, 0x057EC, 0x057E1, 0x0580E, 0x057FC, 0x05810, 0x057E7, 0x05801,
+    0x0580C, 0x057F1, 0x057E9, 0x057F0, 0x0580D, 0x05804, 0x0595C, 0x05A60, 0x05A58, 0x05A55,
+    0x05A67, 0x05A5E, 0x05A38, 0x05A35, 0x05A6D, 0x05A50, 0x05A5F, 0x05A65, 0x05A6C, 0x05A53,
+    0x05A64, 0x05A57, 0x05A43, 0x05A5D, 0x05A52, 0x05A44, 0x05A5B, 0x05A48, 0x05A8E, 0x05A3E,
+    0x05A4D, 0x05A39, 0x05A4C, 0x05A70, 0x05A69, 0x05A47, 0x05A51, 0x05A56, 0x05A42, 0x05A5C,
+    0x05B72, 0x05B6E, 0x05BC1, 0x05BC0, 0x05C59, 0x05D1E, 0x05D0B, 0x05D1D, 0x05D1A, 0x05D20,
+    0x05D0C, 0x05D28, 0x05D0D, 0x05D26, 0x05D25, 0x05D0F, 0x05D30, 0x05D12, 0x05D23, 0x05D1F,
+    0x05D2E, 0x05E3E, 0x05E34, 0x05EB1, 0x05EB4, 0x05EB9, 0x05EB2, 0x05EB3, 0x05F36, 0x05F38,
+    0x05F9B, 0x05F96, 0x05F9F, 0x0608A, 0x06090, 0x06086, 0x060BE, 0x060B0, 0x060BA, 0x060D3,
+    0x060D4, 0x060CF, 0x060E4, 0x060D9, 0x060DD, 0x060C8, 0x060B1, 0x060DB, 0x060B7, 0x060CA,
+    0x060BF, 0x060C3, 0x060CD, 0x060C0, 0x06332, 0x06365, 0x0638A, 0x06382, 0x0637D, 0x063BD,
+    0x0639E, 0x063AD, 0x0639D, 0x06397, 0x063AB, 0x0638E, 0x0636F, 0x06387, 0x06390, 0x0636E,
+    0x063AF, 0x06375, 0x0639C, 0x0636D, 0x063AE, 0x0637C, 0x063A4, 0x0633B, 0x0639F, 0x06378,
+    0x06385, 0x06381, 0x06391, 0x0638D, 0x06370, 0x06553, 0x065CD, 0x06665, 0x06661, 0x0665B,
+    0x06659, 0x0665C, 0x06662, 0x06718, 0x06879, 0x06887, 0x06890, 0x0689C, 0x0686D, 0x0686E,
+    0x068AE, 0x068AB, 0x06956, 0x0686F, 0x068A3, 0x068AC, 0x068A9, 0x06875, 0x06874, 0x068B2,
+    0x0688F, 0x06877, 0x06892, 0x0687C, 0x0686B, 0x06872, 0x068AA, 0x06880, 0x06871, 0x0687E,
+    0x0689B, 0x06896, 0x0688B, 0x068A0, 0x06889, 0x068A4, 0x06878, 0x0687B, 0x06891, 0x0688C,
+    0x0688A, 0x0687D, 0x06B36, 0x06B33, 0x06B37, 0x06B38, 0x06B91, 0x06B8F, 0x06B8D, 0x06B8E,
+    0x06B8C, 0x06C2A, 0x06DC0, 0x06DAB, 0x06DB4, 0x06DB3, 0x06E74, 0x06DAC, 0x06DE9, 0x06DE2,
+    0x06DB7, 0x06DF6, 0x06DD4, 0x06E00, 0x06DC8, 0x06DE0, 0x06DDF, 0x06DD6, 0x06DBE, 0x06DE5,
+    0x06DDC, 0x06DDD, 0x06DDB, 0x06DF4, 0x06DCA, 0x06DBD, 0x06DED, 0x06DF0, 0x06DBA, 0x06DD5,
+    0x06DC2, 0x06DCF, 0x06DC9, 0x06DD0, 0x06DF2, 0x06DD3, 0x06DFD, 0x06DD7, 0x06DCD, 0x06DE3,
+    0x06DBB, 0x070FA, 0x0710D, 0x070F7, 0x07117, 0x070F4, 0x0710C, 0x070F0, 0x07104, 0x070F3,
+    0x07110, 0x070FC, 0x070FF, 0x07106, 0x07113, 0x07100, 0x070F8, 0x070F6, 0x0710B, 0x07102,
+    0x0710E, 0x0727E, 0x0727B, 0x0727C, 0x0727F, 0x0731D, 0x07317, 0x07307, 0x07311, 0x07318,
+    0x0730A, 0x07308, 0x072FF, 0x0730F, 0x0731E, 0x07388, 0x073F6, 0x073F8, 0x073F5, 0x07404,
+    0x07401, 0x073FD, 0x07407, 0x07400, 0x073FA, 0x073FC, 0x073FF, 0x0740C, 0x0740B, 0x073F4,
+    0x07408, 0x07564, 0x07563, 0x075CE, 0x075D2, 0x075CF, 0x075CB, 0x075CC, 0x075D1, 0x075D0,
+    0x0768F, 0x07689, 0x076D3, 0x07739, 0x0772F, 0x0772D, 0x07731, 0x07732, 0x07734, 0x07733,
+    0x0773D, 0x07725, 0x0773B, 0x07735, 0x07848, 0x07852, 0x07849, 0x0784D, 0x0784A, 0x0784C,
+    0x07826, 0x07845, 0x07850, 0x07964, 0x07967, 0x07969, 0x0796A, 0x07963, 0x0796B, 0x07961,
+    0x079BB, 0x079FA, 0x079F8, 0x079F6, 0x079F7, 0x07A8F, 0x07A94, 0x07A90, 0x07B35, 0x07B47,
+    0x07B34, 0x07B25, 0x07B30, 0x07B22, 0x07B24, 0x07B33, 0x07B18, 0x07B2A, 0x07B1D, 0x07B31,
+    0x07B2B, 0x07B2D, 0x07B2F, 0x07B32, 0x07B38, 0x07B1A, 0x07B23, 0x07C94, 0x07C98, 0x07C96,
+    0x07CA3, 0x07D35, 0x07D3D, 0x07D38, 0x07D36, 0x07D3A, 0x07D45, 0x07D2C, 0x07D29, 0x07D41,
+    0x07D47, 0x07D3E, 0x07D3F, 0x07D4A, 0x07D3B, 0x07D28, 0x07F63, 0x07F95, 0x07F9C, 0x07F9D,
+    0x07F9B, 0x07FCA, 0x07FCB, 0x07FCD, 0x07FD0, 0x07FD1, 0x07FC7, 0x07FCF, 0x07FC9, 0x0801F,
+    0x0801E, 0x0801B, 0x08047, 0x08043, 0x08048, 0x08118, 0x08125, 0x08119, 0x0811B, 0x0812D,
+    0x0811F, 0x0812C, 0x0811E, 0x08121, 0x08115, 0x08127, 0x0811D, 0x08122, 0x08211, 0x08238,
+    0x08233, 0x0823A, 0x08234, 0x08232, 0x08274, 0x08390, 0x083A3, 0x083A8, 0x0838D, 0x0837A,
+    0x08373, 0x083A4, 0x08374, 0x0838F, 0x08381, 0x08395, 0x08399, 0x08375, 0x08394, 0x083A9,
+    0x0837D, 0x08383, 0x0838C, 0x0839D, 0x0839B, 0x083AA, 0x0838B, 0x0837E, 0x083A5, 0x083AF,
+    0x08388, 0x08397, 0x083B0, 0x0837F, 0x083A6, 0x08387, 0x083AE, 0x08376, 0x0839A, 0x08659,
+    0x08656, 0x086BF, 0x086B7, 0x086C2, 0x086C1, 0x086C5, 0x086BA, 0x086B0, 0x086C8, 0x086B9,
+    0x086B3, 0x086B8, 0x086CC, 0x086B4, 0x086BB, 0x086BC, 0x086C3, 0x086BD, 0x086BE, 0x08852,
+    0x08889, 0x08895, 0x088A8, 0x088A2, 0x088AA, 0x0889A, 0x08891, 0x088A1, 0x0889F, 0x08898,
+    0x088A7, 0x08899, 0x0889B, 0x08897, 0x088A4, 0x088AC, 0x0888C, 0x08893, 0x0888E, 0x08982,
+    0x089D6, 0x089D9, 0x089D5, 0x08A30, 0x08A27, 0x08A2C, 0x08A1E, 0x08C39, 0x08C3B, 0x08C5C,
+    0x08C5D, 0x08C7D, 0x08CA5, 0x08D7D, 0x08D7B, 0x08D79, 0x08DBC, 0x08DC2, 0x08DB9, 0x08DBF,
+    0x08DC1, 0x08ED8, 0x08EDE, 0x08EDD, 0x08EDC, 0x08ED7, 0x08EE0, 0x08EE1, 0x09024, 0x0900B,
+    0x09011, 0x0901C, 0x0900C, 0x09021, 0x090EF, 0x090EA, 0x090F0, 0x090F4, 0x090F2, 0x090F3,
+    0x090D4, 0x090EB, 0x090EC, 0x090E9, 0x09156, 0x09158, 0x0915A, 0x09153, 0x09155, 0x091EC,
+    0x091F4, 0x091F1, 0x091F3, 0x091F8, 0x091E4, 0x091F9, 0x091EA, 0x091EB, 0x091F7, 0x091E8,
+    0x091EE, 0x0957A, 0x09586, 0x09588, 0x0967C, 0x0966D, 0x0966B, 0x09671, 0x0966F, 0x096BF,
+    0x0976A, 0x09804, 0x098E5, 0x09997, 0x0509B, 0x05095, 0x05094, 0x0509E, 0x0508B, 0x050A3,
+    0x05083, 0x0508C, 0x0508E, 0x0509D, 0x05068, 0x0509C, 0x05092, 0x05082, 0x05087, 0x0515F,
+    0x051D4, 0x05312, 0x05311, 0x053A4, 0x053A7, 0x05591, 0x055A8, 0x055A5, 0x055AD, 0x05577,
+    0x05645, 0x055A2, 0x05593, 0x05588, 0x0558F, 0x055B5, 0x05581, 0x055A3, 0x05592, 0x055A4,
+    0x0557D, 0x0558C, 0x055A6, 0x0557F, 0x05595, 0x055A1, 0x0558E, 0x0570C, 0x05829, 0x05837,
+    0x05819, 0x0581E, 0x05827, 0x05823, 0x05828, 0x057F5, 0x05848, 0x05825, 0x0581C, 0x0581B,
+    0x05833, 0x0583F, 0x05836, 0x0582E, 0x05839, 0x05838, 0x0582D, 0x0582C, 0x0583B, 0x05961,
+    0x05AAF, 0x05A94, 0x05A9F, 0x05A7A, 0x05AA2, 0x05A9E, 0x05A78, 0x05AA6, 0x05A7C, 0x05AA5,
+    0x05AAC, 0x05A95, 0x05AAE, 0x05A37, 0x05A84, 0x05A8A, 0x05A97, 0x05A83, 0x05A8B, 0x05AA9,
+    0x05A7B, 0x05A7D, 0x05A8C, 0x05A9C, 0x05A8F, 0x05A93, 0x05A9D, 0x05BEA, 0x05BCD, 0x05BCB,
+    0x05BD4, 0x05BD1, 0x05BCA, 0x05BCE, 0x05C0C, 0x05C30, 0x05D37, 0x05D43, 0x05D6B, 0x05D41,
+    0x05D4B, 0x05D3F, 0x05D35, 0x05D51, 0x05D4E, 0x05D55, 0x05D33, 0x05D3A, 0x05D52, 0x05D3D,
+    0x05D31, 0x05D59, 0x05D42, 0x05D39, 0x05D49, 0x05D38, 0x05D3C, 0x05D32, 0x05D36, 0x05D40,
+    0x05D45, 0x05E44, 0x05E41, 0x05F58, 0x05FA6, 0x05FA5, 0x05FAB, 0x060C9, 0x060B9, 0x060CC,
+    0x060E2, 0x060CE, 0x060C4, 0x06114, 0x060F2, 0x0610A, 0x06116, 0x06105, 0x060F5, 0x06113,
+    0x060F8, 0x060FC, 0x060FE, 0x060C1, 0x06103, 0x06118, 0x0611D, 0x06110, 0x060FF, 0x06104,
+    0x0610B, 0x0624A, 0x06394, 0x063B1, 0x063B0, 0x063CE, 0x063E5, 0x063E8, 0x063EF, 0x063C3,
+    0x0649D, 0x063F3, 0x063CA, 0x063E0, 0x063F6, 0x063D5, 0x063F2, 0x063F5, 0x06461, 0x063DF,
+    0x063BE, 0x063DD, 0x063DC, 0x063C4, 0x063D8, 0x063D3, 0x063C2, 0x063C7, 0x063CC, 0x063CB,
+    0x063C8, 0x063F0, 0x063D7, 0x063D9, 0x06532, 0x06567, 0x0656A, 0x06564, 0x0655C, 0x06568,
+    0x06565, 0x0658C, 0x0659D, 0x0659E, 0x065AE, 0x065D0, 0x065D2, 0x0667C, 0x0666C, 0x0667B,
+    0x06680, 0x06671, 0x06679, 0x0666A, 0x06672, 0x06701, 0x0690C, 0x068D3, 0x06904, 0x068DC,
+    0x0692A, 0x068EC, 0x068EA, 0x068F1, 0x0690F, 0x068D6, 0x068F7, 0x068EB, 0x068E4, 0x068F6,
+    0x06913, 0x06910, 0x068F3, 0x068E1, 0x06907, 0x068CC, 0x06908, 0x06970, 0x068B4, 0x06911,
+    0x068EF, 0x068C6, 0x06914, 0x068F8, 0x068D0, 0x068FD, 0x068FC, 0x068E8, 0x0690B, 0x0690A,
+    0x06917, 0x068CE, 0x068C8, 0x068DD, 0x068DE, 0x068E6, 0x068F4, 0x068D1, 0x06906, 0x068D4,
+    0x068E9, 0x06915, 0x06925, 0x068C7, 0x06B39, 0x06B3B, 0x06B3F, 0x06B3C, 0x06B94, 0x06B97,
+    0x06B99, 0x06B95, 0x06BBD, 0x06BF0, 0x06BF2, 0x06BF3, 0x06C30, 0x06DFC, 0x06E46, 0x06E47,
+    0x06E1F, 0x06E49, 0x06E88, 0x06E3C, 0x06E3D, 0x06E45, 0x06E62, 0x06E2B, 0x06E3F, 0x06E41,
+    0x06E5D, 0x06E73, 0x06E1C, 0x06E33, 0x06E4B, 0x06E40, 0x06E51, 0x06E3B, 0x06E03, 0x06E2E,
+    0x06E5E, 0x06E68, 0x06E5C, 0x06E61, 0x06E31, 0x06E28, 0x06E60, 0x06E71, 0x06E6B, 0x06E39,
+    0x06E22, 0x06E30, 0x06E53, 0x06E65, 0x06E27, 0x06E78, 0x06E64, 0x06E77, 0x06E55, 0x06E79,
+    0x06E52, 0x06E66, 0x06E35, 0x06E36, 0x06E5A, 0x07120, 0x0711E, 0x0712F, 0x070FB, 0x0712E,
+    0x07131, 0x07123, 0x07125, 0x07122, 0x07132, 0x0711F, 0x07128, 0x0713A, 0x0711B, 0x0724B,
+    0x0725A, 0x07288, 0x07289, 0x07286, 0x07285, 0x0728B, 0x07312, 0x0730B, 0x07330, 0x07322,
+    0x07331, 0x07333, 0x07327, 0x07332, 0x0732D, 0x07326, 0x07323, 0x07335, 0x0730C, 0x0742E,
+    0x0742C, 0x07430, 0x0742B, 0x07416, 0x0741A, 0x07421, 0x0742D, 0x07431, 0x07424, 0x07423,
+    0x0741D, 0x07429, 0x07420, 0x07432, 0x074FB, 0x0752F, 0x0756F, 0x0756C, 0x075E7, 0x075DA,
+    0x075E1, 0x075E6, 0x075DD, 0x075DF, 0x075E4, 0x075D7, 0x07695, 0x07692, 0x076DA, 0x07746,
+    0x07747, 0x07744, 0x0774D, 0x07745, 0x0774A, 0x0774E, 0x0774B, 0x0774C, 0x077DE, 0x077EC,
+    0x07860, 0x07864, 0x07865, 0x0785C, 0x0786D, 0x07871, 0x0786A, 0x0786E, 0x07870, 0x07869,
+    0x07868, 0x0785E, 0x07862, 0x07974, 0x07973, 0x07972, 0x07970, 0x07A02, 0x07A0A, 0x07A03,
+    0x07A0C, 0x07A04, 0x07A99, 0x07AE6, 0x07AE4, 0x07B4A, 0x07B3B, 0x07B44, 0x07B48, 0x07B4C,
+    0x07B4E, 0x07B40, 0x07B58, 0x07B45, 0x07CA2, 0x07C9E, 0x07CA8, 0x07CA1, 0x07D58, 0x07D6F,
+    0x07D63, 0x07D53, 0x07D56, 0x07D67, 0x07D6A, 0x07D4F, 0x07D6D, 0x07D5C, 0x07D6B, 0x07D52,
+    0x07D54, 0x07D69, 0x07D51, 0x07D5F, 0x07D4E, 0x07F3E, 0x07F3F, 0x07F65, 0x07F66, 0x07FA2,
+    0x07FA0, 0x07FA1, 0x07FD7, 0x08051, 0x0804F, 0x08050, 0x080FE, 0x080D4, 0x08143, 0x0814A,
+    0x08152, 0x0814F, 0x08147, 0x0813D, 0x0814D, 0x0813A, 0x081E6, 0x081EE, 0x081F7, 0x081F8,
+    0x081F9, 0x08204, 0x0823C, 0x0823D, 0x0823F, 0x08275, 0x0833B, 0x083CF, 0x083F9, 0x08423,
+    0x083C0, 0x083E8, 0x08412, 0x083E7, 0x083E4, 0x083FC, 0x083F6, 0x08410, 0x083C6, 0x083C8,
+    0x083EB, 0x083E3, 0x083BF, 0x08401, 0x083DD, 0x083E5, 0x083D8, 0x083FF, 0x083E1, 0x083CB,
+    0x083CE, 0x083D6, 0x083F5, 0x083C9, 0x08409, 0x0840F, 0x083DE, 0x08411, 0x08406, 0x083C2,
+    0x083F3, 0x083D5, 0x083FA, 0x083C7, 0x083D1, 0x083EA, 0x08413, 0x083C3, 0x083EC, 0x083EE,
+    0x083C4, 0x083FB, 0x083D7, 0x083E2, 0x0841B, 0x083DB, 0x083FE, 0x086D8, 0x086E2, 0x086E6,
+    0x086D3, 0x086E3, 0x086DA, 0x086EA, 0x086DD, 0x086EB, 0x086DC, 0x086EC, 0x086E9, 0x086D7,
+    0x086E8, 0x086D1, 0x08848, 0x08856, 0x08855, 0x088BA, 0x088D7, 0x088B9, 0x088B8, 0x088C0,
+    0x088BE, 0x088B6, 0x088BC, 0x088B7, 0x088BD, 0x088B2, 0x08901, 0x088C9, 0x08995, 0x08998,
+    0x08997, 0x089DD, 0x089DA, 0x089DB, 0x08A4E, 0x08A4D, 0x08A39, 0x08A59, 0x08A40, 0x08A57,
+    0x08A58, 0x08A44, 0x08A45, 0x08A52, 0x08A48, 0x08A51, 0x08A4A, 0x08A4C, 0x08A4F, 0x08C5F,
+    0x08C81, 0x08C80, 0x08CBA, 0x08CBE, 0x08CB0, 0x08CB9, 0x08CB5, 0x08D84, 0x08D80, 0x08D89,
+    0x08DD8, 0x08DD3, 0x08DCD, 0x08DC7, 0x08DD6, 0x08DDC, 0x08DCF, 0x08DD5, 0x08DD9, 0x08DC8,
+    0x08DD7, 0x08DC5, 0x08EEF, 0x08EF7, 0x08EFA, 0x08EF9, 0x08EE6, 0x08EEE, 0x08EE5, 0x08EF5,
+    0x08EE7, 0x08EE8, 0x08EF6, 0x08EEB, 0x08EF1, 0x08EEC, 0x08EF4, 0x08EE9, 0x0902D, 0x09034,
+    0x0902F, 0x09106, 0x0912C, 0x09104, 0x090FF, 0x090FC, 0x09108, 0x090F9, 0x090FB, 0x09101,
+    0x09100, 0x09107, 0x09105, 0x09103, 0x09161, 0x09164, 0x0915F, 0x09162, 0x09160, 0x09201,
+    0x0920A, 0x09225, 0x09203, 0x0921A, 0x09226, 0x0920F, 0x0920C, 0x09200, 0x09212, 0x091FF,
+    0x091FD, 0x09206, 0x09204, 0x09227, 0x09202, 0x0921C, 0x09224, 0x09219, 0x09217, 0x09205,
+    0x09216, 0x0957B, 0x0958D, 0x0958C, 0x09590, 0x09687, 0x0967E, 0x09688, 0x09689, 0x09683,
+    0x09680, 0x096C2, 0x096C8, 0x096C3, 0x096F1, 0x096F0, 0x0976C, 0x09770, 0x0976E, 0x09807,
+    0x098A9, 0x098EB, 0x09CE6, 0x09EF9, 0x04E83, 0x04E84, 0x04EB6, 0x050BD, 0x050BF, 0x050C6,
+    0x050AE, 0x050C4, 0x050CA, 0x050B4, 0x050C8, 0x050C2, 0x050B0, 0x050C1, 0x050BA, 0x050B1,
+    0x050CB, 0x050C9, 0x050B6, 0x050B8, 0x051D7, 0x0527A, 0x05278, 0x0527B, 0x0527C, 0x055C3,
+    0x055DB, 0x055CC, 0x055D0, 0x055CB, 0x055CA, 0x055DD, 0x055C0, 0x055D4, 0x055C4, 0x055E9,
+    0x055BF, 0x055D2, 0x0558D, 0x055CF, 0x055D5, 0x055E2, 0x055D6, 0x055C8, 0x055F2, 0x055CD,
+    0x055D9, 0x055C2, 0x05714, 0x05853, 0x05868, 0x05864, 0x0584F, 0x0584D, 0x05849, 0x0586F,
+    0x05855, 0x0584E, 0x0585D, 0x05859, 0x05865, 0x0585B, 0x0583D, 0x05863, 0x05871, 0x058FC,
+    0x05AC7, 0x05AC4, 0x05ACB, 0x05ABA, 0x05AB8, 0x05AB1, 0x05AB5, 0x05AB0, 0x05ABF, 0x05AC8,
+    0x05ABB, 0x05AC6, 0x05AB7, 0x05AC0, 0x05ACA, 0x05AB4, 0x05AB6, 0x05ACD, 0x05AB9, 0x05A90,
+    0x05BD6, 0x05BD8, 0x05BD9, 0x05C1F, 0x05C33, 0x05D71, 0x05D63, 0x05D4A, 0x05D65, 0x05D72,
+    0x05D6C, 0x05D5E, 0x05D68, 0x05D67, 0x05D62, 0x05DF0, 0x05E4F, 0x05E4E, 0x05E4A, 0x05E4D,
+    0x05E4B, 0x05EC5, 0x05ECC, 0x05EC6, 0x05ECB, 0x05EC7, 0x05F40, 0x05FAF, 0x05FAD, 0x060F7,
+    0x06149, 0x0614A, 0x0612B, 0x06145, 0x06136, 0x06132, 0x0612E, 0x06146, 0x0612F, 0x0614F,
+    0x06129, 0x06140, 0x06220, 0x09168, 0x06223, 0x06225, 0x06224, 0x063C5, 0x063F1, 0x063EB,
+    0x06410, 0x06412, 0x06409, 0x06420, 0x06424, 0x06433, 0x06443, 0x0641F, 0x06415, 0x06418,
+    0x06439, 0x06437, 0x06422, 0x06423, 0x0640C, 0x06426, 0x06430, 0x06428, 0x06441, 0x06435,
+    0x0642F, 0x0640A, 0x0641A, 0x06440, 0x06425, 0x06427, 0x0640B, 0x063E7, 0x0641B, 0x0642E,
+    0x06421, 0x0640E, 0x0656F, 0x06592, 0x065D3, 0x06686, 0x0668C, 0x06695, 0x06690, 0x0668B,
+    0x0668A, 0x06699, 0x06694, 0x06678, 0x06720, 0x06966, 0x0695F, 0x06938, 0x0694E, 0x06962,
+    0x06971, 0x0693F, 0x06945, 0x0696A, 0x06939, 0x06942, 0x06957, 0x06959, 0x0697A, 0x06948,
+    0x06949, 0x06935, 0x0696C, 0x06933, 0x0693D, 0x06965, 0x068F0, 0x06978, 0x06934, 0x06969,
+    0x06940, 0x0696F, 0x06944, 0x06976, 0x06958, 0x06941, 0x06974, 0x0694C, 0x0693B, 0x0694B,
+    0x06937, 0x0695C, 0x0694F, 0x06951, 0x06932, 0x06952, 0x0692F, 0x0697B, 0x0693C, 0x06B46,
+    0x06B45, 0x06B43, 0x06B42, 0x06B48, 0x06B41, 0x06B9B, 0x0FA0D, 0x06BFB, 0x06BFC, 0x06BF9,
+    0x06BF7, 0x06BF8, 0x06E9B, 0x06ED6, 0x06EC8, 0x06E8F, 0x06EC0, 0x06E9F, 0x06E93, 0x06E94,
+    0x06EA0, 0x06EB1, 0x06EB9, 0x06EC6, 0x06ED2, 0x06EBD, 0x06EC1, 0x06E9E, 0x06EC9, 0x06EB7,
+    0x06EB0, 0x06ECD, 0x06EA6, 0x06ECF, 0x06EB2, 0x06EBE, 0x06EC3, 0x06EDC, 0x06ED8, 0x06E99,
+    0x06E92, 0x06E8E, 0x06E8D, 0x06EA4, 0x06EA1, 0x06EBF, 0x06EB3, 0x06ED0, 0x06ECA, 0x06E97,
+    0x06EAE, 0x06EA3, 0x07147, 0x07154, 0x07152, 0x07163, 0x07160, 0x07141, 0x0715D, 0x07162,
+    0x07172, 0x07178, 0x0716A, 0x07161, 0x07142, 0x07158, 0x07143, 0x0714B, 0x07170, 0x0715F,
+    0x07150, 0x07153, 0x07144, 0x0714D, 0x0715A, 0x0724F, 0x0728D, 0x0728C, 0x07291, 0x07290,
+    0x0728E, 0x0733C, 0x07342, 0x0733B, 0x0733A, 0x07340, 0x0734A, 0x07349, 0x07444, 0x0744A,
+    0x0744B, 0x07452, 0x07451, 0x07457, 0x07440, 0x0744F, 0x07450, 0x0744E, 0x07442, 0x07446,
+    0x0744D, 0x07454, 0x074E1, 0x074FF, 0x074FE, 0x074FD, 0x0751D, 0x07579, 0x07577, 0x06983,
+    0x075EF, 0x0760F, 0x07603, 0x075F7, 0x075FE, 0x075FC, 0x075F9, 0x075F8, 0x07610, 0x075FB,
+    0x075F6, 0x075ED, 0x075F5, 0x075FD, 0x07699, 0x076B5, 0x076DD, 0x07755, 0x0775F, 0x07760,
+    0x07752, 0x07756, 0x0775A, 0x07769, 0x07767, 0x07754, 0x07759, 0x0776D, 0x077E0, 0x07887,
+    0x0789A, 0x07894, 0x0788F, 0x07884, 0x07895, 0x07885, 0x07886, 0x078A1, 0x07883, 0x07879,
+    0x07899, 0x07880, 0x07896, 0x0787B, 0x0797C, 0x07982, 0x0797D, 0x07979, 0x07A11, 0x07A18,
+    0x07A19, 0x07A12, 0x07A17, 0x07A15, 0x07A22, 0x07A13, 0x07A1B, 0x07A10, 0x07AA3, 0x07AA2,
+    0x07A9E, 0x07AEB, 0x07B66, 0x07B64, 0x07B6D, 0x07B74, 0x07B69, 0x07B72, 0x07B65, 0x07B73,
+    0x07B71, 0x07B70, 0x07B61, 0x07B78, 0x07B76, 0x07B63, 0x07CB2, 0x07CB4, 0x07CAF, 0x07D88,
+    0x07D86, 0x07D80, 0x07D8D, 0x07D7F, 0x07D85, 0x07D7A, 0x07D8E, 0x07D7B, 0x07D83, 0x07D7C,
+    0x07D8C, 0x07D94, 0x07D84, 0x07D7D, 0x07D92, 0x07F6D, 0x07F6B, 0x07F67, 0x07F68, 0x07F6C,
+    0x07FA6, 0x07FA5, 0x07FA7, 0x07FDB, 0x07FDC, 0x08021, 0x08164, 0x08160, 0x08177, 0x0815C,
+    0x08169, 0x0815B, 0x08162, 0x08172, 0x06721, 0x0815E, 0x08176, 0x08167, 0x0816F, 0x08144,
+    0x08161, 0x0821D, 0x08249, 0x08244, 0x08240, 0x08242, 0x08245, 0x084F1, 0x0843F, 0x08456,
+    0x08476, 0x08479, 0x0848F, 0x0848D, 0x08465, 0x08451, 0x08440, 0x08486, 0x08467, 0x08430,
+    0x0844D, 0x0847D, 0x0845A, 0x08459, 0x08474, 0x08473, 0x0845D, 0x08507, 0x0845E, 0x08437,
+    0x0843A, 0x08434, 0x0847A, 0x08443, 0x08478, 0x08432, 0x08445, 0x08429, 0x083D9, 0x0844B,
+    0x0842F, 0x08442, 0x0842D, 0x0845F, 0x08470, 0x08439, 0x0844E, 0x0844C, 0x08452, 0x0846F,
+    0x084C5, 0x0848E, 0x0843B, 0x08447, 0x08436, 0x08433, 0x08468, 0x0847E, 0x08444, 0x0842B,
+    0x08460, 0x08454, 0x0846E, 0x08450, 0x0870B, 0x08704, 0x086F7, 0x0870C, 0x086FA, 0x086D6,
+    0x086F5, 0x0874D, 0x086F8, 0x0870E, 0x08709, 0x08701, 0x086F6, 0x0870D, 0x08705, 0x088D6,
+    0x088CB, 0x088CD, 0x088CE, 0x088DE, 0x088DB, 0x088DA, 0x088CC, 0x088D0, 0x08985, 0x0899B,
+    0x089DF, 0x089E5, 0x089E4, 0x089E1, 0x089E0, 0x089E2, 0x089DC, 0x089E6, 0x08A76, 0x08A86,
+    0x08A7F, 0x08A61, 0x08A3F, 0x08A77, 0x08A82, 0x08A84, 0x08A75, 0x08A83, 0x08A81, 0x08A74,
+    0x08A7A, 0x08C3C, 0x08C4B, 0x08C4A, 0x08C65, 0x08C64, 0x08C66, 0x08C86, 0x08C84, 0x08C85,
+    0x08CCC, 0x08D68, 0x08D69, 0x08D91, 0x08D8C, 0x08D8E, 0x08D8F, 0x08D8D, 0x08D93, 0x08D94,
+    0x08D90, 0x08D92, 0x08DF0, 0x08DE0, 0x08DEC, 0x08DF1, 0x08DEE, 0x08DD0, 0x08DE9, 0x08DE3,
+    0x08DE2, 0x08DE7, 0x08DF2, 0x08DEB, 0x08DF4, 0x08F06, 0x08EFF, 0x08F01, 0x08F00, 0x08F05,
+    0x08F07, 0x08F08, 0x08F02, 0x08F0B, 0x09052, 0x0903F, 0x09044, 0x09049, 0x0903D, 0x09110,
+    0x0910D, 0x0910F, 0x09111, 0x09116, 0x09114, 0x0910B, 0x0910E, 0x0916E, 0x0916F, 0x09248,
+    0x09252, 0x09230, 0x0923A, 0x09266, 0x09233, 0x09265, 0x0925E, 0x09283, 0x0922E, 0x0924A,
+    0x09246, 0x0926D, 0x0926C, 0x0924F, 0x09260, 0x09267, 0x0926F, 0x09236, 0x09261, 0x09270,
+    0x09231, 0x09254, 0x09263, 0x09250, 0x09272, 0x0924E, 0x09253, 0x0924C, 0x09256, 0x09232,
+    0x0959F, 0x0959C, 0x0959E, 0x0959B, 0x09692, 0x09693, 0x09691, 0x09697, 0x096CE, 0x096FA,
+    0x096FD, 0x096F8, 0x096F5, 0x09773, 0x09777, 0x09778, 0x09772, 0x0980F, 0x0980D, 0x0980E,
+    0x098AC, 0x098F6, 0x098F9, 0x099AF, 0x099B2, 0x099B0, 0x099B5, 0x09AAD, 0x09AAB, 0x09B5B,
+    0x09CEA, 0x09CED, 0x09CE7, 0x09E80, 0x09EFD, 0x050E6, 0x050D4, 0x050D7, 0x050E8, 0x050F3,
+    0x050DB, 0x050EA, 0x050DD, 0x050E4, 0x050D3, 0x050EC, 0x050F0, 0x050EF, 0x050E3, 0x050E0,
+    0x051D8, 0x05280, 0x05281, 0x052E9, 0x052EB, 0x05330, 0x053AC, 0x05627, 0x05615, 0x0560C,
+    0x05612, 0x055FC, 0x0560F, 0x0561C, 0x05601, 0x05613, 0x05602, 0x055FA, 0x0561D, 0x05604,
+    0x055FF, 0x055F9, 0x05889, 0x0587C, 0x05890, 0x05898, 0x05886, 0x05881, 0x0587F, 0x05874,
+    0x0588B, 0x0587A, 0x05887, 0x05891, 0x0588E, 0x05876, 0x05882, 0x05888, 0x0587B, 0x05894,
+    0x0588F, 0x058FE, 0x0596B, 0x05ADC, 0x05AEE, 0x05AE5, 0x05AD5, 0x05AEA, 0x05ADA, 0x05AED,
+    0x05AEB, 0x05AF3, 0x05AE2, 0x05AE0, 0x05ADB, 0x05AEC, 0x05ADE, 0x05ADD, 0x05AD9, 0x05AE8,
+    0x05ADF, 0x05B77, 0x05BE0, 0x05BE3, 0x05C63, 0x05D82, 0x05D80, 0x05D7D, 0x05D86, 0x05D7A,
+    0x05D81, 0x05D77, 0x05D8A, 0x05D89, 0x05D88, 0x05D7E, 0x05D7C, 0x05D8D, 0x05D79, 0x05D7F,
+    0x05E58, 0x05E59, 0x05E53, 0x05ED8, 0x05ED1, 0x05ED7, 0x05ECE, 0x05EDC, 0x05ED5, 0x05ED9,
+    0x05ED2, 0x05ED4, 0x05F44, 0x05F43, 0x05F6F, 0x05FB6, 0x0612C, 0x06128, 0x06141, 0x0615E,
+    0x06171, 0x06173, 0x06152, 0x06153, 0x06172, 0x0616C, 0x06180, 0x06174, 0x06154, 0x0617A,
+    0x0615B, 0x06165, 0x0613B, 0x0616A, 0x06161, 0x06156, 0x06229, 0x06227, 0x0622B, 0x0642B,
+    0x0644D, 0x0645B, 0x0645D, 0x06474, 0x06476, 0x06472, 0x06473, 0x0647D, 0x06475, 0x06466,
+    0x064A6, 0x0644E, 0x06482, 0x0645E, 0x0645C, 0x0644B, 0x06453, 0x06460, 0x06450, 0x0647F,
+    0x0643F, 0x0646C, 0x0646B, 0x06459, 0x06465, 0x06477, 0x06573, 0x065A0, 0x066A1, 0x066A0,
+    0x0669F, 0x06705, 0x06704, 0x06722, 0x069B1, 0x069B6, 0x069C9, 0x069A0, 0x069CE, 0x06996,
+    0x069B0, 0x069AC, 0x069BC, 0x06991, 0x06999, 0x0698E, 0x069A7, 0x0698D, 0x069A9, 0x069BE,
+    0x069AF, 0x069BF, 0x069C4, 0x069BD, 0x069A4, 0x069D4, 0x069B9, 0x069CA, 0x0699A, 0x069CF,
+    0x069B3, 0x06993, 0x069AA, 0x069A1, 0x0699E, 0x069D9, 0x06997, 0x06990, 0x069C2, 0x069B5,
+    0x069A5, 0x069C6, 0x06B4A, 0x06B4D, 0x06B4B, 0x06B9E, 0x06B9F, 0x06BA0, 0x06BC3, 0x06BC4,
+    0x06BFE, 0x06ECE, 0x06EF5, 0x06EF1, 0x06F03, 0x06F25, 0x06EF8, 0x06F37, 0x06EFB, 0x06F2E,
+    0x06F09, 0x06F4E, 0x06F19, 0x06F1A, 0x06F27, 0x06F18, 0x06F3B, 0x06F12, 0x06EED, 0x06F0A,
+    0x06F36, 0x06F73, 0x06EF9, 0x06EEE, 0x06F2D, 0x06F40, 0x06F30, 0x06F3C, 0x06F35, 0x06EEB,
+    0x06F07, 0x06F0E, 0x06F43, 0x06F05, 0x06EFD, 0x06EF6, 0x06F39, 0x06F1C, 0x06EFC, 0x06F3A,
+    0x06F1F, 0x06F0D, 0x06F1E, 0x06F08, 0x06F21, 0x07187, 0x07190, 0x07189, 0x07180, 0x07185,
+    0x07182, 0x0718F, 0x0717B, 0x07186, 0x07181, 0x07197, 0x07244, 0x07253, 0x07297, 0x07295,
+    0x07293, 0x07343, 0x0734D, 0x07351, 0x0734C, 0x07462, 0x07473, 0x07471, 0x07475, 0x07472,
+    0x07467, 0x0746E, 0x07500, 0x07502, 0x07503, 0x0757D, 0x07590, 0x07616, 0x07608, 0x0760C,
+    0x07615, 0x07611, 0x0760A, 0x07614, 0x076B8, 0x07781, 0x0777C, 0x07785, 0x07782, 0x0776E,
+    0x07780, 0x0776F, 0x0777E, 0x07783, 0x078B2, 0x078AA, 0x078B4, 0x078AD, 0x078A8, 0x0787E,
+    0x078AB, 0x0789E, 0x078A5, 0x078A0, 0x078AC, 0x078A2, 0x078A4, 0x07998, 0x0798A, 0x0798B,
+    0x07996, 0x07995, 0x07994, 0x07993, 0x07997, 0x07988, 0x07992, 0x07990, 0x07A2B, 0x07A4A,
+    0x07A30, 0x07A2F, 0x07A28, 0x07A26, 0x07AA8, 0x07AAB, 0x07AAC, 0x07AEE, 0x07B88, 0x07B9C,
+    0x07B8A, 0x07B91, 0x07B90, 0x07B96, 0x07B8D, 0x07B8C, 0x07B9B, 0x07B8E, 0x07B85, 0x07B98,
+    0x05284, 0x07B99, 0x07BA4, 0x07B82, 0x07CBB, 0x07CBF, 0x07CBC, 0x07CBA, 0x07DA7, 0x07DB7,
+    0x07DC2, 0x07DA3, 0x07DAA, 0x07DC1, 0x07DC0, 0x07DC5, 0x07D9D, 0x07DCE, 0x07DC4, 0x07DC6,
+    0x07DCB, 0x07DCC, 0x07DAF, 0x07DB9, 0x07D96, 0x07DBC, 0x07D9F, 0x07DA6, 0x07DAE, 0x07DA9,
+    0x07DA1, 0x07DC9, 0x07F73, 0x07FE2, 0x07FE3, 0x07FE5, 0x07FDE, 0x08024, 0x0805D, 0x0805C,
+    0x08189, 0x08186, 0x08183, 0x08187, 0x0818D, 0x0818C, 0x0818B, 0x08215, 0x08497, 0x084A4,
+    0x084A1, 0x0849F, 0x084BA, 0x084CE, 0x084C2, 0x084AC, 0x084AE, 0x084AB, 0x084B9, 0x084B4,
+    0x084C1, 0x084CD, 0x084AA, 0x0849A, 0x084B1, 0x084D0, 0x0849D, 0x084A7, 0x084BB, 0x084A2,
+    0x08494, 0x084C7, 0x084CC, 0x0849B, 0x084A9, 0x084AF, 0x084A8, 0x084D6, 0x08498, 0x084B6,
+    0x084CF, 0x084A0, 0x084D7, 0x084D4, 0x084D2, 0x084DB, 0x084B0, 0x08491, 0x08661, 0x08733,
+    0x08723, 0x08728, 0x0876B, 0x08740, 0x0872E, 0x0871E, 0x08721, 0x08719, 0x0871B, 0x08743,
+    0x0872C, 0x08741, 0x0873E, 0x08746, 0x08720, 0x08732, 0x0872A, 0x0872D, 0x0873C, 0x08712,
+    0x0873A, 0x08731, 0x08735, 0x08742, 0x08726, 0x08727, 0x08738, 0x08724, 0x0871A, 0x08730,
+    0x08711, 0x088F7, 0x088E7, 0x088F1, 0x088F2, 0x088FA, 0x088FE, 0x088EE, 0x088FC, 0x088F6,
+    0x088FB, 0x088F0, 0x088EC, 0x088EB, 0x0899D, 0x089A1, 0x0899F, 0x0899E, 0x089E9, 0x089EB,
+    0x089E8, 0x08AAB, 0x08A99, 0x08A8B, 0x08A92, 0x08A8F, 0x08A96, 0x08C3D, 0x08C68, 0x08C69,
+    0x08CD5, 0x08CCF, 0x08CD7, 0x08D96, 0x08E09, 0x08E02, 0x08DFF, 0x08E0D, 0x08DFD, 0x08E0A,
+    0x08E03, 0x08E07, 0x08E06, 0x08E05, 0x08DFE, 0x08E00, 0x08E04, 0x08F10, 0x08F11, 0x08F0E,
+    0x08F0D, 0x09123, 0x0911C, 0x09120, 0x09122, 0x0911F, 0x0911D, 0x0911A, 0x09124, 0x09121,
+    0x0911B, 0x0917A, 0x09172, 0x09179, 0x09173, 0x092A5, 0x092A4, 0x09276, 0x0929B, 0x0927A,
+    0x092A0, 0x09294, 0x092AA, 0x0928D, 0x092A6, 0x0929A, 0x092AB, 0x09279, 0x09297, 0x0927F,
+    0x092A3, 0x092EE, 0x0928E, 0x09282, 0x09295, 0x092A2, 0x0927D, 0x09288, 0x092A1, 0x0928A,
+    0x09286, 0x0928C, 0x09299, 0x092A7, 0x0927E, 0x09287, 0x092A9, 0x0929D, 0x0928B, 0x0922D,
+    0x0969E, 0x096A1, 0x096FF, 0x09758, 0x0977D, 0x0977A, 0x0977E, 0x09783, 0x09780, 0x09782,
+    0x0977B, 0x09784, 0x09781, 0x0977F, 0x097CE, 0x097CD, 0x09816, 0x098AD, 0x098AE, 0x09902,
+    0x09900, 0x09907, 0x0999D, 0x0999C, 0x099C3, 0x099B9, 0x099BB, 0x099BA, 0x099C2, 0x099BD,
+    0x099C7, 0x09AB1, 0x09AE3, 0x09AE7, 0x09B3E, 0x09B3F, 0x09B60, 0x09B61, 0x09B5F, 0x09CF1,
+    0x09CF2, 0x09CF5, 0x09EA7, 0x050FF, 0x05103, 0x05130, 0x050F8, 0x05106, 0x05107, 0x050F6,
+    0x050FE, 0x0510B, 0x0510C, 0x050FD, 0x0510A, 0x0528B, 0x0528C, 0x052F1, 0x052EF, 0x05648,
+    0x05642, 0x0564C, 0x05635, 0x05641, 0x0564A, 0x05649, 0x05646, 0x05658, 0x0565A, 0x05640,
+    0x05633, 0x0563D, 0x0562C, 0x0563E, 0x05638, 0x0562A, 0x0563A, 0x0571A, 0x058AB, 0x0589D,
+    0x058B1, 0x058A0, 0x058A3, 0x058AF, 0x058AC, 0x058A5, 0x058A1, 0x058FF, 0x05AFF, 0x05AF4,
+    0x05AFD, 0x05AF7, 0x05AF6, 0x05B03, 0x05AF8, 0x05B02, 0x05AF9, 0x05B01, 0x05B07, 0x05B05,
+    0x05B0F, 0x05C67, 0x05D99, 0x05D97, 0x05D9F, 0x05D92, 0x05DA2, 0x05D93, 0x05D95, 0x05DA0,
+    0x05D9C, 0x05DA1, 0x05D9A, 0x05D9E, 0x05E69, 0x05E5D, 0x05E60, 0x05E5C, 0x07DF3, 0x05EDB,
+    0x05EDE, 0x05EE1, 0x05F49, 0x05FB2, 0x0618B, 0x06183, 0x06179, 0x061B1, 0x061B0, 0x061A2,
+    0x06189, 0x0619B, 0x06193, 0x061AF, 0x061AD, 0x0619F, 0x06192, 0x061AA, 0x061A1, 0x0618D,
+    0x06166, 0x061B3, 0x0622D, 0x0646E, 0x06470, 0x06496, 0x064A0, 0x06485, 0x06497, 0x0649C,
+    0x0648F, 0x0648B, 0x0648A, 0x0648C, 0x064A3, 0x0649F, 0x06468, 0x064B1, 0x06498, 0x06576,
+    0x0657A, 0x06579, 0x0657B, 0x065B2, 0x065B3, 0x066B5, 0x066B0, 0x066A9, 0x066B2, 0x066B7,
+    0x066AA, 0x066AF, 0x06A00, 0x06A06, 0x06A17, 0x069E5, 0x069F8, 0x06A15, 0x069F1, 0x069E4,
+    0x06A20, 0x069FF, 0x069EC, 0x069E2, 0x06A1B, 0x06A1D, 0x069FE, 0x06A27, 0x069F2, 0x069EE,
+    0x06A14, 0x069F7, 0x069E7, 0x06A40, 0x06A08, 0x069E6, 0x069FB, 0x06A0D, 0x069FC, 0x069EB,
+    0x06A09, 0x06A04, 0x06A18, 0x06A25, 0x06A0F, 0x069F6, 0x06A26, 0x06A07, 0x069F4, 0x06A16,
+    0x06B51, 0x06BA5, 0x06BA3, 0x06BA2, 0x06BA6, 0x06C01, 0x06C00, 0x06BFF, 0x06C02, 0x06F41,
+    0x06F26, 0x06F7E, 0x06F87, 0x06FC6, 0x06F92, 0x06F8D, 0x06F89, 0x06F8C, 0x06F62, 0x06F4F,
+    0x06F85, 0x06F5A, 0x06F96, 0x06F76, 0x06F6C, 0x06F82, 0x06F55, 0x06F72, 0x06F52, 0x06F50,
+    0x06F57, 0x06F94, 0x06F93, 0x06F5D, 0x06F00, 0x06F61, 0x06F6B, 0x06F7D, 0x06F67, 0x06F90,
+    0x06F53, 0x06F8B, 0x06F69, 0x06F7F, 0x06F95, 0x06F63, 0x06F77, 0x06F6A, 0x06F7B, 0x071B2,
+    0x071AF, 0x0719B, 0x071B0, 0x071A0, 0x0719A, 0x071A9, 0x071B5, 0x0719D, 0x071A5, 0x0719E,
+    0x071A4, 0x071A1, 0x071AA, 0x0719C, 0x071A7, 0x071B3, 0x07298, 0x0729A, 0x07358, 0x07352,
+    0x0735E, 0x0735F, 0x07360, 0x0735D, 0x0735B, 0x07361, 0x0735A, 0x07359, 0x07362, 0x07487,
+    0x07489, 0x0748A, 0x07486, 0x07481, 0x0747D, 0x07485, 0x07488, 0x0747C, 0x07479, 0x07508,
+    0x07507, 0x0757E, 0x07625, 0x0761E, 0x07619, 0x0761D, 0x0761C, 0x07623, 0x0761A, 0x07628,
+    0x0761B, 0x0769C, 0x0769D, 0x0769E, 0x0769B, 0x0778D, 0x0778F, 0x07789, 0x07788, 0x078CD,
+    0x078BB, 0x078CF, 0x078CC, 0x078D1, 0x078CE, 0x078D4, 0x078C8, 0x078C3, 0x078C4, 0x078C9,
+    0x0799A, 0x079A1, 0x079A0, 0x0799C, 0x079A2, 0x0799B, 0x06B76, 0x07A39, 0x07AB2, 0x07AB4,
+    0x07AB3, 0x07BB7, 0x07BCB, 0x07BBE, 0x07BAC, 0x07BCE, 0x07BAF, 0x07BB9, 0x07BCA, 0x07BB5,
+    0x07CC5, 0x07CC8, 0x07CCC, 0x07CCB, 0x07DF7, 0x07DDB, 0x07DEA, 0x07DE7, 0x07DD7, 0x07DE1,
+    0x07E03, 0x07DFA, 0x07DE6, 0x07DF6, 0x07DF1, 0x07DF0, 0x07DEE, 0x07DDF, 0x07F76, 0x07FAC,
+    0x07FB0, 0x07FAD, 0x07FED, 0x07FEB, 0x07FEA, 0x07FEC, 0x07FE6, 0x07FE8, 0x08064, 0x08067,
+    0x081A3, 0x0819F, 0x0819E, 0x08195, 0x081A2, 0x08199, 0x08197, 0x08216, 0x0824F, 0x08253,
+    0x08252, 0x08250, 0x0824E, 0x08251, 0x08524, 0x0853B, 0x0850F, 0x08500, 0x08529, 0x0850E,
+    0x08509, 0x0850D, 0x0851F, 0x0850A, 0x08527, 0x0851C, 0x084FB, 0x0852B, 0x084FA, 0x08508,
+    0x0850C, 0x084F4, 0x0852A, 0x084F2, 0x08515, 0x084F7, 0x084EB, 0x084F3, 0x084FC, 0x08512,
+    0x084EA, 0x084E9, 0x08516, 0x084FE, 0x08528, 0x0851D, 0x0852E, 0x08502, 0x084FD, 0x0851E,
+    0x084F6, 0x08531, 0x08526, 0x084E7, 0x084E8, 0x084F0, 0x084EF, 0x084F9, 0x08518, 0x08520,
+    0x08530, 0x0850B, 0x08519, 0x0852F, 0x08662, 0x08756, 0x08763, 0x08764, 0x08777, 0x087E1,
+    0x08773, 0x08758, 0x08754, 0x0875B, 0x08752, 0x08761, 0x0875A, 0x08751, 0x0875E, 0x0876D,
+    0x0876A, 0x08750, 0x0874E, 0x0875F, 0x0875D, 0x0876F, 0x0876C, 0x0877A, 0x0876E, 0x0875C,
+    0x08765, 0x0874F, 0x0877B, 0x08775, 0x08762, 0x08767, 0x08769, 0x0885A, 0x08905, 0x0890C,
+    0x08914, 0x0890B, 0x08917, 0x08918, 0x08919, 0x08906, 0x08916, 0x08911, 0x0890E, 0x08909,
+    0x089A2, 0x089A4, 0x089A3, 0x089ED, 0x089F0, 0x089EC, 0x08ACF, 0x08AC6, 0x08AB8, 0x08AD3,
+    0x08AD1, 0x08AD4, 0x08AD5, 0x08ABB, 0x08AD7, 0x08ABE, 0x08AC0, 0x08AC5, 0x08AD8, 0x08AC3,
+    0x08ABA, 0x08ABD, 0x08AD9, 0x08C3E, 0x08C4D, 0x08C8F, 0x08CE5, 0x08CDF, 0x08CD9, 0x08CE8,
+    0x08CDA, 0x08CDD, 0x08CE7, 0x08DA0, 0x08D9C, 0x08DA1, 0x08D9B, 0x08E20, 0x08E23, 0x08E25,
+    0x08E24, 0x08E2E, 0x08E15, 0x08E1B, 0x08E16, 0x08E11, 0x08E19, 0x08E26, 0x08E27, 0x08E14,
+    0x08E12, 0x08E18, 0x08E13, 0x08E1C, 0x08E17, 0x08E1A, 0x08F2C, 0x08F24, 0x08F18, 0x08F1A,
+    0x08F20, 0x08F23, 0x08F16, 0x08F17, 0x09073, 0x09070, 0x0906F, 0x09067, 0x0906B, 0x0912F,
+    0x0912B, 0x09129, 0x0912A, 0x09132, 0x09126, 0x0912E, 0x09185, 0x09186, 0x0918A, 0x09181,
+    0x09182, 0x09184, 0x09180, 0x092D0, 0x092C3, 0x092C4, 0x092C0, 0x092D9, 0x092B6, 0x092CF,
+    0x092F1, 0x092DF, 0x092D8, 0x092E9, 0x092D7, 0x092DD, 0x092CC, 0x092EF, 0x092C2, 0x092E8,
+    0x092CA, 0x092C8, 0x092CE, 0x092E6, 0x092CD, 0x092D5, 0x092C9, 0x092E0, 0x092DE, 0x092E7,
+    0x092D1, 0x092D3, 0x092B5, 0x092E1, 0x092C6, 0x092B4, 0x0957C, 0x095AC, 0x095AB, 0x095AE,
+    0x095B0, 0x096A4, 0x096A2, 0x096D3, 0x09705, 0x09708, 0x09702, 0x0975A, 0x0978A, 0x0978E,
+    0x09788, 0x097D0, 0x097CF, 0x0981E, 0x0981D, 0x09826, 0x09829, 0x09828, 0x09820, 0x0981B,
+    0x09827, 0x098B2, 0x09908, 0x098FA, 0x09911, 0x09914, 0x09916, 0x09917, 0x09915, 0x099DC,
+    0x099CD, 0x099CF, 0x099D3, 0x099D4, 0x099CE, 0x099C9, 0x099D6, 0x099D8, 0x099CB, 0x099D7,
+    0x099CC, 0x09AB3, 0x09AEC, 0x09AEB, 0x09AF3, 0x09AF2, 0x09AF1, 0x09B46, 0x09B43, 0x09B67,
+    0x09B74, 0x09B71, 0x09B66, 0x09B76, 0x09B75, 0x09B70, 0x09B68, 0x09B64, 0x09B6C, 0x09CFC,
+    0x09CFA, 0x09CFD, 0x09CFF, 0x09CF7, 0x09D07, 0x09D00, 0x09CF9, 0x09CFB, 0x09D08, 0x09D05,
+    0x09D04, 0x09E83, 0x09ED3, 0x09F0F, 0x09F10, 0x0511C, 0x05113, 0x05117, 0x0511A, 0x05111,
+    0x051DE, 0x05334, 0x053E1, 0x05670, 0x05660, 0x0566E, 0x05673, 0x05666, 0x05663, 0x0566D,
+    0x05672, 0x0565E, 0x05677, 0x0571C, 0x0571B, 0x058C8, 0x058BD, 0x058C9, 0x058BF, 0x058BA,
+    0x058C2, 0x058BC, 0x058C6, 0x05B17, 0x05B19, 0x05B1B, 0x05B21, 0x05B14, 0x05B13, 0x05B10,
+    0x05B16, 0x05B28, 0x05B1A, 0x05B20, 0x05B1E, 0x05BEF, 0x05DAC, 0x05DB1, 0x05DA9, 0x05DA7,
+    0x05DB5, 0x05DB0, 0x05DAE, 0x05DAA, 0x05DA8, 0x05DB2, 0x05DAD, 0x05DAF, 0x05DB4, 0x05E67,
+    0x05E68, 0x05E66, 0x05E6F, 0x05EE9, 0x05EE7, 0x05EE6, 0x05EE8, 0x05EE5, 0x05F4B, 0x05FBC,
+    0x0619D, 0x061A8, 0x06196, 0x061C5, 0x061B4, 0x061C6, 0x061C1, 0x061CC, 0x061BA, 0x061BF,
+    0x061B8, 0x0618C, 0x064D7, 0x064D6, 0x064D0, 0x064CF, 0x064C9, 0x064BD, 0x06489, 0x064C3,
+    0x064DB, 0x064F3, 0x064D9, 0x06533, 0x0657F, 0x0657C, 0x065A2, 0x066C8, 0x066BE, 0x066C0,
+    0x066CA, 0x066CB, 0x066CF, 0x066BD, 0x066BB, 0x066BA, 0x066CC, 0x06723, 0x06A34, 0x06A66,
+    0x06A49, 0x06A67, 0x06A32, 0x06A68, 0x06A3E, 0x06A5D, 0x06A6D, 0x06A76, 0x06A5B, 0x06A51,
+    0x06A28, 0x06A5A, 0x06A3B, 0x06A3F, 0x06A41, 0x06A6A, 0x06A64, 0x06A50, 0x06A4F, 0x06A54,
+    0x06A6F, 0x06A69, 0x06A60, 0x06A3C, 0x06A5E, 0x06A56, 0x06A55, 0x06A4D, 0x06A4E, 0x06A46,
+    0x06B55, 0x06B54, 0x06B56, 0x06BA7, 0x06BAA, 0x06BAB, 0x06BC8, 0x06BC7, 0x06C04, 0x06C03,
+    0x06C06, 0x06FAD, 0x06FCB, 0x06FA3, 0x06FC7, 0x06FBC, 0x06FCE, 0x06FC8, 0x06F5E, 0x06FC4,
+    0x06FBD, 0x06F9E, 0x06FCA, 0x06FA8, 0x07004, 0x06FA5, 0x06FAE, 0x06FBA, 0x06FAC, 0x06FAA,
+    0x06FCF, 0x06FBF, 0x06FB8, 0x06FA2, 0x06FC9, 0x06FAB, 0x06FCD, 0x06FAF, 0x06FB2, 0x06FB0,
+    0x071C5, 0x071C2, 0x071BF, 0x071B8, 0x071D6, 0x071C0, 0x071C1, 0x071CB, 0x071D4, 0x071CA,
+    0x071C7, 0x071CF, 0x071BD, 0x071D8, 0x071BC, 0x071C6, 0x071DA, 0x071DB, 0x0729D, 0x0729E,
+    0x07369, 0x07366, 0x07367, 0x0736C, 0x07365, 0x0736B, 0x0736A, 0x0747F, 0x0749A, 0x074A0,
+    0x07494, 0x07492, 0x07495, 0x074A1, 0x0750B, 0x07580, 0x0762F, 0x0762D, 0x07631, 0x0763D,
+    0x07633, 0x0763C, 0x07635, 0x07632, 0x07630, 0x076BB, 0x076E6, 0x0779A, 0x0779D, 0x077A1,
+    0x0779C, 0x0779B, 0x077A2, 0x077A3, 0x07795, 0x07799, 0x07797, 0x078DD, 0x078E9, 0x078E5,
+    0x078EA, 0x078DE, 0x078E3, 0x078DB, 0x078E1, 0x078E2, 0x078ED, 0x078DF, 0x078E0, 0x079A4,
+    0x07A44, 0x07A48, 0x07A47, 0x07AB6, 0x07AB8, 0x07AB5, 0x07AB1, 0x07AB7, 0x07BDE, 0x07BE3,
+    0x07BE7, 0x07BDD, 0x07BD5, 0x07BE5, 0x07BDA, 0x07BE8, 0x07BF9, 0x07BD4, 0x07BEA, 0x07BE2,
+    0x07BDC, 0x07BEB, 0x07BD8, 0x07BDF, 0x07CD2, 0x07CD4, 0x07CD7, 0x07CD0, 0x07CD1, 0x07E12,
+    0x07E21, 0x07E17, 0x07E0C, 0x07E1F, 0x07E20, 0x07E13, 0x07E0E, 0x07E1C, 0x07E15, 0x07E1A,
+    0x07E22, 0x07E0B, 0x07E0F, 0x07E16, 0x07E0D, 0x07E14, 0x07E25, 0x07E24, 0x07F43, 0x07F7B,
+    0x07F7C, 0x07F7A, 0x07FB1, 0x07FEF, 0x0802A, 0x08029, 0x0806C, 0x081B1, 0x081A6, 0x081AE,
+    0x081B9, 0x081B5, 0x081AB, 0x081B0, 0x081AC, 0x081B4, 0x081B2, 0x081B7, 0x081A7, 0x081F2,
+    0x08255, 0x08256, 0x08257, 0x08556, 0x08545, 0x0856B, 0x0854D, 0x08553, 0x08561, 0x08558,
+    0x08540, 0x08546, 0x08564, 0x08541, 0x08562, 0x08544, 0x08551, 0x08547, 0x08563, 0x0853E,
+    0x0855B, 0x08571, 0x0854E, 0x0856E, 0x08575, 0x08555, 0x08567, 0x08560, 0x0858C, 0x08566,
+    0x0855D, 0x08554, 0x08565, 0x0856C, 0x08663, 0x08665, 0x08664, 0x0879B, 0x0878F, 0x08797,
+    0x08793, 0x08792, 0x08788, 0x08781, 0x08796, 0x08798, 0x08779, 0x08787, 0x087A3, 0x08785,
+    0x08790, 0x08791, 0x0879D, 0x08784, 0x08794, 0x0879C, 0x0879A, 0x08789, 0x0891E, 0x08926,
+    0x08930, 0x0892D, 0x0892E, 0x08927, 0x08931, 0x08922, 0x08929, 0x08923, 0x0892F, 0x0892C,
+    0x0891F, 0x089F1, 0x08AE0, 0x08AE2, 0x08AF2, 0x08AF4, 0x08AF5, 0x08ADD, 0x08B14, 0x08AE4,
+    0x08ADF, 0x08AF0, 0x08AC8, 0x08ADE, 0x08AE1, 0x08AE8, 0x08AFF, 0x08AEF, 0x08AFB, 0x08C91,
+    0x08C92, 0x08C90, 0x08CF5, 0x08CEE, 0x08CF1, 0x08CF0, 0x08CF3, 0x08D6C, 0x08D6E, 0x08DA5,
+    0x08DA7, 0x08E33, 0x08E3E, 0x08E38, 0x08E40, 0x08E45, 0x08E36, 0x08E3C, 0x08E3D, 0x08E41,
+    0x08E30, 0x08E3F, 0x08EBD, 0x08F36, 0x08F2E, 0x08F35, 0x08F32, 0x08F39, 0x08F37, 0x08F34,
+    0x09076, 0x09079, 0x0907B, 0x09086, 0x090FA, 0x09133, 0x09135, 0x09136, 0x09193, 0x09190,
+    0x09191, 0x0918D, 0x0918F, 0x09327, 0x0931E, 0x09308, 0x0931F, 0x09306, 0x0930F, 0x0937A,
+    0x09338, 0x0933C, 0x0931B, 0x09323, 0x09312, 0x09301, 0x09346, 0x0932D, 0x0930E, 0x0930D,
+    0x092CB, 0x0931D, 0x092FA, 0x09325, 0x09313, 0x092F9, 0x092F7, 0x09334, 0x09302, 0x09324,
+    0x092FF, 0x09329, 0x09339, 0x09335, 0x0932A, 0x09314, 0x0930C, 0x0930B, 0x092FE, 0x09309,
+    0x09300, 0x092FB, 0x09316, 0x095BC, 0x095CD, 0x095BE, 0x095B9, 0x095BA, 0x095B6, 0x095BF,
+    0x095B5, 0x095BD, 0x096A9, 0x096D4, 0x0970B, 0x09712, 0x09710, 0x09799, 0x09797, 0x09794,
+    0x097F0, 0x097F8, 0x09835, 0x0982F, 0x09832, 0x09924, 0x0991F, 0x09927, 0x09929, 0x0999E,
+    0x099EE, 0x099EC, 0x099E5, 0x099E4, 0x099F0, 0x099E3, 0x099EA, 0x099E9, 0x099E7, 0x09AB9,
+    0x09ABF, 0x09AB4, 0x09ABB, 0x09AF6, 0x09AFA, 0x09AF9, 0x09AF7, 0x09B33, 0x09B80, 0x09B85,
+    0x09B87, 0x09B7C, 0x09B7E, 0x09B7B, 0x09B82, 0x09B93, 0x09B92, 0x09B90, 0x09B7A, 0x09B95,
+    0x09B7D, 0x09B88, 0x09D25, 0x09D17, 0x09D20, 0x09D1E, 0x09D14, 0x09D29, 0x09D1D, 0x09D18,
+    0x09D22, 0x09D10, 0x09D19, 0x09D1F, 0x09E88, 0x09E86, 0x09E87, 0x09EAE, 0x09EAD, 0x09ED5,
+    0x09ED6, 0x09EFA, 0x09F12, 0x09F3D, 0x05126, 0x05125, 0x05122, 0x05124, 0x05120, 0x05129,
+    0x052F4, 0x05693, 0x0568C, 0x0568D, 0x05686, 0x05684, 0x05683, 0x0567E, 0x05682, 0x0567F,
+    0x05681, 0x058D6, 0x058D4, 0x058CF, 0x058D2, 0x05B2D, 0x05B25, 0x05B32, 0x05B23, 0x05B2C,
+    0x05B27, 0x05B26, 0x05B2F, 0x05B2E, 0x05B7B, 0x05BF1, 0x05BF2, 0x05DB7, 0x05E6C, 0x05E6A,
+    0x05FBE, 0x05FBB, 0x061C3, 0x061B5, 0x061BC, 0x061E7, 0x061E0, 0x061E5, 0x061E4, 0x061E8,
+    0x061DE, 0x064EF, 0x064E9, 0x064E3, 0x064EB, 0x064E4, 0x064E8, 0x06581, 0x06580, 0x065B6,
+    0x065DA, 0x066D2, 0x06A8D, 0x06A96, 0x06A81, 0x06AA5, 0x06A89, 0x06A9F, 0x06A9B, 0x06AA1,
+    0x06A9E, 0x06A87, 0x06A93, 0x06A8E, 0x06A95, 0x06A83, 0x06AA8, 0x06AA4, 0x06A91, 0x06A7F,
+    0x06AA6, 0x06A9A, 0x06A85, 0x06A8C, 0x06A92, 0x06B5B, 0x06BAD, 0x06C09, 0x06FCC, 0x06FA9,
+    0x06FF4, 0x06FD4, 0x06FE3, 0x06FDC, 0x06FED, 0x06FE7, 0x06FE6, 0x06FDE, 0x06FF2, 0x06FDD,
+    0x06FE2, 0x06FE8, 0x071E1, 0x071F1, 0x071E8, 0x071F2, 0x071E4, 0x071F0, 0x071E2, 0x07373,
+    0x0736E, 0x0736F, 0x07497, 0x074B2, 0x074AB, 0x07490, 0x074AA, 0x074AD, 0x074B1, 0x074A5,
+    0x074AF, 0x07510, 0x07511, 0x07512, 0x0750F, 0x07584, 0x07643, 0x07648, 0x07649, 0x07647,
+    0x076A4, 0x076E9, 0x077B5, 0x077AB, 0x077B2, 0x077B7, 0x077B6, 0x077B4, 0x077B1, 0x077A8,
+    0x077F0, 0x078F3, 0x078FD, 0x07902, 0x078FB, 0x078FC, 0x078F2, 0x07905, 0x078F9, 0x078FE,
+    0x07904, 0x079AB, 0x079A8, 0x07A5C, 0x07A5B, 0x07A56, 0x07A58, 0x07A54, 0x07A5A, 0x07ABE,
+    0x07AC0, 0x07AC1, 0x07C05, 0x07C0F, 0x07BF2, 0x07C00, 0x07BFF, 0x07BFB, 0x07C0E, 0x07BF4,
+    0x07C0B, 0x07BF3, 0x07C02, 0x07C09, 0x07C03, 0x07C01, 0x07BF8, 0x07BFD, 0x07C06, 0x07BF0,
+    0x07BF1, 0x07C10, 0x07C0A, 0x07CE8, 0x07E2D, 0x07E3C, 0x07E42, 0x07E33, 0x09848, 0x07E38,
+    0x07E2A, 0x07E49, 0x07E40, 0x07E47, 0x07E29, 0x07E4C, 0x07E30, 0x07E3B, 0x07E36, 0x07E44,
+    0x07E3A, 0x07F45, 0x07F7F, 0x07F7E, 0x07F7D, 0x07FF4, 0x07FF2, 0x0802C, 0x081BB, 0x081C4,
+    0x081CC, 0x081CA, 0x081C5, 0x081C7, 0x081BC, 0x081E9, 0x0825B, 0x0825A, 0x0825C, 0x08583,
+    0x08580, 0x0858F, 0x085A7, 0x08595, 0x085A0, 0x0858B, 0x085A3, 0x0857B, 0x085A4, 0x0859A,
+    0x0859E, 0x08577, 0x0857C, 0x08589, 0x085A1, 0x0857A, 0x08578, 0x08557, 0x0858E, 0x08596,
+    0x08586, 0x0858D, 0x08599, 0x0859D, 0x08581, 0x085A2, 0x08582, 0x08588, 0x08585, 0x08579,
+    0x08576, 0x08598, 0x08590, 0x0859F, 0x08668, 0x087BE, 0x087AA, 0x087AD, 0x087C5, 0x087B0,
+    0x087AC, 0x087B9, 0x087B5, 0x087BC, 0x087AE, 0x087C9, 0x087C3, 0x087C2, 0x087CC, 0x087B7,
+    0x087AF, 0x087C4, 0x087CA, 0x087B4, 0x087B6, 0x087BF, 0x087B8, 0x087BD, 0x087DE, 0x087B2,
+    0x08935, 0x08933, 0x0893C, 0x0893E, 0x08941, 0x08952, 0x08937, 0x08942, 0x089AD, 0x089AF,
+    0x089AE, 0x089F2, 0x089F3, 0x08B1E, 0x08B18, 0x08B16, 0x08B11, 0x08B05, 0x08B0B, 0x08B22,
+    0x08B0F, 0x08B12, 0x08B15, 0x08B07, 0x08B0D, 0x08B08, 0x08B06, 0x08B1C, 0x08B13, 0x08B1A,
+    0x08C4F, 0x08C70, 0x08C72, 0x08C71, 0x08C6F, 0x08C95, 0x08C94, 0x08CF9, 0x08D6F, 0x08E4E,
+    0x08E4D, 0x08E53, 0x08E50, 0x08E4C, 0x08E47, 0x08F43, 0x08F40, 0x09085, 0x0907E, 0x09138,
+    0x0919A, 0x091A2, 0x0919B, 0x09199, 0x0919F, 0x091A1, 0x0919D, 0x091A0, 0x093A1, 0x09383,
+    0x093AF, 0x09364, 0x09356, 0x09347, 0x0937C, 0x09358, 0x0935C, 0x09376, 0x09349, 0x09350,
+    0x09351, 0x09360, 0x0936D, 0x0938F, 0x0934C, 0x0936A, 0x09379, 0x09357, 0x09355, 0x09352,
+    0x0934F, 0x09371, 0x09377, 0x0937B, 0x09361, 0x0935E, 0x09363, 0x09367, 0x09380, 0x0934E,
+    0x09359, 0x095C7, 0x095C0, 0x095C9, 0x095C3, 0x095C5, 0x095B7, 0x096AE, 0x096B0, 0x096AC,
+    0x09720, 0x0971F, 0x09718, 0x0971D, 0x09719, 0x0979A, 0x097A1, 0x0979C, 0x0979E, 0x0979D,
+    0x097D5, 0x097D4, 0x097F1, 0x09841, 0x09844, 0x0984A, 0x09849, 0x09845, 0x09843, 0x09925,
+    0x0992B, 0x0992C, 0x0992A, 0x09933, 0x09932, 0x0992F, 0x0992D, 0x09931, 0x09930, 0x09998,
+    0x099A3, 0x099A1, 0x09A02, 0x099FA, 0x099F4, 0x099F7, 0x099F9, 0x099F8, 0x099F6, 0x099FB,
+    0x099FD, 0x099FE, 0x099FC, 0x09A03, 0x09ABE, 0x09AFE, 0x09AFD, 0x09B01, 0x09AFC, 0x09B48,
+    0x09B9A, 0x09BA8, 0x09B9E, 0x09B9B, 0x09BA6, 0x09BA1, 0x09BA5, 0x09BA4, 0x09B86, 0x09BA2,
+    0x09BA0, 0x09BAF, 0x09D33, 0x09D41, 0x09D67, 0x09D36, 0x09D2E, 0x09D2F, 0x09D31, 0x09D38,
+    0x09D30, 0x09D45, 0x09D42, 0x09D43, 0x09D3E, 0x09D37, 0x09D40, 0x09D3D, 0x07FF5, 0x09D2D,
+    0x09E8A, 0x09E89, 0x09E8D, 0x09EB0, 0x09EC8, 0x09EDA, 0x09EFB, 0x09EFF, 0x09F24, 0x09F23,
+    0x09F22, 0x09F54, 0x09FA0, 0x05131, 0x0512D, 0x0512E, 0x05698, 0x0569C, 0x05697, 0x0569A,
+    0x0569D, 0x05699, 0x05970, 0x05B3C, 0x05C69, 0x05C6A, 0x05DC0, 0x05E6D, 0x05E6E, 0x061D8,
+    0x061DF, 0x061ED, 0x061EE, 0x061F1, 0x061EA, 0x061F0, 0x061EB, 0x061D6, 0x061E9, 0x064FF,
+    0x06504, 0x064FD, 0x064F8, 0x06501, 0x06503, 0x064FC, 0x06594, 0x065DB, 0x066DA, 0x066DB,
+    0x066D8, 0x06AC5, 0x06AB9, 0x06ABD, 0x06AE1, 0x06AC6, 0x06ABA, 0x06AB6, 0x06AB7, 0x06AC7,
+    0x06AB4, 0x06AAD, 0x06B5E, 0x06BC9, 0x06C0B, 0x07007, 0x0700C, 0x0700D, 0x07001, 0x07005,
+    0x07014, 0x0700E, 0x06FFF, 0x07000, 0x06FFB, 0x07026, 0x06FFC, 0x06FF7, 0x0700A, 0x07201,
+    0x071FF, 0x071F9, 0x07203, 0x071FD, 0x07376, 0x074B8, 0x074C0, 0x074B5, 0x074C1, 0x074BE,
+    0x074B6, 0x074BB, 0x074C2, 0x07514, 0x07513, 0x0765C, 0x07664, 0x07659, 0x07650, 0x07653,
+    0x07657, 0x0765A, 0x076A6, 0x076BD, 0x076EC, 0x077C2, 0x077BA, 0x078FF, 0x0790C, 0x07913,
+    0x07914, 0x07909, 0x07910, 0x07912, 0x07911, 0x079AD, 0x079AC, 0x07A5F, 0x07C1C, 0x07C29,
+    0x07C19, 0x07C20, 0x07C1F, 0x07C2D, 0x07C1D, 0x07C26, 0x07C28, 0x07C22, 0x07C25, 0x07C30,
+    0x07E5C, 0x07E50, 0x07E56, 0x07E63, 0x07E58, 0x07E62, 0x07E5F, 0x07E51, 0x07E60, 0x07E57,
+    0x07E53, 0x07FB5, 0x07FB3, 0x07FF7, 0x07FF8, 0x08075, 0x081D1, 0x081D2, 0x081D0, 0x0825F,
+    0x0825E, 0x085B4, 0x085C6, 0x085C0, 0x085C3, 0x085C2, 0x085B3, 0x085B5, 0x085BD, 0x085C7,
+    0x085C4, 0x085BF, 0x085CB, 0x085CE, 0x085C8, 0x085C5, 0x085B1, 0x085B6, 0x085D2, 0x08624,
+    0x085B8, 0x085B7, 0x085BE, 0x08669, 0x087E7, 0x087E6, 0x087E2, 0x087DB, 0x087EB, 0x087EA,
+    0x087E5, 0x087DF, 0x087F3, 0x087E4, 0x087D4, 0x087DC, 0x087D3, 0x087ED, 0x087D8, 0x087E3,
+    0x087A4, 0x087D7, 0x087D9, 0x08801, 0x087F4, 0x087E8, 0x087DD, 0x08953, 0x0894B, 0x0894F,
+    0x0894C, 0x08946, 0x08950, 0x08951, 0x08949, 0x08B2A, 0x08B27, 0x08B23, 0x08B33, 0x08B30,
+    0x08B35, 0x08B47, 0x08B2F, 0x08B3C, 0x08B3E, 0x08B31, 0x08B25, 0x08B37, 0x08B26, 0x08B36,
+    0x08B2E, 0x08B24, 0x08B3B, 0x08B3D, 0x08B3A, 0x08C42, 0x08C75, 0x08C99, 0x08C98, 0x08C97,
+    0x08CFE, 0x08D04, 0x08D02, 0x08D00, 0x08E5C, 0x08E62, 0x08E60, 0x08E57, 0x08E56, 0x08E5E,
+    0x08E65, 0x08E67, 0x08E5B, 0x08E5A, 0x08E61, 0x08E5D, 0x08E69, 0x08E54, 0x08F46, 0x08F47,
+    0x08F48, 0x08F4B, 0x09128, 0x0913A, 0x0913B, 0x0913E, 0x091A8, 0x091A5, 0x091A7, 0x091AF,
+    0x091AA, 0x093B5, 0x0938C, 0x09392, 0x093B7, 0x0939B, 0x0939D, 0x09389, 0x093A7, 0x0938E,
+    0x093AA, 0x0939E, 0x093A6, 0x09395, 0x09388, 0x09399, 0x0939F, 0x0938D, 0x093B1, 0x09391,
+    0x093B2, 0x093A4, 0x093A8, 0x093B4, 0x093A3, 0x093A5, 0x095D2, 0x095D3, 0x095D1, 0x096B3,
+    0x096D7, 0x096DA, 0x05DC2, 0x096DF, 0x096D8, 0x096DD, 0x09723, 0x09722, 0x09725, 0x097AC,
+    0x097AE, 0x097A8, 0x097AB, 0x097A4, 0x097AA, 0x097A2, 0x097A5, 0x097D7, 0x097D9, 0x097D6,
+    0x097D8, 0x097FA, 0x09850, 0x09851, 0x09852, 0x098B8, 0x09941, 0x0993C, 0x0993A, 0x09A0F,
+    0x09A0B, 0x09A09, 0x09A0D, 0x09A04, 0x09A11, 0x09A0A, 0x09A05, 0x09A07, 0x09A06, 0x09AC0,
+    0x09ADC, 0x09B08, 0x09B04, 0x09B05, 0x09B29, 0x09B35, 0x09B4A, 0x09B4C, 0x09B4B, 0x09BC7,
+    0x09BC6, 0x09BC3, 0x09BBF, 0x09BC1, 0x09BB5, 0x09BB8, 0x09BD3, 0x09BB6, 0x09BC4, 0x09BB9,
+    0x09BBD, 0x09D5C, 0x09D53, 0x09D4F, 0x09D4A, 0x09D5B, 0x09D4B, 0x09D59, 0x09D56, 0x09D4C,
+    0x09D57, 0x09D52, 0x09D54, 0x09D5F, 0x09D58, 0x09D5A, 0x09E8E, 0x09E8C, 0x09EDF, 0x09F01,
+    0x09F00, 0x09F16, 0x09F25, 0x09F2B, 0x09F2A, 0x09F29, 0x09F28, 0x09F4C, 0x09F55, 0x05134,
+    0x05135, 0x05296, 0x052F7, 0x053B4, 0x056AB, 0x056AD, 0x056A6, 0x056A7, 0x056AA, 0x056AC,
+    0x058DA, 0x058DD, 0x058DB, 0x05912, 0x05B3D, 0x05B3E, 0x05B3F, 0x05DC3, 0x05E70, 0x05FBF,
+    0x061FB, 0x06507, 0x06510, 0x0650D, 0x06509, 0x0650C, 0x0650E, 0x06584, 0x065DE, 0x065DD,
+    0x066DE, 0x06AE7, 0x06AE0, 0x06ACC, 0x06AD1, 0x06AD9, 0x06ACB, 0x06ADF, 0x06ADC, 0x06AD0,
+    0x06AEB, 0x06ACF, 0x06ACD, 0x06ADE, 0x06B60, 0x06BB0, 0x06C0C, 0x07019, 0x07027, 0x07020,
+    0x07016, 0x0702B, 0x07021, 0x07022, 0x07023, 0x07029, 0x07017, 0x07024, 0x0701C, 0x0702A,
+    0x0720C, 0x0720A, 0x07207, 0x07202, 0x07205, 0x072A5, 0x072A6, 0x072A4, 0x072A3, 0x072A1,
+    0x074CB, 0x074C5, 0x074B7, 0x074C3, 0x07516, 0x07660, 0x077C9, 0x077CA, 0x077C4, 0x077F1,
+    0x0791D, 0x0791B, 0x07921, 0x0791C, 0x07917, 0x0791E, 0x079B0, 0x07A67, 0x07A68, 0x07C33,
+    0x07C3C, 0x07C39, 0x07C2C, 0x07C3B, 0x07CEC, 0x07CEA, 0x07E76, 0x07E75, 0x07E78, 0x07E70,
+    0x07E77, 0x07E6F, 0x07E7A, 0x07E72, 0x07E74, 0x07E68, 0x07F4B, 0x07F4A, 0x07F83, 0x07F86,
+    0x07FB7, 0x07FFD, 0x07FFE, 0x08078, 0x081D7, 0x081D5, 0x08264, 0x08261, 0x08263, 0x085EB,
+    0x085F1, 0x085ED, 0x085D9, 0x085E1, 0x085E8, 0x085DA, 0x085D7, 0x085EC, 0x085F2, 0x085F8,
+    0x085D8, 0x085DF, 0x085E3, 0x085DC, 0x085D1, 0x085F0, 0x085E6, 0x085EF, 0x085DE, 0x085E2,
+    0x08800, 0x087FA, 0x08803, 0x087F6, 0x087F7, 0x08809, 0x0880C, 0x0880B, 0x08806, 0x087FC,
+    0x08808, 0x087FF, 0x0880A, 0x08802, 0x08962, 0x0895A, 0x0895B, 0x08957, 0x08961, 0x0895C,
+    0x08958, 0x0895D, 0x08959, 0x08988, 0x089B7, 0x089B6, 0x089F6, 0x08B50, 0x08B48, 0x08B4A,
+    0x08B40, 0x08B53, 0x08B56, 0x08B54, 0x08B4B, 0x08B55, 0x08B51, 0x08B42, 0x08B52, 0x08B57,
+    0x08C43, 0x08C77, 0x08C76, 0x08C9A, 0x08D06, 0x08D07, 0x08D09, 0x08DAC, 0x08DAA, 0x08DAD,
+    0x08DAB, 0x08E6D, 0x08E78, 0x08E73, 0x08E6A, 0x08E6F, 0x08E7B, 0x08EC2, 0x08F52, 0x08F51,
+    0x08F4F, 0x08F50, 0x08F53, 0x08FB4, 0x09140, 0x0913F, 0x091B0, 0x091AD, 0x093DE, 0x093C7,
+    0x093CF, 0x093C2, 0x093DA, 0x093D0, 0x093F9, 0x093EC, 0x093CC, 0x093D9, 0x093A9, 0x093E6,
+    0x093CA, 0x093D4, 0x093EE, 0x093E3, 0x093D5, 0x093C4, 0x093CE, 0x093C0, 0x093D2, 0x093E7,
+    0x0957D, 0x095DA, 0x095DB, 0x096E1, 0x09729, 0x0972B, 0x0972C, 0x09728, 0x09726, 0x097B3,
+    0x097B7, 0x097B6, 0x097DD, 0x097DE, 0x097DF, 0x0985C, 0x09859, 0x0985D, 0x09857, 0x098BF,
+    0x098BD, 0x098BB, 0x098BE, 0x09948, 0x09947, 0x09943, 0x099A6, 0x099A7, 0x09A1A, 0x09A15,
+    0x09A25, 0x09A1D, 0x09A24, 0x09A1B, 0x09A22, 0x09A20, 0x09A27, 0x09A23, 0x09A1E, 0x09A1C,
+    0x09A14, 0x09AC2, 0x09B0B, 0x09B0A, 0x09B0E, 0x09B0C, 0x09B37, 0x09BEA, 0x09BEB, 0x09BE0,
+    0x09BDE, 0x09BE4, 0x09BE6, 0x09BE2, 0x09BF0, 0x09BD4, 0x09BD7, 0x09BEC, 0x09BDC, 0x09BD9,
+    0x09BE5, 0x09BD5, 0x09BE1, 0x09BDA, 0x09D77, 0x09D81, 0x09D8A, 0x09D84, 0x09D88, 0x09D71,
+    0x09D80, 0x09D78, 0x09D86, 0x09D8B, 0x09D8C, 0x09D7D, 0x09D6B, 0x09D74, 0x09D75, 0x09D70,
+    0x09D69, 0x09D85, 0x09D73, 0x09D7B, 0x09D82, 0x09D6F, 0x09D79, 0x09D7F, 0x09D87, 0x09D68,
+    0x09E94, 0x09E91, 0x09EC0, 0x09EFC, 0x09F2D, 0x09F40, 0x09F41, 0x09F4D, 0x09F56, 0x09F57,
+    0x09F58, 0x05337, 0x056B2, 0x056B5, 0x056B3, 0x058E3, 0x05B45, 0x05DC6, 0x05DC7, 0x05EEE,
+    0x05EEF, 0x05FC0, 0x05FC1, 0x061F9, 0x06517, 0x06516, 0x06515, 0x06513, 0x065DF, 0x066E8,
+    0x066E3, 0x066E4, 0x06AF3, 0x06AF0, 0x06AEA, 0x06AE8, 0x06AF9, 0x06AF1, 0x06AEE, 0x06AEF,
+    0x0703C, 0x07035, 0x0702F, 0x07037, 0x07034, 0x07031, 0x07042, 0x07038, 0x0703F, 0x0703A,
+    0x07039, 0x07040, 0x0703B, 0x07033, 0x07041, 0x07213, 0x07214, 0x072A8, 0x0737D, 0x0737C,
+    0x074BA, 0x076AB, 0x076AA, 0x076BE, 0x076ED, 0x077CC, 0x077CE, 0x077CF, 0x077CD, 0x077F2,
+    0x07925, 0x07923, 0x07927, 0x07928, 0x07924, 0x07929, 0x079B2, 0x07A6E, 0x07A6C, 0x07A6D,
+    0x07AF7, 0x07C49, 0x07C48, 0x07C4A, 0x07C47, 0x07C45, 0x07CEE, 0x07E7B, 0x07E7E, 0x07E81,
+    0x07E80, 0x07FBA, 0x07FFF, 0x08079, 0x081DB, 0x081D9, 0x0820B, 0x08268, 0x08269, 0x08622,
+    0x085FF, 0x08601, 0x085FE, 0x0861B, 0x08600, 0x085F6, 0x08604, 0x08609, 0x08605, 0x0860C,
+    0x085FD, 0x08819, 0x08810, 0x08811, 0x08817, 0x08813, 0x08816, 0x08963, 0x08966, 0x089B9,
+    0x089F7, 0x08B60, 0x08B6A, 0x08B5D, 0x08B68, 0x08B63, 0x08B65, 0x08B67, 0x08B6D, 0x08DAE,
+    0x08E86, 0x08E88, 0x08E84, 0x08F59, 0x08F56, 0x08F57, 0x08F55, 0x08F58, 0x08F5A, 0x0908D,
+    0x09143, 0x09141, 0x091B7, 0x091B5, 0x091B2, 0x091B3, 0x0940B, 0x09413, 0x093FB, 0x09420,
+    0x0940F, 0x09414, 0x093FE, 0x09415, 0x09410, 0x09428, 0x09419, 0x0940D, 0x093F5, 0x09400,
+    0x093F7, 0x09407, 0x0940E, 0x09416, 0x09412, 0x093FA, 0x09409, 0x093F8, 0x0940A, 0x093FF,
+    0x093FC, 0x0940C, 0x093F6, 0x09411, 0x09406, 0x095DE, 0x095E0, 0x095DF, 0x0972E, 0x0972F,
+    0x097B9, 0x097BB, 0x097FD, 0x097FE, 0x09860, 0x09862, 0x09863, 0x0985F, 0x098C1, 0x098C2,
+    0x09950, 0x0994E, 0x09959, 0x0994C, 0x0994B, 0x09953, 0x09A32, 0x09A34, 0x09A31, 0x09A2C,
+    0x09A2A, 0x09A36, 0x09A29, 0x09A2E, 0x09A38, 0x09A2D, 0x09AC7, 0x09ACA, 0x09AC6, 0x09B10,
+    0x09B12, 0x09B11, 0x09C0B, 0x09C08, 0x09BF7, 0x09C05, 0x09C12, 0x09BF8, 0x09C40, 0x09C07,
+    0x09C0E, 0x09C06, 0x09C17, 0x09C14, 0x09C09, 0x09D9F, 0x09D99, 0x09DA4, 0x09D9D, 0x09D92,
+    0x09D98, 0x09D90, 0x09D9B, 0x09DA0, 0x09D94, 0x09D9C, 0x09DAA, 0x09D97, 0x09DA1, 0x09D9A,
+    0x09DA2, 0x09DA8, 0x09D9E, 0x09DA3, 0x09DBF, 0x09DA9, 0x09D96, 0x09DA6, 0x09DA7, 0x09E99,
+    0x09E9B, 0x09E9A, 0x09EE5, 0x09EE4, 0x09EE7, 0x09EE6, 0x09F30, 0x09F2E, 0x09F5B, 0x09F60,
+    0x09F5E, 0x09F5D, 0x09F59, 0x09F91, 0x0513A, 0x05139, 0x05298, 0x05297, 0x056C3, 0x056BD,
+    0x056BE, 0x05B48, 0x05B47, 0x05DCB, 0x05DCF, 0x05EF1, 0x061FD, 0x0651B, 0x06B02, 0x06AFC,
+    0x06B03, 0x06AF8, 0x06B00, 0x07043, 0x07044, 0x0704A, 0x07048, 0x07049, 0x07045, 0x07046,
+    0x0721D, 0x0721A, 0x07219, 0x0737E, 0x07517, 0x0766A, 0x077D0, 0x0792D, 0x07931, 0x0792F,
+    0x07C54, 0x07C53, 0x07CF2, 0x07E8A, 0x07E87, 0x07E88, 0x07E8B, 0x07E86, 0x07E8D, 0x07F4D,
+    0x07FBB, 0x08030, 0x081DD, 0x08618, 0x0862A, 0x08626, 0x0861F, 0x08623, 0x0861C, 0x08619,
+    0x08627, 0x0862E, 0x08621, 0x08620, 0x08629, 0x0861E, 0x08625, 0x08829, 0x0881D, 0x0881B,
+    0x08820, 0x08824, 0x0881C, 0x0882B, 0x0884A, 0x0896D, 0x08969, 0x0896E, 0x0896B, 0x089FA,
+    0x08B79, 0x08B78, 0x08B45, 0x08B7A, 0x08B7B, 0x08D10, 0x08D14, 0x08DAF, 0x08E8E, 0x08E8C,
+    0x08F5E, 0x08F5B, 0x08F5D, 0x09146, 0x09144, 0x09145, 0x091B9, 0x0943F, 0x0943B, 0x09436,
+    0x09429, 0x0943D, 0x0943C, 0x09430, 0x09439, 0x0942A, 0x09437, 0x0942C, 0x09440, 0x09431,
+    0x095E5, 0x095E4, 0x095E3, 0x09735, 0x0973A, 0x097BF, 0x097E1, 0x09864, 0x098C9, 0x098C6,
+    0x098C0, 0x09958, 0x09956, 0x09A39, 0x09A3D, 0x09A46, 0x09A44, 0x09A42, 0x09A41, 0x09A3A,
+    0x09A3F, 0x09ACD, 0x09B15, 0x09B17, 0x09B18, 0x09B16, 0x09B3A, 0x09B52, 0x09C2B, 0x09C1D,
+    0x09C1C, 0x09C2C, 0x09C23, 0x09C28, 0x09C29, 0x09C24, 0x09C21, 0x09DB7, 0x09DB6, 0x09DBC,
+    0x09DC1, 0x09DC7, 0x09DCA, 0x09DCF, 0x09DBE, 0x09DC5, 0x09DC3, 0x09DBB, 0x09DB5, 0x09DCE,
+    0x09DB9, 0x09DBA, 0x09DAC, 0x09DC8, 0x09DB1, 0x09DAD, 0x09DCC, 0x09DB3, 0x09DCD, 0x09DB2,
+    0x09E7A, 0x09E9C, 0x09EEB, 0x09EEE, 0x09EED, 0x09F1B, 0x09F18, 0x09F1A, 0x09F31, 0x09F4E,
+    0x09F65, 0x09F64, 0x09F92, 0x04EB9, 0x056C6, 0x056C5, 0x056CB, 0x05971, 0x05B4B, 0x05B4C,
+    0x05DD5, 0x05DD1, 0x05EF2, 0x06521, 0x06520, 0x06526, 0x06522, 0x06B0B, 0x06B08, 0x06B09,
+    0x06C0D, 0x07055, 0x07056, 0x07057, 0x07052, 0x0721E, 0x0721F, 0x072A9, 0x0737F, 0x074D8,
+    0x074D5, 0x074D9, 0x074D7, 0x0766D, 0x076AD, 0x07935, 0x079B4, 0x07A70, 0x07A71, 0x07C57,
+    0x07C5C, 0x07C59, 0x07C5B, 0x07C5A, 0x07CF4, 0x07CF1, 0x07E91, 0x07F4F, 0x07F87, 0x081DE,
+    0x0826B, 0x08634, 0x08635, 0x08633, 0x0862C, 0x08632, 0x08636, 0x0882C, 0x08828, 0x08826,
+    0x0882A, 0x08825, 0x08971, 0x089BF, 0x089BE, 0x089FB, 0x08B7E, 0x08B84, 0x08B82, 0x08B86,
+    0x08B85, 0x08B7F, 0x08D15, 0x08E95, 0x08E94, 0x08E9A, 0x08E92, 0x08E90, 0x08E96, 0x08E97,
+    0x08F60, 0x08F62, 0x09147, 0x0944C, 0x09450, 0x0944A, 0x0944B, 0x0944F, 0x09447, 0x09445,
+    0x09448, 0x09449, 0x09446, 0x0973F, 0x097E3, 0x0986A, 0x09869, 0x098CB, 0x09954, 0x0995B,
+    0x09A4E, 0x09A53, 0x09A54, 0x09A4C, 0x09A4F, 0x09A48, 0x09A4A, 0x09A49, 0x09A52, 0x09A50,
+    0x09AD0, 0x09B19, 0x09B2B, 0x09B3B, 0x09B56, 0x09B55, 0x09C46, 0x09C48, 0x09C3F, 0x09C44,
+    0x09C39, 0x09C33, 0x09C41, 0x09C3C, 0x09C37, 0x09C34, 0x09C32, 0x09C3D, 0x09C36, 0x09DDB,
+    0x09DD2, 0x09DDE, 0x09DDA, 0x09DCB, 0x09DD0, 0x09DDC, 0x09DD1, 0x09DDF, 0x09DE9, 0x09DD9,
+    0x09DD8, 0x09DD6, 0x09DF5, 0x09DD5, 0x09DDD, 0x09EB6, 0x09EF0, 0x09F35, 0x09F33, 0x09F32,
+    0x09F42, 0x09F6B, 0x09F95, 0x09FA2, 0x0513D, 0x05299, 0x058E8, 0x058E7, 0x05972, 0x05B4D,
+    0x05DD8, 0x0882F, 0x05F4F, 0x06201, 0x06203, 0x06204, 0x06529, 0x06525, 0x06596, 0x066EB,
+    0x06B11, 0x06B12, 0x06B0F, 0x06BCA, 0x0705B, 0x0705A, 0x07222, 0x07382, 0x07381, 0x07383,
+    0x07670, 0x077D4, 0x07C67, 0x07C66, 0x07E95, 0x0826C, 0x0863A, 0x08640, 0x08639, 0x0863C,
+    0x08631, 0x0863B, 0x0863E, 0x08830, 0x08832, 0x0882E, 0x08833, 0x08976, 0x08974, 0x08973,
+    0x089FE, 0x08B8C, 0x08B8E, 0x08B8B, 0x08B88, 0x08C45, 0x08D19, 0x08E98, 0x08F64, 0x08F63,
+    0x091BC, 0x09462, 0x09455, 0x0945D, 0x09457, 0x0945E, 0x097C4, 0x097C5, 0x09800, 0x09A56,
+    0x09A59, 0x09B1E, 0x09B1F, 0x09B20, 0x09C52, 0x09C58, 0x09C50, 0x09C4A, 0x09C4D, 0x09C4B,
+    0x09C55, 0x09C59, 0x09C4C, 0x09C4E, 0x09DFB, 0x09DF7, 0x09DEF, 0x09DE3, 0x09DEB, 0x09DF8,
+    0x09DE4, 0x09DF6, 0x09DE1, 0x09DEE, 0x09DE6, 0x09DF2, 0x09DF0, 0x09DE2, 0x09DEC, 0x09DF4,
+    0x09DF3, 0x09DE8, 0x09DED, 0x09EC2, 0x09ED0, 0x09EF2, 0x09EF3, 0x09F06, 0x09F1C, 0x09F38,
+    0x09F37, 0x09F36, 0x09F43, 0x09F4F, 0x09F71, 0x09F70, 0x09F6E, 0x09F6F, 0x056D3, 0x056CD,
+    0x05B4E, 0x05C6D, 0x0652D, 0x066ED, 0x066EE, 0x06B13, 0x0705F, 0x07061, 0x0705D, 0x07060,
+    0x07223, 0x074DB, 0x074E5, 0x077D5, 0x07938, 0x079B7, 0x079B6, 0x07C6A, 0x07E97, 0x07F89,
+    0x0826D, 0x08643, 0x08838, 0x08837, 0x08835, 0x0884B, 0x08B94, 0x08B95, 0x08E9E, 0x08E9F,
+    0x08EA0, 0x08E9D, 0x091BE, 0x091BD, 0x091C2, 0x0946B, 0x09468, 0x09469, 0x096E5, 0x09746,
+    0x09743, 0x09747, 0x097C7, 0x097E5, 0x09A5E, 0x09AD5, 0x09B59, 0x09C63, 0x09C67, 0x09C66,
+    0x09C62, 0x09C5E, 0x09C60, 0x09E02, 0x09DFE, 0x09E07, 0x09E03, 0x09E06, 0x09E05, 0x09E00,
+    0x09E01, 0x09E09, 0x09DFF, 0x09DFD, 0x09E04, 0x09EA0, 0x09F1E, 0x09F46, 0x09F74, 0x09F75,
+    0x09F76, 0x056D4, 0x0652E, 0x065B8, 0x06B18, 0x06B19, 0x06B17, 0x06B1A, 0x07062, 0x07226,
+    0x072AA, 0x077D8, 0x077D9, 0x07939, 0x07C69, 0x07C6B, 0x07CF6, 0x07E9A, 0x07E98, 0x07E9B,
+    0x07E99, 0x081E0, 0x081E1, 0x08646, 0x08647, 0x08648, 0x08979, 0x0897A, 0x0897C, 0x0897B,
+    0x089FF, 0x08B98, 0x08B99, 0x08EA5, 0x08EA4, 0x08EA3, 0x0946E, 0x0946D, 0x0946F, 0x09471,
+    0x09473, 0x09749, 0x09872, 0x0995F, 0x09C68, 0x09C6E, 0x09C6D, 0x09E0B, 0x09E0D, 0x09E10,
+    0x09E0F, 0x09E12, 0x09E11, 0x09EA1, 0x09EF5, 0x09F09, 0x09F47, 0x09F78, 0x09F7B, 0x09F7A,
+    0x09F79, 0x0571E, 0x07066, 0x07C6F, 0x0883C, 0x08DB2, 0x08EA6, 0x091C3, 0x09474, 0x09478,
+    0x09476, 0x09475, 0x09A60, 0x09C74, 0x09C73, 0x09C71, 0x09C75, 0x09E14, 0x09E13, 0x09EF6,
+    0x09F0A, 0x09FA4, 0x07068, 0x07065, 0x07CF7, 0x0866A, 0x0883E, 0x0883D, 0x0883F, 0x08B9E,
+    0x08C9C, 0x08EA9, 0x08EC9, 0x0974B, 0x09873, 0x09874, 0x098CC, 0x09961, 0x099AB, 0x09A64,
+    0x09A66, 0x09A67, 0x09B24, 0x09E15, 0x09E17, 0x09F48, 0x06207, 0x06B1E, 0x07227, 0x0864C,
+    0x08EA8, 0x09482, 0x09480, 0x09481, 0x09A69, 0x09A68, 0x09B2E, 0x09E19, 0x07229, 0x0864B,
+    0x08B9F, 0x09483, 0x09C79, 0x09EB7, 0x07675, 0x09A6B, 0x09C7A, 0x09E1D, 0x07069, 0x0706A,
+    0x09EA4, 0x09F7E, 0x09F49, 0x09F98, 0x07881, 0x092B9, 0x088CF, 0x058BB, 0x06052, 0x07CA7,
+    0x05AFA, 0x02554, 0x02566, 0x02557, 0x02560, 0x0256C, 0x02563, 0x0255A, 0x02569, 0x0255D,
+    0x02552, 0x02564, 0x02555, 0x0255E, 0x0256A, 0x02561, 0x02558, 0x02567, 0x0255B, 0x02553,
+    0x02565, 0x02556, 0x0255F, 0x0256B, 0x02562, 0x02559, 0x02568, 0x0255C, 0x02551, 0x02550,
+    0x0256D, 0x0256E, 0x02570, 0x0256F, 0x0FFED, 0x20547, 0x092DB, 0x205DF, 0x23FC5, 0x0854C,
+    0x042B5, 0x073EF, 0x051B5, 0x03649, 0x24942, 0x289E4, 0x09344, 0x219DB, 0x082EE, 0x23CC8,
+    0x0783C, 0x06744, 0x062DF, 0x24933, 0x289AA, 0x202A0, 0x26BB3, 0x21305, 0x04FAB, 0x224ED,
+    0x05008, 0x26D29, 0x27A84, 0x23600, 0x24AB1, 0x22513, 0x00000, 0x2037E, 0x05FA4, 0x20380,
+    0x20347, 0x06EDB, 0x2041F, 0x00000, 0x05101, 0x0347A, 0x0510E, 0x0986C, 0x03743, 0x08416,
+    0x249A4, 0x20487, 0x05160, 0x233B4, 0x0516A, 0x20BFF, 0x220FC, 0x202E5, 0x22530, 0x2058E,
+    0x23233, 0x21983, 0x05B82, 0x0877D, 0x205B3, 0x23C99, 0x051B2, 0x051B8, 0x09D34, 0x051C9,
+    0x051CF, 0x051D1, 0x03CDC, 0x051D3, 0x24AA6, 0x051B3, 0x051E2, 0x05342, 0x051ED, 0x083CD,
+    0x0693E, 0x2372D, 0x05F7B, 0x0520B, 0x05226, 0x0523C, 0x052B5, 0x05257, 0x05294, 0x052B9,
+    0x052C5, 0x07C15, 0x08542, 0x052E0, 0x0860D, 0x26B13, 0x00000, 0x28ADE, 0x05549, 0x06ED9,
+    0x23F80, 0x20954, 0x23FEC, 0x05333, 0x00000, 0x20BE2, 0x06CCB, 0x21726, 0x0681B, 0x073D5,
+    0x0604A, 0x03EAA, 0x038CC, 0x216E8, 0x071DD, 0x044A2, 0x0536D, 0x05374, 0x286AB, 0x0537E,
+    0x00000, 0x21596, 0x21613, 0x077E6, 0x05393, 0x28A9B, 0x053A0, 0x053AB, 0x053AE, 0x073A7,
+    0x25772, 0x03F59, 0x0739C, 0x053C1, 0x053C5, 0x06C49, 0x04E49, 0x057FE, 0x053D9, 0x03AAB,
+    0x20B8F, 0x053E0, 0x23FEB, 0x22DA3, 0x053F6, 0x20C77, 0x05413, 0x07079, 0x0552B, 0x06657,
+    0x06D5B, 0x0546D, 0x26B53, 0x20D74, 0x0555D, 0x0548F, 0x054A4, 0x047A6, 0x2170D, 0x20EDD,
+    0x03DB4, 0x20D4D, 0x289BC, 0x22698, 0x05547, 0x04CED, 0x0542F, 0x07417, 0x05586, 0x055A9,
+    0x00000, 0x218D7, 0x2403A, 0x04552, 0x24435, 0x066B3, 0x210B4, 0x05637, 0x066CD, 0x2328A,
+    0x066A4, 0x066AD, 0x0564D, 0x0564F, 0x078F1, 0x056F1, 0x09787, 0x053FE, 0x05700, 0x056EF,
+    0x056ED, 0x28B66, 0x03623, 0x2124F, 0x05746, 0x241A5, 0x06C6E, 0x0708B, 0x05742, 0x036B1,
+    0x26C7E, 0x057E6, 0x21416, 0x05803, 0x21454, 0x24363, 0x05826, 0x24BF5, 0x0585C, 0x058AA,
+    0x03561, 0x058E0, 0x058DC, 0x2123C, 0x058FB, 0x05BFF, 0x05743, 0x2A150, 0x24278, 0x093D3,
+    0x035A1, 0x0591F, 0x068A6, 0x036C3, 0x06E59, 0x2163E, 0x05A24, 0x05553, 0x21692, 0x08505,
+    0x059C9, 0x20D4E, 0x26C81, 0x26D2A, 0x217DC, 0x059D9, 0x217FB, 0x217B2, 0x26DA6, 0x06D71,
+    0x21828, 0x216D5, 0x059F9, 0x26E45, 0x05AAB, 0x05A63, 0x036E6, 0x249A9, 0x00000, 0x03708,
+    0x05A96, 0x07465, 0x05AD3, 0x26FA1, 0x22554, 0x03D85, 0x21911, 0x03732, 0x216B8, 0x05E83,
+    0x052D0, 0x05B76, 0x06588, 0x05B7C, 0x27A0E, 0x04004, 0x0485D, 0x20204, 0x05BD5, 0x06160,
+    0x21A34, 0x259CC, 0x205A5, 0x05BF3, 0x05B9D, 0x04D10, 0x05C05, 0x21B44, 0x05C13, 0x073CE,
+    0x05C14, 0x21CA5, 0x26B28, 0x05C49, 0x048DD, 0x05C85, 0x05CE9, 0x05CEF, 0x05D8B, 0x21DF9,
+    0x21E37, 0x05D10, 0x05D18, 0x05D46, 0x21EA4, 0x05CBA, 0x05DD7, 0x082FC, 0x0382D, 0x24901,
+    0x22049, 0x22173, 0x08287, 0x03836, 0x03BC2, 0x05E2E, 0x06A8A, 0x00000, 0x05E7A, 0x244BC,
+    0x20CD3, 0x053A6, 0x04EB7, 0x00000, 0x053A8, 0x21771, 0x05E09, 0x05EF4, 0x28482, 0x05EF9,
+    0x05EFB, 0x038A0, 0x05EFC, 0x0683E, 0x0941B, 0x05F0D, 0x201C1, 0x2F894, 0x03ADE, 0x048AE,
+    0x2133A, 0x05F3A, 0x26888, 0x223D0, 0x00000, 0x22471, 0x05F63, 0x097BD, 0x26E6E, 0x05F72,
+    0x09340, 0x28A36, 0x05FA7, 0x05DB6, 0x03D5F, 0x25250, 0x21F6A, 0x270F8, 0x22668, 0x091D6,
+    0x2029E, 0x28A29, 0x06031, 0x06685, 0x21877, 0x03963, 0x03DC7, 0x03639, 0x05790, 0x227B4,
+    0x07971, 0x03E40, 0x0609E, 0x00000, 0x060B3, 0x24982, 0x2498F, 0x27A53, 0x074A4, 0x050E1,
+    0x05AA0, 0x06164, 0x08424, 0x06142, 0x2F8A6, 0x26ED2, 0x06181, 0x051F4, 0x20656, 0x06187,
+    0x05BAA, 0x23FB7, 0x2285F, 0x061D3, 0x28B9D, 0x2995D, 0x061D0, 0x03932, 0x22980, 0x228C1,
+    0x06023, 0x0615C, 0x0651E, 0x0638B, 0x20118, 0x062C5, 0x21770, 0x062D5, 0x22E0D, 0x0636C,
+    0x249DF, 0x03A17, 0x06438, 0x063F8, 0x2138E, 0x217FC, 0x00000, 0x06F8A, 0x22E36, 0x09814,
+    0x2408C, 0x2571D, 0x064E1, 0x064E5, 0x0947B, 0x03A66, 0x0643A, 0x03A57, 0x0654D, 0x06F16,
+    0x24A28, 0x24A23, 0x06585, 0x0656D, 0x0655F, 0x2307E, 0x065B5, 0x24940, 0x04B37, 0x065D1,
+    0x040D8, 0x21829, 0x065E0, 0x065E3, 0x05FDF, 0x23400, 0x06618, 0x231F7, 0x231F8, 0x06644,
+    0x231A4, 0x231A5, 0x0664B, 0x20E75, 0x06667, 0x251E6, 0x06673, 0x00000, 0x21E3D, 0x23231,
+    0x285F4, 0x231C8, 0x25313, 0x077C5, 0x228F7, 0x099A4, 0x06702, 0x2439C, 0x24A21, 0x03B2B,
+    0x069FA, 0x237C2, 0x00000, 0x06767, 0x06762, 0x241CD, 0x290ED, 0x067D7, 0x044E9, 0x06822,
+    0x06E50, 0x0923C, 0x06801, 0x233E6, 0x26DA0, 0x0685D, 0x2346F, 0x069E1, 0x06A0B, 0x28ADF,
+    0x06973, 0x068C3, 0x235CD, 0x06901, 0x06900, 0x03D32, 0x03A01, 0x2363C, 0x03B80, 0x067AC,
+    0x06961, 0x28A4A, 0x042FC, 0x06936, 0x06998, 0x03BA1, 0x203C9, 0x08363, 0x05090, 0x069F9,
+    0x23659, 0x2212A, 0x06A45, 0x23703, 0x06A9D, 0x03BF3, 0x067B1, 0x06AC8, 0x2919C, 0x03C0D,
+    0x06B1D, 0x20923, 0x060DE, 0x06B35, 0x06B74, 0x227CD, 0x06EB5, 0x23ADB, 0x203B5, 0x21958,
+    0x03740, 0x05421, 0x23B5A, 0x06BE1, 0x23EFC, 0x06BDC, 0x06C37, 0x2248B, 0x248F1, 0x26B51,
+    0x06C5A, 0x08226, 0x06C79, 0x23DBC, 0x044C5, 0x23DBD, 0x241A4, 0x2490C, 0x24900, 0x23CC9,
+    0x036E5, 0x03CEB, 0x20D32, 0x09B83, 0x231F9, 0x22491, 0x07F8F, 0x06837, 0x26D25, 0x26DA1,
+    0x26DEB, 0x06D96, 0x06D5C, 0x06E7C, 0x06F04, 0x2497F, 0x24085, 0x26E72, 0x08533, 0x26F74,
+    0x051C7, 0x00000, 0x00000, 0x0842E, 0x28B21, 0x00000, 0x23E2F, 0x07453, 0x23F82, 0x079CC,
+    0x06E4F, 0x05A91, 0x2304B, 0x06FF8, 0x0370D, 0x06F9D, 0x23E30, 0x06EFA, 0x21497, 0x2403D,
+    0x04555, 0x093F0, 0x06F44, 0x06F5C, 0x03D4E, 0x06F74, 0x29170, 0x03D3B, 0x06F9F, 0x24144,
+    0x06FD3, 0x24091, 0x24155, 0x24039, 0x23FF0, 0x23FB4, 0x2413F, 0x051DF, 0x24156, 0x24157,
+    0x24140, 0x261DD, 0x0704B, 0x0707E, 0x070A7, 0x07081, 0x070CC, 0x070D5, 0x070D6, 0x070DF,
+    0x04104, 0x03DE8, 0x071B4, 0x07196, 0x24277, 0x0712B, 0x07145, 0x05A88, 0x0714A, 0x00000,
+    0x05C9C, 0x24365, 0x0714F, 0x09362, 0x242C1, 0x0712C, 0x2445A, 0x24A27, 0x24A22, 0x071BA,
+    0x28BE8, 0x070BD, 0x0720E, 0x09442, 0x07215, 0x05911, 0x09443, 0x07224, 0x09341, 0x25605,
+    0x0722E, 0x07240, 0x24974, 0x068BD, 0x07255, 0x07257, 0x03E55, 0x23044, 0x0680D, 0x06F3D,
+    0x07282, 0x00000, 0x0732B, 0x24823, 0x2882B, 0x048ED, 0x28804, 0x07328, 0x0732E, 0x073CF,
+    0x073AA, 0x20C3A, 0x26A2E, 0x073C9, 0x07449, 0x241E2, 0x216E7, 0x24A24, 0x06623, 0x036C5,
+    0x249B7, 0x2498D, 0x249FB, 0x073F7, 0x07415, 0x06903, 0x24A26, 0x07439, 0x205C3, 0x03ED7,
+    0x00000, 0x228AD, 0x07460, 0x28EB2, 0x07447, 0x073E4, 0x07476, 0x083B9, 0x0746C, 0x03730,
+    0x07474, 0x093F1, 0x06A2C, 0x07482, 0x04953, 0x24A8C, 0x2415F, 0x24A79, 0x28B8F, 0x05B46,
+    0x28C03, 0x2189E, 0x074C8, 0x21988, 0x0750E, 0x00000, 0x0751E, 0x28ED9, 0x21A4B, 0x05BD7,
+    0x28EAC, 0x09385, 0x0754D, 0x0754A, 0x07567, 0x0756E, 0x24F82, 0x03F04, 0x24D13, 0x0758E,
+    0x0745D, 0x0759E, 0x075B4, 0x07602, 0x0762C, 0x07651, 0x0764F, 0x0766F, 0x07676, 0x263F5,
+    0x07690, 0x081EF, 0x037F8, 0x26911, 0x2690E, 0x076A1, 0x076A5, 0x076B7, 0x076CC, 0x26F9F,
+    0x08462, 0x2509D, 0x2517D, 0x21E1C, 0x0771E, 0x07726, 0x07740, 0x064AF, 0x25220, 0x07758,
+    0x232AC, 0x077AF, 0x28964, 0x28968, 0x216C1, 0x077F4, 0x00000, 0x21376, 0x24A12, 0x068CA,
+    0x078AF, 0x078C7, 0x078D3, 0x096A5, 0x0792E, 0x255E0, 0x078D7, 0x07934, 0x078B1, 0x2760C,
+    0x08FB8, 0x08884, 0x28B2B, 0x26083, 0x2261C, 0x07986, 0x08900, 0x06902, 0x07980, 0x25857,
+    0x0799D, 0x27B39, 0x0793C, 0x079A9, 0x06E2A, 0x27126, 0x03EA8, 0x079C6, 0x2910D, 0x079D4,
+];
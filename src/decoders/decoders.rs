@@ -1,5 +1,5 @@
 use crate::encoders::big5::encode_big5;
-use crate::encoders::gbk::encode_gbk;
+use crate::encoders::gbk::{encode_gb18030, encode_gbk};
 use crate::encoders::shiftjis::encode_shiftjis;
 use crate::encoders::koi8r::encode_koi8r;
 use crate::encoders::windows1252::encode_windows1252;
@@ -8,6 +8,7 @@ use crate::encoders::latin9::encode_latin9;
 use crate::encoders::latin2::encode_latin2;
 use crate::encoders::latin1::encode_latin1;
 use crate::encoders::utf8::encode_utf8;
+use crate::encoders::utf16::{encode_utf16be, encode_utf16le};
 use std::borrow::Cow;
 
 use crate::decoders::{
@@ -21,13 +22,15 @@ use crate::decoders::{
     shiftjis::{decode_shiftjis},
     gbk::{decode_gbk},
     big5::{decode_big5},
+    utf16::{decode_utf16be, decode_utf16le},
+    detect::{detect_bom, detect_encoding},
 };
 
 /// ## Encoding Enum
 ///
 /// Represents the available character set transformations.
 /// Used to decode byte data into Unicode `str` and encode `str` into byte data.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[allow(dead_code)]
 pub enum Encoding {
     Utf8,
@@ -39,8 +42,14 @@ pub enum Encoding {
     ShiftJIS,
     GBK,
     GB2312,
+    /// Superset of GBK, covering all of Unicode with four byte sequences.
+    GB18030,
     Big5,
     ISO8859_2,
+    /// UTF-16 little endian (Excel "Unicode text"). `CsvReaderWithMap` transcodes it to UTF-8 on open.
+    Utf16Le,
+    /// UTF-16 big endian. `CsvReaderWithMap` transcodes it to UTF-8 on open.
+    Utf16Be,
 }
 
 impl Encoding {
@@ -61,8 +70,10 @@ impl Encoding {
             Encoding::Windows1252 => decode_windows1252(input),
             Encoding::KOI8R => decode_koi8r(input),
             Encoding::ShiftJIS => decode_shiftjis(input),
-            Encoding::GBK | Encoding::GB2312 => decode_gbk(input),
+            Encoding::GBK | Encoding::GB2312 | Encoding::GB18030 => decode_gbk(input),
             Encoding::Big5 => decode_big5(input),
+            Encoding::Utf16Le => decode_utf16le(input),
+            Encoding::Utf16Be => decode_utf16be(input),
         }
     }
 
@@ -117,7 +128,47 @@ impl Encoding {
             Encoding::KOI8R => encode_koi8r(input),
             Encoding::ShiftJIS => encode_shiftjis(input),
             Encoding::GBK | Encoding::GB2312 => encode_gbk(input),
+            Encoding::GB18030 => encode_gb18030(input),
             Encoding::Big5 => encode_big5(input),
+            Encoding::Utf16Le => encode_utf16le(input),
+            Encoding::Utf16Be => encode_utf16be(input),
         }
     }
+
+    /// ## Is UTF-16
+    ///
+    /// Returns `true` for `Utf16Le` and `Utf16Be`, whose records can not be split byte by byte.
+    pub fn is_utf16(self) -> bool {
+        matches!(self, Encoding::Utf16Le | Encoding::Utf16Be)
+    }
+
+    /// ## From BOM
+    ///
+    /// Checks the byte order mark at the start of `input`.
+    ///
+    /// ### Returns:
+    /// - The encoding given by the BOM (`Utf8`, `Utf16Le` or `Utf16Be`), and the BOM length in bytes.
+    /// - `None` if there is no BOM.
+    pub fn from_bom(input: &[u8]) -> Option<(Encoding, usize)> {
+        detect_bom(input)
+    }
+
+    /// ## Detect Function
+    ///
+    /// Inspects a sample (like the first KBs of a file) and picks the most likely encoding.
+    ///
+    /// - A BOM wins. Then UTF-16 (by the position of the zero bytes), valid UTF-8, the CJK double byte encodings,
+    ///   and the single byte encodings (Windows1251 / KOI8R, Windows1252 / ISO8859_15 / ISO8859_2).
+    /// - It is a heuristic: pure ASCII samples return `Utf8`, and short samples may be ambiguous.
+    /// - `GBK` is returned for `GB2312` text, and `Windows1252` for `ISO8859_1` text (same printable characters).
+    /// ### Code Example:
+    /// ```
+    /// use csv_lib::decoders::decoders::Encoding;
+    ///
+    /// assert_eq!(Encoding::detect("id;ciudad\n1;Peñarol".as_bytes()), Encoding::Utf8);
+    /// assert_eq!(Encoding::detect(b"id;ciudad\n1;Pe\xF1arol"), Encoding::Windows1252);
+    /// ```
+    pub fn detect(sample: &[u8]) -> Encoding {
+        detect_encoding(sample)
+    }
 }
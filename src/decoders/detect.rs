@@ -0,0 +1,270 @@
+use crate::decoders::decoders::Encoding;
+
+/// Bytes inspected by the readers when the encoding detection is enabled.
+pub(crate) const DETECTION_SAMPLE_SIZE: usize = 64 * 1024;
+
+const UTF8_BOM: [u8; 3] = [0xEF, 0xBB, 0xBF];
+const UTF16LE_BOM: [u8; 2] = [0xFF, 0xFE];
+const UTF16BE_BOM: [u8; 2] = [0xFE, 0xFF];
+
+/// Symbols usual in text, scored as letters by the single byte detection.
+const COMMON_SYMBOLS: [char; 28] = [
+    '€', '£', '¥', '§', '©', '®', '°', '«', '»', '¿', '¡', '·', '\u{00A0}', '‘', '’', '“', '”', '„', '‚', '–',
+    '—', '…', '•', '™', '‰', '‹', '›', '№',
+];
+
+/// Returns the encoding and the length of the BOM at the start of `input`.
+pub(crate) fn detect_bom(input: &[u8]) -> Option<(Encoding, usize)> {
+    if input.starts_with(&UTF8_BOM) {
+        Some((Encoding::Utf8, UTF8_BOM.len()))
+    } else if input.starts_with(&UTF16LE_BOM) {
+        Some((Encoding::Utf16Le, UTF16LE_BOM.len()))
+    } else if input.starts_with(&UTF16BE_BOM) {
+        Some((Encoding::Utf16Be, UTF16BE_BOM.len()))
+    } else {
+        None
+    }
+}
+
+/// Picks the most likely encoding of a sample.
+/// 1. BOM.
+/// 2. UTF-16 without BOM, by the position of the zero bytes.
+/// 3. Valid UTF-8 (pure ASCII included). A sequence cut by the end of the sample is accepted.
+/// 4. CJK double byte encodings (Shift_JIS, GBK, Big5), if the pairs are valid and in the common ranges.
+/// 5. Single byte encodings, scoring the decoded text: Cyrillic if the non-ASCII bytes form whole words,
+///    else Western and Central European.
+pub(crate) fn detect_encoding(sample: &[u8]) -> Encoding {
+    if let Some((encoding, _)) = detect_bom(sample) {
+        return encoding;
+    }
+    if let Some(encoding) = detect_utf16(sample) {
+        return encoding;
+    }
+    if is_utf8(sample) {
+        return Encoding::Utf8;
+    }
+    if let Some(encoding) = detect_cjk(sample) {
+        return encoding;
+    }
+    if is_cyrillic(sample) {
+        best_single_byte(sample, &[Encoding::Windows1251, Encoding::KOI8R], true)
+    } else {
+        best_single_byte(
+            sample,
+            &[Encoding::Windows1252, Encoding::ISO8859_15, Encoding::ISO8859_2],
+            false,
+        )
+    }
+}
+
+/// ASCII text in UTF-16 has a zero byte on each code unit.
+pub(crate) fn detect_utf16(sample: &[u8]) -> Option<Encoding> {
+    let units = sample.len() / 2;
+    if units < 2 {
+        return None;
+    }
+    let mut zero_first = 0usize;
+    let mut zero_second = 0usize;
+    for pair in sample.chunks_exact(2) {
+        zero_first += (pair[0] == 0) as usize;
+        zero_second += (pair[1] == 0) as usize;
+    }
+    let many = |zeros: usize| zeros * 10 >= units * 3;
+    let few = |zeros: usize| zeros * 10 < units;
+    if many(zero_second) && few(zero_first) {
+        Some(Encoding::Utf16Le)
+    } else if many(zero_first) && few(zero_second) {
+        Some(Encoding::Utf16Be)
+    } else {
+        None
+    }
+}
+
+fn is_utf8(sample: &[u8]) -> bool {
+    match std::str::from_utf8(sample) {
+        Ok(_) => true,
+        // `None` means an incomplete sequence at the end of the sample
+        Err(err) => err.error_len().is_none(),
+    }
+}
+
+/// Result of reading a sample as a double byte encoding.
+struct CjkScan {
+    encoding: Encoding,
+    pairs: usize,
+    common: usize,
+    errors: usize,
+}
+
+/// Structure of a double byte encoding.
+struct CjkRules {
+    encoding: Encoding,
+    lead: fn(u8) -> bool,
+    trail: fn(u8) -> bool,
+    single: fn(u8) -> bool,
+    /// Pairs in the ranges of the usual characters (kana, frequent hanzi / kanji).
+    common: fn(u8, u8) -> bool,
+}
+
+const CJK_RULES: [CjkRules; 3] = [
+    CjkRules {
+        encoding: Encoding::ShiftJIS,
+        lead: |b| matches!(b, 0x81..=0x9F | 0xE0..=0xFC),
+        trail: |b| matches!(b, 0x40..=0x7E | 0x80..=0xFC),
+        // Half width katakana
+        single: |b| matches!(b, 0xA1..=0xDF),
+        common: |lead, _| matches!(lead, 0x82 | 0x83 | 0x88..=0x9F | 0xE0..=0xEA),
+    },
+    CjkRules {
+        encoding: Encoding::GBK,
+        lead: |b| matches!(b, 0x81..=0xFE),
+        trail: |b| matches!(b, 0x40..=0x7E | 0x80..=0xFE),
+        single: |_| false,
+        common: |lead, trail| matches!(lead, 0xB0..=0xF7) && matches!(trail, 0xA1..=0xFE),
+    },
+    CjkRules {
+        encoding: Encoding::Big5,
+        lead: |b| matches!(b, 0x81..=0xFE),
+        trail: |b| matches!(b, 0x40..=0x7E | 0xA1..=0xFE),
+        single: |_| false,
+        common: |lead, _| matches!(lead, 0xA4..=0xC6),
+    },
+];
+
+fn scan_cjk(sample: &[u8], rules: &CjkRules) -> CjkScan {
+    let mut scan = CjkScan {
+        encoding: rules.encoding,
+        pairs: 0,
+        common: 0,
+        errors: 0,
+    };
+    let mut i = 0usize;
+    while i < sample.len() {
+        let b = sample[i];
+        if b < 0x80 || (rules.single)(b) {
+            i += 1;
+        } else if (rules.lead)(b) {
+            let trail = match sample.get(i + 1) {
+                Some(trail) => *trail,
+                // Pair cut by the end of the sample
+                None => break,
+            };
+            if (rules.trail)(trail) {
+                scan.pairs += 1;
+                scan.common += (rules.common)(b, trail) as usize;
+                i += 2;
+            } else {
+                scan.errors += 1;
+                i += 1;
+            }
+        } else {
+            scan.errors += 1;
+            i += 1;
+        }
+    }
+    scan
+}
+
+/// Picks the double byte encoding with less than 2% invalid sequences, and most pairs in the common ranges.
+fn detect_cjk(sample: &[u8]) -> Option<Encoding> {
+    CJK_RULES
+        .iter()
+        .map(|rules| scan_cjk(sample, rules))
+        .filter(|scan| scan.pairs > 0 && scan.errors * 50 <= scan.pairs && scan.common * 2 >= scan.pairs)
+        .fold(None, |best: Option<CjkScan>, scan| match best {
+            Some(best) if best.common * scan.pairs >= scan.common * best.pairs => Some(best),
+            _ => Some(scan),
+        })
+        .map(|scan| scan.encoding)
+}
+
+/// Cyrillic words are made only of non-ASCII bytes, while Western text mixes accents with ASCII letters.
+fn is_cyrillic(sample: &[u8]) -> bool {
+    let mut high_high = 0usize;
+    let mut high_ascii = 0usize;
+    for pair in sample.windows(2) {
+        let (a, b) = (pair[0], pair[1]);
+        if a >= 0x80 && b >= 0x80 {
+            high_high += 1;
+        } else if (a >= 0x80 && b.is_ascii_alphabetic()) || (b >= 0x80 && a.is_ascii_alphabetic()) {
+            high_ascii += 1;
+        }
+    }
+    high_high > high_ascii
+}
+
+/// Decodes the sample with each candidate, and keeps the one with more letters and usual symbols.
+/// On ties, the first candidate wins.
+fn best_single_byte(sample: &[u8], candidates: &[Encoding], prefer_lowercase: bool) -> Encoding {
+    let mut best = (candidates[0], i64::MIN);
+    for encoding in candidates {
+        let score: i64 = encoding
+            .decode(sample)
+            .chars()
+            .filter(|c| !c.is_ascii())
+            .map(|c| {
+                if prefer_lowercase && c.is_lowercase() {
+                    3
+                } else if c.is_alphabetic() || COMMON_SYMBOLS.contains(&c) {
+                    2
+                } else if c.is_control() || c == '\u{FFFD}' {
+                    -4
+                } else {
+                    0
+                }
+            })
+            .sum();
+        if score > best.1 {
+            best = (*encoding, score);
+        }
+    }
+    best.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_bom_and_utf16() {
+        assert_eq!(detect_bom(b"\xEF\xBB\xBFid;name"), Some((Encoding::Utf8, 3)));
+        assert_eq!(detect_bom(b"\xFF\xFEi\x00d\x00"), Some((Encoding::Utf16Le, 2)));
+        assert_eq!(detect_bom(b"id;name"), None);
+        assert_eq!(detect_encoding(b"i\x00d\x00\t\x00n\x00\n\x00"), Encoding::Utf16Le);
+        assert_eq!(detect_encoding(b"\x00i\x00d\x00\t\x00n\x00\n"), Encoding::Utf16Be);
+    }
+
+    #[test]
+    fn test_detect_utf8() {
+        assert_eq!(detect_encoding(b"id;name\n1;plain"), Encoding::Utf8);
+        assert_eq!(detect_encoding("id;nombre\n1;Peñarol".as_bytes()), Encoding::Utf8);
+        // `é` cut by the end of the sample
+        assert_eq!(detect_encoding(b"1;caf\xC3"), Encoding::Utf8);
+    }
+
+    #[test]
+    fn test_detect_cjk() {
+        // 日本語;ひらがな
+        let sjis = b"id;\x93\xFA\x96\x7B\x8C\xEA;\x82\xD0\x82\xE7\x82\xAA\x82\xC8\n";
+        assert_eq!(detect_encoding(sjis), Encoding::ShiftJIS);
+        // 中文;数据
+        let gbk = b"id;\xD6\xD0\xCE\xC4;\xCA\xFD\xBE\xDD\n";
+        assert_eq!(detect_encoding(gbk), Encoding::GBK);
+        // 中文;資料
+        let big5 = b"id;\xA4\xA4\xA4\xE5;\xB8\xEA\xAE\xC6\n";
+        assert_eq!(detect_encoding(big5), Encoding::Big5);
+    }
+
+    #[test]
+    fn test_detect_single_byte() {
+        // привет;мир
+        assert_eq!(detect_encoding(b"\xEF\xF0\xE8\xE2\xE5\xF2;\xEC\xE8\xF0\n"), Encoding::Windows1251);
+        assert_eq!(detect_encoding(b"\xD0\xD2\xC9\xD7\xC5\xD4;\xCD\xC9\xD2\n"), Encoding::KOI8R);
+        // Müller;café;“quoted”
+        assert_eq!(detect_encoding(b"M\xFCller;caf\xE9;\x93quoted\x94\n"), Encoding::Windows1252);
+        // Price: 5 €
+        assert_eq!(detect_encoding(b"Price;5 \xA4;caf\xE9\n"), Encoding::ISO8859_15);
+        // Łódź;Kraków
+        assert_eq!(detect_encoding(b"\xA3\xF3d\xBC;Krak\xF3w;\xB1\n"), Encoding::ISO8859_2);
+    }
+}
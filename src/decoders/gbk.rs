@@ -9,31 +9,92 @@ use std::arch::aarch64::*;
 #[cfg(target_arch = "aarch64")]
 use std::arch::is_aarch64_feature_detected;
 
-/// Scalar GBK decoder (basic version).
-/// ASCII passed through, double-byte sequences replaced with placeholder for now.
+use crate::decoders::gbk_table::{GB18030_RANGES, GBK_TABLE, GBK_TRAIL_COUNT};
 
+/// Number of four byte pointers mapped to the BMP.
+const GB18030_BMP_POINTERS: u32 = 39420;
+/// First four byte pointer of the supplementary planes (`90 30 81 30`, U+10000).
+const GB18030_SUPPLEMENTARY_START: u32 = 189000;
+const GB18030_SUPPLEMENTARY_END: u32 = GB18030_SUPPLEMENTARY_START + 0x10FFFF - 0x10000;
+
+/// Index of a two byte sequence inside `GBK_TABLE`, if the trail byte is valid.
+#[inline(always)]
+pub(crate) fn gbk_index(lead: u8, trail: u8) -> Option<usize> {
+    let offset = match trail {
+        0x40..=0x7E => trail - 0x40,
+        0x80..=0xFE => trail - 0x41,
+        _ => return None,
+    };
+    Some((lead - 0x81) as usize * GBK_TRAIL_COUNT + offset as usize)
+}
+
+/// Code point of a GB18030 four byte pointer.
+pub(crate) fn gb18030_pointer_to_char(pointer: u32) -> Option<char> {
+    if (GB18030_SUPPLEMENTARY_START..=GB18030_SUPPLEMENTARY_END).contains(&pointer) {
+        return char::from_u32(0x10000 + pointer - GB18030_SUPPLEMENTARY_START);
+    }
+    if pointer >= GB18030_BMP_POINTERS {
+        return None;
+    }
+    let range = GB18030_RANGES.partition_point(|(start, _)| *start <= pointer) - 1;
+    let (start, code_point) = GB18030_RANGES[range];
+    char::from_u32(code_point + pointer - start)
+}
+
+/// Scalar GBK / GB18030 decoder.
+/// - ASCII passed through, `0x80` is the euro sign (Windows code page 936).
+/// - Two byte sequences use the GBK table (user defined areas map to the Private Use Area).
+/// - GB18030 four byte sequences are decoded too, so `GB18030` text is read by the same decoder.
+/// - Invalid sequences are replaced with '�', and the next byte is read again.
 #[allow(dead_code)]
 pub(crate) fn decode_gbk_scalar(input: &[u8]) -> Cow<'_, str> {
-    let mut out = String::with_capacity(input.len());
+    if let Ok(ascii) = std::str::from_utf8(input) {
+        if ascii.is_ascii() {
+            return Cow::Borrowed(ascii);
+        }
+    }
+    let mut out = String::with_capacity(input.len() * 3 / 2);
     let mut i = 0;
     while i < input.len() {
         let b = input[i];
-        if b <= 0x7F {
-            // ASCII
-            out.push(b as char);
-            i += 1;
-        } else if b >= 0x81 && b <= 0xFE {
-            if i + 1 < input.len() {
-                // Start of a multibyte character
-                out.push('�'); // Placeholder
-                i += 2;
-            } else {
-                out.push('�');
+        match b {
+            0x00..=0x7F => {
+                out.push(b as char);
+                i += 1;
+            }
+            0x80 => {
+                out.push('\u{20AC}');
+                i += 1;
+            }
+            0x81..=0xFE => {
+                let second = input.get(i + 1).copied().unwrap_or(0);
+                if (0x30..=0x39).contains(&second) {
+                    // Four byte sequence: [81-FE] [30-39] [81-FE] [30-39]
+                    let third = input.get(i + 2).copied().unwrap_or(0);
+                    let fourth = input.get(i + 3).copied().unwrap_or(0);
+                    if (0x81..=0xFE).contains(&third) && (0x30..=0x39).contains(&fourth) {
+                        let pointer = (((b - 0x81) as u32 * 10 + (second - 0x30) as u32) * 126
+                            + (third - 0x81) as u32)
+                            * 10
+                            + (fourth - 0x30) as u32;
+                        out.push(gb18030_pointer_to_char(pointer).unwrap_or('\u{FFFD}'));
+                        i += 4;
+                    } else {
+                        out.push('\u{FFFD}');
+                        i += 1;
+                    }
+                } else if let Some(index) = gbk_index(b, second) {
+                    out.push(char::from_u32(GBK_TABLE[index] as u32).unwrap_or('\u{FFFD}'));
+                    i += 2;
+                } else {
+                    out.push('\u{FFFD}');
+                    i += 1;
+                }
+            }
+            _ => {
+                out.push('\u{FFFD}');
                 i += 1;
             }
-        } else {
-            out.push('�');
-            i += 1;
         }
     }
     Cow::Owned(out)
@@ -71,7 +132,7 @@ mod tests {
     use super::*;
 
     const VALID_ASCII: &[u8] = b"Hello, world!";
-    const EXTENDED_GBK: &[u8] = &[0x81, 0x40, 0x82, 0x45]; // Sample multibyte (would map to Chinese chars)
+    const EXTENDED_GBK: &[u8] = &[0x81, 0x40, 0xD6, 0xD0, 0xCE, 0xC4]; // 丂中文
 
     #[test]
     fn test_decode_gbk_scalar_ascii() {
@@ -82,7 +143,16 @@ mod tests {
     #[test]
     fn test_decode_gbk_scalar_multibyte() {
         let decoded = decode_gbk_scalar(EXTENDED_GBK);
-        assert_eq!(decoded.as_ref(), "��");
+        assert_eq!(decoded.as_ref(), "丂中文");
+    }
+
+    #[test]
+    fn test_decode_gb18030_four_bytes() {
+        // U+0080, U+FFFF and U+10000, with the euro sign
+        let decoded = decode_gbk_scalar(&[0x81, 0x30, 0x81, 0x30, 0x84, 0x31, 0xA4, 0x39, 0x90, 0x30, 0x81, 0x30, 0x80]);
+        assert_eq!(decoded.as_ref(), "\u{80}\u{FFFF}\u{10000}€");
+        // Truncated pair and invalid trail
+        assert_eq!(decode_gbk_scalar(&[b'a', 0xD6, b';', 0xD6]).as_ref(), "a�;�");
     }
 
     #[test]
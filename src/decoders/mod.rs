@@ -9,3 +9,8 @@ pub(crate) mod koi8r;
 pub(crate) mod shiftjis;
 pub(crate) mod gbk;
 pub(crate) mod big5;
+pub(crate) mod gbk_table;
+pub(crate) mod shiftjis_table;
+pub(crate) mod big5_table;
+pub(crate) mod utf16;
+pub(crate) mod detect;
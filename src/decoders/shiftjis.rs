@@ -9,39 +9,66 @@ use std::arch::aarch64::*;
 #[cfg(target_arch = "aarch64")]
 use std::arch::is_aarch64_feature_detected;
 
-/// Scalar Shift_JIS decoder (basic version).
-/// Handles ASCII and Katakana, marks others as invalid for now.
+use crate::decoders::shiftjis_table::{SHIFTJIS_TABLE, SHIFTJIS_TRAIL_COUNT};
+
+/// Index of a two byte sequence inside `SHIFTJIS_TABLE`, if the lead and trail bytes are valid.
+#[inline(always)]
+pub(crate) fn shiftjis_index(lead: u8, trail: u8) -> Option<usize> {
+    let lead = match lead {
+        0x81..=0x9F => lead - 0x81,
+        0xE0..=0xFC => lead - 0xC1,
+        _ => return None,
+    };
+    let trail = match trail {
+        0x40..=0x7E => trail - 0x40,
+        0x80..=0xFC => trail - 0x41,
+        _ => return None,
+    };
+    Some(lead as usize * SHIFTJIS_TRAIL_COUNT + trail as usize)
+}
+
+/// Scalar Shift_JIS (Windows-31J) decoder.
+/// - ASCII and half width katakana (0xA1..0xDF) are single bytes.
+/// - Two byte sequences use the code page 932 table: JIS X 0208, NEC / IBM extensions and user defined area.
+/// - Invalid sequences are replaced with '�'. An ASCII byte after a lead byte is read again.
 #[allow(dead_code)]
 pub(crate) fn decode_shiftjis_scalar(input: &[u8]) -> Cow<'_, str> {
-    let mut out = String::with_capacity(input.len());
+    if let Ok(ascii) = std::str::from_utf8(input) {
+        if ascii.is_ascii() {
+            return Cow::Borrowed(ascii);
+        }
+    }
+    let mut out = String::with_capacity(input.len() * 3 / 2);
     let mut i = 0;
     while i < input.len() {
         let b = input[i];
-        if b <= 0x7F {
-            out.push(b as char);
-            i += 1;
-        } else if b >= 0xA1 && b <= 0xDF {
-            // Half-width Katakana range
-            let unicode = 0xFF61 + (b as u32 - 0xA1);
-            if let Some(ch) = std::char::from_u32(unicode) {
-                out.push(ch);
-            } else {
-                out.push('\u{FFFD}'); // Replacement character
+        match b {
+            0x00..=0x80 => {
+                out.push(b as char);
+                i += 1;
             }
-            i += 1;
-        } else if (b >= 0x81 && b <= 0x9F) || (b >= 0xE0 && b <= 0xEF) {
-            // Start of a double-byte sequence (kanji, kana, etc.)
-            if i + 1 < input.len() {
-                // For now: placeholder for complex multi-byte
-                out.push('�');
-                i += 2;
-            } else {
-                out.push('\u{FFFD}');
+            0xA1..=0xDF => {
+                // Half-width Katakana range
+                out.push(char::from_u32(0xFF61 + (b as u32 - 0xA1)).unwrap_or('\u{FFFD}'));
                 i += 1;
             }
-        } else {
-            out.push('\u{FFFD}');
-            i += 1;
+            _ => {
+                let second = input.get(i + 1).copied().unwrap_or(0);
+                match shiftjis_index(b, second) {
+                    Some(index) if SHIFTJIS_TABLE[index] != 0 => {
+                        out.push(char::from_u32(SHIFTJIS_TABLE[index] as u32).unwrap_or('\u{FFFD}'));
+                        i += 2;
+                    }
+                    Some(_) => {
+                        out.push('\u{FFFD}');
+                        i += if second < 0x80 { 1 } else { 2 };
+                    }
+                    None => {
+                        out.push('\u{FFFD}');
+                        i += 1;
+                    }
+                }
+            }
         }
     }
     Cow::Owned(out)
@@ -93,6 +120,15 @@ mod tests {
         assert_eq!(decoded.as_ref(), "\u{FF66}\u{FF67}\u{FF68}"); // Katakana Half-width Wo, A, I
     }
 
+    #[test]
+    fn test_decode_shiftjis_scalar_doublebyte() {
+        // 日本語 ひらがな, NEC ① and IBM 髙
+        let decoded = decode_shiftjis_scalar(&[0x93, 0xFA, 0x96, 0x7B, 0x8C, 0xEA, 0x20, 0x82, 0xD0, 0x82, 0xE7, 0x82, 0xAA, 0x82, 0xC8, 0x87, 0x40, 0xFB, 0xFC]);
+        assert_eq!(decoded.as_ref(), "日本語 ひらがな①髙");
+        // Unmapped pair and lead byte followed by ASCII: the ASCII byte is kept
+        assert_eq!(decode_shiftjis_scalar(&[0x85, 0x40, 0x93, b';']).as_ref(), "�@�;");
+    }
+
     #[test]
     fn test_decode_shiftjis_avx2_or_neon_ascii() {
         let decoded = {
@@ -0,0 +1,49 @@
+use std::borrow::Cow;
+
+/// Scalar UTF-16 decoder.
+/// Pairs of bytes are read as code units, with the given byte order.
+/// Unpaired surrogates, and an odd byte at the end, are replaced with '�'.
+#[allow(dead_code)]
+pub(crate) fn decode_utf16_scalar(input: &[u8], big_endian: bool) -> Cow<'_, str> {
+    let pairs = input.chunks_exact(2);
+    let odd = !pairs.remainder().is_empty();
+    let units = pairs.map(|pair| {
+        if big_endian {
+            u16::from_be_bytes([pair[0], pair[1]])
+        } else {
+            u16::from_le_bytes([pair[0], pair[1]])
+        }
+    });
+    let mut out: String = char::decode_utf16(units)
+        .map(|c| c.unwrap_or('\u{FFFD}'))
+        .collect();
+    if odd {
+        out.push('\u{FFFD}');
+    }
+    Cow::Owned(out)
+}
+
+/// Public UTF-16LE decoder.
+#[allow(dead_code)]
+pub fn decode_utf16le(input: &[u8]) -> Cow<'_, str> {
+    decode_utf16_scalar(input, false)
+}
+
+/// Public UTF-16BE decoder.
+#[allow(dead_code)]
+pub fn decode_utf16be(input: &[u8]) -> Cow<'_, str> {
+    decode_utf16_scalar(input, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_utf16() {
+        assert_eq!(decode_utf16le(&[0x48, 0x00, 0xE9, 0x00, 0x3D, 0xD8, 0x00, 0xDE]).as_ref(), "Hé😀");
+        assert_eq!(decode_utf16be(&[0x00, 0x48, 0x00, 0xE9]).as_ref(), "Hé");
+        // Unpaired surrogate and odd byte
+        assert_eq!(decode_utf16le(&[0x3D, 0xD8, 0x41, 0x00, 0x42]).as_ref(), "�A�");
+    }
+}
@@ -1,12 +1,54 @@
+use crate::decoders::big5_table::{BIG5_COMBINING, BIG5_TABLE, BIG5_TRAIL_COUNT};
+use std::sync::OnceLock;
+
+/// Reverse of `BIG5_TABLE`: `(code point, two bytes)`, sorted by code point. Built on first use.
+/// For the few duplicated characters, the last sequence wins (the Big5 one over the Eten / HKSCS one).
+static BIG5_ENCODE_TABLE: OnceLock<Vec<(u32, [u8; 2])>> = OnceLock::new();
+
+fn encode_table() -> &'static [(u32, [u8; 2])] {
+    BIG5_ENCODE_TABLE.get_or_init(|| {
+        let mut table: Vec<(u32, [u8; 2])> = BIG5_TABLE
+            .iter()
+            .enumerate()
+            .rev()
+            .filter(|(_, code_point)| **code_point != 0)
+            .map(|(index, code_point)| {
+                let lead = (index / BIG5_TRAIL_COUNT) as u8 + 0x87;
+                let offset = (index % BIG5_TRAIL_COUNT) as u8;
+                let trail = if offset < 0x3F { offset + 0x40 } else { offset + 0x62 };
+                (*code_point, [lead, trail])
+            })
+            .collect();
+        // Stable over the reversed table, so the last sequence of a code point wins
+        table.sort_by_key(|(code_point, _)| *code_point);
+        table.dedup_by_key(|(code_point, _)| *code_point);
+        table
+    })
+}
 
 pub(crate) fn encode_big5_scalar(input: &str) -> Vec<u8> {
-    input.chars().map(|c| {
+    let table = encode_table();
+    let mut out = Vec::with_capacity(input.len());
+    let mut chars = input.chars().peekable();
+    while let Some(c) = chars.next() {
         if c.is_ascii() {
-            c as u8
-        } else {
-            b'?'
+            out.push(c as u8);
+            continue;
+        }
+        // A letter with a combining mark may have its own sequence
+        if let Some(next) = chars.peek() {
+            if let Some((bytes, _)) = BIG5_COMBINING.iter().find(|(_, pair)| *pair == [c, *next]) {
+                out.extend_from_slice(bytes);
+                chars.next();
+                continue;
+            }
+        }
+        match table.binary_search_by_key(&(c as u32), |(key, _)| *key) {
+            Ok(found) => out.extend_from_slice(&table[found].1),
+            Err(_) => out.push(b'?'),
         }
-    }).collect()
+    }
+    out
 }
 
 pub fn encode_big5(input: &str) -> Vec<u8> {
@@ -16,11 +58,34 @@ pub fn encode_big5(input: &str) -> Vec<u8> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::decoders::big5::decode_big5;
 
     #[test]
     fn test_encode_big5() {
         let input = "Hello!";
         let encoded = encode_big5(input);
         assert_eq!(encoded, b"Hello!");
+        assert_eq!(encode_big5("中文;資料"), b"\xA4\xA4\xA4\xE5;\xB8\xEA\xAE\xC6");
+        // HKSCS, outside the BMP and combining sequences
+        assert_eq!(encode_big5("\u{43F0}\u{27267}\u{CA}\u{304}\u{CA}"), b"\x87\x40\x87\x45\x88\x62\x88\x66");
+        // Duplicates use the Big5 sequence
+        assert_eq!(encode_big5("卅"), b"\xA4\xCA");
+        assert_eq!(encode_big5("😀"), b"?");
+    }
+
+    #[test]
+    fn test_big5_round_trip() {
+        let mut mapped = 0;
+        for lead in 0x87u8..=0xFE {
+            for trail in (0x40u8..=0x7E).chain(0xA1..=0xFE) {
+                let decoded = decode_big5(&[lead, trail]).into_owned();
+                if decoded.contains('\u{FFFD}') {
+                    continue;
+                }
+                mapped += 1;
+                assert_eq!(decode_big5(&encode_big5(&decoded)), decoded, "{:02X}{:02X}", lead, trail);
+            }
+        }
+        assert_eq!(mapped, 18402);
     }
 }
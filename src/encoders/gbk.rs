@@ -1,26 +1,131 @@
+use crate::decoders::gbk_table::{GB18030_RANGES, GBK_TABLE, GBK_TRAIL_COUNT};
+use std::convert::TryFrom;
+use std::sync::OnceLock;
+
+/// Reverse of `GBK_TABLE`: `(code point, two bytes)`, sorted by code point. Built on first use.
+static GBK_ENCODE_TABLE: OnceLock<Vec<(u16, [u8; 2])>> = OnceLock::new();
+
+fn encode_table() -> &'static [(u16, [u8; 2])] {
+    GBK_ENCODE_TABLE.get_or_init(|| {
+        let mut table: Vec<(u16, [u8; 2])> = GBK_TABLE
+            .iter()
+            .enumerate()
+            .map(|(index, code_point)| {
+                let lead = (index / GBK_TRAIL_COUNT) as u8 + 0x81;
+                let offset = (index % GBK_TRAIL_COUNT) as u8;
+                let trail = if offset < 0x3F { offset + 0x40 } else { offset + 0x41 };
+                (*code_point, [lead, trail])
+            })
+            .collect();
+        // Stable, so the first sequence of a code point wins
+        table.sort_by_key(|(code_point, _)| *code_point);
+        table.dedup_by_key(|(code_point, _)| *code_point);
+        table
+    })
+}
+
+/// Two byte sequence of a character, if it is in the GBK table.
+fn encode_two_bytes(c: char) -> Option<[u8; 2]> {
+    let code_point = u16::try_from(c as u32).ok()?;
+    let table = encode_table();
+    table
+        .binary_search_by_key(&code_point, |(cp, _)| *cp)
+        .ok()
+        .map(|found| table[found].1)
+}
+
+/// GB18030 four byte sequence of a character not in the GBK table.
+fn encode_four_bytes(c: char) -> [u8; 4] {
+    let code_point = c as u32;
+    let pointer = if code_point >= 0x10000 {
+        189000 + code_point - 0x10000
+    } else {
+        let range = GB18030_RANGES.partition_point(|(_, first)| *first <= code_point) - 1;
+        let (start, first) = GB18030_RANGES[range];
+        start + code_point - first
+    };
+    [
+        (pointer / 12600) as u8 + 0x81,
+        (pointer / 1260 % 10) as u8 + 0x30,
+        (pointer / 10 % 126) as u8 + 0x81,
+        (pointer % 10) as u8 + 0x30,
+    ]
+}
 
 pub(crate) fn encode_gbk_scalar(input: &str) -> Vec<u8> {
-    input.chars().map(|c| {
+    let mut out = Vec::with_capacity(input.len());
+    for c in input.chars() {
         if c.is_ascii() {
-            c as u8
+            out.push(c as u8);
+        } else if let Some(bytes) = encode_two_bytes(c) {
+            out.extend_from_slice(&bytes);
         } else {
-            b'?'
+            out.push(b'?');
         }
-    }).collect()
+    }
+    out
 }
 
+pub(crate) fn encode_gb18030_scalar(input: &str) -> Vec<u8> {
+    let mut out = Vec::with_capacity(input.len());
+    for c in input.chars() {
+        if c.is_ascii() {
+            out.push(c as u8);
+        } else if let Some(bytes) = encode_two_bytes(c) {
+            out.extend_from_slice(&bytes);
+        } else {
+            out.extend_from_slice(&encode_four_bytes(c));
+        }
+    }
+    out
+}
+
+/// GBK encoder. Characters not in the GBK table are replaced with `?`.
 pub fn encode_gbk(input: &str) -> Vec<u8> {
     encode_gbk_scalar(input)
 }
 
+/// GB18030 encoder. Every character is encoded, the ones not in the GBK table with four bytes.
+pub fn encode_gb18030(input: &str) -> Vec<u8> {
+    encode_gb18030_scalar(input)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::decoders::gbk::decode_gbk;
 
     #[test]
     fn test_encode_gbk() {
         let input = "World!";
         let encoded = encode_gbk(input);
         assert_eq!(encoded, b"World!");
+        assert_eq!(encode_gbk("中文;数据"), b"\xD6\xD0\xCE\xC4;\xCA\xFD\xBE\xDD");
+        // Not in GBK
+        assert_eq!(encode_gbk("a😀"), b"a?");
+    }
+
+    #[test]
+    fn test_gbk_round_trip() {
+        // Every two byte sequence decodes, and encodes back to itself
+        for lead in 0x81u8..=0xFE {
+            for trail in (0x40u8..=0x7E).chain(0x80..=0xFE) {
+                let bytes = [lead, trail];
+                let decoded = decode_gbk(&bytes);
+                assert_eq!(encode_gbk(&decoded), bytes, "{:02X}{:02X}", lead, trail);
+            }
+        }
+    }
+
+    #[test]
+    fn test_gb18030_round_trip() {
+        let text: String = (0x80u32..0x10000)
+            .step_by(7)
+            .chain((0x10000..0x110000).step_by(4099))
+            .filter_map(char::from_u32)
+            .collect();
+        let encoded = encode_gb18030(&text);
+        assert_eq!(decode_gbk(&encoded), text);
+        assert_eq!(encode_gb18030("€😀"), b"\xA2\xE3\x94\x39\xFC\x36");
     }
 }
@@ -7,4 +7,5 @@ pub(crate) mod latin9;
 pub(crate) mod koi8r;
 pub(crate) mod shiftjis;
 pub(crate) mod gbk;
-pub(crate) mod big5;
\ No newline at end of file
+pub(crate) mod big5;
+pub(crate) mod utf16;
\ No newline at end of file
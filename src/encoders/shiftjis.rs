@@ -1,12 +1,53 @@
+use crate::decoders::shiftjis_table::{SHIFTJIS_TABLE, SHIFTJIS_TRAIL_COUNT};
+use std::convert::TryFrom;
+use std::sync::OnceLock;
+
+/// Reverse of `SHIFTJIS_TABLE`: `(code point, two bytes)`, sorted by code point. Built on first use.
+/// For characters with NEC and IBM duplicates, the first sequence wins (same as Windows).
+static SHIFTJIS_ENCODE_TABLE: OnceLock<Vec<(u16, [u8; 2])>> = OnceLock::new();
+
+fn encode_table() -> &'static [(u16, [u8; 2])] {
+    SHIFTJIS_ENCODE_TABLE.get_or_init(|| {
+        let mut table: Vec<(u16, [u8; 2])> = SHIFTJIS_TABLE
+            .iter()
+            .enumerate()
+            .filter(|(_, code_point)| **code_point != 0)
+            .map(|(index, code_point)| {
+                let lead = (index / SHIFTJIS_TRAIL_COUNT) as u8;
+                let lead = if lead < 0x1F { lead + 0x81 } else { lead + 0xC1 };
+                let offset = (index % SHIFTJIS_TRAIL_COUNT) as u8;
+                let trail = if offset < 0x3F { offset + 0x40 } else { offset + 0x41 };
+                (*code_point, [lead, trail])
+            })
+            .collect();
+        // Stable, so the first sequence of a code point wins
+        table.sort_by_key(|(code_point, _)| *code_point);
+        table.dedup_by_key(|(code_point, _)| *code_point);
+        table
+    })
+}
 
 pub(crate) fn encode_shiftjis_scalar(input: &str) -> Vec<u8> {
-    input.chars().map(|c| {
-        if c.is_ascii() {
-            c as u8
+    let table = encode_table();
+    let mut out = Vec::with_capacity(input.len());
+    for c in input.chars() {
+        let code_point = c as u32;
+        if code_point <= 0x80 {
+            out.push(code_point as u8);
+        } else if (0xFF61..=0xFF9F).contains(&code_point) {
+            // Half-width Katakana
+            out.push((code_point - 0xFF61) as u8 + 0xA1);
         } else {
-            b'?'
+            let found = u16::try_from(code_point)
+                .ok()
+                .and_then(|cp| table.binary_search_by_key(&cp, |(key, _)| *key).ok());
+            match found {
+                Some(found) => out.extend_from_slice(&table[found].1),
+                None => out.push(b'?'),
+            }
         }
-    }).collect()
+    }
+    out
 }
 
 pub fn encode_shiftjis(input: &str) -> Vec<u8> {
@@ -16,11 +57,33 @@ pub fn encode_shiftjis(input: &str) -> Vec<u8> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::decoders::shiftjis::decode_shiftjis;
 
     #[test]
     fn test_encode_shiftjis() {
         let input = "Shift";
         let encoded = encode_shiftjis(input);
         assert_eq!(encoded, b"Shift");
+        assert_eq!(encode_shiftjis("日本語;ｱ"), b"\x93\xFA\x96\x7B\x8C\xEA;\xB1");
+        // NEC row 13 wins over the IBM duplicate
+        assert_eq!(encode_shiftjis("∵"), b"\x81\xE6");
+        assert_eq!(encode_shiftjis("😀"), b"?");
+    }
+
+    #[test]
+    fn test_shiftjis_round_trip() {
+        let mut mapped = 0;
+        for lead in (0x81u8..=0x9F).chain(0xE0..=0xFC) {
+            for trail in (0x40u8..=0x7E).chain(0x80..=0xFC) {
+                let decoded = decode_shiftjis(&[lead, trail]).into_owned();
+                if decoded.contains('\u{FFFD}') {
+                    continue;
+                }
+                mapped += 1;
+                // Duplicates encode to their first sequence, which decodes to the same text
+                assert_eq!(decode_shiftjis(&encode_shiftjis(&decoded)), decoded, "{:02X}{:02X}", lead, trail);
+            }
+        }
+        assert_eq!(mapped, 9604);
     }
 }
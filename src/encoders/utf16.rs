@@ -0,0 +1,30 @@
+pub(crate) fn encode_utf16_scalar(input: &str, big_endian: bool) -> Vec<u8> {
+    let mut out = Vec::with_capacity(input.len() * 2);
+    for unit in input.encode_utf16() {
+        if big_endian {
+            out.extend_from_slice(&unit.to_be_bytes());
+        } else {
+            out.extend_from_slice(&unit.to_le_bytes());
+        }
+    }
+    out
+}
+
+pub fn encode_utf16le(input: &str) -> Vec<u8> {
+    encode_utf16_scalar(input, false)
+}
+
+pub fn encode_utf16be(input: &str) -> Vec<u8> {
+    encode_utf16_scalar(input, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_utf16() {
+        assert_eq!(encode_utf16le("Hé"), vec![0x48, 0x00, 0xE9, 0x00]);
+        assert_eq!(encode_utf16be("😀"), vec![0xD8, 0x3D, 0xDE, 0x00]);
+    }
+}
@@ -0,0 +1,482 @@
+use crate::decoders::decoders::Encoding;
+use crate::models::csv_error::{CsvError, CsvErrorKind};
+use crate::models::field::Field;
+use crate::models::header::Header;
+use crate::models::row::Row;
+use serde::de::value::{BorrowedStrDeserializer, StrDeserializer, StringDeserializer};
+use serde::de::{DeserializeSeed, Deserializer, Error, MapAccess, SeqAccess, Unexpected, Visitor};
+use serde::{forward_to_deserialize_any, Deserialize};
+use std::borrow::Cow;
+use std::fmt::Display;
+
+/// ## serde Error implementation
+/// - Messages built by serde (missing fields, invalid types, ...) are `CsvErrorKind::Decode` errors.
+/// - Errors raised on a field are reported as `CsvErrorKind::Parse`, with the record number, field index, byte offset
+///   and record snippet.
+impl Error for CsvError {
+    fn custom<T: Display>(msg: T) -> Self {
+        CsvError::new(CsvErrorKind::Decode, msg.to_string())
+    }
+}
+
+impl<'mmap> Row<'mmap> {
+    /// ## Deserialize
+    /// - Builds a `T` from the fields of the row. Needs the `serde` feature.
+    /// - Structs are filled by header name if the row has a header read from the file, and by position otherwise.
+    ///   Columns without a matching struct field are ignored.
+    /// - Tuples, tuple structs and sequences are always filled by position.
+    /// - `Option` fields are `None` when the field is empty, or when the column is missing.
+    /// - `&str` and `&[u8]` fields borrow from the file when the bytes do not need changes:
+    ///   no escaped quotes, and `Utf8` encoding or pure ASCII text. Use `String` or `Cow<str>` for any other case.
+    /// - Rows of `RowParallel` work the same way, through `as_row`.
+    /// ### Code Example:
+    /// ```
+    /// use csv_lib::decoders::decoders::Encoding;
+    /// use csv_lib::models::header::Header;
+    /// use csv_lib::models::row::Row;
+    /// use serde::Deserialize;
+    /// use std::sync::Arc;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct City<'a> {
+    ///     name: &'a str,
+    ///     population: u32,
+    ///     country: Option<String>,
+    /// }
+    ///
+    /// let header = Arc::new(Header::from_names(vec!["population", "name", "country"]));
+    /// let row = Row::new(b"1300000;Montevideo;", b';', b'"', false).with_header(Some(header));
+    /// let city: City = row.deserialize(Encoding::Utf8).unwrap();
+    /// assert_eq!((city.name, city.population, city.country), ("Montevideo", 1300000, None));
+    /// ```
+    pub fn deserialize<T: Deserialize<'mmap>>(&self, encoding: Encoding) -> Result<T, CsvError> {
+        let by_name = self.get_header().is_some_and(|header| !header.is_generated());
+        T::deserialize(RowDeserializer {
+            row: self,
+            encoding,
+            by_name,
+        })
+        .map_err(|e| locate_row(self, e))
+    }
+
+    /// ## Deserialize By Position
+    /// - Same as `deserialize`, but structs are always filled by position, ignoring the header.
+    pub fn deserialize_by_position<T: Deserialize<'mmap>>(&self, encoding: Encoding) -> Result<T, CsvError> {
+        T::deserialize(RowDeserializer {
+            row: self,
+            encoding,
+            by_name: false,
+        })
+        .map_err(|e| locate_row(self, e))
+    }
+}
+
+/// Adds the position of the row to the errors raised outside a field (missing fields, no header...).
+fn locate_row(row: &Row<'_>, error: CsvError) -> CsvError {
+    if error.byte_offset().is_some() {
+        return error;
+    }
+    let error = error.with_byte_offset(row.get_byte_range().start).with_snippet(row.get_slice());
+    match row.get_record_number() {
+        0 => error,
+        record => error.with_record(record),
+    }
+}
+
+/// Deserializer over the fields of a row.
+struct RowDeserializer<'r, 'mmap> {
+    row: &'r Row<'mmap>,
+    encoding: Encoding,
+    by_name: bool,
+}
+
+impl<'r, 'mmap> RowDeserializer<'r, 'mmap> {
+    fn fields(&self) -> RowFields<'r, 'mmap> {
+        RowFields {
+            row: self.row,
+            encoding: self.encoding,
+            index: 0,
+            count: self.row.field_count(),
+        }
+    }
+
+    fn columns(&self) -> Result<RowColumns<'r, 'mmap>, CsvError> {
+        match self.row.get_header() {
+            Some(header) => Ok(RowColumns {
+                row: self.row,
+                encoding: self.encoding,
+                header,
+                index: 0,
+            }),
+            None => Err(CsvError::new(CsvErrorKind::Decode, "the row has no header to map the columns")),
+        }
+    }
+}
+
+impl<'de, 'r> Deserializer<'de> for RowDeserializer<'r, 'de> {
+    type Error = CsvError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, CsvError> {
+        if self.by_name {
+            visitor.visit_map(self.columns()?)
+        } else {
+            visitor.visit_seq(self.fields())
+        }
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, CsvError> {
+        self.deserialize_any(visitor)
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, CsvError> {
+        visitor.visit_map(self.columns()?)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, CsvError> {
+        visitor.visit_seq(self.fields())
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value, CsvError> {
+        visitor.visit_seq(self.fields())
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, CsvError> {
+        visitor.visit_seq(self.fields())
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(self, _name: &'static str, visitor: V) -> Result<V::Value, CsvError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct enum identifier ignored_any
+    }
+}
+
+/// Fields of a row, by position.
+struct RowFields<'r, 'mmap> {
+    row: &'r Row<'mmap>,
+    encoding: Encoding,
+    index: usize,
+    count: usize,
+}
+
+impl<'de, 'r> SeqAccess<'de> for RowFields<'r, 'de> {
+    type Error = CsvError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>, CsvError> {
+        if self.index >= self.count {
+            return Ok(None);
+        }
+        let field = self.row.get_index(self.index);
+        self.index += 1;
+        deserialize_field(seed, field, self.encoding).map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.count - self.index)
+    }
+}
+
+/// Fields of a row, keyed by the header names.
+struct RowColumns<'r, 'mmap> {
+    row: &'r Row<'mmap>,
+    encoding: Encoding,
+    header: &'r Header,
+    index: usize,
+}
+
+impl<'de, 'r> MapAccess<'de> for RowColumns<'r, 'de> {
+    type Error = CsvError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>, CsvError> {
+        match self.header.name(self.index) {
+            Some(name) => seed.deserialize(StrDeserializer::new(name)).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, CsvError> {
+        let field = self.row.get_index(self.index);
+        self.index += 1;
+        deserialize_field(seed, field, self.encoding)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.header.len() - self.index)
+    }
+}
+
+/// Deserializes a field, adding its position to the serde messages.
+fn deserialize_field<'de, T: DeserializeSeed<'de>>(seed: T, field: Field<'de>, encoding: Encoding) -> Result<T::Value, CsvError> {
+    seed.deserialize(FieldDeserializer { field: field.clone(), encoding }).map_err(|e| {
+        if e.kind() != CsvErrorKind::Decode {
+            return e;
+        }
+        let value = String::from_utf8_lossy(field.get_cleaned_slice());
+        field.locate(CsvError::new(CsvErrorKind::Parse, format!("{:?}: {}", value, e.message())))
+    })
+}
+
+/// Deserializer over a single field.
+struct FieldDeserializer<'mmap> {
+    field: Field<'mmap>,
+    encoding: Encoding,
+}
+
+impl<'mmap> FieldDeserializer<'mmap> {
+    /// Text of the field, borrowed when it is valid UTF-8 as it is in the file.
+    fn text(&self) -> Result<Cow<'mmap, str>, CsvError> {
+        let ascii_compatible = !self.encoding.is_utf16();
+        match self.field.get_unescaped_slice() {
+            Cow::Borrowed(bytes) if self.encoding == Encoding::Utf8 || (ascii_compatible && bytes.is_ascii()) => {
+                std::str::from_utf8(bytes)
+                    .map(Cow::Borrowed)
+                    .map_err(|e| CsvError::new(CsvErrorKind::Decode, e.to_string()))
+            }
+            Cow::Owned(bytes) if self.encoding == Encoding::Utf8 => {
+                String::from_utf8(bytes).map(Cow::Owned).map_err(|e| CsvError::new(CsvErrorKind::Decode, e.to_string()))
+            }
+            bytes => Ok(Cow::Owned(self.encoding.decode(&bytes).into_owned())),
+        }
+    }
+
+    fn visit_text<V: Visitor<'mmap>>(&self, visitor: V) -> Result<V::Value, CsvError> {
+        match self.text()? {
+            Cow::Borrowed(text) => visitor.visit_borrowed_str(text),
+            Cow::Owned(text) => visitor.visit_string(text),
+        }
+    }
+}
+
+impl<'de> Deserializer<'de> for FieldDeserializer<'de> {
+    type Error = CsvError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, CsvError> {
+        self.visit_text(visitor)
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, CsvError> {
+        visitor.visit_bool(self.field.try_get_boolean()?)
+    }
+
+    fn deserialize_i8<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, CsvError> {
+        visitor.visit_i8(self.field.try_get_i8()?)
+    }
+
+    fn deserialize_i16<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, CsvError> {
+        visitor.visit_i16(self.field.try_get_i16()?)
+    }
+
+    fn deserialize_i32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, CsvError> {
+        visitor.visit_i32(self.field.try_get_i32()?)
+    }
+
+    fn deserialize_i64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, CsvError> {
+        visitor.visit_i64(self.field.try_get_i64()?)
+    }
+
+    fn deserialize_u8<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, CsvError> {
+        visitor.visit_u8(self.field.try_get_u8()?)
+    }
+
+    fn deserialize_u16<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, CsvError> {
+        visitor.visit_u16(self.field.try_get_u16()?)
+    }
+
+    fn deserialize_u32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, CsvError> {
+        visitor.visit_u32(self.field.try_get_u32()?)
+    }
+
+    fn deserialize_u64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, CsvError> {
+        visitor.visit_u64(self.field.try_get_u64()?)
+    }
+
+    fn deserialize_f32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, CsvError> {
+        visitor.visit_f32(self.field.try_get_f32()?)
+    }
+
+    fn deserialize_f64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, CsvError> {
+        visitor.visit_f64(self.field.try_get_f64()?)
+    }
+
+    fn deserialize_char<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, CsvError> {
+        let text = self.text()?;
+        let mut chars = text.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => visitor.visit_char(c),
+            _ => Err(CsvError::invalid_value(Unexpected::Str(&text), &"a single character")),
+        }
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, CsvError> {
+        self.visit_text(visitor)
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, CsvError> {
+        self.visit_text(visitor)
+    }
+
+    fn deserialize_bytes<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, CsvError> {
+        match self.field.get_unescaped_slice() {
+            Cow::Borrowed(bytes) => visitor.visit_borrowed_bytes(bytes),
+            Cow::Owned(bytes) => visitor.visit_byte_buf(bytes),
+        }
+    }
+
+    fn deserialize_byte_buf<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, CsvError> {
+        self.deserialize_bytes(visitor)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, CsvError> {
+        if self.field.is_empty() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, CsvError> {
+        if self.field.is_empty() {
+            visitor.visit_unit()
+        } else {
+            self.visit_text(visitor)
+        }
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(self, _name: &'static str, visitor: V) -> Result<V::Value, CsvError> {
+        self.deserialize_unit(visitor)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(self, _name: &'static str, visitor: V) -> Result<V::Value, CsvError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    /// Unit variants, by name.
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, CsvError> {
+        match self.text()? {
+            Cow::Borrowed(text) => visitor.visit_enum(BorrowedStrDeserializer::new(text)),
+            Cow::Owned(text) => visitor.visit_enum(StringDeserializer::new(text)),
+        }
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, CsvError> {
+        visitor.visit_unit()
+    }
+
+    forward_to_deserialize_any! {
+        i128 u128 seq tuple tuple_struct map struct identifier
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::decoders::decoders::Encoding;
+    use crate::models::csv_error::CsvErrorKind;
+    use crate::models::header::Header;
+    use crate::models::row::Row;
+    use serde::Deserialize;
+    use std::borrow::Cow;
+    use std::sync::Arc;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    enum Status {
+        Active,
+        Closed,
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct Account<'a> {
+        id: u32,
+        owner: &'a str,
+        #[serde(borrow)]
+        note: Cow<'a, str>,
+        raw: &'a [u8],
+        balance: Option<f64>,
+        status: Status,
+        verified: bool,
+    }
+
+    fn header(names: &str) -> Option<Arc<Header>> {
+        Some(Arc::new(Header::from_names(names.split(';').collect())))
+    }
+
+    #[test]
+    fn test_deserialize_by_name() {
+        let data = b"Active;7;ana;\"say \"\"hi\"\"\";;ab;extra;true";
+        let row = Row::new(data, b';', b'"', false)
+            .with_header(header("status;id;owner;note;balance;raw;unused;verified"));
+        let account: Account = row.deserialize(Encoding::Utf8).unwrap();
+        assert_eq!(account.id, 7);
+        assert_eq!(account.owner, "ana");
+        assert_eq!(account.note, "say \"hi\"");
+        // Escaped quotes need a copy
+        assert!(matches!(account.note, Cow::Owned(_)));
+        assert_eq!(account.raw, b"ab");
+        assert_eq!(account.balance, None);
+        assert_eq!(account.status, Status::Active);
+        assert!(account.verified);
+    }
+
+    #[test]
+    fn test_deserialize_by_position() {
+        let row = Row::new(b"7,ana,plain,ab,-2.5,Closed,false", b',', b'"', false);
+        let account: Account = row.deserialize(Encoding::Utf8).unwrap();
+        assert_eq!((account.id, account.owner, account.balance), (7, "ana", Some(-2.5)));
+        assert!(matches!(account.note, Cow::Borrowed("plain")));
+        assert_eq!(account.status, Status::Closed);
+        let tuple: (String, u8, Option<i64>) = Row::new(b"x;9;", b';', b'"', false)
+            .deserialize(Encoding::Utf8)
+            .unwrap();
+        assert_eq!(tuple, ("x".to_string(), 9, None));
+        let all: Vec<u16> = Row::new(b"1;2;3", b';', b'"', false).deserialize(Encoding::Utf8).unwrap();
+        assert_eq!(all, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_deserialize_encoding_and_errors() {
+        // Non ASCII text of other encodings is decoded into an owned string
+        let row = Row::new(b"caf\xE9;1", b';', b'"', false);
+        let (name, _): (String, u8) = row.deserialize(Encoding::Windows1252).unwrap();
+        assert_eq!(name, "café");
+        assert!(row.deserialize::<(&str, u8)>(Encoding::Windows1252).is_err());
+        // ASCII text borrows with any encoding
+        let (name, _): (&str, u8) = Row::new(b"cafe;1", b';', b'"', false)
+            .deserialize(Encoding::Windows1252)
+            .unwrap();
+        assert_eq!(name, "cafe");
+        let row = Row::new(b"x;300", b';', b'"', false).with_record(4);
+        let err = row.deserialize::<(String, u8)>(Encoding::Utf8).unwrap_err();
+        assert_eq!((err.kind(), err.record(), err.field()), (CsvErrorKind::Parse, Some(4), Some(1)));
+        assert_eq!((err.byte_offset(), err.snippet()), (Some(2), Some("x;300")));
+        let err = Row::new(b"Open", b';', b'"', false)
+            .with_record(2)
+            .deserialize::<(Status,)>(Encoding::Utf8)
+            .unwrap_err();
+        assert_eq!((err.kind(), err.record(), err.field()), (CsvErrorKind::Parse, Some(2), Some(0)));
+        assert!(err.message().contains("unknown variant"));
+        // Errors outside a field get the position of the row
+        let err = Row::new(b"1", b';', b'"', false)
+            .with_record(7)
+            .with_offset(40)
+            .deserialize::<(u8, u8)>(Encoding::Utf8)
+            .unwrap_err();
+        assert_eq!((err.kind(), err.record(), err.field(), err.byte_offset()), (CsvErrorKind::Decode, Some(7), None, Some(40)));
+    }
+}
@@ -20,6 +20,9 @@ fn encode_solver(codepage: u32) -> Encoding {
         9=> Encoding::GB2312,
         10=> Encoding::Big5,
         11=> Encoding::ISO8859_2,
+        12=> Encoding::Utf16Le,
+        13=> Encoding::Utf16Be,
+        14=> Encoding::GB18030,
         _ => Encoding::Utf8 }
 }
 
@@ -1,2 +1,6 @@
 #[cfg(feature = "ffi")]
-pub mod ffi;
\ No newline at end of file
+pub mod ffi;
+#[cfg(feature = "serde")]
+pub mod deserialize;
+#[cfg(feature = "serde")]
+pub(crate) mod serialize;
@@ -0,0 +1,510 @@
+use crate::csv::csv_writer::FieldFormat;
+use crate::models::csv_error::{CsvError, CsvErrorKind};
+use serde::ser::{
+    Error, Impossible, Serialize, SerializeMap, SerializeSeq, SerializeStruct, SerializeTuple,
+    SerializeTupleStruct, Serializer,
+};
+use std::fmt::Display;
+use std::io::Write;
+
+/// ## serde Error implementation
+/// - Messages built by serde, and unsupported values, are `CsvErrorKind::Encode` errors.
+impl Error for CsvError {
+    fn custom<T: Display>(msg: T) -> Self {
+        CsvError::new(CsvErrorKind::Encode, msg.to_string())
+    }
+}
+
+/// Writes the fields of a record, without the line break.
+/// If `names` is given, the field names of a struct are pushed into it.
+pub(crate) fn write_record<O: Write, T: Serialize + ?Sized>(
+    out: &mut O,
+    format: FieldFormat,
+    names: Option<&mut Vec<&'static str>>,
+    record: &T,
+) -> Result<(), CsvError> {
+    record.serialize(&mut RecordSerializer {
+        out,
+        format,
+        names,
+        field: 0,
+    })
+}
+
+fn unsupported(what: &str) -> CsvError {
+    CsvError::new(CsvErrorKind::Encode, format!("{} can not be written in a CSV field", what))
+}
+
+/// Serializer of a whole record. Structs, tuples, sequences and maps are split into fields.
+struct RecordSerializer<'s, O: Write> {
+    out: &'s mut O,
+    format: FieldFormat,
+    names: Option<&'s mut Vec<&'static str>>,
+    field: usize,
+}
+
+impl<'s, O: Write> RecordSerializer<'s, O> {
+    /// Writes the delimiter if needed, and moves to the next field.
+    fn begin_field(&mut self) -> Result<(), CsvError> {
+        self.format.write_separator(self.out, self.field)?;
+        self.field += 1;
+        Ok(())
+    }
+
+    fn write_number<T: Display>(&mut self, value: T) -> Result<(), CsvError> {
+        self.begin_field()?;
+        self.format.write_number(self.out, value)?;
+        Ok(())
+    }
+
+    fn write_str(&mut self, text: &str) -> Result<(), CsvError> {
+        self.begin_field()?;
+        self.format.write_str(self.out, text)?;
+        Ok(())
+    }
+}
+
+/// A single value at the top level is a record of one field.
+macro_rules! forward_to_field {
+    ($($method:ident($($arg:ident: $ty:ty),*)),* $(,)?) => {
+        $(
+            fn $method(self, $($arg: $ty),*) -> Result<(), CsvError> {
+                FieldSerializer { record: self }.$method($($arg),*)
+            }
+        )*
+    };
+}
+
+impl<'r, 's, O: Write> Serializer for &'r mut RecordSerializer<'s, O> {
+    type Ok = ();
+    type Error = CsvError;
+    type SerializeSeq = Self;
+    type SerializeTuple = Self;
+    type SerializeTupleStruct = Self;
+    type SerializeTupleVariant = Impossible<(), CsvError>;
+    type SerializeMap = Self;
+    type SerializeStruct = Self;
+    type SerializeStructVariant = Impossible<(), CsvError>;
+
+    forward_to_field! {
+        serialize_bool(v: bool),
+        serialize_i8(v: i8),
+        serialize_i16(v: i16),
+        serialize_i32(v: i32),
+        serialize_i64(v: i64),
+        serialize_i128(v: i128),
+        serialize_u8(v: u8),
+        serialize_u16(v: u16),
+        serialize_u32(v: u32),
+        serialize_u64(v: u64),
+        serialize_u128(v: u128),
+        serialize_f32(v: f32),
+        serialize_f64(v: f64),
+        serialize_char(v: char),
+        serialize_str(v: &str),
+        serialize_bytes(v: &[u8]),
+        serialize_unit_struct(name: &'static str),
+        serialize_unit_variant(name: &'static str, index: u32, variant: &'static str),
+    }
+
+    fn serialize_none(self) -> Result<(), CsvError> {
+        Ok(())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<(), CsvError> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<(), CsvError> {
+        Ok(())
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(self, _name: &'static str, value: &T) -> Result<(), CsvError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<(), CsvError> {
+        Err(unsupported("an enum variant with data"))
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self, CsvError> {
+        Ok(self)
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self, CsvError> {
+        Ok(self)
+    }
+
+    fn serialize_tuple_struct(self, _name: &'static str, _len: usize) -> Result<Self, CsvError> {
+        Ok(self)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, CsvError> {
+        Err(unsupported("an enum variant with data"))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self, CsvError> {
+        Ok(self)
+    }
+
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self, CsvError> {
+        Ok(self)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, CsvError> {
+        Err(unsupported("an enum variant with data"))
+    }
+}
+
+impl<'r, 's, O: Write> SerializeSeq for &'r mut RecordSerializer<'s, O> {
+    type Ok = ();
+    type Error = CsvError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), CsvError> {
+        value.serialize(FieldSerializer { record: &mut **self })
+    }
+
+    fn end(self) -> Result<(), CsvError> {
+        Ok(())
+    }
+}
+
+impl<'r, 's, O: Write> SerializeTuple for &'r mut RecordSerializer<'s, O> {
+    type Ok = ();
+    type Error = CsvError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), CsvError> {
+        value.serialize(FieldSerializer { record: &mut **self })
+    }
+
+    fn end(self) -> Result<(), CsvError> {
+        Ok(())
+    }
+}
+
+impl<'r, 's, O: Write> SerializeTupleStruct for &'r mut RecordSerializer<'s, O> {
+    type Ok = ();
+    type Error = CsvError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), CsvError> {
+        value.serialize(FieldSerializer { record: &mut **self })
+    }
+
+    fn end(self) -> Result<(), CsvError> {
+        Ok(())
+    }
+}
+
+impl<'r, 's, O: Write> SerializeMap for &'r mut RecordSerializer<'s, O> {
+    type Ok = ();
+    type Error = CsvError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, _key: &T) -> Result<(), CsvError> {
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), CsvError> {
+        value.serialize(FieldSerializer { record: &mut **self })
+    }
+
+    fn end(self) -> Result<(), CsvError> {
+        Ok(())
+    }
+}
+
+impl<'r, 's, O: Write> SerializeStruct for &'r mut RecordSerializer<'s, O> {
+    type Ok = ();
+    type Error = CsvError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, key: &'static str, value: &T) -> Result<(), CsvError> {
+        if let Some(names) = self.names.as_mut() {
+            names.push(key);
+        }
+        value.serialize(FieldSerializer { record: &mut **self })
+    }
+
+    fn end(self) -> Result<(), CsvError> {
+        Ok(())
+    }
+}
+
+/// Serializer of a single field. Only scalar values are supported.
+struct FieldSerializer<'r, 's, O: Write> {
+    record: &'r mut RecordSerializer<'s, O>,
+}
+
+impl<'r, 's, O: Write> Serializer for FieldSerializer<'r, 's, O> {
+    type Ok = ();
+    type Error = CsvError;
+    type SerializeSeq = Impossible<(), CsvError>;
+    type SerializeTuple = Impossible<(), CsvError>;
+    type SerializeTupleStruct = Impossible<(), CsvError>;
+    type SerializeTupleVariant = Impossible<(), CsvError>;
+    type SerializeMap = Impossible<(), CsvError>;
+    type SerializeStruct = Impossible<(), CsvError>;
+    type SerializeStructVariant = Impossible<(), CsvError>;
+
+    fn serialize_bool(self, v: bool) -> Result<(), CsvError> {
+        self.record.write_str(if v { "true" } else { "false" })
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<(), CsvError> {
+        self.record.write_number(v)
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<(), CsvError> {
+        self.record.write_number(v)
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<(), CsvError> {
+        self.record.write_number(v)
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<(), CsvError> {
+        self.record.write_number(v)
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<(), CsvError> {
+        self.record.write_number(v)
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<(), CsvError> {
+        self.record.write_number(v)
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<(), CsvError> {
+        self.record.write_number(v)
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<(), CsvError> {
+        self.record.write_number(v)
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<(), CsvError> {
+        self.record.write_number(v)
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<(), CsvError> {
+        self.record.write_number(v)
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<(), CsvError> {
+        self.record.write_number(v)
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<(), CsvError> {
+        self.record.write_number(v)
+    }
+
+    fn serialize_char(self, v: char) -> Result<(), CsvError> {
+        self.record.write_str(v.encode_utf8(&mut [0u8; 4]))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<(), CsvError> {
+        self.record.write_str(v)
+    }
+
+    /// Bytes are written as they are, without encoding.
+    fn serialize_bytes(self, v: &[u8]) -> Result<(), CsvError> {
+        self.record.begin_field()?;
+        self.record.format.write_bytes(self.record.out, v)?;
+        Ok(())
+    }
+
+    fn serialize_none(self) -> Result<(), CsvError> {
+        self.record.begin_field()
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<(), CsvError> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<(), CsvError> {
+        self.record.begin_field()
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<(), CsvError> {
+        self.record.begin_field()
+    }
+
+    fn serialize_unit_variant(self, _name: &'static str, _index: u32, variant: &'static str) -> Result<(), CsvError> {
+        self.record.write_str(variant)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(self, _name: &'static str, value: &T) -> Result<(), CsvError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<(), CsvError> {
+        Err(unsupported("an enum variant with data"))
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, CsvError> {
+        Err(unsupported("a sequence"))
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, CsvError> {
+        Err(unsupported("a tuple"))
+    }
+
+    fn serialize_tuple_struct(self, _name: &'static str, _len: usize) -> Result<Self::SerializeTupleStruct, CsvError> {
+        Err(unsupported("a tuple struct"))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, CsvError> {
+        Err(unsupported("an enum variant with data"))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, CsvError> {
+        Err(unsupported("a map"))
+    }
+
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self::SerializeStruct, CsvError> {
+        Err(unsupported("a nested struct"))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, CsvError> {
+        Err(unsupported("an enum variant with data"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csv::csv_writer::CsvWriter;
+    use crate::decoders::decoders::Encoding;
+    use crate::models::csv_error::CsvErrorKind;
+    use crate::models::quote_style::QuoteStyle;
+    use serde::Serialize;
+    use std::collections::BTreeMap;
+
+    #[derive(Serialize)]
+    enum Status {
+        Active,
+    }
+
+    #[derive(Serialize)]
+    struct Account<'a> {
+        id: u32,
+        owner: &'a str,
+        balance: Option<f64>,
+        status: Status,
+        verified: bool,
+    }
+
+    #[test]
+    fn test_serialize_structs() {
+        let mut writer = CsvWriter::new(Vec::new(), b';', b"\n", Encoding::Windows1252);
+        let accounts = [
+            Account { id: 1, owner: "Müller; Co", balance: Some(-2.5), status: Status::Active, verified: true },
+            Account { id: 2, owner: "say \"hi\"", balance: None, status: Status::Active, verified: false },
+        ];
+        for account in accounts.iter() {
+            writer.serialize(account).unwrap();
+        }
+        let out = writer.flush_and_get().unwrap();
+        assert_eq!(
+            out,
+            b"id;owner;balance;status;verified\n\
+              1;\"M\xFCller; Co\";-2.5;Active;true\n\
+              2;\"say \"\"hi\"\"\";;Active;false\n"
+        );
+    }
+
+    #[test]
+    fn test_serialize_utf16() {
+        let line_break = Encoding::Utf16Le.encode("\r\n");
+        let mut writer = CsvWriter::new(Vec::new(), b',', &line_break, Encoding::Utf16Le);
+        let account = Account { id: 7, owner: "Ĭ, \"Ĭ\"", balance: Some(0.5), status: Status::Active, verified: false };
+        writer.serialize(&account).unwrap();
+        writer.serialize(&("Ĭ", 'Ĭ')).unwrap();
+        let out = writer.flush_and_get().unwrap();
+        assert_eq!(
+            Encoding::Utf16Le.decode(&out),
+            "id,owner,balance,status,verified\r\n7,\"Ĭ, \"\"Ĭ\"\"\",0.5,Active,false\r\nĬ,Ĭ\r\n"
+        );
+    }
+
+    #[test]
+    fn test_serialize_without_header() {
+        let mut writer = CsvWriter::new(Vec::new(), b',', b"\r\n", Encoding::Utf8);
+        writer.write_row_fast(&["a", "b"]).unwrap();
+        let account = Account { id: 3, owner: "line\nbreak", balance: Some(1.0), status: Status::Active, verified: true };
+        writer.serialize(&account).unwrap();
+        writer.serialize(&("x", 1u8, None::<i32>, 'c')).unwrap();
+        let mut map = BTreeMap::new();
+        map.insert("k", 7);
+        writer.serialize(&map).unwrap();
+        let out = writer.flush_and_get().unwrap();
+        assert_eq!(
+            std::str::from_utf8(&out).unwrap(),
+            "a,b\r\n3,\"line\nbreak\",1,Active,true\r\nx,1,,c\r\n7\r\n"
+        );
+    }
+
+    #[test]
+    fn test_serialize_quote_styles() {
+        let record = ("a", 1.5f64, true, -3i32);
+        let mut writer = CsvWriter::new(Vec::new(), b',', b"\n", Encoding::Utf8).with_quote_style(QuoteStyle::NonNumeric);
+        writer.serialize(&record).unwrap();
+        assert_eq!(writer.flush_and_get().unwrap(), b"\"a\",1.5,\"true\",-3\n");
+        let mut writer = CsvWriter::new(Vec::new(), b',', b"\n", Encoding::Utf8).with_quote_style(QuoteStyle::Always);
+        writer.serialize(&record).unwrap();
+        assert_eq!(writer.flush_and_get().unwrap(), b"\"a\",\"1.5\",\"true\",\"-3\"\n");
+    }
+
+    #[test]
+    fn test_serialize_nested_fails() {
+        #[derive(Serialize)]
+        struct Nested {
+            values: Vec<u8>,
+        }
+        #[derive(Serialize)]
+        struct Single {
+            id: u8,
+        }
+        let mut writer = CsvWriter::new(Vec::new(), b',', b"\n", Encoding::Utf8);
+        assert!(matches!(writer.serialize(&Nested { values: vec![1] }), Err(e) if e.kind() == CsvErrorKind::Encode));
+        // A record failing after some fields writes nothing, and the header is still written
+        assert!(writer.serialize(&("a", vec![1u8])).is_err());
+        writer.serialize(&Single { id: 2 }).unwrap();
+        writer.serialize(&("b", 3)).unwrap();
+        assert!(writer.serialize(&("c", vec![1u8])).is_err());
+        assert_eq!(writer.flush_and_get().unwrap(), b"id\n2\nb,3\n");
+    }
+}
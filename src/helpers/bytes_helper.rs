@@ -1,61 +1,386 @@
-use memchr::{memchr, memchr2, memchr3, memrchr};
+use memchr::{memchr, memchr2, memchr3, memchr_iter};
+use crate::models::csv_config::CsvConfig;
+use crate::models::delimiter::Delimiter;
+use std::borrow::Cow;
 //=================================================================//
 //=====================   PUBLIC  FUNCTIONS   =====================//
 //=================================================================//
 
 /// ## Locate Line Break using Memchr3
 /// - Locates the line break, using memchr2. Is compatible with CPU made before 2013. (Don't have AVX2 compatibility)
-pub(crate) fn locate_line_break_memchr3(slice: &[u8], cursor: usize, separator: u8) -> usize {
-    let r = if separator == b'\r' || separator == b'\n' {
-        memchr2(b'\n', b'\r', slice).map(|i| {
-            if slice[i] == b'\r' && slice.get(i + 1) == Some(&b'\n') {
-                cursor + i + 2
-            } else {
-                cursor + i + 1
-            }
-        })
-    } else {
-        memchr3(b'\n', b'\r', separator, slice).map(|i| {
-            if slice[i] == b'\r' && slice.get(i + 1) == Some(&b'\n') {
-                cursor + i + 2
-            } else {
-                cursor + i + 1
+/// - If `string_separator` is not `0u8`, line breaks inside quoted fields are skipped (RFC 4180, `""` escapes included).
+pub(crate) fn locate_line_break_memchr3(slice: &[u8], cursor: usize, separator: u8, string_separator: u8) -> usize {
+    let mut scan = RecordScan::default();
+    resume_line_break_memchr3(slice, separator, string_separator, &mut scan).map_or(0, |end| cursor + end)
+}
+
+/// ## Record Scan
+/// - Progress of a record search, so it can resume when more bytes are appended to the slice.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct RecordScan {
+    /// Position to resume from, relative to the start of the record.
+    pub pos: usize,
+    /// Quote state at `pos`.
+    pub in_string: bool,
+}
+
+/// ## Resume Line Break
+/// - Same as `locate_line_break` (AVX2, NEON or memchr3), starting at `scan.pos` with the quote state `scan.in_string`.
+/// - Returns the end of the record, line break included. Returns `None` if there is no line break yet, and `scan`
+///   then points to the end of `slice`.
+pub(crate) fn resume_line_break(
+    slice: &[u8],
+    line_break: u8,
+    string_separator: u8,
+    force_memchr3: bool,
+    scan: &mut RecordScan,
+) -> Option<usize> {
+    #[cfg(target_arch = "x86_64")]
+    {
+        if !force_memchr3 && is_x86_feature_detected!("avx2") {
+            return unsafe { resume_line_break_avx2(slice, line_break, string_separator, scan) };
+        }
+    }
+    #[cfg(target_arch = "aarch64")]
+    {
+        if !force_memchr3 {
+            return unsafe { resume_line_break_neon(slice, line_break, string_separator, scan) };
+        }
+    }
+    resume_line_break_memchr3(slice, line_break, string_separator, scan)
+}
+
+/// ## Resume Terminator
+/// - Same as `locate_terminator`, starting at `scan.pos` with the quote state `scan.in_string`.
+/// - Returns the end of the record, terminator included. Returns `None` if there is no terminator yet, and `scan`
+///   then points to where the search must resume (a terminator cut by the end of `slice` is searched again).
+pub(crate) fn resume_terminator(slice: &[u8], terminator: Delimiter, string_separator: u8, scan: &mut RecordScan) -> Option<usize> {
+    let lead = terminator.first();
+    let mut pos = scan.pos;
+    loop {
+        if scan.in_string {
+            // Jump to the closing quote. An escaped quote ("") closes and reopens.
+            match memchr(string_separator, &slice[pos..]) {
+                Some(close) => {
+                    pos += close + 1;
+                    scan.in_string = false;
+                }
+                None => break,
             }
-        })
-    };
-    r.unwrap_or_else(|| 0)
+        }
+        let hit = if string_separator == 0 {
+            memchr(lead, &slice[pos..])
+        } else {
+            memchr2(lead, string_separator, &slice[pos..])
+        };
+        let at = match hit {
+            Some(i) => pos + i,
+            None => break,
+        };
+        if string_separator != 0 && slice[at] == string_separator {
+            scan.in_string = true;
+            pos = at + 1;
+        } else if at + terminator.len() > slice.len() {
+            scan.pos = at;
+            return None;
+        } else if terminator.matches_at(slice, at) {
+            return Some(at + terminator.len());
+        } else {
+            pos = at + 1;
+        }
+    }
+    scan.pos = slice.len();
+    None
 }
 
-/// ## Find Delimiter Bounds
-/// - Finds the first and last occurrence of a delimiter in a slice.
-/// - Uses SIMD under the hood via memchr and memrchr.
-pub(crate) fn find_delimiter_bounds(slice: &[u8], delimiter: u8) -> Option<(usize, usize)> {
-    let first = memchr(delimiter, slice)?;
-    let last = memrchr(delimiter, slice)?;
-    Some((first, last))
+/// ## Locate Terminator
+/// - Locates the end of the record, for an exact terminator sequence (`\r\n`, 0x1E, or a custom one).
+/// - Candidates are found with memchr on the first byte of the sequence, so bytes that only look like a part of
+///   the sequence are data.
+/// - If `string_separator` is not `0u8`, terminators inside quoted fields are skipped.
+/// - Returns the end of the record, terminator included, relative to `slice`. `0` if not found, or if a quote is
+///   not closed.
+pub(crate) fn locate_terminator(slice: &[u8], terminator: Delimiter, string_separator: u8) -> usize {
+    resume_terminator(slice, terminator, string_separator, &mut RecordScan::default()).unwrap_or(0)
+}
+
+/// ## Record Bounds
+/// - Bounds of a record located by `next_record`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct RecordBounds {
+    /// Start of the record.
+    pub start: usize,
+    /// End of the record, without its terminator.
+    pub end: usize,
+    /// Start of the next record.
+    pub next: usize,
+    /// Records consumed, skipped comment lines and empty records included.
+    pub records: usize,
+}
+
+/// ## Next Record
+/// - Locates the next record of `slice` starting at `cursor`, with the backend selected by the config
+///   (exact terminator, AVX2, NEON or memchr3).
+/// - Skips comment lines (quotes inside them are ignored), and empty records if `skip_empty_records` is set.
+/// - A last record without terminator is returned, and a terminator at the end of the slice does not produce an
+///   extra empty record. The same on every backend.
+/// - Returns `None` at the end of the slice.
+pub(crate) fn next_record(slice: &[u8], cursor: usize, config: &CsvConfig) -> Option<RecordBounds> {
+    let mut start = cursor;
+    let mut records = 0usize;
+    while start < slice.len() {
+        let rest = &slice[start..];
+        let comment = config.is_comment(rest);
+        let string_separator = if comment { 0 } else { config.string_separator };
+        let (len, consumed) = split_record(rest, config, string_separator);
+        records += 1;
+        let skipped = comment || (len == 0 && config.skip_empty_records);
+        if !skipped {
+            return Some(RecordBounds {
+                start,
+                end: start + len,
+                next: start + consumed,
+                records,
+            });
+        }
+        start += consumed;
+    }
+    None
 }
 
 /// ## Get Cleaned Slice
 /// - Returns a slice without the delimiters if they are at the first and last position.
 pub(crate) fn get_cleaned(slice: &[u8], delimiter: u8) -> &[u8] {
-    if delimiter == 0 || slice.len() < 2 {
-        return slice;
-    }
-    if let Some((first, last)) = find_delimiter_bounds(slice, delimiter) {
-        return &slice[first + 1..last];
+    if delimiter != 0 && slice.len() >= 2 && slice[0] == delimiter && slice[slice.len() - 1] == delimiter {
+        return &slice[1..slice.len() - 1];
     }
     slice
 }
 
+/// ## Unescape Quotes
+/// - Replaces the escaped (doubled) delimiters of a quoted field content by a single one.
+/// - Borrows the input if there is nothing to unescape.
+pub(crate) fn unescape_quotes(content: &[u8], delimiter: u8) -> Cow<'_, [u8]> {
+    if delimiter == 0 || memchr(delimiter, content).is_none() {
+        return Cow::Borrowed(content);
+    }
+    let mut out = Vec::with_capacity(content.len());
+    let mut pos = 0usize;
+    while let Some(i) = memchr(delimiter, &content[pos..]) {
+        out.extend_from_slice(&content[pos..pos + i + 1]);
+        pos += i + 1;
+        if content.get(pos) == Some(&delimiter) {
+            pos += 1;
+        }
+    }
+    out.extend_from_slice(&content[pos..]);
+    Cow::Owned(out)
+}
+
+/// ## Split Record
+/// - Splits the next record of a non empty slice.
+/// - Returns the length of the record without its terminator, and the length consumed (terminator included).
+/// - A record without terminator (or with an unclosed quote) takes the rest of the slice.
+#[inline(always)]
+fn split_record(slice: &[u8], config: &CsvConfig, string_separator: u8) -> (usize, usize) {
+    if let Some(terminator) = config.terminator.sequence() {
+        return match locate_terminator(slice, terminator, string_separator) {
+            0 => (slice.len(), slice.len()),
+            end => (end - terminator.len(), end),
+        };
+    }
+    let end = locate_line_break(slice, config.line_break, string_separator, config.force_memcach3);
+    (trim_record_end(&slice[..end], config.line_break).len(), end)
+}
+
+/// ## Trim Record End
+/// - Returns the record without its line break: `\r\n`, `\n`, `\r`, or the custom `line_break` byte.
+#[inline(always)]
+pub(crate) fn trim_record_end(record: &[u8], line_break: u8) -> &[u8] {
+    match record.split_last() {
+        Some((&byte, rest)) if byte == line_break && byte != b'\r' && byte != b'\n' => rest,
+        _ => trim_line_break(record),
+    }
+}
+
+/// ## Locate Line Break
+/// - Dispatches to the AVX2, NEON or memchr3 version.
+/// - Returns the end of the record, line break included. `slice.len()` if there is no line break.
+#[inline(always)]
+fn locate_line_break(slice: &[u8], line_break: u8, string_separator: u8, force_memchr3: bool) -> usize {
+    #[cfg(target_arch = "x86_64")]
+    {
+        if !force_memchr3 && is_x86_feature_detected!("avx2") {
+            return unsafe { locate_line_break_avx2(slice, line_break, string_separator) };
+        }
+    }
+    #[cfg(target_arch = "aarch64")]
+    {
+        if !force_memchr3 {
+            return unsafe { locate_line_break_neon(slice, line_break, string_separator) };
+        }
+    }
+    match locate_line_break_memchr3(slice, 0, line_break, string_separator) {
+        0 => slice.len(),
+        end => end,
+    }
+}
+
+/// ## Find Record End
+/// - Finds the first `line_break` at or after `start`, outside quoted fields.
+/// - `in_string` is the quote state at `start`. Every string separator toggles it, so `""` escapes keep the state.
+/// - Returns the end of the record (line break excluded) and the start of the next one. Both are `slice.len()`
+///   if there is no line break.
+pub(crate) fn find_record_end(
+    slice: &[u8],
+    start: usize,
+    line_break: Delimiter,
+    string_separator: u8,
+    mut in_string: bool,
+) -> (usize, usize) {
+    let first = line_break.first();
+    let mut pos = start;
+    while pos < slice.len() {
+        let rest = &slice[pos..];
+        let found = if string_separator == 0 {
+            memchr(first, rest)
+        } else if in_string {
+            memchr(string_separator, rest)
+        } else {
+            memchr2(first, string_separator, rest)
+        };
+        let at = match found {
+            Some(offset) => pos + offset,
+            None => break,
+        };
+        if string_separator != 0 && slice[at] == string_separator {
+            in_string = !in_string;
+        } else if line_break.matches_at(slice, at) {
+            return (at, at + line_break.len());
+        }
+        pos = at + 1;
+    }
+    (slice.len(), slice.len())
+}
+
+/// ## Is Inside Quotes
+/// - Returns `true` if the end of `slice` is inside a quoted field, `slice` starting outside quotes.
+#[inline(always)]
+pub(crate) fn ends_inside_quotes(slice: &[u8], string_separator: u8) -> bool {
+    string_separator != 0 && memchr_iter(string_separator, slice).count() % 2 == 1
+}
+
+/// ## Needs Quotes
+/// - Returns `true` if the field holds the delimiter, the string separator, `\r` or `\n` (RFC 4180).
+/// - Uses AVX2 / NEON on fields of one chunk or more.
+#[inline(always)]
+pub(crate) fn needs_quotes(field: &[u8], delimiter: u8, string_separator: u8) -> bool {
+    #[cfg(target_arch = "x86_64")]
+    {
+        if field.len() >= 32 && is_x86_feature_detected!("avx2") {
+            return unsafe { needs_quotes_avx2(field, delimiter, string_separator) };
+        }
+    }
+    #[cfg(target_arch = "aarch64")]
+    {
+        if field.len() >= 16 {
+            return unsafe { needs_quotes_neon(field, delimiter, string_separator) };
+        }
+    }
+    needs_quotes_scalar(field, delimiter, string_separator)
+}
+
+/// ## Needs Quotes AVX2
+/// - Tests 32 bytes at a time against the delimiter, the string separator, `\r` and `\n`.
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+pub(crate) unsafe fn needs_quotes_avx2(field: &[u8], delimiter: u8, string_separator: u8) -> bool {
+    unsafe {
+        use std::arch::x86_64::*;
+
+        let pattern_delimiter = _mm256_set1_epi8(delimiter as i8);
+        let pattern_quote = _mm256_set1_epi8(string_separator as i8);
+        let pattern_n = _mm256_set1_epi8(b'\n' as i8);
+        let pattern_r = _mm256_set1_epi8(b'\r' as i8);
+
+        let mut i = 0;
+        while i + 32 <= field.len() {
+            let chunk = _mm256_loadu_si256(field.as_ptr().add(i) as *const __m256i);
+            let hits = _mm256_or_si256(
+                _mm256_or_si256(_mm256_cmpeq_epi8(chunk, pattern_delimiter), _mm256_cmpeq_epi8(chunk, pattern_quote)),
+                _mm256_or_si256(_mm256_cmpeq_epi8(chunk, pattern_n), _mm256_cmpeq_epi8(chunk, pattern_r)),
+            );
+            if _mm256_movemask_epi8(hits) != 0 {
+                return true;
+            }
+            i += 32;
+        }
+        needs_quotes_scalar(&field[i..], delimiter, string_separator)
+    }
+}
+
+/// ## Needs Quotes NEON
+/// - Tests 16 bytes at a time against the delimiter, the string separator, `\r` and `\n`.
+#[cfg(target_arch = "aarch64")]
+#[allow(dead_code, unsafe_code)]
+pub(crate) unsafe fn needs_quotes_neon(field: &[u8], delimiter: u8, string_separator: u8) -> bool {
+    unsafe {
+        use std::arch::aarch64::*;
+
+        let pattern_delimiter = vdupq_n_u8(delimiter);
+        let pattern_quote = vdupq_n_u8(string_separator);
+        let pattern_n = vdupq_n_u8(b'\n');
+        let pattern_r = vdupq_n_u8(b'\r');
+
+        let mut i = 0;
+        while i + 16 <= field.len() {
+            let chunk = vld1q_u8(field.as_ptr().add(i));
+            let hits = vorrq_u8(
+                vorrq_u8(vceqq_u8(chunk, pattern_delimiter), vceqq_u8(chunk, pattern_quote)),
+                vorrq_u8(vceqq_u8(chunk, pattern_n), vceqq_u8(chunk, pattern_r)),
+            );
+            if vmaxvq_u8(hits) != 0 {
+                return true;
+            }
+            i += 16;
+        }
+        needs_quotes_scalar(&field[i..], delimiter, string_separator)
+    }
+}
+
+/// ## Trim Line Break
+/// - Returns a slice without the trailing `\r\n`, `\n` or `\r`, if present.
+pub(crate) fn trim_line_break(record: &[u8]) -> &[u8] {
+    if record.ends_with(b"\r\n") {
+        &record[..record.len() - 2]
+    } else if record.ends_with(b"\n") || record.ends_with(b"\r") {
+        &record[..record.len() - 1]
+    } else {
+        record
+    }
+}
+
 /// ## Locate Line Break AVX2
 /// - Finds the next line break, using AVX2 feature.
+/// - If `string_separator` is not `0u8`, line breaks inside quoted fields are skipped.
+/// - Chunks without quotes or breaks are skipped with a single mask test, and chunks fully inside a quoted field
+///   only test the quote mask.
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+pub(crate) unsafe fn locate_line_break_avx2(buffer: &[u8], separator: u8, string_separator: u8) -> usize {
+    unsafe { resume_line_break_avx2(buffer, separator, string_separator, &mut RecordScan::default()).unwrap_or(buffer.len()) }
+}
+
+/// ## Resume Line Break AVX2
+/// - `locate_line_break_avx2` starting at `scan.pos` with the quote state `scan.in_string`.
+/// - Returns `None` if no break is found, and `scan` then points to the end of `buffer`.
 #[cfg(target_arch = "x86_64")]
 #[target_feature(enable = "avx2")]
-pub(crate) unsafe fn locate_line_break_avx2(buffer: &[u8], separator: u8) -> usize {
+unsafe fn resume_line_break_avx2(buffer: &[u8], separator: u8, string_separator: u8, scan: &mut RecordScan) -> Option<usize> {
     unsafe {
         use std::arch::x86_64::*;
 
-        let mut i = 0;
+        let mut i = scan.pos;
         let len = buffer.len();
 
         let pattern_n = _mm256_set1_epi8(b'\n' as i8);
@@ -66,10 +391,24 @@ pub(crate) unsafe fn locate_line_break_avx2(buffer: &[u8], separator: u8) -> usi
         } else {
             None
         };
+        let check_string = string_separator != 0;
+        let pattern_quote = _mm256_set1_epi8(string_separator as i8);
+        let mut in_string = scan.in_string;
 
         while i + 32 <= len {
             let chunk = _mm256_loadu_si256(buffer.as_ptr().add(i) as *const __m256i);
 
+            let mask_quote = if check_string {
+                _mm256_movemask_epi8(_mm256_cmpeq_epi8(chunk, pattern_quote)) as u32
+            } else {
+                0
+            };
+            // Whole chunk inside a quoted field: breaks are data
+            if in_string && mask_quote == 0 {
+                i += 32;
+                continue;
+            }
+
             let cmp_n = _mm256_cmpeq_epi8(chunk, pattern_n);
             let cmp_r = _mm256_cmpeq_epi8(chunk, pattern_r);
             let cmp_sep = if let Some(pattern_sep) = pattern_sep {
@@ -78,120 +417,315 @@ pub(crate) unsafe fn locate_line_break_avx2(buffer: &[u8], separator: u8) -> usi
                 None
             };
 
-            let mask_n = _mm256_movemask_epi8(cmp_n);
-            let mask_r = _mm256_movemask_epi8(cmp_r);
-            let mask_sep = cmp_sep.map_or(0, |c| _mm256_movemask_epi8(c));
+            let mask_n = _mm256_movemask_epi8(cmp_n) as u32;
+            let mask_r = _mm256_movemask_epi8(cmp_r) as u32;
+            let mask_sep = cmp_sep.map_or(0, |c| _mm256_movemask_epi8(c) as u32);
 
-            if mask_n != 0 || mask_r != 0 || mask_sep != 0 {
-                for j in 0..32 {
-                    let pos = i + j;
-                    if pos >= len {
-                        break;
-                    }
-                    match buffer[pos] {
-                        b'\r' => {
-                            if pos + 1 < len && buffer[pos + 1] == b'\n' {
-                                return pos + 2;
-                            } else {
-                                return pos + 1;
-                            }
-                        }
-                        b'\n' => return pos + 1,
-                        byte if check_separator && byte == separator => return pos + 1,
-                        _ => {}
-                    }
+            let mut mask = mask_n | mask_r | mask_sep | mask_quote;
+            while mask != 0 {
+                let pos = i + mask.trailing_zeros() as usize;
+                if let Some(end) = classify_break_byte(buffer, pos, separator, string_separator, &mut in_string) {
+                    return Some(end);
                 }
+                mask &= mask - 1;
             }
 
             i += 32;
         }
 
-        while i < len {
-            match buffer[i] {
-                b'\r' if i + 1 < len && buffer[i + 1] == b'\n' => return i + 2,
-                b'\r' | b'\n' => return i + 1,
-                byte if check_separator && byte == separator => return i + 1,
-                _ => i += 1,
-            }
-        }
-
-        buffer.len()
+        scan.in_string = in_string;
+        locate_line_break_scalar(buffer, i, separator, string_separator, scan)
     }
 }
 
 /// ## Locate Line Break NEON
 ///
 /// - Finds the next line break, using aarch64 feature NEON.
+/// - If `string_separator` is not `0u8`, line breaks inside quoted fields are skipped.
 #[cfg(target_arch = "aarch64")]
 #[allow(dead_code, unsafe_code)]
-pub(crate) unsafe fn locate_line_break_neon(buffer: &[u8], separator: u8) -> usize {
+pub(crate) unsafe fn locate_line_break_neon(buffer: &[u8], separator: u8, string_separator: u8) -> usize {
+    unsafe { resume_line_break_neon(buffer, separator, string_separator, &mut RecordScan::default()).unwrap_or(buffer.len()) }
+}
+
+/// ## Resume Line Break NEON
+/// - `locate_line_break_neon` starting at `scan.pos` with the quote state `scan.in_string`.
+/// - Returns `None` if no break is found, and `scan` then points to the end of `buffer`.
+#[cfg(target_arch = "aarch64")]
+#[allow(unsafe_code)]
+unsafe fn resume_line_break_neon(buffer: &[u8], separator: u8, string_separator: u8, scan: &mut RecordScan) -> Option<usize> {
     unsafe {
         use std::arch::aarch64::*;
 
-        let mut i = 0;
+        let mut i = scan.pos;
         let len = buffer.len();
 
         let pattern_n = vdupq_n_u8(b'\n');
         let pattern_r = vdupq_n_u8(b'\r');
         // Only create separator pattern if separator is different from \r and \n
         let check_separator = separator != b'\r' && separator != b'\n';
-        let pattern_sep = if check_separator {
-            Some(vdupq_n_u8(separator))
-        } else {
-            None
-        };
+        let pattern_sep = vdupq_n_u8(if check_separator { separator } else { b'\n' });
+        let check_string = string_separator != 0;
+        let pattern_quote = vdupq_n_u8(if check_string { string_separator } else { b'\n' });
+        let mut in_string = scan.in_string;
 
         while i + 16 <= len {
             let chunk = vld1q_u8(buffer.as_ptr().add(i));
+            let cmp_quote = vceqq_u8(chunk, pattern_quote);
+            // Whole chunk inside a quoted field: breaks are data
+            if in_string && vmaxvq_u8(cmp_quote) == 0 {
+                i += 16;
+                continue;
+            }
+
             let cmp_n = vceqq_u8(chunk, pattern_n);
             let cmp_r = vceqq_u8(chunk, pattern_r);
-            let cmp_sep = if let Some(pattern_sep) = pattern_sep {
-                Some(vceqq_u8(chunk, pattern_sep))
-            } else {
-                None
-            };
+            let cmp_sep = vceqq_u8(chunk, pattern_sep);
+            let cmp_any = vorrq_u8(vorrq_u8(cmp_n, cmp_r), vorrq_u8(cmp_sep, cmp_quote));
 
-            let mask_n = vmaxvq_u8(cmp_n);
-            let mask_r = vmaxvq_u8(cmp_r);
-            let mask_sep = cmp_sep.map_or(0, |c| vmaxvq_u8(c));
-
-            if mask_n != 0 || mask_r != 0 || mask_sep != 0 {
-                let mut tmp_n = [0u8; 16];
-                let mut tmp_r = [0u8; 16];
-                let mut tmp_sep = [0u8; 16];
-
-                vst1q_u8(tmp_n.as_mut_ptr(), cmp_n);
-                vst1q_u8(tmp_r.as_mut_ptr(), cmp_r);
-                if let Some(cmp) = cmp_sep {
-                    vst1q_u8(tmp_sep.as_mut_ptr(), cmp);
-                }
+            if vmaxvq_u8(cmp_any) != 0 {
+                let mut tmp = [0u8; 16];
+                vst1q_u8(tmp.as_mut_ptr(), cmp_any);
 
-                for j in 0..16 {
-                    if tmp_r[j] == 0xFF {
-                        if i + j + 1 < len && buffer[i + j + 1] == b'\n' {
-                            return i + j + 2; // \r\n combo
-                        } else {
-                            return i + j + 1; // Solo \r
+                for (j, hit) in tmp.iter().enumerate() {
+                    if *hit == 0xFF {
+                        if let Some(end) = classify_break_byte(buffer, i + j, separator, string_separator, &mut in_string) {
+                            return Some(end);
                         }
-                    } else if tmp_n[j] == 0xFF {
-                        return i + j + 1; // Solo \n
-                    } else if check_separator && tmp_sep[j] == 0xFF {
-                        return i + j + 1; // Custom separator
                     }
                 }
             }
 
             i += 16;
         }
-        while i < len {
-            match buffer[i] {
-                b'\r' if i + 1 < len && buffer[i + 1] == b'\n' => return i + 2,
-                b'\r' | b'\n' => return i + 1,
-                byte if check_separator && byte == separator => return i + 1,
-                _ => i += 1,
+
+        scan.in_string = in_string;
+        locate_line_break_scalar(buffer, i, separator, string_separator, scan)
+    }
+}
+
+//=================================================================//
+//=====================   PRIVATE FUNCTIONS   =====================//
+//=================================================================//
+
+/// ## Classify Break Byte
+/// - Inspects a candidate byte found by a SIMD mask.
+/// - Toggles the quote state on `string_separator`, and returns the end of the record if the byte is a line break
+///   outside quotes.
+#[inline(always)]
+fn classify_break_byte(buffer: &[u8], pos: usize, separator: u8, string_separator: u8, in_string: &mut bool) -> Option<usize> {
+    let byte = buffer[pos];
+    if string_separator != 0 && byte == string_separator {
+        *in_string = !*in_string;
+        return None;
+    }
+    if *in_string {
+        return None;
+    }
+    match byte {
+        b'\r' if pos + 1 < buffer.len() && buffer[pos + 1] == b'\n' => Some(pos + 2),
+        b'\r' | b'\n' => Some(pos + 1),
+        _ if byte == separator => Some(pos + 1),
+        _ => None,
+    }
+}
+
+/// ## Needs Quotes Scalar
+/// - Byte by byte version of `needs_quotes`, used on short fields and SIMD tails.
+#[inline(always)]
+fn needs_quotes_scalar(field: &[u8], delimiter: u8, string_separator: u8) -> bool {
+    field
+        .iter()
+        .any(|b| *b == delimiter || *b == string_separator || *b == b'\n' || *b == b'\r')
+}
+
+/// ## Locate Line Break Scalar
+/// - Byte by byte tail used by the SIMD functions, starting at `start` with the quote state of `scan`.
+/// - Returns `None` if no break is found, and `scan` then points to the end of `buffer`.
+#[inline(always)]
+fn locate_line_break_scalar(buffer: &[u8], start: usize, separator: u8, string_separator: u8, scan: &mut RecordScan) -> Option<usize> {
+    for pos in start..buffer.len() {
+        if let Some(end) = classify_break_byte(buffer, pos, separator, string_separator, &mut scan.in_string) {
+            return Some(end);
+        }
+    }
+    scan.pos = buffer.len();
+    None
+}
+
+/// ## Resume Line Break Memchr3
+/// - memchr3 version of `resume_line_break`. Jumps from quote to quote inside quoted fields.
+fn resume_line_break_memchr3(slice: &[u8], separator: u8, string_separator: u8, scan: &mut RecordScan) -> Option<usize> {
+    let check_separator = separator != b'\r' && separator != b'\n';
+    let find_break = |haystack: &[u8]| {
+        if check_separator {
+            memchr3(b'\n', b'\r', separator, haystack)
+        } else {
+            memchr2(b'\n', b'\r', haystack)
+        }
+    };
+    let break_end = |i: usize| {
+        if slice[i] == b'\r' && slice.get(i + 1) == Some(&b'\n') {
+            i + 2
+        } else {
+            i + 1
+        }
+    };
+    let mut pos = scan.pos;
+    loop {
+        if scan.in_string {
+            // Jump to the closing quote. An escaped quote ("") closes and reopens.
+            match memchr(string_separator, &slice[pos..]) {
+                Some(close) => {
+                    pos += close + 1;
+                    scan.in_string = false;
+                }
+                None => break,
+            }
+        }
+        let brk = find_break(&slice[pos..]).map(|b| pos + b);
+        let end = brk.unwrap_or(slice.len());
+        let open = if string_separator == 0 { None } else { memchr(string_separator, &slice[pos..end]) };
+        match open {
+            // No quote before the break, so the break is a real one
+            None if brk.is_some() => return brk.map(break_end),
+            None => break,
+            Some(open) => {
+                pos += open + 1;
+                scan.in_string = true;
+            }
+        }
+    }
+    scan.pos = slice.len();
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const QUOTED: &[u8] = b"1,\"Main St\nApt 4\",x\r\n2,\"He said \"\"hi\r\n\"\"\",y\n3,z";
+
+    #[test]
+    fn test_get_cleaned_only_outer_quotes() {
+        assert_eq!(get_cleaned(b"\"abc\"", b'"'), b"abc");
+        assert_eq!(get_cleaned(b"a\"b\"c", b'"'), b"a\"b\"c");
+        assert_eq!(get_cleaned(b"\"", b'"'), b"\"");
+        assert_eq!(get_cleaned(b"\"abc\"", 0u8), b"\"abc\"");
+    }
+
+    #[test]
+    fn test_unescape_quotes() {
+        assert!(matches!(unescape_quotes(b"plain", b'"'), Cow::Borrowed(_)));
+        assert_eq!(unescape_quotes(b"He said \"\"hi\"\"", b'"').as_ref(), b"He said \"hi\"");
+        assert_eq!(unescape_quotes(b"\"\"\"\"", b'"').as_ref(), b"\"\"");
+        assert_eq!(unescape_quotes(b"lone\"", b'"').as_ref(), b"lone\"");
+    }
+
+    #[test]
+    fn test_locate_memchr3_skips_quoted_breaks() {
+        let first = locate_line_break_memchr3(QUOTED, 0, b'\n', b'"');
+        assert_eq!(&QUOTED[..first], b"1,\"Main St\nApt 4\",x\r\n");
+        let second = locate_line_break_memchr3(&QUOTED[first..], first, b'\n', b'"');
+        assert_eq!(&QUOTED[first..second], b"2,\"He said \"\"hi\r\n\"\"\",y\n");
+        // Last record has no line break
+        assert_eq!(locate_line_break_memchr3(&QUOTED[second..], second, b'\n', b'"'), 0);
+    }
+
+    #[test]
+    fn test_locate_memchr3_without_string_separator() {
+        let first = locate_line_break_memchr3(QUOTED, 0, b'\n', 0u8);
+        assert_eq!(&QUOTED[..first], b"1,\"Main St\n");
+    }
+
+    #[test]
+    fn test_locate_terminator() {
+        let data = b"a\nb,\"x\r\ny\"\r\nc";
+        assert_eq!(locate_terminator(data, Delimiter::CRLF, b'"'), 12);
+        assert_eq!(locate_terminator(data, Delimiter::CRLF, 0), 8);
+        assert_eq!(locate_terminator(b"a\x1Fb\x1Ec", Delimiter::byte(0x1E), 0), 4);
+        let eor = Delimiter::new(b"<EOR>").unwrap();
+        assert_eq!(locate_terminator(b"a<EO<EOR>b", eor, b'"'), 9);
+        assert_eq!(locate_terminator(b"a,b", eor, b'"'), 0);
+        assert_eq!(locate_terminator(b"\"a<EOR>", eor, b'"'), 0);
+    }
+
+    #[test]
+    fn test_locate_memchr3_unterminated_quote() {
+        assert_eq!(locate_line_break_memchr3(b"1,\"open\n2,3\n", 0, b'\n', b'"'), 0);
+    }
+
+    #[test]
+    fn test_locate_scalar_skips_quoted_breaks() {
+        let first = locate_line_break_scalar(QUOTED, 0, b'\n', b'"', &mut RecordScan::default()).unwrap();
+        assert_eq!(&QUOTED[..first], b"1,\"Main St\nApt 4\",x\r\n");
+        let mut scan = RecordScan::default();
+        assert_eq!(locate_line_break_scalar(b"a\"b\nc", 0, b'\n', b'"', &mut scan), None);
+        assert_eq!(scan, RecordScan { pos: 5, in_string: true });
+    }
+
+    #[test]
+    fn test_resume_matches_full_scan() {
+        for force_memchr3 in [false, true].iter().copied() {
+            // Every prefix is scanned once, resuming from the previous state
+            let mut scan = RecordScan::default();
+            let found = (1..=QUOTED.len())
+                .find_map(|len| resume_line_break(&QUOTED[..len], b'\n', b'"', force_memchr3, &mut scan))
+                .unwrap();
+            assert_eq!(&QUOTED[..found], b"1,\"Main St\nApt 4\",x\r");
+            let mut scan = RecordScan::default();
+            let found = (1..=QUOTED.len())
+                .find_map(|len| resume_terminator(&QUOTED[..len], Delimiter::CRLF, b'"', &mut scan))
+                .unwrap();
+            assert_eq!(&QUOTED[..found], b"1,\"Main St\nApt 4\",x\r\n");
+        }
+    }
+
+    #[test]
+    fn test_find_record_end() {
+        let data = b"a,\"b\nc\",d\r\n\"e\"\"\r\n\",f\r\nlast";
+        let crlf = Delimiter::new(b"\r\n").unwrap();
+        assert_eq!(find_record_end(data, 0, crlf, b'"', false), (9, 11));
+        assert_eq!(find_record_end(data, 11, crlf, b'"', false), (20, 22));
+        assert_eq!(find_record_end(data, 22, crlf, b'"', false), (data.len(), data.len()));
+        // Without string separator the quoted break splits the record
+        assert_eq!(find_record_end(data, 0, Delimiter::byte(b'\n'), 0, false), (4, 5));
+        // Starting inside the quoted field of the first record
+        assert!(ends_inside_quotes(&data[..4], b'"'));
+        assert_eq!(find_record_end(data, 4, crlf, b'"', true), (9, 11));
+    }
+
+    #[test]
+    fn test_needs_quotes() {
+        let long = "x".repeat(70);
+        for (special, expected) in [("", false), (",", true), ("\"", true), ("\n", true), ("\r", true), (";", false)].iter() {
+            for at in [0usize, 31, 32, 69].iter() {
+                let mut field = long.clone();
+                field.insert_str(*at, special);
+                assert_eq!(needs_quotes(field.as_bytes(), b',', b'"'), *expected, "{:?} at {}", special, at);
             }
+            assert_eq!(needs_quotes(special.as_bytes(), b',', b'"'), *expected);
         }
+    }
 
-        buffer.len()
+    #[cfg(target_arch = "x86_64")]
+    #[test]
+    fn test_locate_avx2_matches_memchr3() {
+        if !is_x86_feature_detected!("avx2") {
+            return;
+        }
+        // Quoted breaks placed on both sides of the 32 byte chunk boundaries
+        let mut data = Vec::new();
+        for i in 0..40 {
+            data.extend_from_slice(format!("{},\"{}\n{}\",end\r\n", i, "x".repeat(i), "y".repeat(40 - i)).as_bytes());
+        }
+        let mut cursor = 0;
+        while cursor < data.len() {
+            let simd = cursor + unsafe { locate_line_break_avx2(&data[cursor..], b'\n', b'"') };
+            let scalar = locate_line_break_memchr3(&data[cursor..], cursor, b'\n', b'"');
+            assert_eq!(simd, scalar);
+            assert!(data[..simd].ends_with(b",end\r\n"));
+            cursor = simd;
+        }
     }
 }
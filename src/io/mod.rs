@@ -1,2 +1,3 @@
 pub mod parser;
-pub(crate) mod number_parser;
\ No newline at end of file
+pub(crate) mod number_parser;
+pub(crate) mod number_formatter;
\ No newline at end of file
@@ -0,0 +1,59 @@
+/// Buffer big enough for any `u64` or `i64`, sign included.
+pub(crate) const INTEGER_BUFFER_SIZE: usize = 20;
+
+/// Two ASCII digits for every number from `00` to `99`.
+const DIGIT_PAIRS: &[u8; 200] = b"\
+    0001020304050607080910111213141516171819\
+    2021222324252627282930313233343536373839\
+    4041424344454647484950515253545556575859\
+    6061626364656667686970717273747576777879\
+    8081828384858687888990919293949596979899";
+
+/// Writes the decimal digits of `value` at the end of `buffer`, two at a time. Returns the used part.
+#[inline(always)]
+pub(crate) fn format_u64(buffer: &mut [u8; INTEGER_BUFFER_SIZE], mut value: u64) -> &[u8] {
+    let mut start = buffer.len();
+    while value >= 100 {
+        let pair = (value % 100) as usize * 2;
+        value /= 100;
+        start -= 2;
+        buffer[start..start + 2].copy_from_slice(&DIGIT_PAIRS[pair..pair + 2]);
+    }
+    if value >= 10 {
+        let pair = value as usize * 2;
+        start -= 2;
+        buffer[start..start + 2].copy_from_slice(&DIGIT_PAIRS[pair..pair + 2]);
+    } else {
+        start -= 1;
+        buffer[start] = b'0' + value as u8;
+    }
+    &buffer[start..]
+}
+
+/// Same as `format_u64`, with a leading `-` for negative values.
+#[inline(always)]
+pub(crate) fn format_i64(buffer: &mut [u8; INTEGER_BUFFER_SIZE], value: i64) -> &[u8] {
+    let digits = format_u64(buffer, value.unsigned_abs()).len();
+    let mut start = buffer.len() - digits;
+    if value < 0 {
+        start -= 1;
+        buffer[start] = b'-';
+    }
+    &buffer[start..]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_integers() {
+        let mut buffer = [0u8; INTEGER_BUFFER_SIZE];
+        for value in [0u64, 7, 10, 99, 100, 12345, u64::MAX].iter() {
+            assert_eq!(format_u64(&mut buffer, *value), value.to_string().as_bytes());
+        }
+        for value in [0i64, -1, 42, -100, i64::MAX, i64::MIN].iter() {
+            assert_eq!(format_i64(&mut buffer, *value), value.to_string().as_bytes());
+        }
+    }
+}
@@ -253,6 +253,65 @@ pub fn parse_f64(bytes: &[u8]) -> f64 {
     parse_f64_fallback(bytes)
 }
 
+/// ## Checked u64 parser
+/// - Accepts an optional leading `+` followed by ASCII digits.
+/// - Returns `None` on empty input, non digit bytes or overflow.
+#[inline(always)]
+pub fn try_parse_u64(bytes: &[u8]) -> Option<u64> {
+    let digits = match bytes.first() {
+        Some(b'+') => &bytes[1..],
+        _ => bytes,
+    };
+    if digits.is_empty() {
+        return None;
+    }
+    let mut result = 0u64;
+    for &b in digits {
+        if !b.is_ascii_digit() {
+            return None;
+        }
+        result = result.checked_mul(10)?.checked_add((b - b'0') as u64)?;
+    }
+    Some(result)
+}
+
+/// ## Checked i64 parser
+/// - Accepts an optional leading `+` or `-` followed by ASCII digits.
+/// - Returns `None` on empty input, non digit bytes or overflow.
+#[inline(always)]
+pub fn try_parse_i64(bytes: &[u8]) -> Option<i64> {
+    let (neg, digits) = match bytes.first() {
+        Some(b'-') => (true, &bytes[1..]),
+        Some(b'+') => (false, &bytes[1..]),
+        _ => (false, bytes),
+    };
+    if digits.is_empty() {
+        return None;
+    }
+    // Accumulate in negative, so i64::MIN does not overflow
+    let mut result = 0i64;
+    for &b in digits {
+        if !b.is_ascii_digit() {
+            return None;
+        }
+        result = result.checked_mul(10)?.checked_sub((b - b'0') as i64)?;
+    }
+    if neg { Some(result) } else { result.checked_neg() }
+}
+
+/// ## Checked f64 parser
+/// - Returns `None` if the bytes are not a valid float literal.
+#[inline(always)]
+pub fn try_parse_f64(bytes: &[u8]) -> Option<f64> {
+    std::str::from_utf8(bytes).ok()?.parse::<f64>().ok()
+}
+
+/// ## Checked f32 parser
+/// - Returns `None` if the bytes are not a valid float literal.
+#[inline(always)]
+pub fn try_parse_f32(bytes: &[u8]) -> Option<f32> {
+    std::str::from_utf8(bytes).ok()?.parse::<f32>().ok()
+}
 
 #[cfg(test)]
 mod tests {
@@ -290,5 +349,24 @@ mod tests {
         assert_eq!(parse_f64(b"1e100"), 1e100);
     }
 
+    #[test]
+    fn test_try_parse_integers() {
+        assert_eq!(try_parse_u64(b"18446744073709551615"), Some(u64::MAX));
+        assert_eq!(try_parse_u64(b"18446744073709551616"), None);
+        assert_eq!(try_parse_u64(b"+42"), Some(42));
+        assert_eq!(try_parse_u64(b"-1"), None);
+        assert_eq!(try_parse_i64(b"-9223372036854775808"), Some(i64::MIN));
+        assert_eq!(try_parse_i64(b"9223372036854775808"), None);
+        assert_eq!(try_parse_i64(b"-"), None);
+        assert_eq!(try_parse_i64(b""), None);
+        assert_eq!(try_parse_i64(b"12a"), None);
+    }
 
-}
\ No newline at end of file
+    #[test]
+    fn test_try_parse_floats() {
+        assert_eq!(try_parse_f64(b"-2.5e3"), Some(-2500.0));
+        assert_eq!(try_parse_f32(b"1.5"), Some(1.5));
+        assert_eq!(try_parse_f64(b"1,5"), None);
+        assert_eq!(try_parse_f64(b""), None);
+    }
+}
@@ -4,9 +4,16 @@
 #[inline(always)]
 #[allow(dead_code)]
 pub fn parse_bool_str(input: &str) -> bool {
+    try_parse_bool_str(input).unwrap_or(false)
+}
+
+/// ## Checked boolean parser from str
+/// - Returns `None` if the value is not a known boolean literal.
+#[inline(always)]
+pub fn try_parse_bool_str(input: &str) -> Option<bool> {
     match input.to_ascii_lowercase().as_str() {
-        "true" | "1" | "verdadero" | "verdadeiro" | "whar" | "vrai" | "waar" => true,
-        "false" | "0" | "falso" | "gefälscht" | "faux" | "nep" => false,
-        _ => false,
+        "true" | "1" | "verdadero" | "verdadeiro" | "whar" | "vrai" | "waar" => Some(true),
+        "false" | "0" | "falso" | "gefälscht" | "faux" | "nep" => Some(false),
+        _ => None,
     }
 }
\ No newline at end of file
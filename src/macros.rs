@@ -175,3 +175,113 @@ macro_rules! is_numeric_like {
         $row.get_index($idx).is_numeric_like()
     };
 }
+
+/// ## Macro `try_get_i8!`
+/// - Retrieves the field value as `Result<i8, CsvError>`.
+/// - Fails on non numeric input or overflow, reporting record and column.
+#[macro_export]
+macro_rules! try_get_i8 {
+    ($row:expr, $idx:expr) => {
+        $row.get_index($idx).try_get_i8()
+    };
+}
+
+/// ## Macro `try_get_u8!`
+/// - Retrieves the field value as `Result<u8, CsvError>`.
+/// - Fails on non numeric input or overflow, reporting record and column.
+#[macro_export]
+macro_rules! try_get_u8 {
+    ($row:expr, $idx:expr) => {
+        $row.get_index($idx).try_get_u8()
+    };
+}
+
+/// ## Macro `try_get_i16!`
+/// - Retrieves the field value as `Result<i16, CsvError>`.
+/// - Fails on non numeric input or overflow, reporting record and column.
+#[macro_export]
+macro_rules! try_get_i16 {
+    ($row:expr, $idx:expr) => {
+        $row.get_index($idx).try_get_i16()
+    };
+}
+
+/// ## Macro `try_get_u16!`
+/// - Retrieves the field value as `Result<u16, CsvError>`.
+/// - Fails on non numeric input or overflow, reporting record and column.
+#[macro_export]
+macro_rules! try_get_u16 {
+    ($row:expr, $idx:expr) => {
+        $row.get_index($idx).try_get_u16()
+    };
+}
+
+/// ## Macro `try_get_i32!`
+/// - Retrieves the field value as `Result<i32, CsvError>`.
+/// - Fails on non numeric input or overflow, reporting record and column.
+#[macro_export]
+macro_rules! try_get_i32 {
+    ($row:expr, $idx:expr) => {
+        $row.get_index($idx).try_get_i32()
+    };
+}
+
+/// ## Macro `try_get_u32!`
+/// - Retrieves the field value as `Result<u32, CsvError>`.
+/// - Fails on non numeric input or overflow, reporting record and column.
+#[macro_export]
+macro_rules! try_get_u32 {
+    ($row:expr, $idx:expr) => {
+        $row.get_index($idx).try_get_u32()
+    };
+}
+
+/// ## Macro `try_get_i64!`
+/// - Retrieves the field value as `Result<i64, CsvError>`.
+/// - Fails on non numeric input or overflow, reporting record and column.
+#[macro_export]
+macro_rules! try_get_i64 {
+    ($row:expr, $idx:expr) => {
+        $row.get_index($idx).try_get_i64()
+    };
+}
+
+/// ## Macro `try_get_u64!`
+/// - Retrieves the field value as `Result<u64, CsvError>`.
+/// - Fails on non numeric input or overflow, reporting record and column.
+#[macro_export]
+macro_rules! try_get_u64 {
+    ($row:expr, $idx:expr) => {
+        $row.get_index($idx).try_get_u64()
+    };
+}
+
+/// ## Macro `try_get_f32!`
+/// - Retrieves the field value as `Result<f32, CsvError>`.
+/// - Fails on non numeric input, reporting record and column. Out of range values saturate to infinity.
+#[macro_export]
+macro_rules! try_get_f32 {
+    ($row:expr, $idx:expr) => {
+        $row.get_index($idx).try_get_f32()
+    };
+}
+
+/// ## Macro `try_get_f64!`
+/// - Retrieves the field value as `Result<f64, CsvError>`.
+/// - Fails on non numeric input, reporting record and column. Out of range values saturate to infinity.
+#[macro_export]
+macro_rules! try_get_f64 {
+    ($row:expr, $idx:expr) => {
+        $row.get_index($idx).try_get_f64()
+    };
+}
+
+/// ## Macro `try_get_bool!`
+/// - Retrieves the field value as `Result<bool, CsvError>`.
+/// - Fails if the value is not a known boolean literal.
+#[macro_export]
+macro_rules! try_get_bool {
+    ($row:expr, $idx:expr) => {
+        $row.get_index($idx).try_get_boolean()
+    };
+}
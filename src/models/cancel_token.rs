@@ -0,0 +1,67 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// ## CancelToken Struct
+/// - Flag shared by the workers of a scan: once cancelled, every worker stops before its next row.
+/// - Clones share the same flag, so a token can be cancelled from another thread (a timeout, a Ctrl-C handler...).
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancelToken {
+    /// ## Constructor
+    /// - Make a new token, not cancelled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// ## Cancel
+    /// - Asks every scan using the token to stop.
+    #[inline(always)]
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// ## Is Cancelled
+    /// - Gets if the token was cancelled.
+    #[inline(always)]
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
+
+/// ## ScanOutcome enum
+/// - Result of a scan that can be stopped: every row was processed, or it stopped early.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScanOutcome {
+    /// Every row was processed.
+    Completed,
+    /// A closure returned `ControlFlow::Break`, or the token was cancelled, before the last row.
+    Cancelled,
+}
+
+impl ScanOutcome {
+    /// ## Is Completed
+    /// - Returns `true` if every row was processed.
+    #[inline(always)]
+    pub fn is_completed(&self) -> bool {
+        *self == ScanOutcome::Completed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clones_share_the_flag() {
+        let token = CancelToken::new();
+        let clone = token.clone();
+        assert!(!clone.is_cancelled());
+        std::thread::spawn(move || clone.cancel()).join().unwrap();
+        assert!(token.is_cancelled());
+        assert!(ScanOutcome::Completed.is_completed());
+        assert!(!ScanOutcome::Cancelled.is_completed());
+    }
+}
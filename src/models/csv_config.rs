@@ -1,4 +1,8 @@
 use crate::decoders::decoders::Encoding;
+use crate::decoders::detect::DETECTION_SAMPLE_SIZE;
+use crate::models::delimiter::Delimiter;
+use crate::models::record_terminator::{RecordTerminator, ASCII_UNIT_SEPARATOR};
+use crate::models::trim::Trim;
 
 #[derive(Debug,Clone)]
 #[allow(dead_code)]
@@ -10,12 +14,26 @@ pub struct CsvConfig {
     pub force_memcach3 : bool,
     /// A u8 with the byte of the chars that can be considered as delimiter.
     pub delimiter: u8,
+    /// Multi-byte delimiter (`||`, `~|~`...). If set, is used instead of `delimiter`. Set it with `with_delimiter`.
+    pub delimiter_sequence: Option<Delimiter>,
     /// Allow to define a string delimiter. Use `0u8` if you want to disable it
     pub string_separator: u8,
     /// Defines the line break char
     pub line_break: u8,
+    /// Defines how records end. `Any` (default) splits on `\r`, `\n`, `\r\n` and `line_break`.
+    pub terminator: RecordTerminator,
     /// Defines de encoding used to open the file.
     pub encoding: Encoding,
+    /// If `true`, the readers replace `encoding` with the one detected from the first 64KB of the input.
+    pub detect_encoding: bool,
+    /// Defines if the first record is a header. If `false`, column names are generated (`column_0`, `column_1`, ...)
+    pub has_header: bool,
+    /// Records starting with this prefix (like `#`) are comments, and are skipped. `None` disables comments.
+    pub comment_prefix: Option<Delimiter>,
+    /// If `true`, empty records (blank lines) are skipped. If `false`, they are returned as empty rows.
+    pub skip_empty_records: bool,
+    /// Defines the whitespace removed around the fields, outside quotes.
+    pub trim: Trim,
 }
 
 
@@ -27,23 +45,39 @@ impl Default for CsvConfig {
     /// //Import zone
     /// use csv_lib::decoders::decoders::Encoding;
     /// use csv_lib::models::csv_config::CsvConfig;
+    /// use csv_lib::models::record_terminator::RecordTerminator;
+    /// use csv_lib::models::trim::Trim;
     ///
     /// //Default CsvConfig construction
     /// let a = CsvConfig{
     ///  force_memcach3 : false,
     ///   delimiter : b';',
+    ///   delimiter_sequence : None,
     ///   string_separator:0u8,
     ///   line_break: b'\n',
-    ///   encoding : Encoding::Windows1252
+    ///   terminator : RecordTerminator::Any,
+    ///   encoding : Encoding::Windows1252,
+    ///   detect_encoding : false,
+    ///   has_header : false,
+    ///   comment_prefix : None,
+    ///   skip_empty_records : false,
+    ///   trim : Trim::None,
     /// };
     /// ```
     fn default() -> Self {
         Self {
             force_memcach3 : false,
             delimiter : b';',
+            delimiter_sequence: None,
             string_separator:0u8,
             line_break: b'\n',
+            terminator: RecordTerminator::Any,
             encoding: Encoding::Windows1252,
+            detect_encoding: false,
+            has_header: false,
+            comment_prefix: None,
+            skip_empty_records: false,
+            trim: Trim::None,
         }
     }
 }
@@ -64,9 +98,136 @@ impl CsvConfig {
         Self {
             force_memcach3,
             delimiter,
+            delimiter_sequence: None,
             string_separator: string_separators,
             line_break,
+            terminator: RecordTerminator::Any,
             encoding,
+            detect_encoding: false,
+            has_header: false,
+            comment_prefix: None,
+            skip_empty_records: false,
+            trim: Trim::None,
         }
     }
+
+    #[inline(always)]
+    /// ## With Header
+    /// - Sets if the first record of the file is a header.
+    pub fn with_header(mut self, has_header: bool) -> Self {
+        self.has_header = has_header;
+        self
+    }
+
+    #[inline(always)]
+    /// ## With Encoding Detection
+    /// - If `true`, the readers inspect the first 64KB of the input and replace `encoding` with the detected one
+    ///   (see `Encoding::detect`). Read it back with `get_config().encoding`.
+    pub fn with_encoding_detection(mut self, detect: bool) -> Self {
+        self.detect_encoding = detect;
+        self
+    }
+
+    #[inline(always)]
+    /// ## With Delimiter
+    /// - Sets the field delimiter. Accepts a single byte, or a `Delimiter` with a byte sequence.
+    /// ### Code Example:
+    /// ```
+    /// use csv_lib::models::csv_config::CsvConfig;
+    /// use csv_lib::models::delimiter::Delimiter;
+    ///
+    /// let cfg = CsvConfig::default().with_delimiter(Delimiter::new(b"~|~").unwrap());
+    /// assert_eq!(cfg.field_delimiter().as_bytes(), b"~|~");
+    /// ```
+    pub fn with_delimiter(mut self, delimiter: impl Into<Delimiter>) -> Self {
+        let delimiter = delimiter.into();
+        self.delimiter = delimiter.first();
+        self.delimiter_sequence = if delimiter.is_single_byte() { None } else { Some(delimiter) };
+        self
+    }
+
+    #[inline(always)]
+    /// ## With Terminator
+    /// - Sets how records end.
+    /// - `RecordTerminator::Ascii` also sets the field delimiter to the unit separator (0x1F).
+    /// ### Code Example:
+    /// ```
+    /// use csv_lib::models::csv_config::CsvConfig;
+    /// use csv_lib::models::record_terminator::RecordTerminator;
+    ///
+    /// // Bare `\n` can be part of unquoted fields
+    /// let cfg = CsvConfig::default().with_terminator(RecordTerminator::Crlf);
+    /// assert_eq!(cfg.record_delimiter().as_bytes(), b"\r\n");
+    /// ```
+    pub fn with_terminator(mut self, terminator: RecordTerminator) -> Self {
+        if terminator == RecordTerminator::Ascii {
+            self = self.with_delimiter(ASCII_UNIT_SEPARATOR);
+        }
+        self.terminator = terminator;
+        self
+    }
+
+    #[inline(always)]
+    /// ## Record Delimiter
+    /// - Gets the sequence ending the records, to use with the parallel functions.
+    /// - For `RecordTerminator::Any` returns `line_break`.
+    pub fn record_delimiter(&self) -> Delimiter {
+        self.terminator.sequence().unwrap_or_else(|| Delimiter::byte(self.line_break))
+    }
+
+    #[inline(always)]
+    /// ## With Comment Prefix
+    /// - Records starting with the prefix (`b'#'`, or a `Delimiter` like `b"//"`) are skipped by the readers.
+    pub fn with_comment_prefix(mut self, prefix: impl Into<Delimiter>) -> Self {
+        self.comment_prefix = Some(prefix.into());
+        self
+    }
+
+    #[inline(always)]
+    /// ## With Skip Empty Records
+    /// - If `true`, the readers skip empty records (blank lines). If `false`, they are returned as empty rows.
+    pub fn with_skip_empty_records(mut self, skip: bool) -> Self {
+        self.skip_empty_records = skip;
+        self
+    }
+
+    #[inline(always)]
+    /// ## With Trim
+    /// - Sets the whitespace removed around the fields (outside quotes), for `get_cleaned_slice`, the typed getters,
+    ///   `is_empty` and `is_numeric_like`.
+    pub fn with_trim(mut self, trim: Trim) -> Self {
+        self.trim = trim;
+        self
+    }
+
+    #[inline(always)]
+    /// ## Is Comment
+    /// - Returns `true` if the record starts with the comment prefix.
+    pub fn is_comment(&self, record: &[u8]) -> bool {
+        match self.comment_prefix {
+            Some(prefix) => prefix.matches_at(record, 0),
+            None => false,
+        }
+    }
+
+    #[inline(always)]
+    /// ## Field Delimiter
+    /// - Gets the delimiter used to split the fields: `delimiter_sequence` if set, or else `delimiter`.
+    pub fn field_delimiter(&self) -> Delimiter {
+        self.delimiter_sequence.unwrap_or_else(|| Delimiter::byte(self.delimiter))
+    }
+
+    /// ## Prepare Input
+    /// - Runs the encoding detection on the start of the input, if enabled.
+    /// - A UTF-16 BOM always sets the encoding, as the input can not be read as bytes.
+    /// - Returns the length of the BOM at the start of the input, to be skipped by the readers.
+    pub(crate) fn prepare_input(&mut self, head: &[u8]) -> usize {
+        let bom = Encoding::from_bom(head);
+        if self.detect_encoding {
+            self.encoding = Encoding::detect(&head[..head.len().min(DETECTION_SAMPLE_SIZE)]);
+        } else if let Some((encoding, _)) = bom.filter(|(encoding, _)| encoding.is_utf16()) {
+            self.encoding = encoding;
+        }
+        bom.map_or(0, |(_, len)| len)
+    }
 }
@@ -1,52 +1,228 @@
+use std::error::Error;
 use std::fmt::{Display, Formatter};
 use std::io;
-use std::io::Error;
+use std::sync::Arc;
+
+/// Maximum number of characters kept in the snippet of an error.
+const SNIPPET_LEN: usize = 80;
+
+/// ## CsvErrorKind
+/// - Category of a `CsvError`, to match on without parsing the message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CsvErrorKind {
+    /// Read or write failure of the underlying reader / writer.
+    Io,
+    /// The file can not be opened or mapped.
+    File,
+    /// A field can not be converted to the requested type.
+    Parse,
+    /// Bytes or rows can not be decoded (invalid text, missing columns...).
+    Decode,
+    /// A value can not be written as CSV.
+    Encode,
+    /// Invalid configuration, like an empty delimiter.
+    Config,
+}
+
+impl Display for CsvErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            CsvErrorKind::Io => "IO error",
+            CsvErrorKind::File => "File error",
+            CsvErrorKind::Parse => "Parse error",
+            CsvErrorKind::Decode => "Decode error",
+            CsvErrorKind::Encode => "Encode error",
+            CsvErrorKind::Config => "Config error",
+        };
+        f.write_str(name)
+    }
+}
 
-#[derive(Debug)]
-#[derive(Clone)]
-#[allow(dead_code)]
 /// ## CsvError
-/// - An enum, that handle the different types of error, that the library can produce.
-pub enum CsvError {
-    IO(String),
-    Parse(i32,i32,String),
-    Decode(String),
-    FileError(String),
-    Unknow
+/// - The error produced by the library: a kind, a message, and where it happened when it is known
+///   (record number, field index, byte offset, and a snippet of the record).
+/// - The error that caused it, if any, is chained through `std::error::Error::source`.
+/// - It is `Send + Sync + 'static`, so it works with `?`, `Box<dyn Error>` and `anyhow`.
+#[derive(Debug, Clone)]
+pub struct CsvError {
+    kind: CsvErrorKind,
+    message: String,
+    record: Option<usize>,
+    field: Option<usize>,
+    byte_offset: Option<usize>,
+    snippet: Option<String>,
+    source: Option<Arc<dyn Error + Send + Sync + 'static>>,
+}
+
+impl CsvError {
+    /// ## Constructor
+    /// - Creates an error of the given kind, without position.
+    pub fn new(kind: CsvErrorKind, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+            record: None,
+            field: None,
+            byte_offset: None,
+            snippet: None,
+            source: None,
+        }
+    }
+
+    /// ## With Record
+    /// - Sets the record number (1 based, header included) where the error happened.
+    pub fn with_record(mut self, record: usize) -> Self {
+        self.record = Some(record);
+        self
+    }
+
+    /// ## With Field
+    /// - Sets the zero based index of the field where the error happened.
+    pub fn with_field(mut self, field: usize) -> Self {
+        self.field = Some(field);
+        self
+    }
+
+    /// ## With Byte Offset
+    /// - Sets the position in the input where the error happened.
+    pub fn with_byte_offset(mut self, byte_offset: usize) -> Self {
+        self.byte_offset = Some(byte_offset);
+        self
+    }
+
+    /// ## With Snippet
+    /// - Keeps the start of the offending record (up to 80 characters), shown by `Display`.
+    pub fn with_snippet(mut self, bytes: &[u8]) -> Self {
+        let text = String::from_utf8_lossy(bytes);
+        let mut snippet: String = text.chars().take(SNIPPET_LEN).collect();
+        if snippet.len() < text.len() {
+            snippet.push_str("...");
+        }
+        self.snippet = Some(snippet);
+        self
+    }
+
+    /// ## With Source
+    /// - Chains the error that caused this one.
+    pub fn with_source(mut self, source: impl Error + Send + Sync + 'static) -> Self {
+        self.source = Some(Arc::new(source));
+        self
+    }
+
+    /// ## Kind
+    /// - Gets the category of the error.
+    pub fn kind(&self) -> CsvErrorKind {
+        self.kind
+    }
+
+    /// ## Message
+    /// - Gets the description of the error, without position.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// ## Record
+    /// - Gets the record number (1 based, header included), if known.
+    pub fn record(&self) -> Option<usize> {
+        self.record
+    }
+
+    /// ## Field
+    /// - Gets the zero based field index, if known.
+    pub fn field(&self) -> Option<usize> {
+        self.field
+    }
+
+    /// ## Byte Offset
+    /// - Gets the position in the input, if known.
+    pub fn byte_offset(&self) -> Option<usize> {
+        self.byte_offset
+    }
 
+    /// ## Snippet
+    /// - Gets the start of the offending record, if known.
+    pub fn snippet(&self) -> Option<&str> {
+        self.snippet.as_deref()
+    }
 }
 
 /// ## Display implementation
-/// - Implement the fmt function for the trait.
+/// - `Parse error at record 18,233,901, field 3, byte 1,024: failed to parse "x" as u8 (record: "7,x")`
 impl Display for CsvError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        match self {
-            CsvError::IO(e) => {
-                write!(f, "IO Error: {}", e)
-            }
-            CsvError::Parse(row,col , v) => {
-                write!(f, "Row {}, Column {}: Extracted value {}, failed", row, col, v)
-            }
-            CsvError::FileError(e) => {
-                write!(f, "File Error: {}", e)
-            }
-            CsvError::Decode(e) => {
-                write!(f, "Error decoding: {}", e)
-            }
-            CsvError::Unknow => {
-                write!(f, "Unknown error")
+        write!(f, "{}", self.kind)?;
+        let position = [("record", self.record), ("field", self.field), ("byte", self.byte_offset)];
+        let mut separator = " at";
+        for (name, value) in position.iter() {
+            if let Some(value) = value {
+                write!(f, "{} {} {}", separator, name, grouped(*value))?;
+                separator = ",";
             }
         }
+        write!(f, ": {}", self.message)?;
+        if let Some(snippet) = &self.snippet {
+            write!(f, " (record: {:?})", snippet)?;
+        }
+        Ok(())
     }
 }
 
-/// Default implent of std:err::Error for CsvError.
-impl std::error::Error for CsvError {}
+/// ## Error implementation
+/// - The chained error is returned by `source`.
+impl Error for CsvError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source.as_ref().map(|source| source.as_ref() as &(dyn Error + 'static))
+    }
+}
 
 /// ## Implements io::Error for CsvError.
-/// - Allows to cast an io::Error into a CsvError
+/// - Allows to cast an io::Error into a CsvError, keeping it as source. The message does not repeat it.
 impl From<io::Error> for CsvError {
-    fn from(value: Error) -> Self {
-        CsvError::IO(value.to_string())
+    fn from(value: io::Error) -> Self {
+        CsvError::new(CsvErrorKind::Io, "read or write failed").with_source(value)
+    }
+}
+
+/// Formats a number with thousands separators: `18233901` as `18,233,901`.
+fn grouped(value: usize) -> String {
+    let digits = value.to_string();
+    let head = (digits.len() - 1) % 3 + 1;
+    let mut out = String::with_capacity(digits.len() + digits.len() / 3);
+    out.push_str(&digits[..head]);
+    for start in (head..digits.len()).step_by(3) {
+        out.push(',');
+        out.push_str(&digits[start..start + 3]);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_and_source() {
+        let err = CsvError::new(CsvErrorKind::Parse, "failed to parse \"x\" as u8")
+            .with_record(18_233_901)
+            .with_field(3)
+            .with_byte_offset(1024)
+            .with_snippet(b"7,\"a\",x");
+        assert_eq!(
+            err.to_string(),
+            "Parse error at record 18,233,901, field 3, byte 1,024: failed to parse \"x\" as u8 (record: \"7,\\\"a\\\",x\")"
+        );
+        assert_eq!((err.kind(), err.record(), err.field(), err.byte_offset()), (CsvErrorKind::Parse, Some(18_233_901), Some(3), Some(1024)));
+        assert!(err.source().is_none());
+
+        let err = CsvError::from(io::Error::new(io::ErrorKind::UnexpectedEof, "truncated"));
+        assert_eq!(err.to_string(), "IO error: read or write failed");
+        assert_eq!(err.source().expect("source").to_string(), "truncated");
+        let boxed: Box<dyn Error + Send + Sync> = Box::new(err.clone());
+        assert_eq!(boxed.to_string(), err.to_string());
+
+        let long = CsvError::new(CsvErrorKind::Decode, "bad").with_snippet(&[b'a'; 100]);
+        assert_eq!(long.snippet().expect("snippet").len(), SNIPPET_LEN + 3);
+        assert_eq!(grouped(999), "999");
+        assert_eq!(grouped(1000), "1,000");
     }
-}
\ No newline at end of file
+}
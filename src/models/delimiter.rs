@@ -0,0 +1,141 @@
+use crate::models::csv_error::{CsvError, CsvErrorKind};
+use memchr::memchr;
+
+/// ## Delimiter struct
+/// - The field delimiter of a CSV file. Can be a single byte (`;`), or a byte sequence (`||`, `~|~`).
+/// - Stored inline (up to `Delimiter::MAX_LEN` bytes), so it is `Copy` and is cheap to pass to each row.
+/// - Single byte delimiters keep the `memchr` fast path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Delimiter {
+    bytes: [u8; Delimiter::MAX_LEN],
+    len: u8,
+}
+
+impl Delimiter {
+    /// Max number of bytes of a delimiter.
+    pub const MAX_LEN: usize = 8;
+
+    /// The `\r\n` sequence.
+    pub const CRLF: Delimiter = Delimiter {
+        bytes: [b'\r', b'\n', 0, 0, 0, 0, 0, 0],
+        len: 2,
+    };
+
+    /// ## Constructor
+    /// - Creates a delimiter from a byte sequence.
+    /// - Fails if the sequence is empty, or longer than `Delimiter::MAX_LEN`.
+    pub fn new(bytes: &[u8]) -> Result<Self, CsvError> {
+        if bytes.is_empty() || bytes.len() > Self::MAX_LEN {
+            return Err(CsvError::new(
+                CsvErrorKind::Config,
+                format!("Invalid delimiter {:?}: must have between 1 and {} bytes", bytes, Self::MAX_LEN),
+            ));
+        }
+        let mut inline = [0u8; Self::MAX_LEN];
+        inline[..bytes.len()].copy_from_slice(bytes);
+        Ok(Self {
+            bytes: inline,
+            len: bytes.len() as u8,
+        })
+    }
+
+    /// ## Byte
+    /// - Creates a single byte delimiter.
+    #[inline(always)]
+    pub const fn byte(byte: u8) -> Self {
+        let mut bytes = [0u8; Self::MAX_LEN];
+        bytes[0] = byte;
+        Self { bytes, len: 1 }
+    }
+
+    /// ## As Bytes
+    /// - Gets the bytes of the delimiter.
+    #[inline(always)]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len as usize]
+    }
+
+    /// ## First
+    /// - Gets the first byte of the delimiter.
+    #[inline(always)]
+    pub fn first(&self) -> u8 {
+        self.bytes[0]
+    }
+
+    /// ## Len
+    /// - Number of bytes of the delimiter.
+    #[inline(always)]
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    /// ## Is Single Byte
+    /// - Returns `true` if the delimiter has only one byte.
+    #[inline(always)]
+    pub fn is_single_byte(&self) -> bool {
+        self.len == 1
+    }
+
+    /// ## Matches At
+    /// - Returns `true` if the delimiter starts at `pos` inside `haystack`.
+    #[inline(always)]
+    pub(crate) fn matches_at(&self, haystack: &[u8], pos: usize) -> bool {
+        if self.len == 1 {
+            haystack.get(pos) == Some(&self.bytes[0])
+        } else {
+            pos <= haystack.len() && haystack[pos..].starts_with(self.as_bytes())
+        }
+    }
+
+    /// ## Find
+    /// - Finds the first position of the delimiter inside `haystack`.
+    #[inline(always)]
+    pub(crate) fn find(&self, haystack: &[u8]) -> Option<usize> {
+        let first = self.bytes[0];
+        if self.len == 1 {
+            return memchr(first, haystack);
+        }
+        let mut pos = 0usize;
+        while let Some(i) = memchr(first, &haystack[pos..]) {
+            if self.matches_at(haystack, pos + i) {
+                return Some(pos + i);
+            }
+            pos += i + 1;
+        }
+        None
+    }
+}
+
+impl Default for Delimiter {
+    /// ## Default for `Delimiter`
+    /// - Same as the default `CsvConfig` delimiter (`;`).
+    fn default() -> Self {
+        Self::byte(b';')
+    }
+}
+
+impl From<u8> for Delimiter {
+    #[inline(always)]
+    fn from(byte: u8) -> Self {
+        Self::byte(byte)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::delimiter::Delimiter;
+
+    #[test]
+    fn test_delimiter_find() {
+        let single = Delimiter::from(b';');
+        assert_eq!(single.find(b"ab;cd"), Some(2));
+        let multi = Delimiter::new(b"~|~").unwrap();
+        assert_eq!(multi.len(), 3);
+        assert_eq!(multi.find(b"a~|b~|~c"), Some(4));
+        assert_eq!(multi.find(b"a~|b~|"), None);
+        assert!(multi.matches_at(b"x~|~", 1));
+        assert!(Delimiter::new(b"").is_err());
+        assert!(Delimiter::new(b"123456789").is_err());
+    }
+}
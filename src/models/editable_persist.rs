@@ -1,15 +1,39 @@
-
-
 /// ## EditablePersist
 /// - A struct that holds a param.
 /// - This param is editable in each thread
 /// - Useful to acumulate info in each row iteration, without block the thread calling
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
-pub struct EditablePersist<T> where T: Clone {
+#[derive(Debug, Clone, Default)]
+pub struct EditablePersist<T> {
     target: T,
 }
 
+impl<T> EditablePersist<T> {
+    /// ## Constructor
+    /// - Wraps the value owned by a thread.
+    #[inline(always)]
+    pub fn new(target: T) -> Self {
+        Self { target }
+    }
 
+    /// ## Get
+    /// - Gets a reference to the value.
+    #[inline(always)]
+    pub fn get(&self) -> &T {
+        &self.target
+    }
 
+    /// ## Get Mut
+    /// - Gets a mutable reference to the value, to update it on each row.
+    #[inline(always)]
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.target
+    }
 
+    /// ## Into Inner
+    /// - Takes the value out, when the thread is done.
+    #[inline(always)]
+    pub fn into_inner(self) -> T {
+        self.target
+    }
+}
@@ -1,16 +1,24 @@
 use crate::decoders::decoders::Encoding;
-use crate::helpers::bytes_helper::{find_delimiter_bounds, get_cleaned};
+use crate::helpers::bytes_helper::{get_cleaned, unescape_quotes};
 use crate::io::number_parser::{
-    parse_f32, parse_f64, parse_i32, parse_i64, parse_i8, parse_u32, parse_u64,
+    parse_f32, parse_f64, parse_i32, parse_i64, parse_i8, parse_u32, parse_u64, try_parse_f32,
+    try_parse_f64, try_parse_i64, try_parse_u64,
 };
-use crate::io::parser::parse_bool_str;
+use crate::io::parser::{parse_bool_str, try_parse_bool_str};
+use crate::models::csv_error::{CsvError, CsvErrorKind};
+use crate::models::trim::Trim;
 use std::borrow::Cow;
+use std::convert::TryFrom;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 #[allow(dead_code)]
 pub struct Field<'mmap> {
     slice: &'mmap [u8],
     string_delimiter: u8,
+    record: usize,
+    column: usize,
+    offset: Option<usize>,
+    row: &'mmap [u8],
 }
 
 impl<'mmap> Field<'mmap> {
@@ -20,6 +28,10 @@ impl<'mmap> Field<'mmap> {
         Self {
             slice,
             string_delimiter,
+            record: 0,
+            column: 0,
+            offset: None,
+            row: &[],
         }
     }
 
@@ -29,8 +41,48 @@ impl<'mmap> Field<'mmap> {
         Self {
             slice: &[],
             string_delimiter,
+            record: 0,
+            column: 0,
+            offset: None,
+            row: &[],
         }
     }
+
+    /// ## With Position
+    /// - Sets the record number and the column index of the field, used on error reports.
+    pub fn with_position(mut self, record: usize, column: usize) -> Self {
+        self.record = record;
+        self.column = column;
+        self
+    }
+
+    /// ## With Row
+    /// - Sets the record holding the field, and the position of the field in the input, used on error reports.
+    pub(crate) fn with_row(mut self, row: &'mmap [u8], offset: Option<usize>) -> Self {
+        self.row = row;
+        self.offset = offset;
+        self
+    }
+
+    /// ## With Trim
+    /// - Removes the whitespace around the field (outside quotes), according to the policy.
+    /// - Rows apply `CsvConfig::trim` (or `ParallelConfig::trim`) already. Use it on fields built by hand.
+    pub fn with_trim(mut self, trim: Trim) -> Self {
+        self.slice = trim.apply(self.slice);
+        self
+    }
+
+    /// ## Get Record Number
+    /// - Gets the record number (1 based, header included) of the row that holds the field. `0` if unknown.
+    pub fn get_record_number(&self) -> usize {
+        self.record
+    }
+
+    /// ## Get Column Index
+    /// - Gets the zero based column index of the field.
+    pub fn get_column_index(&self) -> usize {
+        self.column
+    }
     /// ## Get Slice
     /// - Obtains a reference of the data.
     pub fn get_slice(&self) -> &[u8] {
@@ -44,12 +96,28 @@ impl<'mmap> Field<'mmap> {
         get_cleaned(self.slice, self.string_delimiter)
     }
 
+    /// ## Get Unescaped Slice
+    /// - Returns the content of the field, without the outer quotes, and with the escaped quotes (`""`) unescaped.
+    /// - Borrows the data if there is nothing to unescape, only allocates when escapes are present.
+    pub fn get_unescaped_slice(&self) -> Cow<'mmap, [u8]> {
+        let cleaned = self.get_cleaned_slice();
+        if cleaned.len() == self.slice.len() {
+            // Unquoted fields keep their bytes as they are
+            return Cow::Borrowed(cleaned);
+        }
+        unescape_quotes(cleaned, self.string_delimiter)
+    }
+
     /// ## Decode Function
     /// - Decode the referenced bytes into a Cow<str>
     /// - Need to define a `Encoding`
+    /// - Escaped quotes are unescaped.
     /// ### `returns`: Cow<str>
-    pub fn decode_bytes(&self, encoding: Encoding) -> Cow<str> {
-        encoding.decode(self.get_cleaned_slice())
+    pub fn decode_bytes(&self, encoding: Encoding) -> Cow<'mmap, str> {
+        match self.get_unescaped_slice() {
+            Cow::Borrowed(bytes) => encoding.decode(bytes),
+            Cow::Owned(bytes) => Cow::Owned(encoding.decode(&bytes).into_owned()),
+        }
     }
 
     /// ## Is Empty property
@@ -62,7 +130,7 @@ impl<'mmap> Field<'mmap> {
     /// Checks if the decoded text inside field, contains param input.
     pub fn contains_chars(&self, input: &str, encoding: Encoding) -> bool {
         //extract row bytes
-        let decoded = encoding.decode(self.get_cleaned_slice());
+        let decoded = self.decode_bytes(encoding);
         //get base
         let base = &decoded.as_ref();
         base.contains(input)
@@ -78,20 +146,32 @@ impl<'mmap> Field<'mmap> {
 
     /// ## Gets &str from utf8.
     /// - Almost null alloc
-    pub fn get_utf8_as_str(&self) -> &str {
+    /// - Escaped quotes (`""`) are kept, use `get_utf8_unescaped` to unescape them.
+    pub fn get_utf8_as_str(&self) -> &'mmap str {
         std::str::from_utf8(get_cleaned(self.slice, self.string_delimiter)).unwrap_or("")
     }
+
+    /// ## Gets Cow<str> from utf8, unescaped.
+    /// - Borrows if there are no escaped quotes, allocates otherwise.
+    pub fn get_utf8_unescaped(&self) -> Cow<'mmap, str> {
+        match self.get_unescaped_slice() {
+            Cow::Borrowed(bytes) => Cow::Borrowed(std::str::from_utf8(bytes).unwrap_or("")),
+            Cow::Owned(bytes) => Cow::Owned(String::from_utf8(bytes).unwrap_or_default()),
+        }
+    }
+
     /// ## Gets the Cow<&str> value
     /// - You provide a decoding
-    pub fn get_as_cow_decoded(&self, encoding: Encoding) -> Cow<str> {
-        encoding.decode(get_cleaned(self.slice, self.string_delimiter))
+    /// - Escaped quotes are unescaped.
+    pub fn get_as_cow_decoded(&self, encoding: Encoding) -> Cow<'mmap, str> {
+        self.decode_bytes(encoding)
     }
 
     /// ## Returns String (Allocates)
     /// - Decode bytes and return a string
+    /// - Escaped quotes are unescaped.
     pub fn get_as_string(&self, encoding: Encoding) -> String {
-        let enc = encoding.decode(get_cleaned(self.slice, self.string_delimiter));
-        String::from(enc)
+        self.decode_bytes(encoding).into_owned()
     }
 
     /// ## Parse i8
@@ -201,4 +281,160 @@ impl<'mmap> Field<'mmap> {
         let dec = self.get_utf8_as_str();
         parse_bool_str(dec)
     }
+
+    /// ## Try Parse i8
+    /// - Gets numeric value as i8.
+    /// - Returns a `CsvErrorKind::Parse` error on non numeric input or overflow.
+    pub fn try_get_i8(&self) -> Result<i8, CsvError> {
+        self.try_signed("i8")
+    }
+    /// ## Try Parse u8
+    /// - Gets numeric value as u8.
+    /// - Returns a `CsvErrorKind::Parse` error on non numeric input or overflow.
+    pub fn try_get_u8(&self) -> Result<u8, CsvError> {
+        self.try_unsigned("u8")
+    }
+    /// ## Try Parse i16
+    /// - Gets numeric value as i16.
+    /// - Returns a `CsvErrorKind::Parse` error on non numeric input or overflow.
+    pub fn try_get_i16(&self) -> Result<i16, CsvError> {
+        self.try_signed("i16")
+    }
+    /// ## Try Parse u16
+    /// - Gets numeric value as u16.
+    /// - Returns a `CsvErrorKind::Parse` error on non numeric input or overflow.
+    pub fn try_get_u16(&self) -> Result<u16, CsvError> {
+        self.try_unsigned("u16")
+    }
+    /// ## Try Parse i32
+    /// - Gets numeric value as i32.
+    /// - Returns a `CsvErrorKind::Parse` error on non numeric input or overflow.
+    pub fn try_get_i32(&self) -> Result<i32, CsvError> {
+        self.try_signed("i32")
+    }
+    /// ## Try Parse u32
+    /// - Gets numeric value as u32.
+    /// - Returns a `CsvErrorKind::Parse` error on non numeric input or overflow.
+    pub fn try_get_u32(&self) -> Result<u32, CsvError> {
+        self.try_unsigned("u32")
+    }
+    /// ## Try Parse i64
+    /// - Gets numeric value as i64.
+    /// - Returns a `CsvErrorKind::Parse` error on non numeric input or overflow.
+    pub fn try_get_i64(&self) -> Result<i64, CsvError> {
+        self.try_signed("i64")
+    }
+    /// ## Try Parse u64
+    /// - Gets numeric value as u64.
+    /// - Returns a `CsvErrorKind::Parse` error on non numeric input or overflow.
+    pub fn try_get_u64(&self) -> Result<u64, CsvError> {
+        self.try_unsigned("u64")
+    }
+    /// ## Try Parse f32
+    /// - Gets numeric value as f32.
+    /// - Returns a `CsvErrorKind::Parse` error if the field is not a float literal.
+    pub fn try_get_f32(&self) -> Result<f32, CsvError> {
+        try_parse_f32(self.get_cleaned_slice()).ok_or_else(|| self.parse_error("f32"))
+    }
+    /// ## Try Parse f64
+    /// - Gets numeric value as f64.
+    /// - Returns a `CsvErrorKind::Parse` error if the field is not a float literal.
+    pub fn try_get_f64(&self) -> Result<f64, CsvError> {
+        try_parse_f64(self.get_cleaned_slice()).ok_or_else(|| self.parse_error("f64"))
+    }
+    /// ## Try Parse boolean
+    /// - Gets the value as boolean.
+    /// - Returns a `CsvErrorKind::Parse` error if the value is not a known boolean literal.
+    pub fn try_get_boolean(&self) -> Result<bool, CsvError> {
+        try_parse_bool_str(self.get_utf8_as_str()).ok_or_else(|| self.parse_error("bool"))
+    }
+
+    //------------------------------------------------------------//
+    //--------------------- PRIVATE ------------------------------//
+    //------------------------------------------------------------//
+
+    /// Parses a signed integer, and narrows it to the target type.
+    fn try_signed<T: TryFrom<i64>>(&self, type_name: &str) -> Result<T, CsvError> {
+        try_parse_i64(self.get_cleaned_slice())
+            .and_then(|v| T::try_from(v).ok())
+            .ok_or_else(|| self.parse_error(type_name))
+    }
+
+    /// Parses an unsigned integer, and narrows it to the target type.
+    fn try_unsigned<T: TryFrom<u64>>(&self, type_name: &str) -> Result<T, CsvError> {
+        try_parse_u64(self.get_cleaned_slice())
+            .and_then(|v| T::try_from(v).ok())
+            .ok_or_else(|| self.parse_error(type_name))
+    }
+
+    /// Builds the parse error, with the position of the field.
+    fn parse_error(&self, type_name: &str) -> CsvError {
+        let value = String::from_utf8_lossy(self.get_cleaned_slice());
+        self.locate(CsvError::new(CsvErrorKind::Parse, format!("failed to parse {:?} as {}", value, type_name)))
+    }
+
+    /// Adds the position of the field to an error: record number (if known), field index, byte offset and record.
+    pub(crate) fn locate(&self, error: CsvError) -> CsvError {
+        let mut error = error.with_field(self.column);
+        if self.record != 0 {
+            error = error.with_record(self.record);
+        }
+        if let Some(offset) = self.offset {
+            error = error.with_byte_offset(offset);
+        }
+        if !self.row.is_empty() {
+            error = error.with_snippet(self.row);
+        }
+        error
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::decoders::decoders::Encoding;
+    use crate::models::csv_error::CsvErrorKind;
+    use crate::models::field::Field;
+    use std::borrow::Cow;
+
+    #[test]
+    fn test_unescaped_decoding() {
+        let field = Field::new(b"\"He said \"\"hi\"\"\"", b'"');
+        assert_eq!(field.get_utf8_unescaped(), "He said \"hi\"");
+        assert_eq!(field.get_as_cow_decoded(Encoding::Windows1252), "He said \"hi\"");
+        assert_eq!(field.get_as_string(Encoding::Utf8), "He said \"hi\"");
+        assert!(field.contains_chars("\"hi\"", Encoding::Utf8));
+        // Nothing to unescape borrows the data
+        let plain = Field::new(b"\"plain\"", b'"');
+        assert!(matches!(plain.get_unescaped_slice(), Cow::Borrowed(b"plain")));
+        assert!(matches!(plain.decode_bytes(Encoding::Utf8), Cow::Borrowed("plain")));
+        // Unquoted fields are not unescaped
+        assert_eq!(Field::new(b"a\"\"b", b'"').get_utf8_unescaped(), "a\"\"b");
+    }
+
+    #[test]
+    fn test_try_get_numbers() {
+        assert_eq!(Field::new(b"-128", 0).try_get_i8().unwrap(), -128);
+        assert_eq!(Field::new(b"\"255\"", b'"').try_get_u8().unwrap(), 255);
+        assert_eq!(Field::new(b"4294967295", 0).try_get_u32().unwrap(), u32::MAX);
+        assert_eq!(Field::new(b"-2.5", 0).try_get_f64().unwrap(), -2.5);
+        assert!(Field::new(b"true", 0).try_get_boolean().unwrap());
+    }
+
+    #[test]
+    fn test_try_get_reports_position() {
+        let field = Field::new(b"256", 0).with_position(18, 3).with_row(b"a,b,c,256", Some(106));
+        let err = field.try_get_u8().unwrap_err();
+        assert_eq!(err.kind(), CsvErrorKind::Parse);
+        assert_eq!((err.record(), err.field(), err.byte_offset()), (Some(18), Some(3), Some(106)));
+        assert_eq!(err.message(), "failed to parse \"256\" as u8");
+        assert_eq!(err.snippet(), Some("a,b,c,256"));
+        // Fields built by hand have no record number, nor offset
+        let err = Field::new(b"x", 0).try_get_i32().unwrap_err();
+        assert_eq!((err.record(), err.field(), err.byte_offset(), err.snippet()), (None, Some(0), None, None));
+        assert!(Field::new(b"12abc", 0).try_get_i32().is_err());
+        assert!(Field::new(b"", 0).try_get_i64().is_err());
+        assert!(Field::new(b"-1", 0).try_get_u64().is_err());
+        assert!(Field::new(b"n/a", 0).try_get_f32().is_err());
+        assert!(Field::new(b"maybe", 0).try_get_boolean().is_err());
+    }
 }
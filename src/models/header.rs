@@ -0,0 +1,143 @@
+use crate::decoders::decoders::Encoding;
+use crate::models::delimiter::Delimiter;
+use crate::models::row::Row;
+use std::collections::HashMap;
+
+/// ## Column struct
+/// - A resolved column handle.
+/// - Resolve it once with `Header::column`, and use it inside hot loops with `Row::get_column`, avoiding the name lookup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Column {
+    index: usize,
+}
+
+impl Column {
+    /// ## Constructor
+    /// - Creates a column handle for a zero based index.
+    pub fn new(index: usize) -> Self {
+        Self { index }
+    }
+
+    /// ## Index
+    /// - Gets the zero based index of the column.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+/// ## Header struct
+/// - Stores the column names of a CSV file, and a map to resolve them into indexes.
+/// - Parsed once by the readers, from the first record, or generated (`column_0`, `column_1`, ...) for headerless files.
+#[derive(Debug, Clone)]
+pub struct Header {
+    names: Vec<String>,
+    lookup: HashMap<String, usize>,
+    generated: bool,
+}
+
+impl Header {
+    /// ## From Names
+    /// - Creates a header from a list of names.
+    /// - If a name is repeated, the first column wins on lookups.
+    pub fn from_names<S: Into<String>>(names: Vec<S>) -> Self {
+        let names: Vec<String> = names.into_iter().map(Into::into).collect();
+        let mut lookup = HashMap::with_capacity(names.len());
+        for (i, name) in names.iter().enumerate() {
+            lookup.entry(name.clone()).or_insert(i);
+        }
+        Self {
+            names,
+            lookup,
+            generated: false,
+        }
+    }
+
+    /// ## From Record
+    /// - Parses the raw bytes of a header record, decoding each name with the given encoding.
+    pub fn from_record(record: &[u8], field_separator: impl Into<Delimiter>, string_separator: u8, encoding: Encoding) -> Self {
+        Self::from_row(&Row::new(record, field_separator, string_separator, false), encoding)
+    }
+
+    /// ## From Row
+    /// - Takes the names from the fields of a row, decoding each name with the given encoding.
+    /// - The trim policy of the row is applied to the names.
+    pub fn from_row(row: &Row<'_>, encoding: Encoding) -> Self {
+        Self::from_names((0..row.field_count()).map(|i| row.get_index(i).get_as_string(encoding)).collect())
+    }
+
+    /// ## Generated
+    /// - Creates a header for headerless files, with names `column_0` to `column_{count - 1}`.
+    pub fn generated(count: usize) -> Self {
+        let mut header = Self::from_names((0..count).map(|i| format!("column_{}", i)).collect());
+        header.generated = true;
+        header
+    }
+
+    /// ## Is Generated
+    /// - Returns `true` if the names were generated, and not read from the file.
+    pub fn is_generated(&self) -> bool {
+        self.generated
+    }
+
+    /// ## Len
+    /// - Number of columns in the header.
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    /// ## Is Empty
+    /// - Returns `true` if the header has no columns.
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// ## Names
+    /// - Gets all the column names, in order.
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    /// ## Name
+    /// - Gets the name of the column at the given index.
+    pub fn name(&self, index: usize) -> Option<&str> {
+        self.names.get(index).map(String::as_str)
+    }
+
+    /// ## Index Of
+    /// - Resolves a column name into its zero based index.
+    pub fn index_of(&self, name: &str) -> Option<usize> {
+        self.lookup.get(name).copied()
+    }
+
+    /// ## Column
+    /// - Resolves a column name into a `Column` handle.
+    pub fn column(&self, name: &str) -> Option<Column> {
+        self.index_of(name).map(Column::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_header_from_record() {
+        let header = Header::from_record(b"id;\"first name\";city;id;\"say \"\"hi\"\"\"", b';', b'"', Encoding::Utf8);
+        assert_eq!(header.len(), 5);
+        assert_eq!(header.name(4), Some("say \"hi\""));
+        assert_eq!(header.index_of("first name"), Some(1));
+        assert_eq!(header.index_of("city"), Some(2));
+        // Duplicated names resolve to the first column
+        assert_eq!(header.index_of("id"), Some(0));
+        assert_eq!(header.index_of("missing"), None);
+        assert!(!header.is_generated());
+    }
+
+    #[test]
+    fn test_header_generated() {
+        let header = Header::generated(3);
+        assert_eq!(header.names(), &["column_0", "column_1", "column_2"]);
+        assert_eq!(header.column("column_2"), Some(Column::new(2)));
+        assert!(header.is_generated());
+    }
+}
@@ -1,3 +1,5 @@
+use crate::models::delimiter::Delimiter;
+use crate::models::trim::{is_blank, Trim};
 use memchr::{memchr, memchr2};
 
 /// ## Struct InRowIter
@@ -5,23 +7,39 @@ use memchr::{memchr, memchr2};
 #[derive(Debug)]
 pub struct InRowIter<'a> {
     line: &'a [u8],
-    field_separator: u8,
+    field_separator: Delimiter,
     string_separator: u8,
     cursor: usize,
+    trim: Trim,
 }
 
 impl<'mmap> InRowIter<'mmap> {
     #[inline(always)]
     /// Creates a new instance of the struct `InRowIter<'a>`
-    pub fn new(line: &'mmap [u8], field_separator: u8, string_separator: u8) -> Self {
+    /// - `field_separator` can be a single byte, or a `Delimiter` with a byte sequence.
+    pub fn new(line: &'mmap [u8], field_separator: impl Into<Delimiter>, string_separator: u8) -> Self {
         Self {
             line,
-            field_separator,
+            field_separator: field_separator.into(),
             string_separator,
             cursor: 0,
+            trim: Trim::None,
         }
     }
     #[inline(always)]
+    /// ## With Trim
+    /// - Sets the trim policy. With leading trim, a quote after the leading whitespace still opens a quoted field.
+    pub fn with_trim(mut self, trim: Trim) -> Self {
+        self.set_trim(trim);
+        self
+    }
+    #[inline(always)]
+    /// ## Inner library Trim Setter
+    /// - Sets the trim policy in place.
+    pub(crate) fn set_trim(&mut self, trim: Trim) {
+        self.trim = trim;
+    }
+    #[inline(always)]
     /// ## Inner library Cursor Setter
     /// - Set a new value for the cursor.
     pub(crate) fn set_cursor(&mut self, new_index :usize){
@@ -32,8 +50,14 @@ impl<'mmap> InRowIter<'mmap> {
     /// - Gets the current value of the cursor
     pub(crate) fn get_cursor(&self) -> usize { self.cursor }
     #[inline(always)]
+    /// ## Field Separator Getter
+    /// - Gets the delimiter used to split the fields.
+    pub fn get_field_separator(&self) -> Delimiter { self.field_separator }
+    #[inline(always)]
     /// Count the number of fields, that a line haves.
-    pub fn count_fields(&self, delimiter: u8, string_separator: u8) -> usize {
+    pub fn count_fields(&self, delimiter: impl Into<Delimiter>, string_separator: u8) -> usize {
+        let delimiter = delimiter.into();
+        let first = delimiter.first();
         let mut count = 0;
         let mut pos = 0;
         let len = self.line.len();
@@ -45,18 +69,18 @@ impl<'mmap> InRowIter<'mmap> {
 
             // Find next interesting byte (delimiter or string_separator)
             if let Some(next_pos) = if check_string {
-                memchr2(delimiter, string_separator, slice)
+                memchr2(first, string_separator, slice)
             } else {
-                memchr(delimiter, slice)
+                memchr(first, slice)
             } {
                 let byte = slice[next_pos];
 
                 if check_string && byte == string_separator {
                     in_string = !in_string;
                     pos += next_pos + 1;
-                } else if byte == delimiter && !in_string {
+                } else if !in_string && delimiter.matches_at(slice, next_pos) {
                     count += 1;
-                    pos += next_pos + 1;
+                    pos += next_pos + delimiter.len();
                 } else {
                     pos += next_pos + 1;
                 }
@@ -67,6 +91,77 @@ impl<'mmap> InRowIter<'mmap> {
 
         count + 1 // Number of fields = number of delimiters + 1
     }
+    #[inline(always)]
+    /// ## Raw Field End
+    /// - Finds the end of the field starting at `start`, keeping the quotes inside the field.
+    /// - Returns the end index, and `true` if a delimiter follows the field (so there is another field after it).
+    /// - The next field starts at `end + self.get_field_separator().len()`.
+    pub(crate) fn raw_field_end(&self, start: usize) -> (usize, bool) {
+        let line = self.line;
+        let quote = self.string_separator;
+        let mut pos = start;
+        if quote != 0 && self.trim.trims_leading() {
+            let first = line[pos..].iter().position(|b| !is_blank(*b)).map_or(line.len(), |i| pos + i);
+            if line.get(first) == Some(&quote) {
+                pos = first;
+            }
+        }
+        if quote != 0 && line.get(pos) == Some(&quote) {
+            // Quoted field: jump quote to quote, `""` is an escaped quote
+            pos += 1;
+            while let Some(i) = memchr(quote, &line[pos..]) {
+                pos += i + 1;
+                if line.get(pos) == Some(&quote) {
+                    pos += 1;
+                } else {
+                    break;
+                }
+            }
+            pos = pos.min(line.len());
+        }
+        match self.field_separator.find(&line[pos..]) {
+            Some(i) => (pos + i, true),
+            None => (line.len(), false),
+        }
+    }
+
+    #[inline(always)]
+    /// ## Tokenize
+    /// - Splits the line up to the field `index`, pushing the raw bounds `(start, end)` of each field into `offsets`.
+    /// - `offsets` holds the fields of this line already split (or nothing), the split resumes after them.
+    /// - Returns `true` once the whole line is split.
+    pub(crate) fn tokenize(&self, offsets: &mut Vec<(usize, usize)>, index: usize) -> bool {
+        let mut start = offsets.last().map_or(0, |&(_, end)| end + self.field_separator.len());
+        while offsets.len() <= index {
+            let (end, more) = self.raw_field_end(start);
+            offsets.push((start, end));
+            if !more {
+                return true;
+            }
+            start = end + self.field_separator.len();
+        }
+        false
+    }
+
+    #[inline(always)]
+    /// ## Peek Raw Field At Index
+    /// - Returns the field at a specific index, with its quotes, without modifying the internal cursor.
+    pub fn peek_raw_field_index(&self, target: usize) -> Option<&'mmap [u8]> {
+        let mut start = 0usize;
+        let mut index = 0usize;
+        loop {
+            let (end, more) = self.raw_field_end(start);
+            if index == target {
+                return Some(&self.line[start..end]);
+            }
+            if !more {
+                return None;
+            }
+            start = end + self.field_separator.len();
+            index += 1;
+        }
+    }
+
     #[inline(always)]
     /// Extract the content of a field in raw format.
     pub fn get_field_index(&mut self, target: usize) -> Option<&'mmap [u8]> {
@@ -105,6 +200,7 @@ impl<'mmap> InRowIter<'mmap> {
             let mut start_offset = 0usize;
             let mut end_offset = 0usize;
             let start = pos;
+            let mut closed = false;
 
             if check_string && slice[pos] == self.string_separator {
                 in_string = true;
@@ -129,20 +225,31 @@ impl<'mmap> InRowIter<'mmap> {
                     }
                 }
 
-                if byte == self.field_separator && !in_string {
+                if !in_string && byte == self.field_separator.first() && self.field_separator.matches_at(slice, pos) {
                     if count == target {
                         field_start = start + start_offset;
                         field_end = pos - end_offset;
                         return Some(&slice[field_start..field_end]);
                     } else {
                         count += 1;
-                        pos += 1;
+                        pos += self.field_separator.len();
+                        closed = true;
                         break;
                     }
                 }
 
                 pos += 1;
             }
+
+            // Last field, without a delimiter after it
+            if !closed {
+                if count != target {
+                    return None;
+                }
+                field_start = start + start_offset;
+                field_end = (slice.len() - end_offset).max(field_start);
+                return Some(&slice[field_start..field_end]);
+            }
         }
 
         if count == target {
@@ -204,9 +311,9 @@ impl<'mmap> Iterator for InRowIter<'mmap> {
                 }
             }
 
-            if byte == self.field_separator && !in_string {
+            if !in_string && byte == self.field_separator.first() && self.field_separator.matches_at(slice, pos) {
                 let field = &slice[start_offset..pos - end_offset];
-                self.cursor += pos + 1;
+                self.cursor += pos + self.field_separator.len();
                 return Some(field);
             }
 
@@ -226,8 +333,62 @@ impl<'mmap> Iterator for InRowIter<'mmap> {
 #[cfg(test)]
 mod tests {
     use crate::decoders::decoders::Encoding::Windows1252;
+    use crate::models::delimiter::Delimiter;
     use crate::models::in_row_iter::InRowIter;
 
+    #[test]
+    fn test_peek_last_field(){
+        let row = InRowIter::new(b"uno;\"dos\";tres", b';', b'"');
+        assert_eq!(row.peek_field_index(0), Some(&b"uno"[..]));
+        assert_eq!(row.peek_field_index(1), Some(&b"dos"[..]));
+        assert_eq!(row.peek_field_index(2), Some(&b"tres"[..]));
+        assert_eq!(row.peek_field_index(3), None);
+        let row = InRowIter::new(b"uno;\"dos\"", b';', b'"');
+        assert_eq!(row.peek_field_index(1), Some(&b"dos"[..]));
+        let row = InRowIter::new(b"uno;", b';', b'"');
+        assert_eq!(row.peek_field_index(1), Some(&b""[..]));
+    }
+
+    #[test]
+    fn test_peek_raw_field(){
+        let row = InRowIter::new(b"1;\"a;\"\"b\"\"\";;\"open", b';', b'"');
+        assert_eq!(row.peek_raw_field_index(0), Some(&b"1"[..]));
+        assert_eq!(row.peek_raw_field_index(1), Some(&b"\"a;\"\"b\"\"\""[..]));
+        assert_eq!(row.peek_raw_field_index(2), Some(&b""[..]));
+        assert_eq!(row.peek_raw_field_index(3), Some(&b"\"open"[..]));
+        assert_eq!(row.peek_raw_field_index(4), None);
+    }
+
+    #[test]
+    fn test_tokenize(){
+        let row = InRowIter::new(b"a;\"b;c\";;d", b';', b'"');
+        let mut offsets = Vec::new();
+        assert!(!row.tokenize(&mut offsets, 1));
+        assert_eq!(offsets, vec![(0, 1), (2, 7)]);
+        assert!(row.tokenize(&mut offsets, usize::MAX));
+        assert_eq!(offsets, vec![(0, 1), (2, 7), (8, 8), (9, 10)]);
+    }
+
+    #[test]
+    fn test_multi_byte_delimiter(){
+        let line = b"uno~|~\"a~|~b\"~|~~|~tres";
+        let delimiter = Delimiter::new(b"~|~").unwrap();
+        let mut row = InRowIter::new(line, delimiter, b'"');
+        assert_eq!(row.count_fields(delimiter, b'"'), 4);
+        assert_eq!(row.peek_field_index(1), Some(&b"a~|~b"[..]));
+        assert_eq!(row.peek_field_index(3), Some(&b"tres"[..]));
+        assert_eq!(row.peek_raw_field_index(2), Some(&b""[..]));
+        let mut offsets = Vec::new();
+        row.tokenize(&mut offsets, usize::MAX);
+        assert_eq!(offsets, vec![(0, 3), (6, 13), (16, 16), (19, 23)]);
+        let fields: Vec<&[u8]> = row.by_ref().collect();
+        assert_eq!(fields, vec![&b"uno"[..], &b"a~|~b"[..], &b""[..], &b"tres"[..]]);
+        // A partial delimiter is part of the field
+        let row = InRowIter::new(b"a|b||c", Delimiter::new(b"||").unwrap(), 0u8);
+        assert_eq!(row.peek_field_index(0), Some(&b"a|b"[..]));
+        assert_eq!(row.peek_field_index(1), Some(&b"c"[..]));
+    }
+
     #[test]
     fn test_iter_next(){
 
@@ -1,9 +1,17 @@
 pub mod csv_config;
+pub mod parallel_config;
+pub mod cancel_token;
+pub mod progress;
 pub mod csv_error;
+pub mod delimiter;
+pub mod record_terminator;
+pub mod trim;
+pub mod quote_style;
 pub mod platform_info;
 pub mod in_row_iter;
 pub mod row;
 pub mod field;
+pub mod header;
 pub mod shared;
 pub mod worker;
-mod editable_persist;
\ No newline at end of file
+pub mod editable_persist;
\ No newline at end of file
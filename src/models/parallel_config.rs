@@ -0,0 +1,98 @@
+use crate::models::progress::Progress;
+use crate::models::trim::Trim;
+
+/// Default size of the work units handed to the threads: 1MB.
+pub const DEFAULT_CHUNK_SIZE: usize = 1024 * 1024;
+
+/// ## ParallelConfig Struct
+/// - Stores how the parallel functions split the work.
+/// - The slice is cut into chunks of about `chunk_size` bytes, ending on record boundaries. The threads take the
+///   chunks from a shared queue, so a slow chunk does not leave the other cores idle.
+#[derive(Debug, Clone)]
+pub struct ParallelConfig {
+    /// Number of threads. `0` uses every core (`num_cpus::get()`).
+    pub threads: usize,
+    /// Approximate size of each work unit, in bytes.
+    pub chunk_size: usize,
+    /// Offset of the slice in the file, added to the byte ranges of the rows.
+    pub offset: usize,
+    /// Record number of the first record of the slice (1 based, header included).
+    /// `0` leaves the record numbers of the rows unknown, and skips the counting pass.
+    pub first_record: usize,
+    /// Whitespace removed around the fields of the rows (outside quotes), as `CsvConfig::trim`.
+    pub trim: Trim,
+    /// Counters updated after each chunk, if set.
+    pub progress: Option<Progress>,
+}
+
+impl Default for ParallelConfig {
+    /// ## Default for `ParallelConfig`
+    /// - Every core, and chunks of `DEFAULT_CHUNK_SIZE`.
+    fn default() -> Self {
+        Self {
+            threads: 0,
+            chunk_size: DEFAULT_CHUNK_SIZE,
+            offset: 0,
+            first_record: 0,
+            trim: Trim::None,
+            progress: None,
+        }
+    }
+}
+
+impl ParallelConfig {
+    /// ## With Threads
+    /// - Caps the number of threads. `0` uses every core.
+    #[inline(always)]
+    pub fn with_threads(self, threads: usize) -> Self {
+        Self { threads, ..self }
+    }
+
+    /// ## With Chunk Size
+    /// - Sets the approximate size of each work unit, in bytes. Smaller chunks balance skewed data better.
+    #[inline(always)]
+    pub fn with_chunk_size(self, chunk_size: usize) -> Self {
+        Self {
+            chunk_size: chunk_size.max(1),
+            ..self
+        }
+    }
+
+    /// ## With Origin
+    /// - Sets the position of the slice in the file: the offset of its first byte, and the record number of its
+    ///   first record. Use `CsvReaderWithMap::get_data_origin` for the data slice.
+    /// - With a record number, the chunks are counted first (in parallel), so each row knows its record number.
+    #[inline(always)]
+    pub fn with_origin(self, offset: usize, first_record: usize) -> Self {
+        Self {
+            offset,
+            first_record,
+            ..self
+        }
+    }
+
+    /// ## With Trim
+    /// - Sets the trim policy applied to the fields of the rows. Use `CsvConfig::trim` to match the reader.
+    #[inline(always)]
+    pub fn with_trim(self, trim: Trim) -> Self {
+        Self { trim, ..self }
+    }
+
+    /// ## With Progress
+    /// - Publishes the bytes and records processed (in total, and by thread) on `progress`, after each chunk.
+    #[inline(always)]
+    pub fn with_progress(self, progress: &Progress) -> Self {
+        Self {
+            progress: Some(progress.clone()),
+            ..self
+        }
+    }
+
+    /// ## Thread Count
+    /// - Threads to spawn for the given number of chunks: never more threads than chunks, and at least one.
+    #[inline(always)]
+    pub fn thread_count(&self, chunks: usize) -> usize {
+        let threads = if self.threads == 0 { num_cpus::get() } else { self.threads };
+        threads.min(chunks).max(1)
+    }
+}
@@ -0,0 +1,141 @@
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// Bytes processed between two updates of the sequential readers: 1MB.
+pub const PROGRESS_INTERVAL: usize = 1024 * 1024;
+
+/// ## Progress Struct
+/// - Counters of a running scan, to poll from another thread (a progress bar, a log line every few seconds...).
+/// - The scan only publishes from time to time: the parallel functions after each chunk, and the readers every
+///   `PROGRESS_INTERVAL` bytes, so the counters cost nothing per row. At the end of the scan they are exact.
+/// - Clones share the same counters.
+#[derive(Debug, Clone, Default)]
+pub struct Progress {
+    state: Arc<ProgressState>,
+}
+
+#[derive(Debug, Default)]
+struct ProgressState {
+    bytes: AtomicUsize,
+    records: AtomicUsize,
+    total_bytes: AtomicUsize,
+    finished: AtomicBool,
+    threads: Mutex<Vec<ThreadProgress>>,
+}
+
+/// ## ThreadProgress Struct
+/// - Work published by one thread of the scan.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ThreadProgress {
+    /// Bytes processed by the thread.
+    pub bytes: usize,
+    /// Records processed by the thread.
+    pub records: usize,
+}
+
+impl Progress {
+    /// ## Constructor
+    /// - Make new counters, at zero.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// ## Bytes
+    /// - Gets the bytes processed so far.
+    #[inline(always)]
+    pub fn bytes(&self) -> usize {
+        self.state.bytes.load(Ordering::Relaxed)
+    }
+
+    /// ## Records
+    /// - Gets the records processed so far.
+    #[inline(always)]
+    pub fn records(&self) -> usize {
+        self.state.records.load(Ordering::Relaxed)
+    }
+
+    /// ## Total Bytes
+    /// - Gets the bytes to process, known when the scan starts.
+    #[inline(always)]
+    pub fn total_bytes(&self) -> usize {
+        self.state.total_bytes.load(Ordering::Relaxed)
+    }
+
+    /// ## Fraction
+    /// - Gets the processed part of the input, from `0.0` to `1.0`. An empty input is `1.0` once finished.
+    pub fn fraction(&self) -> f64 {
+        let total = self.total_bytes();
+        if total == 0 {
+            return if self.is_finished() { 1.0 } else { 0.0 };
+        }
+        self.bytes() as f64 / total as f64
+    }
+
+    /// ## Is Finished
+    /// - Gets if the scan reached its end (or was cancelled).
+    #[inline(always)]
+    pub fn is_finished(&self) -> bool {
+        self.state.finished.load(Ordering::Relaxed)
+    }
+
+    /// ## Threads
+    /// - Gets the work published by each thread, indexed by `thread_id`.
+    pub fn threads(&self) -> Vec<ThreadProgress> {
+        self.lock_threads().clone()
+    }
+
+    /// Resets the counters for a scan of `total_bytes`, with `threads` threads.
+    pub(crate) fn start(&self, total_bytes: usize, threads: usize) {
+        let mut lock = self.lock_threads();
+        lock.clear();
+        lock.resize(threads, ThreadProgress::default());
+        self.state.bytes.store(0, Ordering::Relaxed);
+        self.state.records.store(0, Ordering::Relaxed);
+        self.state.total_bytes.store(total_bytes, Ordering::Relaxed);
+        self.state.finished.store(false, Ordering::Relaxed);
+    }
+
+    /// Adds the work done by a thread since its last update.
+    pub(crate) fn add(&self, thread_id: usize, bytes: usize, records: usize) {
+        if let Some(thread) = self.lock_threads().get_mut(thread_id) {
+            thread.bytes += bytes;
+            thread.records += records;
+        }
+        self.state.bytes.fetch_add(bytes, Ordering::Relaxed);
+        self.state.records.fetch_add(records, Ordering::Relaxed);
+    }
+
+    /// Marks the end of the scan.
+    pub(crate) fn finish(&self) {
+        self.state.finished.store(true, Ordering::Relaxed);
+    }
+
+    fn lock_threads(&self) -> MutexGuard<'_, Vec<ThreadProgress>> {
+        // The counters stay usable if a thread panicked while holding the lock
+        self.state.threads.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_progress_counters() {
+        let progress = Progress::new();
+        assert_eq!(progress.fraction(), 0.0);
+        progress.start(100, 2);
+        let clone = progress.clone();
+        clone.add(0, 30, 3);
+        clone.add(1, 20, 2);
+        clone.add(1, 50, 5);
+        assert_eq!((progress.bytes(), progress.records(), progress.total_bytes()), (100, 10, 100));
+        assert_eq!(progress.threads(), vec![ThreadProgress { bytes: 30, records: 3 }, ThreadProgress { bytes: 70, records: 7 }]);
+        assert!(!progress.is_finished());
+        clone.finish();
+        assert!(progress.is_finished());
+        assert_eq!(progress.fraction(), 1.0);
+        progress.start(0, 1);
+        assert_eq!((progress.bytes(), progress.threads().len()), (0, 1));
+    }
+}
@@ -0,0 +1,83 @@
+/// ## QuoteStyle enum
+/// - Defines which fields are quoted by `CsvWriter` (RFC 4180).
+/// - Quoted fields have their string separators doubled (`"` to `""`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum QuoteStyle {
+    /// Quotes the fields holding the delimiter, the string separator, `\r` or `\n`.
+    Necessary,
+    /// Quotes every field.
+    Always,
+    /// Quotes every field that is not a number, and the ones that need it.
+    NonNumeric,
+    /// Never quotes. Fields are written as they are, even if the output can not be read back.
+    Never,
+}
+
+impl QuoteStyle {
+    /// ## Should Quote
+    /// - Returns `true` if the field must be quoted. `needs_quotes` tells if the field holds special bytes.
+    #[inline(always)]
+    pub(crate) fn should_quote(&self, field: &[u8], needs_quotes: impl FnOnce() -> bool) -> bool {
+        match self {
+            QuoteStyle::Necessary => needs_quotes(),
+            QuoteStyle::Always => true,
+            QuoteStyle::NonNumeric => !is_number(field) || needs_quotes(),
+            QuoteStyle::Never => false,
+        }
+    }
+}
+
+impl Default for QuoteStyle {
+    /// ## Default for `QuoteStyle`
+    /// - `Necessary`, only the fields that need it are quoted.
+    fn default() -> Self {
+        QuoteStyle::Necessary
+    }
+}
+
+/// Returns `true` for integers and decimals (`-12`, `+3.5`, `.5`, `1e-3`).
+fn is_number(field: &[u8]) -> bool {
+    let digits = match field {
+        [b'-' | b'+', rest @ ..] => rest,
+        _ => field,
+    };
+    let (mantissa, exponent) = match digits.iter().position(|b| *b == b'e' || *b == b'E') {
+        Some(e) => (&digits[..e], Some(&digits[e + 1..])),
+        None => (digits, None),
+    };
+    let mut dots = 0;
+    let mut has_digit = false;
+    for b in mantissa {
+        match b {
+            b'0'..=b'9' => has_digit = true,
+            b'.' => dots += 1,
+            _ => return false,
+        }
+    }
+    let valid_exponent = match exponent {
+        Some([b'-' | b'+', rest @ ..]) | Some(rest) => !rest.is_empty() && rest.iter().all(u8::is_ascii_digit),
+        None => true,
+    };
+    has_digit && dots <= 1 && valid_exponent
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::quote_style::{is_number, QuoteStyle};
+
+    #[test]
+    fn test_should_quote() {
+        for number in [&b"12"[..], b"-3.5", b"+.5", b"1e-3", b"2E10"].iter() {
+            assert!(is_number(number));
+        }
+        for text in [&b""[..], b"-", b"1.2.3", b"1e", b"abc", b"12a", b"."].iter() {
+            assert!(!is_number(text));
+        }
+        assert!(QuoteStyle::Always.should_quote(b"1", || false));
+        assert!(!QuoteStyle::Never.should_quote(b"a,b", || true));
+        assert!(QuoteStyle::NonNumeric.should_quote(b"abc", || false));
+        assert!(!QuoteStyle::NonNumeric.should_quote(b"-1.5", || false));
+        assert!(QuoteStyle::Necessary.should_quote(b"a,b", || true));
+        assert!(!QuoteStyle::Necessary.should_quote(b"ab", || false));
+    }
+}
@@ -0,0 +1,43 @@
+use crate::models::delimiter::Delimiter;
+
+/// ASCII record separator (0x1E).
+pub const ASCII_RECORD_SEPARATOR: u8 = 0x1E;
+/// ASCII unit separator (0x1F), used as field delimiter by `RecordTerminator::Ascii`.
+pub const ASCII_UNIT_SEPARATOR: u8 = 0x1F;
+
+/// ## RecordTerminator enum
+/// - Defines how the records of a file end.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RecordTerminator {
+    /// Any of `\r`, `\n` or `\r\n`, plus `CsvConfig::line_break`. Uses the AVX2 / NEON paths.
+    Any,
+    /// Only `\r\n`. A bare `\r` or `\n` is part of the field, even outside quotes.
+    Crlf,
+    /// A custom byte sequence, like `b"|\n"` or `b"<EOR>"`.
+    Custom(Delimiter),
+    /// ASCII delimited text: records end with 0x1E (record separator), and fields are split by 0x1F (unit separator).
+    Ascii,
+}
+
+impl RecordTerminator {
+    /// ## Sequence
+    /// - Gets the exact byte sequence ending a record.
+    /// - Returns `None` for `Any`, that matches several sequences.
+    #[inline(always)]
+    pub fn sequence(&self) -> Option<Delimiter> {
+        match self {
+            RecordTerminator::Any => None,
+            RecordTerminator::Crlf => Some(Delimiter::CRLF),
+            RecordTerminator::Custom(sequence) => Some(*sequence),
+            RecordTerminator::Ascii => Some(Delimiter::byte(ASCII_RECORD_SEPARATOR)),
+        }
+    }
+}
+
+impl Default for RecordTerminator {
+    /// ## Default for `RecordTerminator`
+    /// - `Any`, the behavior of previous versions.
+    fn default() -> Self {
+        RecordTerminator::Any
+    }
+}
@@ -1,20 +1,73 @@
 use crate::decoders::decoders::Encoding;
-#[cfg(target_arch = "x86_64")]
-use crate::helpers::bytes_helper::locate_line_break_avx2;
-use crate::helpers::bytes_helper::locate_line_break_memchr3;
+use crate::models::delimiter::Delimiter;
 use crate::models::field::Field;
+use crate::models::header::{Column, Header};
 use crate::models::in_row_iter::InRowIter;
+use crate::models::trim::Trim;
 use std::borrow::Cow;
+use std::cell::{Cell, RefCell};
+use std::ops::Range;
+use std::sync::{Arc, Mutex};
 
 #[derive(Debug)]
 #[allow(dead_code)]
 pub struct Row<'mmap> {
     slice: &'mmap [u8],
     cursor: usize,
-    field_separator: u8,
+    field_separator: Delimiter,
     string_delimiter: u8,
     force_mem_cacher: bool,
     iter: InRowIter<'mmap>,
+    header: Option<Arc<Header>>,
+    record: usize,
+    offset: usize,
+    offsets: RefCell<Offsets>,
+    tokenized: Cell<bool>,
+    trim: Trim,
+}
+
+/// Field offsets of a row, and the pool of the reader they are given back to when the row is dropped.
+/// - The drop lives here, not in `Row`, so a row does not hold the borrow of its reader until the end of the scope.
+/// - Rows never split do not touch the pool.
+#[derive(Debug, Default)]
+struct Offsets {
+    fields: Vec<(usize, usize)>,
+    pool: Option<Arc<OffsetsPool>>,
+}
+
+impl Drop for Offsets {
+    fn drop(&mut self) {
+        if self.fields.capacity() == 0 {
+            return;
+        }
+        if let Some(pool) = &self.pool {
+            pool.give(std::mem::take(&mut self.fields));
+        }
+    }
+}
+
+/// ## Offsets Pool
+/// - A spare buffer of field offsets, kept by a reader and lent to the rows it returns, which may outlive the next
+///   row. Loops driven by the reader use a plain buffer instead (`with_offsets_buffer` / `take_offsets_buffer`).
+/// - A row takes it when first split, and gives it back when dropped, so the reader does not allocate per row.
+#[derive(Debug, Default)]
+pub(crate) struct OffsetsPool(Mutex<Vec<(usize, usize)>>);
+
+impl OffsetsPool {
+    /// Takes the spare buffer. It has no capacity if another row holds it.
+    fn take(&self) -> Vec<(usize, usize)> {
+        self.0.lock().map(|mut spare| std::mem::take(&mut *spare)).unwrap_or_default()
+    }
+
+    /// Gives a buffer back, keeping the largest one.
+    fn give(&self, mut buffer: Vec<(usize, usize)>) {
+        if let Ok(mut spare) = self.0.lock() {
+            if buffer.capacity() > spare.capacity() {
+                buffer.clear();
+                *spare = buffer;
+            }
+        }
+    }
 }
 
 impl<'mmap> Row<'mmap> {
@@ -22,10 +75,11 @@ impl<'mmap> Row<'mmap> {
     /// - Make a new instance of `Row` struct.
     pub fn new(
         slice: &'mmap [u8],
-        field_separator: u8,
+        field_separator: impl Into<Delimiter>,
         string_delimiter: u8,
         force_mem_cacher: bool,
     ) -> Self {
+        let field_separator = field_separator.into();
         let i = InRowIter::new(slice, field_separator, string_delimiter);
         Self {
             slice,
@@ -34,6 +88,12 @@ impl<'mmap> Row<'mmap> {
             string_delimiter,
             force_mem_cacher,
             iter: i,
+            header: None,
+            record: 0,
+            offset: 0,
+            offsets: RefCell::default(),
+            tokenized: Cell::new(false),
+            trim: Trim::None,
         }
     }
     /// ## Creates a new Empty row
@@ -41,13 +101,75 @@ impl<'mmap> Row<'mmap> {
         Self {
             slice: &[],
             cursor: 0,
-            field_separator: 0,
+            field_separator: Delimiter::byte(0),
             string_delimiter: 0,
             force_mem_cacher: false,
             iter: InRowIter::new(&[], 0, 0),
+            header: None,
+            record: 0,
+            offset: 0,
+            offsets: RefCell::default(),
+            tokenized: Cell::new(false),
+            trim: Trim::None,
         }
     }
 
+    /// ## With Header
+    /// - Attaches the header of the file, enabling the by-name lookups.
+    pub fn with_header(mut self, header: Option<Arc<Header>>) -> Self {
+        self.header = header;
+        self
+    }
+
+    /// ## With Trim
+    /// - Sets the trim policy applied to the fields of the row (outside quotes only).
+    pub fn with_trim(mut self, trim: Trim) -> Self {
+        self.trim = trim;
+        self.iter.set_trim(trim);
+        self.offsets.get_mut().fields.clear();
+        self.tokenized.set(false);
+        self
+    }
+
+    /// ## Get Trim
+    /// - Gets the trim policy applied to the fields of the row.
+    pub fn get_trim(&self) -> Trim {
+        self.trim
+    }
+
+    /// ## With Record
+    /// - Sets the record number of the row (1 based, header included), used on error reports.
+    pub fn with_record(mut self, record: usize) -> Self {
+        self.record = record;
+        self
+    }
+
+    /// ## Get Record Number
+    /// - Gets the record number of the row (1 based, header included). `0` if unknown.
+    pub fn get_record_number(&self) -> usize {
+        self.record
+    }
+
+    /// ## With Offset
+    /// - Sets the position of the first byte of the row, in the bytes read by the reader.
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// ## Get Byte Range
+    /// - Gets the position of the row in the bytes read by the reader (the file, BOM included), without its line break.
+    /// - For UTF-16 files, the positions refer to the text transcoded to UTF-8.
+    pub fn get_byte_range(&self) -> Range<usize> {
+        self.offset..self.offset + self.slice.len()
+    }
+
+    /// ## Get Header
+    /// - Gets the header attached to the row, if any.
+    pub fn get_header(&self) -> Option<&Header> {
+        self.header.as_deref()
+    }
+
     /// ## Is Empty
     /// - Gets if a row is empty
     pub fn is_empty(&self) -> bool {
@@ -65,52 +187,90 @@ impl<'mmap> Row<'mmap> {
     /// ## Next Field
     /// - Finds the next slice between current position and delimiter
     /// - Pack this slice, inside a Field struct in order to access field functions.
-    /// - Can iter between differents Fields, in order.
+    /// - Can iter between differents Fields, in order. After the last field, returns `None` and goes back to the first one.
     /// #### `returns` : An Option<Field<'mmap>>
     pub fn next_field(&mut self) -> Option<Field<'mmap>> {
-        //If we move here the cfg, and target compariision, is faster. only doit once, and not on each line iter.
-        if self.force_mem_cacher {
-            return match self.next_raw_memchr3() {
-                Some(row) => Some(Field::new(row, self.string_delimiter)),
-                None => None,
-            };
-        }
-        #[cfg(target_arch = "x86_64")]
-        {
-            //En x86, si soporta avx2 lo uso
-            if is_x86_feature_detected!("avx2") {
-                match unsafe { self.new_raw_avx2() } {
-                    Some(row) => Some(Field::new(row, self.string_delimiter)),
-                    None => None,
-                }
-            } else {
-                //En x86, si no soporta avx2, uso el memcach3
-                match self.next_raw_memchr3() {
-                    Some(row) => Some(Field::new(row, self.string_delimiter)),
-                    None => None,
-                }
-            }
-        }
-        #[cfg(target_arch = "aarch64")]
-        {
-            match self.new_raw_neon() {
-                Some(row) => Some(Field::new(row, self.string_delimiter)),
-                None => None,
-            }
+        if self.cursor > self.slice.len() || self.slice.is_empty() {
+            self.reset_cursor();
+            return None;
         }
+        let start = self.cursor;
+        let (end, more) = self.iter.raw_field_end(start);
+        self.cursor = if more {
+            end + self.field_separator.len()
+        } else {
+            // Past the end, so the next call returns None
+            self.slice.len() + 1
+        };
+        Some(Field::new(self.trim.apply(&self.slice[start..end]), self.string_delimiter))
     }
 
     #[allow(dead_code)]
     /// # Get Field by Index
     /// - Receives an usize (zero based index), and returns the field associated to the iteration.
-    /// #### `returns`: An Option<Field<'mmap>>
+    /// - The row is split up to `index` once, and the field offsets are cached. Next calls are O(1).
+    /// #### `returns`: A Field<'mmap> (empty if the index is out of bounds)
     pub fn get_index(&self, index: usize) -> Field<'mmap> {
-        let data = &self.iter.peek_field_index(index);
-        match data {
-            Some(f) => Field::new(f, self.string_delimiter),
+        self.ensure_tokenized(index);
+        let bounds = self.offsets.borrow().fields.get(index).copied();
+        match bounds {
+            Some((start, end)) => Field::new(self.trim.apply(&self.slice[start..end]), self.string_delimiter)
+                .with_row(self.slice, Some(self.offset + start)),
+            None => Field::new_empty(self.string_delimiter).with_row(self.slice, None),
+        }
+        .with_position(self.record, index)
+    }
+
+    /// ## Field Count
+    /// - Gets the number of fields in the row. Splits the row, if not done yet.
+    pub fn field_count(&self) -> usize {
+        self.ensure_tokenized(usize::MAX);
+        self.offsets.borrow().fields.len()
+    }
+
+    /// ## With Offsets Buffer
+    /// - Gives a buffer to store the field offsets, so its allocation is reused between rows.
+    pub(crate) fn with_offsets_buffer(mut self, mut buffer: Vec<(usize, usize)>) -> Self {
+        buffer.clear();
+        self.offsets.get_mut().fields = buffer;
+        self.tokenized.set(false);
+        self
+    }
+
+    /// ## With Offsets Pool
+    /// - Borrows the buffer of field offsets from the pool of the reader, and gives it back on drop.
+    pub(crate) fn with_offsets_pool(mut self, pool: &Arc<OffsetsPool>) -> Self {
+        self.offsets.get_mut().pool = Some(Arc::clone(pool));
+        self
+    }
+
+    /// ## Take Offsets Buffer
+    /// - Takes the buffer of field offsets back, to reuse it in the next row.
+    pub(crate) fn take_offsets_buffer(&mut self) -> Vec<(usize, usize)> {
+        self.tokenized.set(false);
+        std::mem::take(&mut self.offsets.get_mut().fields)
+    }
+
+    /// # Get Field by Name
+    /// - Receives a column name, resolves it with the header, and returns the field.
+    /// - If the row has no header, or the name is not found, returns an empty field.
+    /// #### `returns`: A Field<'mmap>
+    pub fn get_by_name(&self, name: &str) -> Field<'mmap> {
+        match self.header.as_ref().and_then(|h| h.index_of(name)) {
+            Some(index) => self.get_index(index),
             None => Field::new_empty(self.string_delimiter),
         }
     }
+
+    /// # Get Field by Column
+    /// - Receives a `Column` resolved with `Header::column`, and returns the field.
+    /// - Avoids the name lookup, use it in hot loops.
+    /// #### `returns`: A Field<'mmap>
+    #[inline(always)]
+    pub fn get_column(&self, column: Column) -> Field<'mmap> {
+        self.get_index(column.index())
+    }
+
     /// ## Decodes the full line
     /// - Must give the encoding.
     pub fn decode_line(&mut self, enc: Encoding) -> Cow<str> {
@@ -120,98 +280,27 @@ impl<'mmap> Row<'mmap> {
     //------------------------------------------------------------//
     //--------------------- PRIVATE ------------------------------//
     //------------------------------------------------------------//
-    #[cfg(target_arch = "aarch64")]
-    fn new_raw_neon(&mut self) -> Option<&'mmap [u8]> {
-        unsafe {
-            // Obtain the unmapped slice starting from the cursor
-            let slice = &self.slice[self.cursor..];
-            // Locate the break index
-            match crate::helpers::bytes_helper::locate_line_break_neon(slice, self.field_separator)
-            {
-                0 => {
-                    // EOF, reset cursor
-                    self.reset_cursor();
-                    None
-                }
-                sep_index => {
-                    // Correctly extract the row WITHOUT including the separator
-                    let row = &self.slice[self.cursor..self.cursor + sep_index];
-
-                    // Now we must remove the separator bytes at the end
-                    // Check if row ends with \r\n
-                    let end = if row.ends_with(b"\r\n") {
-                        2
-                    } else if row.ends_with(&[b'\n']) || row.ends_with(&[b'\r']) {
-                        1
-                    } else {
-                        0 // in case of custom separator (or no separator)
-                    };
-
-                    // Final row slice without line break or separator
-                    let row = &row[..row.len() - end];
-
-                    // Move the cursor forward to after the separator
-                    self.cursor += sep_index;
-
-                    Some(row)
-                }
-            }
+    /// Splits the row into the offsets buffer, up to the field `index`. The fields already split are kept.
+    #[inline(always)]
+    fn ensure_tokenized(&self, index: usize) {
+        if self.tokenized.get() {
+            return;
         }
-    }
-    #[cfg(target_arch = "x86_64")]
-    #[target_feature(enable = "avx2")]
-    unsafe fn new_raw_avx2(&mut self) -> Option<&'mmap [u8]> {
-        unsafe {
-            let slice = &self.slice[self.cursor..];
-
-            let sep_index = locate_line_break_avx2(slice, self.field_separator);
-
-            if sep_index == 0 {
-                self.reset_cursor();
-                return None;
-            }
-
-            let full_row = &self.slice[self.cursor..self.cursor + sep_index];
-
-            let trim_len = if full_row.ends_with(b"\r\n") {
-                2
-            } else if full_row.ends_with(&[b'\r']) || full_row.ends_with(&[b'\n']) {
-                1
-            } else {
-                0
-            };
-
-            let valid_len = full_row.len().saturating_sub(trim_len);
-
-            let row = &full_row[..valid_len];
-
-            self.cursor += sep_index;
-
-            Some(row)
+        let mut offsets = self.offsets.borrow_mut();
+        let Offsets { fields, pool } = &mut *offsets;
+        if fields.len() > index {
+            return;
         }
-    }
-
-    fn next_raw_memchr3(&mut self) -> Option<&'mmap [u8]> {
-        //determine the tos end slice
-        let slice = &self.slice[self.cursor..];
-        //Determine the line break cursor position
-        match locate_line_break_memchr3(slice, self.cursor, self.field_separator) {
-            0 => {
-                //EOF, so, reset cursor
-                self.reset_cursor();
-                None
+        if fields.capacity() == 0 {
+            if let Some(pool) = pool {
+                *fields = pool.take();
             }
-            i => {
-                //Take a reference of the map file
-                let map = &self.slice[..];
-                //Return the byte slice of a row
-                let row = &map[self.cursor..i];
-                //Move the cursor position
-                self.cursor = i;
-                //Extract the byte line
-                Some(row)
+            if fields.capacity() == 0 {
+                fields.reserve(self.header.as_ref().map_or(16, |h| h.len()));
             }
         }
+        let done = self.iter.tokenize(fields, index);
+        self.tokenized.set(done);
     }
 
     /// Reset the internal cursor
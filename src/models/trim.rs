@@ -0,0 +1,76 @@
+/// ## Trim enum
+/// - Defines which whitespace (spaces and tabs) is removed around the fields.
+/// - Applied outside quotes only: in `  " a "  `, the spaces inside the quotes are kept.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Trim {
+    /// Fields are kept as they are.
+    None,
+    /// Removes the whitespace before the field.
+    Leading,
+    /// Removes the whitespace after the field.
+    Trailing,
+    /// Removes the whitespace before and after the field.
+    Both,
+}
+
+impl Trim {
+    /// ## Apply
+    /// - Returns the slice without the whitespace selected by the policy.
+    #[inline(always)]
+    pub fn apply<'a>(&self, slice: &'a [u8]) -> &'a [u8] {
+        match self {
+            Trim::None => slice,
+            Trim::Leading => trim_start(slice),
+            Trim::Trailing => trim_end(slice),
+            Trim::Both => trim_end(trim_start(slice)),
+        }
+    }
+
+    /// ## Trims Leading
+    /// - Returns `true` if the whitespace before the field is removed.
+    #[inline(always)]
+    pub fn trims_leading(&self) -> bool {
+        matches!(self, Trim::Leading | Trim::Both)
+    }
+}
+
+impl Default for Trim {
+    /// ## Default for `Trim`
+    /// - `None`, fields are kept as they are.
+    fn default() -> Self {
+        Trim::None
+    }
+}
+
+/// Returns `true` for the whitespace removed by the trim policies.
+#[inline(always)]
+pub(crate) fn is_blank(byte: u8) -> bool {
+    byte == b' ' || byte == b'\t'
+}
+
+#[inline(always)]
+fn trim_start(slice: &[u8]) -> &[u8] {
+    let start = slice.iter().position(|b| !is_blank(*b)).unwrap_or(slice.len());
+    &slice[start..]
+}
+
+#[inline(always)]
+fn trim_end(slice: &[u8]) -> &[u8] {
+    let end = slice.iter().rposition(|b| !is_blank(*b)).map_or(0, |i| i + 1);
+    &slice[..end]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::trim::Trim;
+
+    #[test]
+    fn test_trim_apply() {
+        let value = b" \t\" a \" ";
+        assert_eq!(Trim::None.apply(value), value);
+        assert_eq!(Trim::Leading.apply(value), b"\" a \" ");
+        assert_eq!(Trim::Trailing.apply(value), b" \t\" a \"");
+        assert_eq!(Trim::Both.apply(value), b"\" a \"");
+        assert_eq!(Trim::Both.apply(b"   "), b"");
+    }
+}
@@ -1,16 +1,44 @@
+use std::ops::ControlFlow;
 use std::sync::{Arc, Mutex};
+use crate::helpers::bytes_helper::find_record_end;
+use crate::models::cancel_token::CancelToken;
+use crate::models::delimiter::Delimiter;
 use crate::models::editable_persist::EditablePersist;
-use crate::models::in_row_iter::InRowIter;
+use crate::models::header::Header;
+use crate::models::trim::Trim;
 use crate::parallel::row_parallel::RowParallel;
 
+/// ## Chunk
+/// - A work unit: the bytes `start..end` of the slice, cut on record boundaries.
+/// - `base` is the offset of the slice in the file, and `record` the record number of the first record of the
+///   chunk (`0` if unknown).
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct Chunk {
+    pub start: usize,
+    pub end: usize,
+    pub base: usize,
+    pub record: usize,
+}
+
+/// ## Chunk Scan
+/// - Work done on a chunk: the bytes and records processed (before a `Break`), and `Break` if the scan stopped
+///   before the end of the chunk.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ChunkScan {
+    pub bytes: usize,
+    pub records: usize,
+    pub flow: ControlFlow<()>,
+}
+
 /// Ejecuta tareas sobre un chunk de CSV, pasando control del lock al closure.
+#[allow(clippy::too_many_arguments)]
 pub fn execute_task_in_thread<'mmap,Closure, Param>(
     slice: &'mmap [u8],
-    line_break: u8,
-    field_separator: u8,
+    line_break: impl Into<Delimiter>,
+    field_separator: impl Into<Delimiter>,
     string_delimiter: u8,
     force_memchr: bool,
-    mut func: Closure,
+    func: Closure,
     param: Arc<Mutex<Param>>,
     thread_id: usize,
 )
@@ -18,26 +46,59 @@ where
     Closure: FnMut(&mut RowParallel<'mmap>,usize, Arc<Mutex<Param>>) + Send,
     Param: Send + 'mmap,
 {
-    //global file iter
-    let mut iterator = InRowIter::new(slice, line_break, string_delimiter);
-    let mut acumulator = 0usize;
-    while let Some(row_data) = iterator.next() {
-        if acumulator == 0{
-            acumulator = iterator.get_cursor();
-        }
-        let mut row = RowParallel::new(slice,row_data, iterator.get_cursor(),line_break, field_separator, string_delimiter, force_memchr);
-        row.set_global_cursor(acumulator);
-        func(&mut row,thread_id, param.clone()); // solo se pasa el Arc
-        acumulator += iterator.get_cursor();
-    }
+    execute_task_in_thread_with_header(
+        slice,
+        line_break,
+        field_separator,
+        string_delimiter,
+        force_memchr,
+        func,
+        param,
+        None,
+        thread_id,
+    )
 }
 
+/// ## Execute Task In Thread With Header
+/// - Same as `execute_task_in_thread`, attaching the header to each `RowParallel`, enabling by-name lookups.
+#[allow(clippy::too_many_arguments)]
+pub fn execute_task_in_thread_with_header<'mmap,Closure, Param>(
+    slice: &'mmap [u8],
+    line_break: impl Into<Delimiter>,
+    field_separator: impl Into<Delimiter>,
+    string_delimiter: u8,
+    force_memchr: bool,
+    mut func: Closure,
+    param: Arc<Mutex<Param>>,
+    header: Option<Arc<Header>>,
+    thread_id: usize,
+)
+where
+    Closure: FnMut(&mut RowParallel<'mmap>,usize, Arc<Mutex<Param>>) + Send,
+    Param: Send + 'mmap,
+{
+    let rows = ChunkRows {
+        slice,
+        line_break: line_break.into(),
+        field_separator: field_separator.into(),
+        string_delimiter,
+        force_memchr,
+        trim: Trim::None,
+        header: &header,
+        chunk: Chunk { end: slice.len(), ..Chunk::default() },
+    };
+    rows.for_each(|row| func(row, thread_id, param.clone())); // solo se pasa el Arc
+}
 
-
+/// ## Execute Task With Param Thread
+/// - Same as `execute_task_in_thread`, with a state owned by the thread: `thread_param` is cloned once, and the
+///   clone is given to `func` with every row.
+/// - To combine the states of the threads, use `parallel_fold`.
+#[allow(clippy::too_many_arguments)]
 pub fn execute_task_with_param_thread<'mmap,Closure, Param, CloneParam>(
     slice: &'mmap [u8],
-    line_break: u8,
-    field_separator: u8,
+    line_break: impl Into<Delimiter>,
+    field_separator: impl Into<Delimiter>,
     string_delimiter: u8,
     force_memchr: bool,
     mut func: Closure,
@@ -45,22 +106,167 @@ pub fn execute_task_with_param_thread<'mmap,Closure, Param, CloneParam>(
     thread_param: EditablePersist<CloneParam>,
     thread_id: usize,
 )
-where Closure: FnMut(&mut RowParallel<'mmap>,usize, Arc<Mutex<Param>>, &mut EditablePersist<CloneParam>) + Send,
-      Param: Send + 'mmap,
-      CloneParam: Clone,
-
+where
+    Closure: FnMut(&mut RowParallel<'mmap>,usize, Arc<Mutex<Param>>, &mut EditablePersist<CloneParam>) + Send,
+    Param: Send + 'mmap,
+    CloneParam: Clone,
 {
-    //global file iter
-    let mut iterator = InRowIter::new(slice, line_break, string_delimiter);
-    let mut acumulator = 0usize;
+    let rows = ChunkRows {
+        slice,
+        line_break: line_break.into(),
+        field_separator: field_separator.into(),
+        string_delimiter,
+        force_memchr,
+        trim: Trim::None,
+        header: &None,
+        chunk: Chunk { end: slice.len(), ..Chunk::default() },
+    };
     let mut param_thread = thread_param.clone();
-    while let Some(row_data) = iterator.next() {
-        if acumulator == 0{
-            acumulator = iterator.get_cursor();
+    rows.for_each(|row| func(row, thread_id, param.clone(), &mut param_thread));
+}
+
+/// ## Execute Chunk In Thread
+/// - Same as `execute_task_in_thread`, for a chunk of the slice. The rows can peek the rows of the next chunks.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn execute_chunk_in_thread<'mmap, Closure, Param>(
+    slice: &'mmap [u8],
+    line_break: Delimiter,
+    field_separator: Delimiter,
+    string_delimiter: u8,
+    force_memchr: bool,
+    trim: Trim,
+    func: &mut Closure,
+    param: &Arc<Mutex<Param>>,
+    header: &Option<Arc<Header>>,
+    thread_id: usize,
+    chunk: Chunk,
+) -> ChunkScan
+where
+    Closure: FnMut(&mut RowParallel<'mmap>, usize, Arc<Mutex<Param>>),
+{
+    let rows = ChunkRows { slice, line_break, field_separator, string_delimiter, force_memchr, trim, header, chunk };
+    rows.for_each(|row| func(row, thread_id, Arc::clone(param)))
+}
+
+/// ## Execute Chunk Until
+/// - Same as `execute_chunk_in_thread`, with a closure that can stop the scan.
+/// - Stops before the next row if `cancel` is cancelled, and cancels it when the closure returns `ControlFlow::Break`,
+///   so the other threads stop too. The returned flow is `Break` if the chunk was not finished.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn execute_chunk_until<'mmap, Closure, Param>(
+    slice: &'mmap [u8],
+    line_break: Delimiter,
+    field_separator: Delimiter,
+    string_delimiter: u8,
+    force_memchr: bool,
+    trim: Trim,
+    func: &mut Closure,
+    param: &Arc<Mutex<Param>>,
+    header: &Option<Arc<Header>>,
+    thread_id: usize,
+    chunk: Chunk,
+    cancel: &CancelToken,
+) -> ChunkScan
+where
+    Closure: FnMut(&mut RowParallel<'mmap>, usize, Arc<Mutex<Param>>) -> ControlFlow<()>,
+{
+    let rows = ChunkRows { slice, line_break, field_separator, string_delimiter, force_memchr, trim, header, chunk };
+    rows.try_for_each(|row| {
+        if cancel.is_cancelled() {
+            return ControlFlow::Break(());
+        }
+        let flow = func(row, thread_id, Arc::clone(param));
+        if flow.is_break() {
+            cancel.cancel();
+        }
+        flow
+    })
+}
+
+/// ## Fold Task In Thread
+/// - Folds every row of a chunk into the state owned by the thread. No lock is taken.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn fold_task_in_thread<'mmap, Fold, State>(
+    slice: &'mmap [u8],
+    line_break: Delimiter,
+    field_separator: Delimiter,
+    string_delimiter: u8,
+    force_memchr: bool,
+    trim: Trim,
+    fold: &mut Fold,
+    state: &mut EditablePersist<State>,
+    header: &Option<Arc<Header>>,
+    chunk: Chunk,
+) -> ChunkScan
+where
+    Fold: FnMut(&mut State, &mut RowParallel<'mmap>),
+{
+    let rows = ChunkRows { slice, line_break, field_separator, string_delimiter, force_memchr, trim, header, chunk };
+    rows.for_each(|row| fold(state.get_mut(), row))
+}
+
+/// ## Count Records
+/// - Counts the records of a chunk, splitting it as the tasks do.
+pub(crate) fn count_records(slice: &[u8], line_break: Delimiter, string_delimiter: u8) -> usize {
+    let mut cursor = 0usize;
+    let mut records = 0usize;
+    while cursor < slice.len() {
+        cursor = find_record_end(slice, cursor, line_break, string_delimiter, false).1;
+        records += 1;
+    }
+    records
+}
+
+/// Rows of a chunk of the slice, split on record boundaries (line breaks inside quotes are skipped).
+struct ChunkRows<'a, 'mmap> {
+    slice: &'mmap [u8],
+    line_break: Delimiter,
+    field_separator: Delimiter,
+    string_delimiter: u8,
+    force_memchr: bool,
+    trim: Trim,
+    header: &'a Option<Arc<Header>>,
+    chunk: Chunk,
+}
+
+impl<'mmap> ChunkRows<'_, 'mmap> {
+    /// Calls `func` with every row of the chunk.
+    fn for_each(self, mut func: impl FnMut(&mut RowParallel<'mmap>)) -> ChunkScan {
+        self.try_for_each(|row| {
+            func(row);
+            ControlFlow::Continue(())
+        })
+    }
+
+    /// Calls `func` with every row of the chunk, until it returns `Break`. The buffer of field offsets is reused
+    /// between rows.
+    /// The rows see the whole slice, so `peek_next` crosses the end of the chunk.
+    fn try_for_each(self, mut func: impl FnMut(&mut RowParallel<'mmap>) -> ControlFlow<()>) -> ChunkScan {
+        let slice = self.slice;
+        let chunk = self.chunk;
+        let mut cursor = chunk.start;
+        let mut records = 0usize;
+        let mut record = chunk.record;
+        let mut offsets = Vec::new();
+        while cursor < chunk.end {
+            let (end, next) = find_record_end(slice, cursor, self.line_break, self.string_delimiter, false);
+            let mut row = RowParallel::new(slice, &slice[cursor..end], next, self.line_break, self.field_separator, self.string_delimiter, self.force_memchr)
+                .with_header(self.header.clone())
+                .with_trim(self.trim)
+                .with_position(chunk.base, cursor, record)
+                .with_offsets_buffer(offsets);
+            row.set_global_cursor(next);
+            let flow = func(&mut row);
+            if flow.is_break() {
+                return ChunkScan { bytes: cursor - chunk.start, records, flow };
+            }
+            offsets = row.take_offsets_buffer();
+            cursor = next;
+            records += 1;
+            if record != 0 {
+                record += 1;
+            }
         }
-        let mut row = RowParallel::new(slice,row_data, iterator.get_cursor(),line_break, field_separator, string_delimiter, force_memchr);
-        row.set_global_cursor(acumulator);
-        func(&mut row,thread_id, param.clone(), &mut param_thread);
-        acumulator += iterator.get_cursor();
+        ChunkScan { bytes: chunk.end - chunk.start, records, flow: ControlFlow::Continue(()) }
     }
 }
@@ -1,14 +1,20 @@
-use std::cmp::min;
-use std::sync::{Arc, Mutex};
+use std::ops::ControlFlow;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
 use std::thread::scope;
-use crate::models::in_row_iter::InRowIter;
-use crate::models::worker::execute_task_in_thread;
+use crate::helpers::bytes_helper::{ends_inside_quotes, find_record_end};
+use crate::models::cancel_token::{CancelToken, ScanOutcome};
+use crate::models::delimiter::Delimiter;
+use crate::models::header::Header;
+use crate::models::parallel_config::ParallelConfig;
+use crate::models::editable_persist::EditablePersist;
+use crate::models::worker::{count_records, execute_chunk_in_thread, execute_chunk_until, fold_task_in_thread, Chunk, ChunkScan};
 use crate::parallel::row_parallel::RowParallel;
 
 pub fn parallel_processing_csv<'mmap,Closure, Param>(
     slice: &'mmap [u8],
-    line_break: u8,
-    field_separator: u8,
+    line_break: impl Into<Delimiter>,
+    field_separator: impl Into<Delimiter>,
     string_delimiter: u8,
     force_memchr: bool,
     func: Closure,
@@ -18,50 +24,563 @@ where
     Closure: FnMut(&mut RowParallel<'mmap>,usize, Arc<Mutex<Param>>) + Send + Clone + 'mmap,
     Param: Send + Default + 'mmap,
 {
-    let cores = num_cpus::get();
-    let average = slice.len() / cores;
-    let mut positions = vec![0; cores + 1];
-    let mut iter = InRowIter::new(slice, line_break, string_delimiter);
-    iter.set_cursor(average);
-    let mut i = 1;
-    while let Some(_) = iter.next() {
-        if i >= positions.len() {
-            break;
+    parallel_processing_csv_with_header(
+        slice,
+        None,
+        line_break,
+        field_separator,
+        string_delimiter,
+        force_memchr,
+        func,
+        shared,
+    )
+}
+
+/// ## Parallel Processing With Header
+/// - Same as `parallel_processing_csv`, but attaches the header to each `RowParallel`, enabling by-name lookups.
+/// - The slice must not contain the header record. Use `CsvReaderWithMap::get_data_slice`.
+#[allow(clippy::too_many_arguments)]
+pub fn parallel_processing_csv_with_header<'mmap,Closure, Param>(
+    slice: &'mmap [u8],
+    header: Option<&Header>,
+    line_break: impl Into<Delimiter>,
+    field_separator: impl Into<Delimiter>,
+    string_delimiter: u8,
+    force_memchr: bool,
+    func: Closure,
+    shared: Arc<Mutex<Param>>,
+)
+where
+    Closure: FnMut(&mut RowParallel<'mmap>,usize, Arc<Mutex<Param>>) + Send + Clone + 'mmap,
+    Param: Send + Default + 'mmap,
+{
+    parallel_processing_csv_with_config(
+        slice,
+        header,
+        line_break,
+        field_separator,
+        string_delimiter,
+        force_memchr,
+        func,
+        shared,
+        &ParallelConfig::default(),
+    )
+}
+
+/// ## Parallel Processing With Config
+/// - Same as `parallel_processing_csv_with_header`, with the number of threads and the chunk size set by `config`.
+/// - The slice is cut into chunks of about `config.chunk_size` bytes, ending on record boundaries (line breaks
+///   inside quoted fields are skipped). Each thread takes the next chunk from a shared queue until none is left.
+/// - Each thread clones `func` once, and uses it for all its chunks. `thread_id` goes from `0` to the number of threads.
+/// - The rows report their byte range in the file, and their record number if `config.first_record` is set
+///   (see `ParallelConfig::with_origin`).
+/// - With `ParallelConfig::with_progress`, the bytes and records processed are published after each chunk.
+#[allow(clippy::too_many_arguments)]
+pub fn parallel_processing_csv_with_config<'mmap,Closure, Param>(
+    slice: &'mmap [u8],
+    header: Option<&Header>,
+    line_break: impl Into<Delimiter>,
+    field_separator: impl Into<Delimiter>,
+    string_delimiter: u8,
+    force_memchr: bool,
+    func: Closure,
+    shared: Arc<Mutex<Param>>,
+    config: &ParallelConfig,
+)
+where
+    Closure: FnMut(&mut RowParallel<'mmap>,usize, Arc<Mutex<Param>>) + Send + Clone + 'mmap,
+    Param: Send + Default + 'mmap,
+{
+    let header = header.cloned().map(Arc::new);
+    let line_break = line_break.into();
+    let field_separator = field_separator.into();
+    let bounds = chunk_bounds(slice, line_break, string_delimiter, config);
+    let chunks = build_chunks(slice, &bounds, line_break, string_delimiter, config);
+    let next_chunk = AtomicUsize::new(0);
+    let threads = config.thread_count(chunks.len());
+    start_progress(config, slice.len(), threads);
+    scope(|s| {
+        for thread_id in 0..threads {
+            let mut func = func.clone();
+            let param = Arc::clone(&shared);
+            let header = header.clone();
+            let (chunks, next_chunk) = (&chunks, &next_chunk);
+            s.spawn(move || {
+                while let Some(&chunk) = chunks.get(next_chunk.fetch_add(1, Ordering::Relaxed)) {
+                    let scan = execute_chunk_in_thread(
+                        slice,
+                        line_break,
+                        field_separator,
+                        string_delimiter,
+                        force_memchr,
+                        config.trim,
+                        &mut func,
+                        &param,
+                        &header,
+                        thread_id,
+                        chunk,
+                    );
+                    report_chunk(config, thread_id, &scan);
+                }
+            });
         }
-        positions[i] = min(iter.get_cursor(), slice.len());
-        iter.set_cursor(average * i);
-        i += 1;
-    }
-    positions[cores] = slice.len();
+    });
+    finish_progress(config);
+}
+
+/// ## Parallel Processing Until
+/// - Same as `parallel_processing_csv_with_config`, with a closure that can stop the scan: returning
+///   `ControlFlow::Break(())` cancels `cancel`, and every thread stops before its next row.
+/// - `cancel` can also be cancelled from outside (another thread, a timeout...). Rows already running are finished.
+/// - The chunks are cut (and their records counted) when a thread asks for one, so a cancelled scan does not read
+///   the rest of the slice.
+/// - Returns `ScanOutcome::Completed` if every row was processed, or `ScanOutcome::Cancelled`.
+/// ### Code Example:
+/// ```
+/// use std::ops::ControlFlow;
+/// use std::sync::{Arc, Mutex};
+/// use csv_lib::models::cancel_token::{CancelToken, ScanOutcome};
+/// use csv_lib::models::parallel_config::ParallelConfig;
+/// use csv_lib::models::shared::Shared;
+/// use csv_lib::parallel::parallel_reader::parallel_processing_csv_until;
+/// use csv_lib::parallel::row_parallel::RowParallel;
+///
+/// let data = b"a,1\nb,2\nneedle,3\nc,4\n";
+/// let found = Shared::<Option<i64>>::default();
+/// let outcome = parallel_processing_csv_until(
+///     data,
+///     None,
+///     b'\n',
+///     b',',
+///     b'"',
+///     false,
+///     |row: &mut RowParallel<'_>, _: usize, found: Arc<Mutex<Option<i64>>>| {
+///         if row.as_row().get_index(0).get_utf8_as_str() == "needle" {
+///             *found.lock().unwrap() = Some(row.as_row().get_index(1).get_i64());
+///             return ControlFlow::Break(());
+///         }
+///         ControlFlow::Continue(())
+///     },
+///     found.arc(),
+///     &ParallelConfig::default(),
+///     &CancelToken::new(),
+/// );
+/// assert_eq!(outcome, ScanOutcome::Cancelled);
+/// assert_eq!(*found.lock(), Some(3));
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn parallel_processing_csv_until<'mmap,Closure, Param>(
+    slice: &'mmap [u8],
+    header: Option<&Header>,
+    line_break: impl Into<Delimiter>,
+    field_separator: impl Into<Delimiter>,
+    string_delimiter: u8,
+    force_memchr: bool,
+    func: Closure,
+    shared: Arc<Mutex<Param>>,
+    config: &ParallelConfig,
+    cancel: &CancelToken,
+) -> ScanOutcome
+where
+    Closure: FnMut(&mut RowParallel<'mmap>,usize, Arc<Mutex<Param>>) -> ControlFlow<()> + Send + Clone + 'mmap,
+    Param: Send + Default + 'mmap,
+{
+    let header = header.cloned().map(Arc::new);
+    let line_break = line_break.into();
+    let field_separator = field_separator.into();
+    // The chunks are cut (and counted) on demand, so a cancelled scan stops reading the slice too
+    let cursor = ChunkCursor::new(slice, line_break, string_delimiter, config);
+    let stopped = AtomicBool::new(false);
+    let threads = config.thread_count(slice.len() / config.chunk_size.max(1) + 1);
+    start_progress(config, slice.len(), threads);
     scope(|s| {
-        for i in 0..cores {
-            let func = func.clone();
+        for thread_id in 0..threads {
+            let mut func = func.clone();
             let param = Arc::clone(&shared);
-            let slice = &slice[positions[i]..positions[i + 1]];
+            let header = header.clone();
+            let (cursor, stopped) = (&cursor, &stopped);
             s.spawn(move || {
-                execute_task_in_thread(
-                    slice,
-                    line_break,
-                    field_separator,
-                    string_delimiter,
-                    force_memchr,
-                    func,
-                    param,
-                    i
-                );
+                while let Some(chunk) = cursor.next_chunk(cancel) {
+                    let scan = execute_chunk_until(
+                        slice,
+                        line_break,
+                        field_separator,
+                        string_delimiter,
+                        force_memchr,
+                        config.trim,
+                        &mut func,
+                        &param,
+                        &header,
+                        thread_id,
+                        chunk,
+                        cancel,
+                    );
+                    report_chunk(config, thread_id, &scan);
+                    if scan.flow.is_break() {
+                        stopped.store(true, Ordering::Relaxed);
+                        break;
+                    }
+                }
             });
         }
     });
+    finish_progress(config);
+    if stopped.into_inner() || !cursor.is_done() {
+        ScanOutcome::Cancelled
+    } else {
+        ScanOutcome::Completed
+    }
+}
+
+/// ## Parallel Fold
+/// - Map-reduce over the rows: each thread owns a state created by `init`, and folds its rows into it with `fold`,
+///   without locks. When every chunk is done, the states are combined with `merge` (in thread order), and returned.
+/// - The chunks are taken from a shared queue, as in `parallel_processing_csv_with_config`.
+/// ### Code Example:
+/// ```
+/// use csv_lib::models::parallel_config::ParallelConfig;
+/// use csv_lib::parallel::parallel_reader::parallel_fold;
+///
+/// let data = b"a,1\nb,2\nc,3\n";
+/// let (rows, total) = parallel_fold(
+///     data,
+///     None,
+///     b'\n',
+///     b',',
+///     b'"',
+///     false,
+///     &ParallelConfig::default().with_threads(2),
+///     || (0usize, 0i64),
+///     |state, row| {
+///         state.0 += 1;
+///         state.1 += row.as_row().get_index(1).get_i64();
+///     },
+///     |a, b| (a.0 + b.0, a.1 + b.1),
+/// );
+/// assert_eq!((rows, total), (3, 6));
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn parallel_fold<'mmap, State, Init, Fold, Merge>(
+    slice: &'mmap [u8],
+    header: Option<&Header>,
+    line_break: impl Into<Delimiter>,
+    field_separator: impl Into<Delimiter>,
+    string_delimiter: u8,
+    force_memchr: bool,
+    config: &ParallelConfig,
+    init: Init,
+    fold: Fold,
+    mut merge: Merge,
+) -> State
+where
+    State: Send,
+    Init: Fn() -> State + Sync,
+    Fold: FnMut(&mut State, &mut RowParallel<'mmap>) + Send + Clone,
+    Merge: FnMut(State, State) -> State,
+{
+    let header = header.cloned().map(Arc::new);
+    let line_break = line_break.into();
+    let field_separator = field_separator.into();
+    let bounds = chunk_bounds(slice, line_break, string_delimiter, config);
+    let chunks = build_chunks(slice, &bounds, line_break, string_delimiter, config);
+    let next_chunk = AtomicUsize::new(0);
+    let threads = config.thread_count(chunks.len());
+    start_progress(config, slice.len(), threads);
+    let states: Vec<State> = scope(|s| {
+        let handles: Vec<_> = (0..threads)
+            .map(|thread_id| {
+                let mut fold = fold.clone();
+                let header = header.clone();
+                let (chunks, next_chunk, init) = (&chunks, &next_chunk, &init);
+                s.spawn(move || {
+                    let mut state = EditablePersist::new(init());
+                    while let Some(&chunk) = chunks.get(next_chunk.fetch_add(1, Ordering::Relaxed)) {
+                        let scan = fold_task_in_thread(
+                            slice,
+                            line_break,
+                            field_separator,
+                            string_delimiter,
+                            force_memchr,
+                            config.trim,
+                            &mut fold,
+                            &mut state,
+                            &header,
+                            chunk,
+                        );
+                        report_chunk(config, thread_id, &scan);
+                    }
+                    state.into_inner()
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap_or_else(|panic| std::panic::resume_unwind(panic)))
+            .collect()
+    });
+    finish_progress(config);
+    let mut states = states.into_iter();
+    // There is always one thread at least
+    let first = states.next().unwrap_or_else(&init);
+    states.fold(first, &mut merge)
+}
+
+/// Resets the progress of `config`, if any, for a scan of `total_bytes` with `threads` threads.
+fn start_progress(config: &ParallelConfig, total_bytes: usize, threads: usize) {
+    if let Some(progress) = &config.progress {
+        progress.start(total_bytes, threads);
+    }
+}
+
+/// Publishes the work done on a chunk.
+fn report_chunk(config: &ParallelConfig, thread_id: usize, scan: &ChunkScan) {
+    if let Some(progress) = &config.progress {
+        progress.add(thread_id, scan.bytes, scan.records);
+    }
+}
+
+/// Marks the end of the scan.
+fn finish_progress(config: &ParallelConfig) {
+    if let Some(progress) = &config.progress {
+        progress.finish();
+    }
+}
+
+/// ## Build Chunks
+/// - Gets the chunks between the bounds, placed in the file with `config.offset` and `config.first_record`.
+/// - If `config.first_record` is set, the records of every chunk are counted in parallel (a line break search per
+///   record, without splitting the fields), and the record number of each chunk is the sum of the previous counts.
+pub(crate) fn build_chunks(
+    slice: &[u8],
+    bounds: &[usize],
+    line_break: Delimiter,
+    string_delimiter: u8,
+    config: &ParallelConfig,
+) -> Vec<Chunk> {
+    let mut chunks: Vec<Chunk> = bounds
+        .windows(2)
+        .map(|bound| Chunk { start: bound[0], end: bound[1], base: config.offset, record: 0 })
+        .collect();
+    if config.first_record == 0 {
+        return chunks;
+    }
+    let counts: Vec<AtomicUsize> = chunks.iter().map(|_| AtomicUsize::new(0)).collect();
+    let next_chunk = AtomicUsize::new(0);
+    scope(|s| {
+        for _ in 0..config.thread_count(chunks.len()) {
+            let (chunks, counts, next_chunk) = (&chunks, &counts, &next_chunk);
+            s.spawn(move || loop {
+                let index = next_chunk.fetch_add(1, Ordering::Relaxed);
+                let chunk = match chunks.get(index) {
+                    Some(chunk) => chunk,
+                    None => break,
+                };
+                let records = count_records(&slice[chunk.start..chunk.end], line_break, string_delimiter);
+                counts[index].store(records, Ordering::Relaxed);
+            });
+        }
+    });
+    let mut record = config.first_record;
+    for (chunk, count) in chunks.iter_mut().zip(counts) {
+        chunk.record = record;
+        record += count.into_inner();
+    }
+    chunks
+}
+
+/// ## Chunk Cursor
+/// - Hands out the chunks of a slice one by one, for the scans that can be cancelled: nothing is cut, nor counted,
+///   past the chunks asked for.
+/// - The threads claim the pieces of `chunk_size` bytes from an atomic queue, and count their quotes (and records,
+///   if `config.first_record` is set) in parallel. The counts are chained in piece order behind a short lock: each
+///   piece gets the quote state and the record number at its start from the previous one.
+/// - A chunk goes from the start of the first record of its piece to the start of the first record of the next one.
+struct ChunkCursor<'s> {
+    slice: &'s [u8],
+    line_break: Delimiter,
+    string_delimiter: u8,
+    chunk_size: usize,
+    base: usize,
+    count_records: bool,
+    pieces: usize,
+    next_piece: AtomicUsize,
+    chain: Mutex<CursorChain>,
+    chained: Condvar,
+}
+
+/// Quote state and record number at the start of the next pieces to chain.
+struct CursorChain {
+    quote_piece: usize,
+    in_string: bool,
+    record_piece: usize,
+    record: usize,
+}
+
+impl<'s> ChunkCursor<'s> {
+    /// Creates a cursor at the start of the slice.
+    fn new(slice: &'s [u8], line_break: Delimiter, string_delimiter: u8, config: &ParallelConfig) -> Self {
+        let chunk_size = config.chunk_size.max(1);
+        Self {
+            slice,
+            line_break,
+            string_delimiter,
+            chunk_size,
+            base: config.offset,
+            count_records: config.first_record != 0,
+            pieces: slice.len().div_ceil(chunk_size),
+            next_piece: AtomicUsize::new(0),
+            chain: Mutex::new(CursorChain { quote_piece: 0, in_string: false, record_piece: 0, record: config.first_record }),
+            chained: Condvar::new(),
+        }
+    }
+
+    /// Cuts the next chunk. Returns `None` at the end of the slice, or if `cancel` is cancelled.
+    /// - A claimed piece is always chained, so the threads waiting for it are never left behind.
+    fn next_chunk(&self, cancel: &CancelToken) -> Option<Chunk> {
+        loop {
+            if cancel.is_cancelled() {
+                return None;
+            }
+            let piece = self.next_piece.fetch_add(1, Ordering::Relaxed);
+            if piece >= self.pieces {
+                return None;
+            }
+            let start = piece * self.chunk_size;
+            let end = (start + self.chunk_size).min(self.slice.len());
+            let odd = ends_inside_quotes(&self.slice[start..end], self.string_delimiter);
+            let in_string = self.chain(|chain| chain.quote_piece == piece, |chain| {
+                let in_string = chain.in_string;
+                chain.in_string ^= odd;
+                chain.quote_piece += 1;
+                in_string
+            });
+            let first = if piece == 0 { 0 } else { self.record_start(start, in_string) };
+            let last = if end == self.slice.len() { end } else { self.record_start(end, in_string ^ odd) };
+            let record = if self.count_records {
+                let records = count_records(&self.slice[first..last], self.line_break, self.string_delimiter);
+                self.chain(|chain| chain.record_piece == piece, |chain| {
+                    let record = chain.record;
+                    chain.record += records;
+                    chain.record_piece += 1;
+                    record
+                })
+            } else {
+                0
+            };
+            // The records of a piece can start in the next pieces
+            if first < last {
+                return Some(Chunk { start: first, end: last, base: self.base, record });
+            }
+        }
+    }
+
+    /// Returns `true` once every chunk was handed out.
+    fn is_done(&self) -> bool {
+        self.next_piece.load(Ordering::Relaxed) >= self.pieces
+    }
+
+    /// Start of the first record at or after `position`, with the quote state at `position`.
+    fn record_start(&self, position: usize, in_string: bool) -> usize {
+        find_record_end(self.slice, position, self.line_break, self.string_delimiter, in_string).1
+    }
+
+    /// Waits until the previous pieces are chained (`ready`), and chains the next one with `step`.
+    fn chain<T>(&self, ready: impl Fn(&CursorChain) -> bool, step: impl FnOnce(&mut CursorChain) -> T) -> T {
+        // The chain stays usable if a thread panicked while holding the lock
+        let mut chain = self.chain.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        while !ready(&chain) {
+            chain = self.chained.wait(chain).unwrap_or_else(|poisoned| poisoned.into_inner());
+        }
+        let value = step(&mut chain);
+        drop(chain);
+        self.chained.notify_all();
+        value
+    }
+}
+
+/// ## Chunk Bounds
+/// - Cuts the slice into chunks of about `config.chunk_size` bytes, each one ending after a line break.
+/// - The slice is cut at every multiple of the chunk size, and each cut is moved to the start of the next record,
+///   with the quote state given by `quote_states`.
+/// - Returns the start of each chunk, and `slice.len()` at the end.
+pub(crate) fn chunk_bounds(slice: &[u8], line_break: Delimiter, string_delimiter: u8, config: &ParallelConfig) -> Vec<usize> {
+    let chunk_size = config.chunk_size.max(1);
+    let states = quote_states(slice, string_delimiter, chunk_size, config);
+    let mut bounds = Vec::with_capacity(states.len() + 2);
+    bounds.push(0);
+    let mut last = 0;
+    for (piece, in_string) in states.into_iter().enumerate() {
+        let cut = (piece + 1) * chunk_size;
+        if cut < last {
+            // Inside the last record of the previous chunk
+            continue;
+        }
+        let (_, next) = find_record_end(slice, cut, line_break, string_delimiter, in_string);
+        if next >= slice.len() {
+            break;
+        }
+        if next > last {
+            bounds.push(next);
+            last = next;
+        }
+    }
+    bounds.push(slice.len());
+    bounds
+}
+
+/// ## Quote States
+/// - Gets the quote state (`true` inside a quoted field) at each multiple of `chunk_size` inside the slice.
+/// - The quotes of the pieces between the cuts are counted in parallel, and the state at a cut is the parity of
+///   the quotes before it.
+fn quote_states(slice: &[u8], string_delimiter: u8, chunk_size: usize, config: &ParallelConfig) -> Vec<bool> {
+    let cuts = slice.len().saturating_sub(1) / chunk_size;
+    let odd: Vec<AtomicBool> = (0..cuts).map(|_| AtomicBool::new(false)).collect();
+    if string_delimiter != 0 {
+        let next_piece = AtomicUsize::new(0);
+        scope(|s| {
+            for _ in 0..config.thread_count(cuts) {
+                let (odd, next_piece) = (&odd, &next_piece);
+                s.spawn(move || loop {
+                    let piece = next_piece.fetch_add(1, Ordering::Relaxed);
+                    if piece >= cuts {
+                        break;
+                    }
+                    let start = piece * chunk_size;
+                    let quotes = ends_inside_quotes(&slice[start..start + chunk_size], string_delimiter);
+                    odd[piece].store(quotes, Ordering::Relaxed);
+                });
+            }
+        });
+    }
+    let mut in_string = false;
+    odd.into_iter()
+        .map(|odd| {
+            in_string ^= odd.into_inner();
+            in_string
+        })
+        .collect()
 }
 
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashMap;
     use std::sync::{Arc, Mutex};
     use crate::csv::csv_reader::CsvReaderWithMap;
     use crate::models::csv_config::CsvConfig;
+    use crate::models::record_terminator::RecordTerminator;
     use crate::models::shared::Shared;
-    use crate::parallel::parallel_reader::parallel_processing_csv;
+    use crate::csv::reader_slice::CsvReaderSlice;
+    use crate::models::cancel_token::{CancelToken, ScanOutcome};
+    use crate::models::delimiter::Delimiter;
+    use crate::models::parallel_config::ParallelConfig;
+    use crate::models::progress::Progress;
+    use crate::models::trim::Trim;
+    use crate::parallel::parallel_reader::{
+        chunk_bounds, parallel_fold, ChunkCursor, parallel_processing_csv, parallel_processing_csv_until, parallel_processing_csv_with_config,
+        parallel_processing_csv_with_header,
+    };
+    use std::ops::ControlFlow;
     use crate::parallel::row_parallel::RowParallel;
 
     #[test]
@@ -109,5 +628,324 @@ mod tests {
 
     }
 
+    #[test]
+    fn test_parallel_read_with_header() {
+        let cfg = CsvConfig {
+            delimiter: b',',
+            has_header: true,
+            ..CsvConfig::default()
+        };
+        let mut data = String::from("name,amount\n");
+        for i in 1..=1000 {
+            data.push_str(&format!("n{},{}\n", i, i));
+        }
+        let reader = CsvReaderSlice::new_from_slice(data.as_bytes(), &cfg);
+        let header = reader.get_header();
+        let amount = header.and_then(|h| h.column("amount")).expect("column");
+        let shared = Shared::<i64>::default();
+        let closure = move |row: &mut RowParallel<'_>, _: usize, target: Arc<Mutex<i64>>| {
+            assert!(row.get_by_name("name").get_utf8_as_str().starts_with('n'));
+            *target.lock().unwrap() += row.get_column(amount).get_i64();
+        };
+        parallel_processing_csv_with_header(
+            reader.get_data_slice(),
+            header,
+            b'\n',
+            b',',
+            0u8,
+            false,
+            closure,
+            shared.arc(),
+        );
+        assert_eq!(*shared.lock(), 500500);
+    }
+
+    #[test]
+    fn test_parallel_read_strict_crlf() {
+        let cfg = CsvConfig {
+            delimiter: b',',
+            ..CsvConfig::default()
+        }
+        .with_terminator(RecordTerminator::Crlf);
+        let mut data = String::new();
+        for i in 1..=1000 {
+            data.push_str(&format!("note\n{},{}\r\n", i, i));
+        }
+        let shared = Shared::<i64>::default();
+        let closure = |row: &mut RowParallel<'_>, _: usize, target: Arc<Mutex<i64>>| {
+            assert!(row.as_row().get_index(0).get_utf8_as_str().starts_with("note\n"));
+            *target.lock().unwrap() += row.as_row().get_index(1).get_i64();
+        };
+        parallel_processing_csv(
+            data.as_bytes(),
+            cfg.record_delimiter(),
+            cfg.field_delimiter(),
+            0u8,
+            false,
+            closure,
+            shared.arc(),
+        );
+        assert_eq!(*shared.lock(), 500500);
+    }
+
+    #[test]
+    fn test_parallel_read_with_config() {
+        // Quoted line breaks, and records starting with quotes, cut at many chunk boundaries
+        let mut data = String::new();
+        for i in 1..=1000 {
+            data.push_str(&format!("\"n\n{}\",{},\"say \"\"hi\"\"\"\n", i, i));
+        }
+        let bounds = chunk_bounds(data.as_bytes(), Delimiter::byte(b'\n'), b'"', &ParallelConfig::default().with_chunk_size(100));
+        assert!(bounds.len() > 100);
+        let inner = &bounds[1..bounds.len() - 1];
+        assert!(inner.iter().all(|b| data.as_bytes()[b - 1] == b'\n' && data.as_bytes()[*b] == b'"'));
+        let shared = Shared::<(i64, usize, usize)>::default();
+        let closure = |row: &mut RowParallel<'_>, thread_id: usize, target: Arc<Mutex<(i64, usize, usize)>>| {
+            let row = row.as_row();
+            assert_eq!(row.get_index(2).get_utf8_unescaped(), "say \"hi\"");
+            let mut lock = target.lock().unwrap();
+            lock.0 += row.get_index(1).get_i64();
+            lock.1 += 1;
+            lock.2 = lock.2.max(thread_id);
+        };
+        parallel_processing_csv_with_config(
+            data.as_bytes(),
+            None,
+            b'\n',
+            b',',
+            b'"',
+            false,
+            closure,
+            shared.arc(),
+            &ParallelConfig::default().with_threads(3).with_chunk_size(100),
+        );
+        let (sum, records, max_thread) = *shared.lock();
+        assert_eq!((sum, records), (500500, 1000));
+        assert!(max_thread < 3);
+    }
+
+    #[test]
+    fn test_parallel_record_numbers() {
+        let cfg = CsvConfig {
+            delimiter: b',',
+            string_separator: b'"',
+            has_header: true,
+            ..CsvConfig::default()
+        };
+        let mut data = String::from("id,note\n");
+        for i in 1..=1000 {
+            data.push_str(&format!("{},\"line\n{}\"\n", i, i));
+        }
+        let reader = CsvReaderSlice::new_from_slice(data.as_bytes(), &cfg);
+        let (offset, first_record) = reader.get_data_origin();
+        let config = ParallelConfig::default().with_threads(3).with_chunk_size(300).with_origin(offset, first_record);
+        let (checked, last_rows) = parallel_fold(
+            reader.get_data_slice(),
+            reader.get_header(),
+            b'\n',
+            b',',
+            b'"',
+            false,
+            &config,
+            || (0usize, 0usize),
+            |(checked, last_rows), row| {
+                let id = row.as_row().get_index(0).get_i64() as usize;
+                // Record 1 is the header
+                assert_eq!(row.get_record_number(), id + 1);
+                assert_eq!(&data.as_bytes()[row.get_byte_range()], row.as_row().get_slice());
+                let next = row.peek_next();
+                if !next.is_empty() {
+                    assert_eq!(next.get_record_number(), id + 2);
+                    assert_eq!(next.get_index(0).get_i64() as usize, id + 1);
+                    assert_eq!(next.get_byte_range().start, row.get_byte_range().end + 1);
+                } else {
+                    *last_rows += 1;
+                }
+                *checked += 1;
+            },
+            |a, b| (a.0 + b.0, a.1 + b.1),
+        );
+        // The peek crosses the chunks, only the last row of the slice has no next row
+        assert_eq!((checked, last_rows), (1000, 1));
+        // Without origin, the record numbers are unknown and the ranges are relative to the slice
+        let data = b"a,1\nb,2\n";
+        type Positions = Vec<(usize, std::ops::Range<usize>)>;
+        let shared = Shared::<Positions>::default();
+        let closure = |row: &mut RowParallel<'_>, _: usize, target: Arc<Mutex<Positions>>| {
+            target.lock().unwrap().push((row.get_record_number(), row.get_byte_range()));
+        };
+        parallel_processing_csv_with_config(data, None, b'\n', b',', 0u8, false, closure, shared.arc(), &ParallelConfig::default().with_threads(1));
+        assert_eq!(*shared.lock(), vec![(0, 0..3), (0, 4..7)]);
+    }
+
+    #[test]
+    fn test_parallel_processing_until() {
+        let mut data = String::new();
+        for i in 1..=100_000 {
+            data.push_str(&format!("{},{}\n", i, i % 7));
+        }
+        let config = ParallelConfig::default().with_threads(4).with_chunk_size(4096);
+        let search = |target: i64| {
+            let shared = Shared::<(usize, Option<i64>)>::default();
+            let closure = move |row: &mut RowParallel<'_>, _: usize, found: Arc<Mutex<(usize, Option<i64>)>>| {
+                let id = row.as_row().get_index(0).get_i64();
+                let mut lock = found.lock().unwrap();
+                lock.0 += 1;
+                if id == target {
+                    lock.1 = Some(id);
+                    return ControlFlow::Break(());
+                }
+                ControlFlow::Continue(())
+            };
+            let cancel = CancelToken::new();
+            let outcome = parallel_processing_csv_until(data.as_bytes(), None, b'\n', b',', 0u8, false, closure, shared.arc(), &config, &cancel);
+            let result = *shared.lock();
+            (outcome, cancel.is_cancelled(), result)
+        };
+        // The other threads stop before their next row, far from the end
+        let (outcome, cancelled, (rows, found)) = search(500);
+        assert_eq!((outcome, cancelled, found), (ScanOutcome::Cancelled, true, Some(500)));
+        assert!(rows < 100_000);
+        let (outcome, cancelled, (rows, found)) = search(0);
+        assert_eq!((outcome, cancelled, rows, found), (ScanOutcome::Completed, false, 100_000, None));
+        // A token cancelled from outside stops the scan before the first row
+        let cancel = CancelToken::new();
+        cancel.cancel();
+        let shared = Shared::<usize>::default();
+        let closure = |_: &mut RowParallel<'_>, _: usize, rows: Arc<Mutex<usize>>| {
+            *rows.lock().unwrap() += 1;
+            ControlFlow::Continue(())
+        };
+        let outcome = parallel_processing_csv_until(data.as_bytes(), None, b'\n', b',', 0u8, false, closure, shared.arc(), &config, &cancel);
+        assert_eq!((outcome, *shared.lock()), (ScanOutcome::Cancelled, 0));
+    }
 
-}
\ No newline at end of file
+    #[test]
+    fn test_chunk_cursor() {
+        let mut data = String::new();
+        for i in 1..=100 {
+            data.push_str(&format!("{},\"x\ny\"\n", i));
+        }
+        let config = ParallelConfig::default().with_chunk_size(64).with_origin(10, 2);
+        let cursor = ChunkCursor::new(data.as_bytes(), Delimiter::byte(b'\n'), b'"', &config);
+        let cancel = CancelToken::new();
+        let mut chunks = vec![cursor.next_chunk(&cancel).expect("chunk")];
+        // Once cancelled, no chunk is cut
+        cancel.cancel();
+        assert!(cursor.next_chunk(&cancel).is_none());
+        assert!(!cursor.is_done());
+        let cancel = CancelToken::new();
+        while let Some(chunk) = cursor.next_chunk(&cancel) {
+            chunks.push(chunk);
+        }
+        assert!(cursor.is_done() && chunks.len() > 10);
+        let mut start = 0;
+        for chunk in &chunks {
+            // Contiguous chunks, cut on record starts, with the records before them counted
+            let records = data[..chunk.start].matches("\"\n").count();
+            assert_eq!((chunk.start, chunk.base, chunk.record), (start, 10, 2 + records));
+            assert!(chunk.end == data.len() || data.as_bytes()[chunk.end - 2..chunk.end] == *b"\"\n");
+            start = chunk.end;
+        }
+        assert_eq!(start, data.len());
+        // Claimed by several threads, the chunks are the same
+        let cursor = ChunkCursor::new(data.as_bytes(), Delimiter::byte(b'\n'), b'"', &config);
+        let claimed = Mutex::new(Vec::new());
+        std::thread::scope(|s| {
+            for _ in 0..4 {
+                s.spawn(|| {
+                    while let Some(chunk) = cursor.next_chunk(&cancel) {
+                        claimed.lock().unwrap().push((chunk.start, chunk.end, chunk.record));
+                    }
+                });
+            }
+        });
+        let mut claimed = claimed.into_inner().unwrap();
+        claimed.sort_unstable();
+        let expected: Vec<_> = chunks.iter().map(|chunk| (chunk.start, chunk.end, chunk.record)).collect();
+        assert_eq!(claimed, expected);
+    }
+
+    #[test]
+    fn test_parallel_progress() {
+        let mut data = String::new();
+        for i in 0..20_000 {
+            data.push_str(&format!("{},\"a\nb\"\n", i));
+        }
+        let progress = Progress::new();
+        let config = ParallelConfig::default().with_threads(3).with_chunk_size(4096).with_progress(&progress);
+        let rows = parallel_fold(data.as_bytes(), None, b'\n', b',', b'"', false, &config, || 0usize, |rows, _| *rows += 1, |a, b| a + b);
+        assert_eq!(rows, 20_000);
+        assert_eq!((progress.bytes(), progress.records(), progress.total_bytes()), (data.len(), 20_000, data.len()));
+        assert!(progress.is_finished());
+        let threads = progress.threads();
+        assert_eq!(threads.len(), 3);
+        assert_eq!(threads.iter().map(|t| t.records).sum::<usize>(), 20_000);
+        assert_eq!(threads.iter().map(|t| t.bytes).sum::<usize>(), data.len());
+        // A cancelled scan publishes the rows before the break
+        let closure = |row: &mut RowParallel<'_>, _: usize, _: Arc<Mutex<i32>>| {
+            if row.as_row().get_index(0).get_i64() == 10 {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        };
+        let config = config.with_threads(1);
+        let outcome = parallel_processing_csv_until(data.as_bytes(), None, b'\n', b',', b'"', false, closure, Shared::<i32>::default().arc(), &config, &CancelToken::new());
+        assert_eq!((outcome, progress.records(), progress.bytes()), (ScanOutcome::Cancelled, 10, 10 * 8));
+    }
+
+    #[test]
+    fn test_parallel_fold() {
+        let mut data = String::new();
+        for i in 0..3000 {
+            data.push_str(&format!("{},{}\n", ["red", "green", "blue"][i % 3], i));
+        }
+        let config = ParallelConfig::default().with_threads(4).with_chunk_size(512);
+        let counts = parallel_fold(
+            data.as_bytes(),
+            None,
+            b'\n',
+            b',',
+            0u8,
+            true,
+            &config,
+            HashMap::<String, (usize, i64)>::new,
+            |counts, row| {
+                let row = row.as_row();
+                let entry = counts.entry(row.get_index(0).get_utf8_as_str().to_string()).or_default();
+                entry.0 += 1;
+                entry.1 += row.get_index(1).get_i64();
+            },
+            |mut a, b| {
+                for (color, (count, sum)) in b {
+                    let entry = a.entry(color).or_default();
+                    entry.0 += count;
+                    entry.1 += sum;
+                }
+                a
+            },
+        );
+        assert_eq!(counts["red"], (1000, (0..3000).step_by(3).sum()));
+        assert_eq!(counts["blue"].0, 1000);
+        let empty = parallel_fold(b"", None, b'\n', b',', 0u8, false, &config, || 7, |_, _| {}, |a, b| a + b);
+        assert_eq!(empty, 7);
+        // The trim of the config applies to the rows, and to the peeked rows
+        let names = parallel_fold(
+            b" a ,1\n b ,2\n",
+            None,
+            b'\n',
+            b',',
+            b'"',
+            false,
+            &config.with_trim(Trim::Both),
+            String::new,
+            |names, row| {
+                names.push_str(row.as_row().get_index(0).get_utf8_as_str());
+                names.push_str(row.peek_next().get_index(0).get_utf8_as_str());
+            },
+            |a, b| a + &b,
+        );
+        assert_eq!(names, "abb");
+    }
+}
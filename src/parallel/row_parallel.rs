@@ -1,16 +1,25 @@
-use crate::models::in_row_iter::InRowIter;
+use crate::helpers::bytes_helper::find_record_end;
+use crate::models::delimiter::Delimiter;
+use crate::models::field::Field;
+use crate::models::header::{Column, Header};
 use crate::models::row::Row;
+use crate::models::trim::Trim;
+use std::ops::Range;
+use std::sync::Arc;
 #[allow(dead_code)]
 pub struct RowParallel<'mmap>{
     full : &'mmap[u8],
     slice: &'mmap[u8],
     cursor : usize,
     global_cursor : usize,
-    line_break: u8,
+    line_break: Delimiter,
     string_separator: u8,
-    field_separator: u8,
+    field_separator: Delimiter,
     force_mem_cacher: bool,
-    fulliter : InRowIter<'mmap>,
+    base: usize,
+    header: Option<Arc<Header>>,
+    trim: Trim,
+    row: Row<'mmap>,
 }
 
 
@@ -21,12 +30,14 @@ impl<'mmap> RowParallel<'mmap>{
         full : &'mmap[u8],
         slice: &'mmap[u8],
         cursor : usize,
-        line_break: u8,
-        field_separator: u8,
+        line_break: impl Into<Delimiter>,
+        field_separator: impl Into<Delimiter>,
         string_separator: u8,
         force_mem_cacher: bool,
     )-> Self{
-        let fulliter = InRowIter::new(full,line_break,string_separator);
+        let line_break = line_break.into();
+        let field_separator = field_separator.into();
+        let row = Row::new(slice, field_separator, string_separator, force_mem_cacher);
         Self{
             full,
             slice,
@@ -36,12 +47,61 @@ impl<'mmap> RowParallel<'mmap>{
             string_separator,
             field_separator,
             force_mem_cacher,
-            fulliter
+            base: 0,
+            header: None,
+            trim: Trim::None,
+            row,
         }
     }
 
+    /// ## With Header
+    /// - Attaches the header of the file, enabling the by-name lookups.
+    pub fn with_header(mut self, header: Option<Arc<Header>>) -> Self {
+        self.row = self.row.with_header(header.clone());
+        self.header = header;
+        self
+    }
+
+    /// ## With Trim
+    /// - Sets the trim policy applied to the fields of the row, and of the peeked rows (outside quotes only).
+    pub fn with_trim(mut self, trim: Trim) -> Self {
+        self.row = self.row.with_trim(trim);
+        self.trim = trim;
+        self
+    }
+
+    /// ## With Position
+    /// - Sets the offset of the slice in the file (`base`), the start of the row in the slice, and the record
+    ///   number of the row (`0` if unknown).
+    pub(crate) fn with_position(mut self, base: usize, start: usize, record: usize) -> Self {
+        self.base = base;
+        self.row = self.row.with_offset(base + start).with_record(record);
+        self
+    }
+
+    /// ## Get Header
+    /// - Gets the header attached to the row, if any.
+    pub fn get_header(&self) -> Option<&Header> {
+        self.header.as_deref()
+    }
+
+    /// ## Get Record Number
+    /// - Gets the record number of the row in the file (1 based, header included).
+    /// - `0` if unknown: set `ParallelConfig::with_origin` to count the records.
+    pub fn get_record_number(&self) -> usize {
+        self.row.get_record_number()
+    }
+
+    /// ## Get Byte Range
+    /// - Gets the position of the row in the file, without its line break.
+    /// - The positions are relative to the slice, plus the offset set with `ParallelConfig::with_origin`.
+    pub fn get_byte_range(&self) -> Range<usize> {
+        self.row.get_byte_range()
+    }
+
     /// ## Get As Row
     /// - Retrives the slice of u8, as Row struct
+    /// - Builds a new row on each call. Use `as_row` to keep the field offsets cached between calls.
     pub fn get_row(&self) -> Row<'mmap>{
         Row::new(
             self.slice,
@@ -49,27 +109,68 @@ impl<'mmap> RowParallel<'mmap>{
             self.string_separator,
             self.force_mem_cacher,
         )
+        .with_header(self.header.clone())
+        .with_trim(self.trim)
+        .with_offset(self.row.get_byte_range().start)
+        .with_record(self.row.get_record_number())
+    }
+
+    /// ## As Row
+    /// - Gets the row, built once, so the field offsets are cached between calls.
+    pub fn as_row(&self) -> &Row<'mmap>{
+        &self.row
+    }
+
+    /// ## Get Field by Name
+    /// - Resolves the column name with the header, and returns the field of the current row.
+    /// - If there is no header, or the name is not found, returns an empty field.
+    pub fn get_by_name(&self, name: &str) -> Field<'mmap> {
+        self.row.get_by_name(name)
+    }
+
+    /// ## Get Field by Column
+    /// - Returns the field of the current row, for a `Column` resolved with `Header::column`.
+    pub fn get_column(&self, column: Column) -> Field<'mmap> {
+        self.row.get_column(column)
     }
 
 
     /// ## Peek Next Row
     /// - Check the newxt row.
     /// - If not return an empty row (checkit whit is empty func of row)
+    /// - The parallel functions give the whole slice to the rows, so the next row can belong to another chunk. Only
+    ///   the last row of the slice peeks an empty row.
     pub fn peek_next(&mut self) -> Row<'mmap>
     {
-        let curs= self.fulliter.get_cursor();
-        if let Some(next) = self.fulliter.next() {
-            self.fulliter.set_cursor(curs);
-            Row::new(
-                next,
-                self.field_separator,
-                self.string_separator,
-                self.force_mem_cacher,
-            )
-        }else {
-            self.fulliter.set_cursor(curs);
-            Row::new_empty()
+        let start = self.global_cursor;
+        if start >= self.full.len() {
+            return Row::new_empty();
         }
+        let (end, _) = find_record_end(self.full, start, self.line_break, self.string_separator, false);
+        let record = self.row.get_record_number();
+        Row::new(
+            &self.full[start..end],
+            self.field_separator,
+            self.string_separator,
+            self.force_mem_cacher,
+        )
+        .with_header(self.header.clone())
+        .with_trim(self.trim)
+        .with_offset(self.base + start)
+        .with_record(if record == 0 { 0 } else { record + 1 })
+    }
+
+    /// ## With Offsets Buffer
+    /// - Gives a buffer to store the field offsets, reused between rows.
+    pub(crate) fn with_offsets_buffer(mut self, buffer: Vec<(usize, usize)>) -> Self {
+        self.row = self.row.with_offsets_buffer(buffer);
+        self
+    }
+
+    /// ## Take Offsets Buffer
+    /// - Takes the buffer of field offsets back, to reuse it in the next row.
+    pub(crate) fn take_offsets_buffer(&mut self) -> Vec<(usize, usize)> {
+        self.row.take_offsets_buffer()
     }
 
     #[allow(dead_code)]
@@ -83,7 +184,6 @@ impl<'mmap> RowParallel<'mmap>{
     #[allow(dead_code)]
     pub(crate) fn set_global_cursor(&mut self, cursor: usize){
         self.global_cursor = cursor;
-        self.fulliter.set_cursor(cursor);
     }
     #[allow(dead_code)]
     pub(crate) fn get_global_cursor(&self) -> usize{
@@ -95,7 +195,8 @@ impl<'mmap> RowParallel<'mmap>{
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::worker::execute_task_in_thread;
+    use crate::models::editable_persist::EditablePersist;
+    use crate::models::worker::{execute_task_in_thread, execute_task_with_param_thread};
     use std::sync::{Arc, Mutex};
 
     #[test]
@@ -140,6 +241,19 @@ mod tests {
         let final_result = results.lock().unwrap();
         assert_eq!(final_result.len(), 3);
     }
+
+    #[test]
+    fn test_execute_task_with_param_thread() {
+        let data = b"1,a\n2,b\n3,c\n";
+        let results: Arc<Mutex<Vec<i64>>> = Arc::new(Mutex::new(Vec::new()));
+        let closure = |row: &mut RowParallel, _thread_id: usize, param: Arc<Mutex<Vec<i64>>>, sum: &mut EditablePersist<i64>| {
+            *sum.get_mut() += row.as_row().get_index(0).get_i64();
+            param.lock().unwrap().push(*sum.get());
+        };
+        execute_task_with_param_thread(data, b'\n', b',', 0u8, false, closure, results.clone(), EditablePersist::new(10), 0);
+        // The thread state is a clone, kept between rows
+        assert_eq!(*results.lock().unwrap(), vec![11, 13, 16]);
+    }
 }
 
 